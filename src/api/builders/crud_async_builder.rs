@@ -1,10 +1,716 @@
 //! Defines our main API (using the Builder Pattern) & related internal model
 //! to allow async CRUD algorithms analysis.
-//! 
+//!
 //! CRUD algorithms -- to create, read, update and delete data -- must be analysed with
 //! different strategies: "Regular Algorithm Analysis" for the read and update operations
 //! and "Dynamic Algorithm Analysis" for create and delete operations.
-//! 
-//! Please refer to [super::dynamic_async_builder] and [super::crud_async_builder] for more info.
+//!
+//! Please refer to [super::dynamic_async_builder] and [super::regular_async_builder] for more info.\
+//! This is the async counterpart of [crate::test_crud_algorithms()] -- for databases, cloud storage APIs, distributed caches
+//! and other inherently async containers.
+//!
+//! Requires the `tokio` feature -- see [super::regular_async_builder].
 
-// TODO
\ No newline at end of file
+use std::fmt::Debug;
+use std::future::Future;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use keen_retry::{loggable_retry_errors, ResolvedResult, RetryResult};
+use crate::api::types::AlgoIteratorAsyncFn;
+use crate::{features::OUTPUT_ERROR, low_level_analysis, BigOAlgorithmComplexity, ComplexityBoundPair, GithubActionsReporter, Reporter, ALLOC, OUTPUT};
+use crate::low_level_analysis::types::{BigOAlgorithmAnalysis, BigOIteratorAlgorithmType, BigOPassMeasurements, BigOSpaceMeasurements, BigOTimeMeasurements,
+                                       ConstantSetIteratorAlgorithmMeasurements, ConstantSetIteratorAlgorithmPassesInfo,
+                                       SetResizingIteratorAlgorithmMeasurements, SetResizingIteratorAlgorithmPassesInfo};
+use crate::runners::common::{in_async_pass, run_async_iterator_pass_timing_iterations, IterationOrder, PassAbort, PassResult, PassTimeout, timer_resolution_note};
+use crate::runners::crud::{crud_op_index, CrudOp, CrudOperationVerdict, CrudVerdict, CRUD_OPS};
+use crate::runners::record::{ComplexityAssertion, ComplexityAssertionRecord};
+#[cfg(feature = "baseline")]
+use crate::runners::baseline::ComplexityBaseline;
+
+/// Analyses the complexities of async Create, Read, Update & Delete operations -- usually from a database or remote container:
+/// ```nocompile
+///     CrudAsyncAnalyzerBuilder::new("MyAsyncContainer")
+///         .with_reset_fn(|_n| async { container.clear().await; 0 })
+///         .create_async(1000, |n| async move { container.insert(n).await })
+///         .read_async(1000,   |n| async move { container.get(n).await })
+///         .with_expected_complexities(CrudOp::Read, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1)
+///         .test_algorithms().await;
+/// ```
+/// Just like in [crate::test_crud_algorithms()], 2 passes of creates, reads & updates are run -- the second one over a set twice
+/// as big -- followed by 2 passes of deletes, tearing the set down.
+pub struct CrudAsyncAnalyzerBuilder {
+
+    test_name: String,
+
+    max_reattempts: Option<u32>,
+    /// see [Self::with_warmup_percentage()]
+    warmup_percentage: u32,
+    reset_fn: Option<AlgoIteratorAsyncFn>,
+
+    /// the (iterations per pass, algorithm) of each operation -- indexed by [crud_op_index()]
+    operations: [Option<(u32, AlgoIteratorAsyncFn)>; 4],
+    /// the expected (time, space) complexities of each operation -- indexed by [crud_op_index()]
+    expected_complexities: [(ComplexityBoundPair, BigOAlgorithmComplexity); 4],
+    /// see [Self::with_max_time_coefficient()] -- indexed by [crud_op_index()]
+    max_time_coefficients: [Option<Duration>; 4],
+    /// see [Self::with_timeout()]
+    timeout: Option<Duration>,
+    /// see [Self::with_per_iteration_timings()]
+    per_iteration_timings: bool,
+    /// see [Self::with_reporter()]
+    reporter: Option<Box<dyn Reporter>>,
+    /// the number of the attempt being run -- starting at 1
+    attempt: u32,
+}
+
+impl CrudAsyncAnalyzerBuilder {
+
+    /// Creates the builder for the `test_name` analysis.\
+    /// When running in GitHub Actions, the analysis is reported through a [GithubActionsReporter], annotating the caller's file
+    #[track_caller]
+    pub fn new(test_name: impl Into<String>) -> Self {
+        let no_expectations = (ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential), BigOAlgorithmComplexity::WorseThanExponential);
+        Self {
+            test_name: test_name.into(),
+            max_reattempts: None,
+            warmup_percentage: 0,
+            reset_fn: None,
+            operations: [None, None, None, None],
+            expected_complexities: [no_expectations; 4],
+            max_time_coefficients: [None; 4],
+            timeout: None,
+            per_iteration_timings: false,
+            reporter: GithubActionsReporter::detect().map(|reporter| Box::new(reporter) as Box<dyn Reporter>),
+            attempt: 0,
+        }
+    }
+
+    /// Reports the verdict of each operation, on each attempt, to `reporter` -- replacing the one automatically detected (see [Self::new()])
+    pub fn with_reporter(mut self, reporter: impl Reporter + 'static) -> Self {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
+    /// Max reattempts -- only mismatched (or anomalous) TIME complexities are retried, as space measurements are deterministic
+    pub fn with_max_reattempts(mut self, attempts: u32) -> Self {
+        self.max_reattempts = Some(attempts);
+        self
+    }
+
+    /// [0..100]: if > 0, causes a warmup pass -- over this percentage of each operation's elements -- to be executed before the
+    /// analysed passes, to hot load caches, establish connections and the like. `reset_fn` must then be provided.
+    pub fn with_warmup_percentage(mut self, warmup_percentage: u32) -> Self {
+        assert!(warmup_percentage <= 100, "'{}': the warmup percentage must be in [0..100] -- not {}", self.test_name, warmup_percentage);
+        self.warmup_percentage = warmup_percentage;
+        self
+    }
+
+    /// The `reset_fn(n)` is executed after the warmup pass & before any reattempts -- receiving the number of elements created so far --
+    /// and must restore the empty state of the container, deallocating any memory the previous operations allocated
+    pub fn with_reset_fn<Fut: Future<Output=u32> + Send + 'static>
+                        (mut self,
+                         reset_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                        -> Self {
+        self.reset_fn.replace(Box::new(move |n| Box::pin(reset_fn(n))));
+        self
+    }
+
+    /// Analyses the async `create_fn(n)` -- inserting the element `n` -- running it `iterations_per_pass` times per pass
+    pub fn create_async<Fut: Future<Output=u32> + Send + 'static>
+                       (self,
+                        iterations_per_pass: u32,
+                        create_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                       -> Self {
+        self.with_operation(CrudOp::Create, iterations_per_pass, create_fn)
+    }
+
+    /// Analyses the async `read_fn(n)` -- reading the element `n`, among the ones created -- running it `iterations_per_pass` times per pass
+    pub fn read_async<Fut: Future<Output=u32> + Send + 'static>
+                     (self,
+                      iterations_per_pass: u32,
+                      read_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                     -> Self {
+        self.with_operation(CrudOp::Read, iterations_per_pass, read_fn)
+    }
+
+    /// Analyses the async `update_fn(n)` -- updating the element `n`, among the ones created -- running it `iterations_per_pass` times per pass
+    pub fn update_async<Fut: Future<Output=u32> + Send + 'static>
+                       (self,
+                        iterations_per_pass: u32,
+                        update_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                       -> Self {
+        self.with_operation(CrudOp::Update, iterations_per_pass, update_fn)
+    }
+
+    /// Analyses the async `delete_fn(n)` -- deleting the element `n`, in descending order -- running it `iterations_per_pass` times per pass
+    pub fn delete_async<Fut: Future<Output=u32> + Send + 'static>
+                       (self,
+                        iterations_per_pass: u32,
+                        delete_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                       -> Self {
+        self.with_operation(CrudOp::Delete, iterations_per_pass, delete_fn)
+    }
+
+    /// The complexities `crud_op` is expected to match: the time accepts either a maximum -- a [BigOAlgorithmComplexity] -- or a
+    /// `(lower, upper)` tuple / [ComplexityBoundPair]; the space, a maximum. Operations without expectations are only reported.
+    pub fn with_expected_complexities(mut self,
+                                      crud_op: CrudOp,
+                                      expected_time_complexity: impl Into<ComplexityBoundPair>,
+                                      expected_space_complexity: BigOAlgorithmComplexity)
+                                     -> Self {
+        self.expected_complexities[crud_op_index(crud_op)] = (expected_time_complexity.into(), expected_space_complexity);
+        self
+    }
+
+    /// Takes the expected complexities of each operation from `baseline` -- looked up as `"<test_name>: <operation>"`, like
+    /// `"MyContainer: Read"` -- replacing the ones set so far. Complexities missing from the baseline keep the ones given to
+    /// [Self::with_expected_complexities()] or, if none were, go unchecked.\
+    /// Requires the `baseline` feature.
+    #[cfg(feature = "baseline")]
+    pub fn with_baseline(mut self, baseline: &ComplexityBaseline) -> Self {
+        for crud_op in CRUD_OPS {
+            let (expected_time_complexity, expected_space_complexity) = &mut self.expected_complexities[crud_op_index(crud_op)];
+            let algorithm = crud_op.algorithm_name(&self.test_name);
+            *expected_time_complexity = baseline.time_complexity_or(&algorithm, *expected_time_complexity);
+            *expected_space_complexity = baseline.space_complexity_or(&algorithm, *expected_space_complexity);
+        }
+        self
+    }
+
+    /// Fails the analysis of `crud_op` if its estimated time coefficient -- the time taken per unit of its complexity's model term:
+    /// per element, for `O(n)` -- exceeds `max_time_coefficient`, even if the complexity class matches. Retried like time mismatches.\
+    /// See [crate::CrudOptions::with_max_time_coefficient()]
+    pub fn with_max_time_coefficient(mut self, crud_op: CrudOp, max_time_coefficient: Duration) -> Self {
+        self.max_time_coefficients[crud_op_index(crud_op)] = Some(max_time_coefficient);
+        self
+    }
+
+    /// Aborts the analysis -- with a fatal error telling the operation & how many of its iterations were completed -- if any of its
+    /// passes, warmups included, takes longer than `timeout`: so a hanging (or pathologically slow) operation fails fast.
+    /// The timeout is best-effort: it is only noticed when the operation's future yields. See [crate::CrudOptions::with_timeout()]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// If `true`, records how long each call of the analysed passes took -- returned by [Self::test_algorithms_with_timings()] through
+    /// [CrudAsyncAnalysis::iteration_timings()], for distribution analysis (percentiles, outliers, ...) of the operations.\
+    /// Timing every call perturbs the time measurements -- more so for the fastest operations -- so this is not meant to be
+    /// used for complexity classification: leave it off when only the complexities matter.
+    pub fn with_per_iteration_timings(mut self, per_iteration_timings: bool) -> Self {
+        self.per_iteration_timings = per_iteration_timings;
+        self
+    }
+
+    /// Runs the analysis, panicking if any of the operations doesn't match its expected complexities -- after retrying up to
+    /// [Self::with_max_reattempts()] times, if it is the time complexity that mismatched
+    pub async fn test_algorithms(self) {
+        self.test_algorithms_with_timings().await;
+    }
+
+    /// Same as [Self::test_algorithms()], but returning the observed complexities -- along with the per-call timings, if
+    /// [Self::with_per_iteration_timings()] was enabled
+    pub async fn test_algorithms_with_timings(self) -> CrudAsyncAnalysis {
+        assert!(self.operations.iter().any(Option::is_some), "'{}': no CRUD operations to analyse", self.test_name);
+        assert!(self.warmup_percentage == 0 || self.reset_fn.is_some(), "'{}': warming up requires a `reset_fn`", self.test_name);
+        assert!(self.iterations_per_pass(CrudOp::Create) > 0 || (self.iterations_per_pass(CrudOp::Read) == 0 && self.iterations_per_pass(CrudOp::Update) == 0),
+                "'{}': the 'Read' & 'Update' operations need a set to operate on, but there are no 'Create' passes to build it", self.test_name);
+        let max_attempts = self.max_reattempts.unwrap_or(0);
+        let result = self.raw_analyse_algorithms().await
+            .retry_with_async(|moved_self| async move {
+                OUTPUT("retrying...\n");
+                moved_self.reset(2 * moved_self.iterations_per_pass(CrudOp::Create)).await;
+                moved_self.raw_analyse_algorithms().await
+            })
+            .with_delays((0..max_attempts).map(|_| Duration::from_secs(1)))
+            .await;
+        let failure_msg = match result {
+            ResolvedResult::Ok { output, .. } => return output,
+            ResolvedResult::Fatal { error, .. } => error,
+            ResolvedResult::Recovered { output, .. } => return output,
+            ResolvedResult::GivenUp { retry_errors, fatal_error, .. } => format!("Given up with '{}' after {max_attempts} attempts. Previous transient errors: {}", fatal_error, loggable_retry_errors(&retry_errors)),
+            ResolvedResult::Unrecoverable { retry_errors, fatal_error, .. } => format!("Stopped after retrying for {max_attempts} attempts due to the fatal outcome '{}'. Previous transient errors: {}", fatal_error, loggable_retry_errors(&retry_errors)),
+        };
+        panic!("{}", failure_msg);
+    }
+
+    /// Runs the analysis once, yielding the observed complexities -- and iteration timings -- of the analysed operations
+    async fn raw_analyse_algorithms(mut self) -> RetryResult<Self, Self, CrudAsyncAnalysis, String> {
+        const NUMBER_OF_PASSES: u32 = 2;
+        self.attempt += 1;
+        let create_iterations_per_pass = self.iterations_per_pass(CrudOp::Create);
+        let delete_iterations_per_pass = self.iterations_per_pass(CrudOp::Delete);
+
+        // accumulation of the results of the operations -- to avoid any call cancellation optimizations when running in release mode
+        let mut r: u32 = 0;
+
+        OUTPUT(&format!("{} async CRUD Algorithm Complexity Analysis:\n  ", self.test_name));
+
+        // warmup
+        if self.warmup_percentage > 0 {
+            let warmup_start = Instant::now();
+            OUTPUT("warming up [");
+            for crud_op in CRUD_OPS {
+                let iterations_per_pass = self.iterations_per_pass(crud_op);
+                if iterations_per_pass > 0 {
+                    OUTPUT(&crud_op.name()[..1]);
+                    let (_warmup_result, _warmup_timings, warmup_r) = match self.run_pass(crud_op, 0 .. iterations_per_pass * self.warmup_percentage / 100).await {
+                        Ok(pass_outcome) => pass_outcome,
+                        Err(pass_abort) => return self.aborted_pass_result(crud_op, pass_abort),
+                    };
+                    r ^= warmup_r;
+                }
+            }
+            OUTPUT("] ");
+            self.reset(create_iterations_per_pass * self.warmup_percentage / 100).await;
+            OUTPUT(&format!("{:?}, ", warmup_start.elapsed()));
+        }
+
+        // create, read & update passes
+        let mut passes_results = [[PassResult::default(); NUMBER_OF_PASSES as usize]; 4];
+        let mut iteration_timings: [[Vec<Duration>; NUMBER_OF_PASSES as usize]; 4] = Default::default();
+        let constant_set_ops = [CrudOp::Create, CrudOp::Read, CrudOp::Update].into_iter()
+            .filter(|&crud_op| self.iterations_per_pass(crud_op) > 0)
+            .collect::<Vec<_>>();
+        for pass_number in 0..NUMBER_OF_PASSES {
+            OUTPUT(if pass_number == 0 {"First Pass ("} else {"); Second Pass ("});
+            for (i, &crud_op) in constant_set_ops.iter().enumerate() {
+                let iterations_per_pass = self.iterations_per_pass(crud_op);
+                // creates grow the set up to this size, while reads & updates run over it
+                let set_size = u64::from(create_iterations_per_pass) * u64::from(pass_number+1);
+                let (pass_result, pass_timings, pass_r) = match in_async_pass(pass_number+1, set_size, self.run_pass(crud_op, pass_range(iterations_per_pass, pass_number))).await {
+                    Ok(pass_outcome) => pass_outcome,
+                    Err(pass_abort) => return self.aborted_pass_result(crud_op, pass_abort),
+                };
+                OUTPUT(&format!("{}: {:?}{}/{}{}", crud_op.name().to_ascii_lowercase(), pass_result.time_measurements, timer_resolution_note(pass_result.time_measurements),
+                                pass_result.space_measurements, if i < constant_set_ops.len()-1 {", "} else {""}));
+                passes_results[crud_op_index(crud_op)][pass_number as usize] = pass_result;
+                iteration_timings[crud_op_index(crud_op)][pass_number as usize] = pass_timings;
+                r ^= pass_r;
+            }
+        }
+        OUTPUT("):\n\n");
+
+        let mut verdict = CrudVerdict { create: None, read: None, update: None, delete: None };
+        for crud_op in constant_set_ops {
+            let iterations_per_pass = self.iterations_per_pass(crud_op);
+            let passes_results = &passes_results[crud_op_index(crud_op)];
+            let operation_verdict = if crud_op == CrudOp::Create {
+                let measurements = set_resizing_measurements("Create", iterations_per_pass, passes_results);
+                let  time_complexity = low_level_analysis::time_analysis::  analyse_time_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
+                let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
+                self.check_analysis(crud_op, measurements, time_complexity, space_complexity)
+            } else {
+                let measurements = constant_set_measurements(crud_op.name(), create_iterations_per_pass, iterations_per_pass, passes_results);
+                let  time_complexity = low_level_analysis::time_analysis::  analyse_time_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
+                let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
+                self.check_analysis(crud_op, measurements, time_complexity, space_complexity)
+            };
+            match operation_verdict {
+                Ok(operation_verdict) => *verdict_of(&mut verdict, crud_op) = Some(operation_verdict),
+                Err(mismatch) => return mismatch.into_retry_result(self),
+            }
+        }
+
+        // delete passes (passes are applied in reverse order)
+        if delete_iterations_per_pass > 0 {
+            OUTPUT("Delete Passes (");
+            for pass_number in (0..NUMBER_OF_PASSES).rev() {
+                let set_size = u64::from(delete_iterations_per_pass) * u64::from(pass_number+1);
+                let (pass_result, pass_timings, pass_r) = match in_async_pass(pass_number+1, set_size, self.run_pass(CrudOp::Delete, pass_range(delete_iterations_per_pass, pass_number))).await {
+                    Ok(pass_outcome) => pass_outcome,
+                    Err(pass_abort) => return self.aborted_pass_result(CrudOp::Delete, pass_abort),
+                };
+                OUTPUT(&format!("{}{:?}{}/{}", if pass_number == NUMBER_OF_PASSES-1 {"2nd: "} else {"; 1st: "}, pass_result.time_measurements,
+                               timer_resolution_note(pass_result.time_measurements), pass_result.space_measurements));
+                passes_results[crud_op_index(CrudOp::Delete)][pass_number as usize] = pass_result;
+                iteration_timings[crud_op_index(CrudOp::Delete)][pass_number as usize] = pass_timings;
+                r ^= pass_r;
+            }
+            OUTPUT(&format!(") r={}:\n", r));
+            let measurements = set_resizing_measurements("Delete", delete_iterations_per_pass, &passes_results[crud_op_index(CrudOp::Delete)]);
+            let  time_complexity = low_level_analysis::time_analysis::  analyse_time_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
+            let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
+            match self.check_analysis(CrudOp::Delete, measurements, time_complexity, space_complexity) {
+                Ok(operation_verdict) => verdict.delete = Some(operation_verdict),
+                Err(mismatch) => return mismatch.into_retry_result(self),
+            }
+        }
+
+        RetryResult::Ok { reported_input: self, output: CrudAsyncAnalysis { verdict, iteration_timings } }
+    }
+
+    /// Outputs the analysis of `crud_op`, checking it against the expected complexities -- see [Self::with_expected_complexities()]
+    fn check_analysis<T: low_level_analysis::types::BigOAlgorithmMeasurements>
+                     (&self, crud_op: CrudOp, measurements: T, time_complexity: BigOAlgorithmComplexity, space_complexity: BigOAlgorithmComplexity)
+                     -> Result<CrudOperationVerdict, ComplexityMismatch> {
+        let (expected_time_complexity, expected_space_complexity) = self.expected_complexities[crud_op_index(crud_op)];
+        // anomalies only matter if there are time expectations to be checked
+        let time_anomaly = (expected_time_complexity != ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential))
+            .then(|| low_level_analysis::time_analysis::detect_time_anomaly(measurements.time_measurements()))
+            .flatten();
+        let analysis = BigOAlgorithmAnalysis {
+            time_complexity,
+            space_complexity,
+            time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient(time_complexity, &measurements),
+            is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&measurements),
+            algorithm_measurements: measurements,
+        };
+        OUTPUT(&format!("{}\n\n", analysis));
+        // untrustworthy measurements fail the analysis -- even if their complexities happened to match
+        ComplexityAssertionRecord::global().record(ComplexityAssertion::with_failure(crud_op.algorithm_name(&self.test_name), expected_time_complexity, time_complexity,
+                                                                                     expected_space_complexity, space_complexity,
+                                                                                     time_anomaly.map(|anomaly| format!("untrustworthy TIME measurements: {}", anomaly))));
+        let operation_name = crud_op.algorithm_name(&self.test_name);
+        if let Some(measurement_anomaly) = time_anomaly {
+            let msg = format!(" ** Aborted due to a TIME measurement anomaly on '{:?}' operation: {} (measured: {:?}) -- a reattempt may be performed...\n\n", crud_op, measurement_anomaly, time_complexity);
+            OUTPUT_ERROR(&msg);
+            self.report_mismatch(&operation_name, &expected_time_complexity, time_complexity, self.retrying());
+            Err(ComplexityMismatch::Time(msg))
+        } else if !expected_time_complexity.contains(time_complexity) {
+            let msg = format!(" ** TIME complexity mismatch on '{:?}' operation: {}, measured: {:?} -- a reattempt may be performed...\n\n", crud_op, expected_time_complexity, time_complexity);
+            OUTPUT_ERROR(&msg);
+            self.report_mismatch(&operation_name, &expected_time_complexity, time_complexity, self.retrying());
+            Err(ComplexityMismatch::Time(msg))
+        } else if !space_complexity.is_at_most(expected_space_complexity) {
+            let msg = format!(" ** Aborted due to SPACE complexity mismatch on '{:?}' operation: maximum: {:?}, measured: {:?}\n\n", crud_op, expected_space_complexity, space_complexity);
+            OUTPUT_ERROR(&msg);
+            self.report_mismatch(&operation_name, &expected_space_complexity, space_complexity, false);
+            Err(ComplexityMismatch::Space(msg))
+        } else if let Some(max_time_coefficient) = self.max_time_coefficients[crud_op_index(crud_op)].filter(|&max_time_coefficient| analysis.exceeds_time_coefficient(max_time_coefficient)) {
+            let msg = format!(" ** TIME coefficient excess on '{:?}' operation: maximum: {:?}, measured: {} -- a reattempt may be performed...\n\n",
+                              crud_op, max_time_coefficient, analysis.fmt_time_coefficient().unwrap_or_default());
+            OUTPUT_ERROR(&msg);
+            if let Some(reporter) = &self.reporter {
+                // exceeding the ceiling implies a coefficient was estimated
+                let observed_time_coefficient = Duration::from_secs_f64(analysis.time_coefficient.unwrap_or_default());
+                reporter.report_coefficient_excess(&operation_name, max_time_coefficient, observed_time_coefficient, self.retrying());
+            }
+            Err(ComplexityMismatch::Time(msg))
+        } else {
+            if let Some(reporter) = &self.reporter {
+                reporter.report_match(&operation_name, time_complexity);
+            }
+            Ok(CrudOperationVerdict { time_complexity, space_complexity })
+        }
+    }
+
+    /// tells the reporter -- if any -- of a complexity mismatch of `operation_name`. See [Reporter::report_mismatch()]
+    fn report_mismatch(&self, operation_name: &str, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool) {
+        if let Some(reporter) = &self.reporter {
+            reporter.report_mismatch(operation_name, expected, observed, retrying);
+        }
+    }
+
+    /// if a reattempt will follow a time mismatch of the current attempt
+    fn retrying(&self) -> bool {
+        self.attempt <= self.max_reattempts.unwrap_or(0)
+    }
+
+    /// the outcome of an analysis whose `crud_op` pass was given up on -- exceeding [Self::with_timeout()]: fatal, as it would, most likely, time out again
+    fn aborted_pass_result(self, crud_op: CrudOp, pass_abort: PassAbort) -> RetryResult<Self, Self, CrudAsyncAnalysis, String> {
+        let msg = format!("\n ** Aborted as the '{:?}' operation's {}\n\n", crud_op, pass_abort);
+        OUTPUT_ERROR(&msg);
+        let (expected_time_complexity, expected_space_complexity) = self.expected_complexities[crud_op_index(crud_op)];
+        ComplexityAssertionRecord::global().record(ComplexityAssertion::aborted(crud_op.algorithm_name(&self.test_name), expected_time_complexity,
+                                                                                expected_space_complexity, pass_abort.to_string()));
+        RetryResult::Fatal { input: self, error: msg }
+    }
+
+    /// Runs a pass of `crud_op` over the elements in `range` -- descending, except for deletes, just like [crate::test_crud_algorithms()] -- giving up if it exceeds [Self::with_timeout()].\
+    /// The boxed futures' (de)allocations are not measured -- see [Self::with_operation()] -- and neither are the ones of the
+    /// per-call timings, empty unless [Self::with_per_iteration_timings()] is enabled: their storage is pre-allocated for the whole pass
+    async fn run_pass(&self, crud_op: CrudOp, range: Range<u32>) -> Result<(PassResult, Vec<Duration>, u32), PassAbort> {
+        let (_iterations_per_pass, algorithm_fn) = self.operations[crud_op_index(crud_op)].as_ref().expect("BUG! Running a pass of an operation that is not present");
+        let (algorithm_type, order) = match crud_op {
+            CrudOp::Read | CrudOp::Update => (BigOIteratorAlgorithmType::ConstantSet, IterationOrder::Descending),
+            CrudOp::Create                => (BigOIteratorAlgorithmType::SetResizing, IterationOrder::Descending),
+            _                             => (BigOIteratorAlgorithmType::SetResizing, IterationOrder::Ascending),
+        };
+        let iterations = range.len() as u32;
+        let completed_iterations = AtomicU32::new(0);
+        let mut iteration_timings = Vec::with_capacity(if self.per_iteration_timings {iterations as usize} else {0});
+        let iterator_algorithm = |e| {
+            let completed_iterations = &completed_iterations;
+            async move {
+                let mut algorithm_future = algorithm_fn(e);
+                let r = (&mut algorithm_future).await;
+                ALLOC.pause();
+                drop(algorithm_future);
+                ALLOC.resume();
+                completed_iterations.fetch_add(1, Ordering::Relaxed);
+                r
+            }
+        };
+        let pass = run_async_iterator_pass_timing_iterations(&iterator_algorithm, &algorithm_type, range, order,
+                                                             self.per_iteration_timings.then_some(&mut iteration_timings));
+        let (pass_result, r) = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, pass).await
+                .map_err(|_elapsed| PassAbort::TimedOut(PassTimeout { timeout, completed_iterations: completed_iterations.load(Ordering::Relaxed), iterations }))?,
+            None => pass.await,
+        };
+        Ok((pass_result, iteration_timings, r))
+    }
+
+    /// Calls `reset_fn`, if present, informing `n` elements were created
+    async fn reset(&self, n: u32) {
+        if let Some(reset_fn) = &self.reset_fn {
+            reset_fn(n).await;
+        }
+    }
+
+    /// the iterations per pass of `crud_op` -- 0 if it is not analysed
+    fn iterations_per_pass(&self, crud_op: CrudOp) -> u32 {
+        self.operations[crud_op_index(crud_op)].as_ref()
+            .map_or(0, |(iterations_per_pass, _algorithm_fn)| *iterations_per_pass)
+    }
+
+    /// Registers `algorithm_fn` as `crud_op`'s algorithm.\
+    /// Its futures are boxed with the allocator metrics paused -- and [Self::run_pass()] drops them likewise -- so the boxing
+    /// won't pollute the space analysis
+    fn with_operation<Fut: Future<Output=u32> + Send + 'static>
+                     (mut self,
+                      crud_op: CrudOp,
+                      iterations_per_pass: u32,
+                      algorithm_fn: impl Fn(u32) -> Fut + Sync + Send + 'static)
+                     -> Self {
+        self.operations[crud_op_index(crud_op)].replace((iterations_per_pass, Box::new(move |e| {
+            let algorithm_future = algorithm_fn(e);
+            ALLOC.pause();
+            let boxed_future = Box::pin(algorithm_future);
+            ALLOC.resume();
+            boxed_future
+        })));
+        self
+    }
+}
+
+/// The outcome of [CrudAsyncAnalyzerBuilder::test_algorithms_with_timings()]
+#[derive(Debug, PartialEq, Clone)]
+pub struct CrudAsyncAnalysis {
+    /// the observed complexities of the analysed operations
+    pub verdict: CrudVerdict,
+    /// the duration of each call of the analysed passes -- indexed by [crud_op_index()] & pass
+    iteration_timings: [[Vec<Duration>; 2]; 4],
+}
+impl CrudAsyncAnalysis {
+    /// How long each call of `crud_op`'s `pass_number` (0 or 1) took, in the order the elements were operated on -- empty if
+    /// `crud_op` wasn't analysed or if [CrudAsyncAnalyzerBuilder::with_per_iteration_timings()] wasn't enabled
+    pub fn iteration_timings(&self, crud_op: CrudOp, pass_number: usize) -> &[Duration] {
+        &self.iteration_timings[crud_op_index(crud_op)][pass_number]
+    }
+}
+
+/// Why an attempt of [CrudAsyncAnalyzerBuilder::test_algorithms()] failed -- carrying the message to report
+enum ComplexityMismatch {
+    /// may be retried, as time measurements are affected by the run-time environment conditions
+    Time(String),
+    /// never retried: space measurements are deterministic
+    Space(String),
+}
+impl ComplexityMismatch {
+    fn into_retry_result(self, builder: CrudAsyncAnalyzerBuilder) -> RetryResult<CrudAsyncAnalyzerBuilder, CrudAsyncAnalyzerBuilder, CrudAsyncAnalysis, String> {
+        match self {
+            ComplexityMismatch::Time(error)  => RetryResult::Transient { input: builder, error },
+            ComplexityMismatch::Space(error) => RetryResult::Fatal     { input: builder, error },
+        }
+    }
+}
+
+/// the verdict field for `crud_op`
+fn verdict_of(verdict: &mut CrudVerdict, crud_op: CrudOp) -> &mut Option<CrudOperationVerdict> {
+    match crud_op {
+        CrudOp::Create => &mut verdict.create,
+        CrudOp::Read   => &mut verdict.read,
+        CrudOp::Update => &mut verdict.update,
+        _              => &mut verdict.delete,
+    }
+}
+
+/// the elements operated on by pass `pass_number`
+fn pass_range(iterations_per_pass: u32, pass_number: u32) -> Range<u32> {
+    iterations_per_pass * pass_number .. iterations_per_pass * (pass_number + 1)
+}
+
+/// the measurements of a create or delete operation, out of its passes' results
+fn set_resizing_measurements(operation_name: &'static str, iterations_per_pass: u32, passes_results: &[PassResult; 2]) -> SetResizingIteratorAlgorithmMeasurements<'static> {
+    SetResizingIteratorAlgorithmMeasurements {
+        measurement_name: operation_name,
+        passes_info: SetResizingIteratorAlgorithmPassesInfo {
+            delta_set_size: u64::from(iterations_per_pass),
+        },
+        time_measurements: BigOTimeMeasurements {
+            pass_1_measurements: passes_results[0].time_measurements,
+            pass_2_measurements: passes_results[1].time_measurements,
+        },
+        space_measurements: BigOSpaceMeasurements {
+            pass_1_measurements: passes_results[0].space_measurements,
+            pass_2_measurements: passes_results[1].space_measurements,
+        },
+    }
+}
+
+/// the measurements of a read or update operation, out of its passes' results
+fn constant_set_measurements(operation_name: &'static str, create_iterations_per_pass: u32, iterations_per_pass: u32, passes_results: &[PassResult; 2]) -> ConstantSetIteratorAlgorithmMeasurements<'static> {
+    ConstantSetIteratorAlgorithmMeasurements {
+        measurement_name: operation_name,
+        passes_info: ConstantSetIteratorAlgorithmPassesInfo {
+            pass_1_set_size: u64::from(create_iterations_per_pass),
+            pass_2_set_size: u64::from(create_iterations_per_pass) * 2,
+            repetitions: u64::from(iterations_per_pass),
+        },
+        time_measurements: BigOTimeMeasurements {
+            pass_1_measurements: passes_results[0].time_measurements,
+            pass_2_measurements: passes_results[1].time_measurements,
+        },
+        space_measurements: BigOSpaceMeasurements {
+            pass_1_measurements: passes_results[0].space_measurements,
+            pass_2_measurements: passes_results[1].space_measurements,
+        },
+        pass1_measurements: BigOPassMeasurements {
+            time_measurements: passes_results[0].time_measurements,
+            space_measurements: passes_results[0].space_measurements,
+            custom_measurements: vec![],
+        },
+        pass2_measurements: BigOPassMeasurements {
+            time_measurements: passes_results[1].time_measurements,
+            space_measurements: passes_results[1].space_measurements,
+            custom_measurements: vec![],
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use super::*;
+
+    /// attests all the operations are run -- with the right elements -- and analysed, over an async "remote" vector
+    #[tokio::test]
+    async fn async_vec_crud() {
+        let iterations_per_pass = 10_000;
+        let store = Arc::new(Mutex::new(Vec::<u32>::with_capacity(3 * iterations_per_pass as usize)));
+        let (create_store, read_store, update_store, delete_store, reset_store) = (store.clone(), store.clone(), store.clone(), store.clone(), store.clone());
+        let result = CrudAsyncAnalyzerBuilder::new("async vec")
+            .with_warmup_percentage(10)
+            .with_reset_fn(move |_n| { let store = reset_store.clone(); async move { store.lock().unwrap().clear(); 0 } })
+            .create_async(iterations_per_pass, move |n| { let store = create_store.clone(); async move { store.lock().unwrap().push(n); n } })
+            .read_async(iterations_per_pass,   move |n| { let store = read_store.clone();   async move { store.lock().unwrap()[n as usize] } })
+            .update_async(iterations_per_pass, move |n| { let store = update_store.clone(); async move { store.lock().unwrap()[n as usize] = n; n } })
+            .delete_async(iterations_per_pass, move |_n| { let store = delete_store.clone(); async move { store.lock().unwrap().pop().expect("Deleting from an empty vec") } })
+            .raw_analyse_algorithms().await;
+        let RetryResult::Ok { output: CrudAsyncAnalysis { verdict, .. }, .. } = result else { panic!("Async CRUD analysis ended with non-ok status") };
+        assert!(verdict.create.is_some() && verdict.read.is_some() && verdict.update.is_some() && verdict.delete.is_some(), "All operations should have been analysed: {:?}", verdict);
+        assert!(store.lock().unwrap().is_empty(), "All created elements should have been deleted");
+    }
+
+    /// checks each analysed call has its timing recorded -- only when asked to
+    #[tokio::test]
+    async fn per_iteration_timings() {
+        let iterations_per_pass = 1000;
+        let analysis = CrudAsyncAnalyzerBuilder::new("timed async reads")
+            .with_per_iteration_timings(true)
+            .create_async(iterations_per_pass, |n| async move { n })
+            .read_async(iterations_per_pass / 2, |n| async move { tokio::task::yield_now().await; n })
+            .test_algorithms_with_timings().await;
+        for pass_number in 0..2 {
+            assert_eq!(analysis.iteration_timings(CrudOp::Create, pass_number).len(), iterations_per_pass as usize, "Wrong number of 'Create' timings on pass {}", pass_number);
+            assert_eq!(analysis.iteration_timings(CrudOp::Read,   pass_number).len(), iterations_per_pass as usize / 2, "Wrong number of 'Read' timings on pass {}", pass_number);
+            assert!(analysis.iteration_timings(CrudOp::Delete, pass_number).is_empty(), "Operations not analysed should have no timings");
+        }
+
+        let analysis = CrudAsyncAnalyzerBuilder::new("untimed async creates")
+            .create_async(iterations_per_pass, |n| async move { n })
+            .test_algorithms_with_timings().await;
+        assert!(analysis.iteration_timings(CrudOp::Create, 0).is_empty(), "Timings should only be recorded when enabled");
+        assert!(analysis.verdict.create.is_some(), "The 'Create' operation should have been analysed");
+    }
+
+    /// assures SPACE complexity mismatches are fatal -- not retried -- while TIME mismatches are
+    #[tokio::test]
+    async fn mismatches() {
+        let store = Arc::new(Mutex::new(Vec::<Vec<u8>>::new()));
+        let create_store = store.clone();
+        let result = CrudAsyncAnalyzerBuilder::new("space hungry creates")
+            .create_async(1000, move |n| { let store = create_store.clone(); async move { store.lock().unwrap().push(vec![0; 1024]); n } })
+            .with_expected_complexities(CrudOp::Create, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::BetterThanO1)
+            .raw_analyse_algorithms().await;
+        assert!(matches!(result, RetryResult::Fatal { .. }), "A space mismatch should be fatal");
+
+        let result = CrudAsyncAnalyzerBuilder::new("impossibly fast reads")
+            .create_async(1000, |n| async move { n })
+            .read_async(1000, |n| async move { n })
+            .with_expected_complexities(CrudOp::Read, ComplexityBoundPair::new(BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential),
+                                        BigOAlgorithmComplexity::WorseThanExponential)
+            .raw_analyse_algorithms().await;
+        assert!(matches!(result, RetryResult::Transient { .. }), "A time mismatch should be retried");
+    }
+
+    /// assures the baseline expectations are looked up by operation -- the missing ones falling back to the explicit expectations
+    #[cfg(feature = "baseline")]
+    #[tokio::test]
+    async fn baseline() {
+        // the time goes unchecked, so no time anomaly gets in the way of the space mismatch
+        let baseline = ComplexityBaseline::from_toml_str(r#"
+            ["baselined creates: Create"]
+            time  = "WorseThanExponential"
+            space = "BetterThanO1"
+        "#).expect("The baseline should have been parsed");
+        let store = Arc::new(Mutex::new(Vec::<Vec<u8>>::new()));
+        let create_store = store.clone();
+        let builder = CrudAsyncAnalyzerBuilder::new("baselined creates")
+            .create_async(1000, move |n| { let store = create_store.clone(); async move { store.lock().unwrap().push(vec![0; 1024]); n } })
+            .with_expected_complexities(CrudOp::Create, BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON)
+            .with_expected_complexities(CrudOp::Read, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1)
+            .with_baseline(&baseline);
+        let no_expectations = (ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential), BigOAlgorithmComplexity::WorseThanExponential);
+        assert_eq!(builder.expected_complexities, [(no_expectations.0, BigOAlgorithmComplexity::BetterThanO1),
+                                                   (BigOAlgorithmComplexity::O1.into(), BigOAlgorithmComplexity::O1),
+                                                   no_expectations, no_expectations],
+                   "Only the 'Create' operation is in the baseline");
+        let result = builder.raw_analyse_algorithms().await;
+        assert!(matches!(result, RetryResult::Fatal { .. }), "The baselined space should have been enforced");
+    }
+
+    /// assures a pass exceeding the timeout ends the analysis with a fatal error -- telling the operation & how far its pass got
+    #[tokio::test]
+    async fn pass_timeout() {
+        let result = CrudAsyncAnalyzerBuilder::new("hanging reads")
+            .create_async(100, |n| async move { n })
+            .read_async(100, |n| async move { tokio::time::sleep(Duration::from_millis(10)).await; n })
+            .with_timeout(Duration::from_millis(50))
+            .raw_analyse_algorithms().await;
+        let RetryResult::Fatal { error, .. } = result else { panic!("A timed out pass should be fatal") };
+        assert!(error.contains("'Read' operation's pass timed out after 50ms, having completed "), "Wrong error: '{}'", error);
+        assert!(error.contains(" of its 100 iterations"), "Wrong error: '{}'", error);
+    }
+    /// checks [CrudAsyncAnalyzerBuilder::with_reporter()]'s reporter is told of each operation's verdict -- and if a reattempt will follow
+    #[tokio::test]
+    async fn reporter() {
+        #[derive(Clone, Default)]
+        struct RecordingReporter(Arc<Mutex<Vec<String>>>);
+        impl Reporter for RecordingReporter {
+            fn report_match(&self, algorithm_name: &str, _observed: BigOAlgorithmComplexity) {
+                self.0.lock().unwrap().push(format!("{algorithm_name}: match"));
+            }
+            fn report_mismatch(&self, algorithm_name: &str, expected: &dyn Debug, _observed: BigOAlgorithmComplexity, retrying: bool) {
+                self.0.lock().unwrap().push(format!("{algorithm_name}: expected {expected:?}, retrying: {retrying}"));
+            }
+        }
+        let reporter = RecordingReporter::default();
+        let _ = CrudAsyncAnalyzerBuilder::new("reported")
+            .with_reporter(reporter.clone())
+            .with_max_reattempts(1)
+            .create_async(1000, |n| async move { n })
+            .read_async(1000, |n| async move { n })
+            .with_expected_complexities(CrudOp::Read, ComplexityBoundPair::new(BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential),
+                                        BigOAlgorithmComplexity::WorseThanExponential)
+            .raw_analyse_algorithms().await;
+        assert_eq!(*reporter.0.lock().unwrap(), vec![
+            "reported: Create: match".to_owned(),
+            "reported: Read: expected ComplexityBoundPair { lower: WorseThanExponential, upper: WorseThanExponential }, retrying: true".to_owned(),
+        ], "Wrong reports");
+    }
+}
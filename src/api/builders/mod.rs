@@ -1,2 +1,4 @@
 mod regular_async_builder;
-pub use regular_async_builder::*;
\ No newline at end of file
+pub use regular_async_builder::*;
+mod crud_async_builder;
+pub use crud_async_builder::*;
\ No newline at end of file
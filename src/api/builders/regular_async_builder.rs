@@ -5,16 +5,25 @@
 //! -- such as algorithms that transform or update the data.
 //!
 //! Alias, better defined in opposition to [super::dynamic_async_builder].
+//!
+//! Requires the `tokio` feature: pass timeouts, cancellations & retry delays are driven by `tokio::time` -- so the analysis
+//! must be awaited from within a tokio runtime.
 
 use std::fmt::Debug;
-use std::future::Future;
+use std::future::{Future, Ready};
 use std::time::Duration;
 use keen_retry::{loggable_retry_errors, ResolvedResult, RetryResult};
+use tokio_util::sync::CancellationToken;
 use crate::api::types::{AlgoAssertionAsyncFn, AlgoManipulationAsyncFn};
-use crate::{low_level_analysis, BigOAlgorithmComplexity, OUTPUT};
+use crate::{features::OUTPUT_ERROR, low_level_analysis, BigOAlgorithmComplexity, ComplexityBoundPair, GithubActionsReporter, Reporter, OUTPUT};
 use crate::low_level_analysis::types::{AlgorithmMeasurements, AlgorithmPassesInfo, BigOAlgorithmAnalysis, BigOPassMeasurements, BigOSpaceMeasurements, BigOTimeMeasurements};
-use crate::runners::common::run_async_pass_verbosely;
-use crate::utils::measurements::measurer::{measure_all_after_event, measure_all_before_event, CustomMeasurement, CustomMeasurer, CustomMeasurerExecutor};
+use crate::runners::common::{in_async_pass, pin_current_thread, run_async_pass_verbosely, run_repeated_async_pass_verbosely, AlgorithmPanic};
+use crate::runners::record::{ComplexityAssertion, ComplexityAssertionRecord};
+#[cfg(feature = "baseline")]
+use crate::runners::baseline::ComplexityBaseline;
+use crate::utils::measurements::measurer::{analyse_custom_measurement_complexity, CustomMeasurement, CustomMeasurer, CustomMeasurerExecutor, MeasurementOrientation};
+#[cfg(test)]
+use crate::utils::measurements::measurer::{measure_all_after_event, measure_all_before_event};
 use crate::utils::measurements::presentable_measurements::PresentableMeasurement;
 
 /// TODO: add docs from elsewhere
@@ -29,25 +38,44 @@ pub struct RegularAsyncAnalyzerBuilder<FirstPassFn:   FnMut(Option<AlgoDataType>
     reset_fn: Option<AlgoManipulationAsyncFn<AlgoDataType>>,
 
     max_reattempts: Option<u32>,
+    /// see [Self::with_cpu_affinity()]
+    cpu_affinity: Option<usize>,
+    /// see [Self::with_repetitions_per_measurement()]
+    repetitions_per_measurement: u32,
+    /// see [Self::with_cancellation_token()]
+    cancellation_token: Option<CancellationToken>,
+    /// see [Self::with_timeout()]
+    timeout: Option<Duration>,
     warmup_fn: Option<AlgoManipulationAsyncFn<AlgoDataType>>,
+    /// see [Self::with_reset_after_warmup()]
+    reset_after_warmup: bool,
 
-    first_pass_n: u32,
+    first_pass_n: u64,
     first_pass_fn: Option<FirstPassFn>,
+    /// the custom measurements of the previous attempt's first pass -- see [Self::add_custom_measurement_with_averages()]
     first_pass_measurements: Option<Vec<CustomMeasurement>>,
     first_pass_assertion_fn: Option<AlgoAssertionAsyncFn<AlgoDataType>>,
 
-    second_pass_n: u32,
+    second_pass_n: u64,
     second_pass_fn: Option<SecondPassFn>,
+    /// the custom measurements of the previous attempt's second pass -- see [Self::add_custom_measurement_with_averages()]
     second_pass_measurements: Option<Vec<CustomMeasurement>>,
     second_pass_assertion_fn: Option<AlgoAssertionAsyncFn<AlgoDataType>>,
 
-    expected_time_complexity: Option<BigOAlgorithmComplexity>,
+    expected_time_complexity: Option<ComplexityBoundPair>,
     expected_space_complexity: Option<BigOAlgorithmComplexity>,
+    /// see [Self::with_max_time_coefficient()]
+    max_time_coefficient: Option<Duration>,
     auxiliary_space_measurement: Option<BigOAlgorithmComplexity>,
 
     /// Measurements are done in a "delta" fashion.
     /// For details, see [Self::add_custom_measurement()].
     custom_measurers: Vec<Box<dyn CustomMeasurerExecutor<AlgoDataType>>>,
+
+    /// see [Self::with_reporter()]
+    reporter: Option<Box<dyn Reporter>>,
+    /// the number of the attempt being run -- starting at 1
+    attempt: u32,
 }
 
 
@@ -63,7 +91,7 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         let result = self.raw_analyse_algorithm(None).await
             .retry_with_async(|(moved_self, algo_data)| {
                 OUTPUT("retrying...\n");
-                moved_self.raw_analyse_algorithm(Some(algo_data))
+                moved_self.raw_analyse_algorithm(algo_data)
             })
             .with_delays((0..max_attempts).map(|_| Duration::from_secs(1)))
             .await;
@@ -79,8 +107,11 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         }
     }
 
-    async fn raw_analyse_algorithm(mut self, previous_attempt_algo_data: Option<AlgoDataType>) -> RetryResult<Self, (Self, AlgoDataType), AlgoDataType, String> {
+    /// Runs the analysis once -- a retry receives the previous attempt's `algo_data`, which is missing if one of the passes panicked
+    async fn raw_analyse_algorithm(mut self, previous_attempt_algo_data: Option<AlgoDataType>) -> RetryResult<Self, (Self, Option<AlgoDataType>), AlgoDataType, String> {
         OUTPUT(&format!("Running '{}' async algorithm:\n", self.test_name));
+        self.attempt += 1;
+        let _pinning = pin_current_thread(self.cpu_affinity);
 
         // first reset
         let algo_data = match &mut self.reset_fn {
@@ -95,8 +126,8 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         let algo_data = match &mut self.warmup_fn {
             Some(warmup_fn) => {
                 let (_warmup_pass_result, algo_data) = run_async_pass_verbosely("  Warming up: ", ";", algo_data, warmup_fn, OUTPUT).await;
-                // reset again
-                if let Some(reset_fn) = &mut self.reset_fn {
+                // reset again -- unless the warmup state should be kept, see [Self::with_reset_after_warmup()]
+                if let (Some(reset_fn), true) = (&mut self.reset_fn, self.reset_after_warmup) {
                     let (_reset_pass_result, algo_data) = run_async_pass_verbosely("  Resetting again: ", ";", None, reset_fn, OUTPUT).await;
                     Some(algo_data)     // return the "after second reset" data
                 } else {
@@ -108,13 +139,20 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
 
         // execute the 2 passes + any assertions
         ////////////////////////////////////////
-        // TODO: the custom measurements are missing from here -- see "test_run()" for more info
-        
+
         let first_pass_fn = self.first_pass_fn.as_mut().expect("BUG! First pass function not present");
-        let second_pass_fn = self.second_pass_fn.as_mut().expect("BUG! Second pass function not present");
 
         // pass 1
-        let (pass1_result, algo_data) = run_async_pass_verbosely("  Pass 1: ", ";", algo_data, first_pass_fn, OUTPUT).await;
+        let pass1_outcome = guard_pass(&self.cancellation_token, self.timeout,
+                                       in_async_pass(1, self.first_pass_n,
+                                                     run_repeated_async_pass_verbosely("  Pass 1: ", ";", algo_data, first_pass_fn, self.reset_fn.as_mut(), self.repetitions_per_measurement,
+                                                                                       &mut self.custom_measurers, OUTPUT))).await;
+        let (pass1_result, algo_data, pass1_custom_measurements) = match pass1_outcome {
+            Ok(Ok(pass1_outcome)) => pass1_outcome,
+            Ok(Err(algorithm_panic)) => return self.panicked_pass_result(1, algorithm_panic),
+            Err(pass_interruption) => return self.interrupted_pass_result(1, pass_interruption),
+        };
+        let pass1_custom_measurements = merge_previous_attempt(pass1_custom_measurements, &mut self.first_pass_measurements);
         // assertions on pass 1 data
         if let Some(ref mut first_pass_assertion_fn) = self.first_pass_assertion_fn {
             first_pass_assertion_fn(&algo_data).await;
@@ -130,7 +168,17 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         };
         
         // pass 2
-        let (pass2_result, algo_data) = run_async_pass_verbosely("  Pass 2: ", "", algo_data, second_pass_fn, OUTPUT).await;
+        let second_pass_fn = self.second_pass_fn.as_mut().expect("BUG! Second pass function not present");
+        let pass2_outcome = guard_pass(&self.cancellation_token, self.timeout,
+                                       in_async_pass(2, self.second_pass_n,
+                                                     run_repeated_async_pass_verbosely("  Pass 2: ", "", algo_data, second_pass_fn, self.reset_fn.as_mut(), self.repetitions_per_measurement,
+                                                                                       &mut self.custom_measurers, OUTPUT))).await;
+        let (pass2_result, algo_data, pass2_custom_measurements) = match pass2_outcome {
+            Ok(Ok(pass2_outcome)) => pass2_outcome,
+            Ok(Err(algorithm_panic)) => return self.panicked_pass_result(2, algorithm_panic),
+            Err(pass_interruption) => return self.interrupted_pass_result(2, pass_interruption),
+        };
+        let pass2_custom_measurements = merge_previous_attempt(pass2_custom_measurements, &mut self.second_pass_measurements);
         // assertions on pass 2 data
         if let Some(ref mut second_pass_assertion_fn) = self.second_pass_assertion_fn {
             second_pass_assertion_fn(&algo_data).await;
@@ -153,49 +201,145 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
             },
             pass1_measurements: BigOPassMeasurements {
                 time_measurements: pass1_result.time_measurements,
-                space_measurements: Default::default(),
-                custom_measurements: vec![],
+                space_measurements: pass1_result.space_measurements,
+                custom_measurements: pass1_custom_measurements,
             },
             pass2_measurements: BigOPassMeasurements {
-                time_measurements: Default::default(),
-                space_measurements: Default::default(),
-                custom_measurements: vec![],
+                time_measurements: pass2_result.time_measurements,
+                space_measurements: pass2_result.space_measurements,
+                custom_measurements: pass2_custom_measurements,
             },
         };
         let observed_time_complexity  = low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
         let observed_space_complexity = low_level_analysis::space_analysis::analyse_space_complexity(&measurements.passes_info, &measurements.space_measurements);
+        let observed_retained_space_complexity  = low_level_analysis::space_analysis::analyse_retained_space_complexity(&measurements.passes_info, &measurements.space_measurements);
+        let observed_auxiliary_space_complexity = low_level_analysis::space_analysis::analyse_auxiliary_space_complexity(&measurements.passes_info, &measurements.space_measurements);
         let algorithm_analysis = BigOAlgorithmAnalysis {
             time_complexity: observed_time_complexity,
             space_complexity: observed_space_complexity,
+            time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient(observed_time_complexity, &measurements),
+            is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&measurements),
             algorithm_measurements: measurements,
         };
 
         OUTPUT("\n\n");
         OUTPUT(&format!("{}\n", algorithm_analysis));
+        OUTPUT(&format!("Space breakdown: retained: {:?}; auxiliary: {:?}\n", observed_retained_space_complexity, observed_auxiliary_space_complexity));
+        for (pass, pass_measurements) in [(1, &algorithm_analysis.algorithm_measurements.pass1_measurements), (2, &algorithm_analysis.algorithm_measurements.pass2_measurements)] {
+            for custom_measurement in &pass_measurements.custom_measurements {
+                OUTPUT(&format!("Pass {} custom measurement: {}\n", pass, custom_measurement));
+            }
+        }
+        // complexities without expectations always pass
+        ComplexityAssertionRecord::global().record(ComplexityAssertion::new(self.test_name.as_str(),
+                                                                            self.expected_time_complexity.unwrap_or(ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential)), observed_time_complexity,
+                                                                            self.expected_space_complexity.unwrap_or(BigOAlgorithmComplexity::WorseThanExponential), observed_space_complexity));
 
         if let Some(expected_space_complexity) = self.expected_space_complexity {
-            if observed_space_complexity as u32 > expected_space_complexity as u32 {
+            if !observed_space_complexity.is_at_most(expected_space_complexity) {
                 let msg = format!("\n ** Aborted due to SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n",
                                          self.test_name, expected_space_complexity, observed_space_complexity);
-                OUTPUT(&msg);
-                return RetryResult::Fatal { input: (self, algo_data), error: msg }
+                OUTPUT_ERROR(&msg);
+                self.report_mismatch(&expected_space_complexity, observed_space_complexity, false);
+                return RetryResult::Fatal { input: (self, Some(algo_data)), error: msg }
+            }
+        }
+
+        if let Some(expected_auxiliary_space_complexity) = self.auxiliary_space_measurement {
+            if !observed_auxiliary_space_complexity.is_at_most(expected_auxiliary_space_complexity) {
+                let msg = format!("\n ** Aborted due to AUXILIARY SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n",
+                                         self.test_name, expected_auxiliary_space_complexity, observed_auxiliary_space_complexity);
+                OUTPUT_ERROR(&msg);
+                self.report_mismatch(&expected_auxiliary_space_complexity, observed_auxiliary_space_complexity, false);
+                return RetryResult::Fatal { input: (self, Some(algo_data)), error: msg }
             }
         }
 
         if let Some(expected_time_complexity) = self.expected_time_complexity {
-            if observed_time_complexity as u32 > expected_time_complexity as u32 {
-                let msg = format!("\n ** TIME complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?} -- a reattempt may be performed...\n\n",
+            if !expected_time_complexity.contains(observed_time_complexity) {
+                let msg = format!("\n ** TIME complexity mismatch on '{}' operation: {}, measured: {:?} -- a reattempt may be performed...\n\n",
                                          self.test_name, expected_time_complexity, observed_time_complexity);
-                OUTPUT(&msg);
-                return RetryResult::Transient { input: (self, algo_data), error: msg }
+                OUTPUT_ERROR(&msg);
+                self.report_mismatch(&expected_time_complexity, observed_time_complexity, self.retrying());
+                return RetryResult::Transient { input: (self, Some(algo_data)), error: msg }
+            }
+        }
+
+        if let Some(max_time_coefficient) = self.max_time_coefficient.filter(|&max_time_coefficient| algorithm_analysis.exceeds_time_coefficient(max_time_coefficient)) {
+            let msg = format!("\n ** TIME coefficient excess on '{}' operation: maximum: {:?}, measured: {} -- a reattempt may be performed...\n\n",
+                              self.test_name, max_time_coefficient, algorithm_analysis.fmt_time_coefficient().unwrap_or_default());
+            OUTPUT_ERROR(&msg);
+            if let Some(reporter) = &self.reporter {
+                // exceeding the ceiling implies a coefficient was estimated
+                let observed_time_coefficient = Duration::from_secs_f64(algorithm_analysis.time_coefficient.unwrap_or_default());
+                reporter.report_coefficient_excess(&self.test_name, max_time_coefficient, observed_time_coefficient, self.retrying());
+            }
+            return RetryResult::Transient { input: (self, Some(algo_data)), error: msg }
+        }
+
+        let algorithm_measurements = &algorithm_analysis.algorithm_measurements;
+        for (pass1_custom_measurement, pass2_custom_measurement) in algorithm_measurements.pass1_measurements.custom_measurements.iter()
+                                                                        .zip(algorithm_measurements.pass2_measurements.custom_measurements.iter()) {
+            let observed_complexity = analyse_custom_measurement_complexity(&algorithm_measurements.passes_info, pass1_custom_measurement, pass2_custom_measurement);
+            if !observed_complexity.is_at_most(pass2_custom_measurement.expected_complexity) {
+                let msg = format!("\n ** CUSTOM MEASUREMENT '{}' complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?} ({:?}) -- a reattempt may be performed...\n\n",
+                                  pass2_custom_measurement.name, self.test_name, pass2_custom_measurement.expected_complexity, observed_complexity, pass2_custom_measurement.orientation);
+                OUTPUT_ERROR(&msg);
+                self.report_mismatch(&pass2_custom_measurement.expected_complexity, observed_complexity, self.retrying());
+                return RetryResult::Transient { input: (self, Some(algo_data)), error: msg }
             }
         }
 
+        if let Some(reporter) = &self.reporter {
+            reporter.report_match(&self.test_name, observed_time_complexity);
+        }
         RetryResult::Ok { reported_input: self, output: algo_data }
 
     }
 
+    /// tells the reporter -- if any -- of a complexity mismatch. See [Reporter::report_mismatch()]
+    fn report_mismatch(&self, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool) {
+        if let Some(reporter) = &self.reporter {
+            reporter.report_mismatch(&self.test_name, expected, observed, retrying);
+        }
+    }
+
+    /// if a reattempt will follow a failure of the current attempt
+    fn retrying(&self) -> bool {
+        self.attempt <= self.max_reattempts.unwrap_or(0)
+    }
+
+    /// the outcome of an analysis whose `pass` panicked -- fatal, as it would panic again, telling which pass (and its `n`) did it
+    fn panicked_pass_result(self, pass: u32, algorithm_panic: AlgorithmPanic) -> RetryResult<Self, (Self, Option<AlgoDataType>), AlgoDataType, String> {
+        let n = if pass == 1 { self.first_pass_n } else { self.second_pass_n };
+        let msg = format!("\n ** Aborted as pass {} (n={}) of '{}' {}\n\n", pass, n, self.test_name, algorithm_panic);
+        OUTPUT_ERROR(&msg);
+        self.record_abort(format!("pass {} (n={}) {}", pass, n, algorithm_panic));
+        RetryResult::Fatal { input: (self, None), error: msg }
+    }
+
+    /// the outcome of an analysis whose `pass` was interrupted -- see [Self::with_cancellation_token()] & [Self::with_timeout()].
+    /// Fatal, with either the "Cancelled" or the "Pass N timed out after ..." error
+    fn interrupted_pass_result(self, pass: u32, pass_interruption: PassInterruption) -> RetryResult<Self, (Self, Option<AlgoDataType>), AlgoDataType, String> {
+        let error = match pass_interruption {
+            PassInterruption::Cancelled         => "Cancelled".to_owned(),
+            PassInterruption::TimedOut(timeout) => format!("Pass {} timed out after {:?}", pass, timeout),
+        };
+        OUTPUT_ERROR(&format!("\n ** Aborted pass {} of '{}': {}\n\n", pass, self.test_name, error));
+        self.record_abort(error.clone());
+        RetryResult::Fatal { input: (self, None), error }
+    }
+
+    /// reports the analysis as aborted -- for the given `reason` -- to the [ComplexityAssertionRecord::global()]
+    fn record_abort(&self, reason: String) {
+        ComplexityAssertionRecord::global().record(ComplexityAssertion::aborted(self.test_name.as_str(),
+                                                                                self.expected_time_complexity.unwrap_or(ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential)),
+                                                                                self.expected_space_complexity.unwrap_or(BigOAlgorithmComplexity::WorseThanExponential), reason));
+    }
+
+    #[cfg(test)]
     async fn test_run(mut self) {
+
         println!("## Wonderful!! We are ready to run.");
         println!("## This is the data we got:");
         println!("##   max_reattempts: {:?}", self.max_reattempts);
@@ -320,6 +464,9 @@ impl<FirstPassFn:   FnMut(Option<AlgoDataType>) -> FirstPassFut + Send + Sync,
      AlgoDataType:  Send + Sync + Debug + 'static>
 RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassFut, AlgoDataType> {
 
+    /// Creates the builder for the `test_name` analysis.\
+    /// When running in GitHub Actions, the analysis is reported through a [GithubActionsReporter], annotating the caller's file
+    #[track_caller]
     pub fn new(test_name: impl Into<String>) -> Self {
         Self {
 
@@ -328,7 +475,12 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
             reset_fn: None,
 
             warmup_fn: None,
+            reset_after_warmup: true,
             max_reattempts: None,
+            cpu_affinity: None,
+            repetitions_per_measurement: 1,
+            cancellation_token: None,
+            timeout: None,
 
             first_pass_n: 0,
             first_pass_fn: None,
@@ -342,20 +494,73 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
 
             expected_time_complexity: None,
             expected_space_complexity: None,
+            max_time_coefficient: None,
             auxiliary_space_measurement: None,
 
             custom_measurers: vec![],
+
+            reporter: GithubActionsReporter::detect().map(|reporter| Box::new(reporter) as Box<dyn Reporter>),
+            attempt: 0,
         }
     }
 
+    /// Reports the verdict of each attempt to `reporter` -- replacing the one automatically detected (see [Self::new()])
+    pub fn with_reporter(mut self, reporter: impl Reporter + 'static) -> Self {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
     /// Max reattempts
     pub fn with_max_reattempts(mut self, attempts: u32) -> Self {
         self.max_reattempts = Some(attempts);
         self
     }
 
+    /// Pins the thread running the analysis to the CPU core `core_id` -- so the OS won't migrate it between cores with different
+    /// frequencies or cache states, making the time measurements bounce. The thread's original affinity is restored after the analysis.\
+    /// Async passes only stay on the pinned thread on single-threaded runtimes -- like `#[tokio::test]`'s default one.
+    /// Requires the platform to support thread affinity: silently does nothing where it is unavailable -- and restoring the original
+    /// affinity is only supported on Linux. Requires the `core_pinning` feature.
+    #[cfg(feature = "core_pinning")]
+    pub fn with_cpu_affinity(mut self, core_id: usize) -> Self {
+        self.cpu_affinity = Some(core_id);
+        self
+    }
+
+    /// Runs each pass `repetitions` times, keeping the fastest -- for CPU-bound micro-benchmarks, the minimum time is the most
+    /// stable estimator, filtering out scheduler interference. Defaults to 1.\
+    /// This is orthogonal to the passes' `n` (the elements each pass processes) and to [Self::with_max_reattempts()] (retries of the
+    /// whole analysis). The `reset_fn`, if given, runs -- untimed -- in-between the repetitions, and each repetition has its own allocator
+    /// save point: the space reported is the one of the fastest repetition. Custom measurements are taken around each repetition, also
+    /// reporting the ones of the fastest.
+    pub fn with_repetitions_per_measurement(mut self, repetitions: u32) -> Self {
+        assert!(repetitions > 0, "'{}': at least 1 repetition per measurement is needed", self.test_name);
+        self.repetitions_per_measurement = repetitions;
+        self
+    }
+
+    /// Allows the analysis to be cancelled -- when a deadline is exceeded, for instance: if `cancellation_token` is cancelled while
+    /// a pass runs, the pass is dropped & the analysis ends with the fatal "Cancelled" error (making [Self::test_algorithm()] panic).\
+    /// Notice the pass is only interrupted when its future yields -- CPU-bound passes that never `.await` run to completion.
+    pub fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Guards against passes hanging forever -- like on a database connection that never returns: if a pass (with all its repetitions,
+    /// see [Self::with_repetitions_per_measurement()]) takes longer than `timeout`, it is dropped & the analysis ends with the
+    /// fatal "Pass N timed out after `timeout`" error. The timeout is best-effort: just like cancellations, timeouts are only noticed
+    /// when the pass' future yields.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// The optional `reset_fn` is executed before any of the passes ([Self::warmup_pass()], [Self::first_pass()], [Self::second_pass()])
-    /// and is intended as setting up (or cleaning) any data so the passes may be cleanly analysed.
+    /// and is intended as setting up (or cleaning) any data so the passes may be cleanly analysed.\
+    /// If the space is analysed -- [Self::with_space_measurements()] or [Self::with_auxiliary_space_measurements()] was given --
+    /// `reset_fn` must deallocate whatever the previous pass allocated, or the space measurements of the next pass are compromised.
+    /// Time-only analyses have no such requirement.
     pub fn with_reset_fn<Fut: Future<Output=AlgoDataType> + Send + 'static>
                         (mut self,
                          reset_fn: impl Fn(Option<AlgoDataType>) -> Fut + Sync + Send + 'static)
@@ -367,7 +572,8 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
     /// Run the given `warmup_fn` so that the measurements are more stable between [Self::first_pass()] and [Self::second_pass()].
     /// By running the algorithm with a smaller set of data, things like populating caches and establishing connections
     /// gets out of the way when measuring time and space.
-    /// Use it if your measurements are inconsistent between passes 1 and 2.
+    /// Use it if your measurements are inconsistent between passes 1 and 2.\
+    /// The warmup is followed by a `reset_fn` call -- if present -- unless [Self::with_reset_after_warmup()] says otherwise.
     pub fn warmup_pass<Fut: Future<Output=AlgoDataType> + Send + 'static>
                       (mut self,
                        mut warmup_fn: impl FnMut(Option<AlgoDataType>) -> Fut + Sync + Send + 'static)
@@ -376,11 +582,19 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         self
     }
 
+    /// If `false`, the [Self::warmup_pass()] isn't followed by a `reset_fn` call: its outcome is handed to [Self::first_pass()] as is
+    /// -- keeping the caches hot. Defaults to `true`.\
+    /// Only disable it for time-only analyses -- see [Self::with_reset_fn()] -- as the warmup allocations would be left around.
+    pub fn with_reset_after_warmup(mut self, reset_after_warmup: bool) -> Self {
+        self.reset_after_warmup = reset_after_warmup;
+        self
+    }
+
     /// Informs the Algorithms Analyser of the code to run on the "first pass".
     /// `first_pass_fn` must execute the same algorithm as [Self::second_pass()],
     /// but with a considerably lower `first_pass_n` -- ideally half.
     pub fn first_pass(mut self,
-                      first_pass_n: u32,
+                      first_pass_n: u64,
                       first_pass_fn: FirstPassFn)
                      -> Self {
         self.first_pass_n = first_pass_n;
@@ -404,7 +618,7 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
     /// `second_pass_fn` must execute the same algorithm as [Self::first_pass()],
     /// but with a considerably higher `second_pass_n` -- ideally the double.
     pub fn second_pass(mut self,
-                       second_pass_n: u32,
+                       second_pass_n: u64,
                        second_pass_fn: SecondPassFn)
                       -> Self {
         self.second_pass_n = second_pass_n;
@@ -425,56 +639,151 @@ RegularAsyncAnalyzerBuilder<FirstPassFn, FirstPassFut, SecondPassFn, SecondPassF
         self
     }
 
-    pub fn with_time_measurements(mut self, measure: BigOAlgorithmComplexity) -> Self {
-        self.expected_time_complexity = Some(measure);
+    /// Sets the acceptable time complexity -- either a maximum, a [BigOAlgorithmComplexity], or a `(lower, upper)` tuple / [ComplexityBoundPair],
+    /// also rejecting algorithms that perform better than expected. Measurements out of it cause a reattempt (see [Self::with_max_reattempts()])
+    pub fn with_time_measurements(mut self, measure: impl Into<ComplexityBoundPair>) -> Self {
+        self.expected_time_complexity = Some(measure.into());
         self
     }
 
+    /// Sets the maximum acceptable time coefficient -- the time taken per unit of the time complexity's model term: per element,
+    /// for `O(n)` -- so constant factor regressions fail the analysis even if the complexity class matches. A greater estimation
+    /// causes a reattempt, like time mismatches do. Not checked if no coefficient could be estimated -- see [BigOAlgorithmAnalysis::time_coefficient]
+    pub fn with_max_time_coefficient(mut self, max_time_coefficient: Duration) -> Self {
+        self.max_time_coefficient = Some(max_time_coefficient);
+        self
+    }
+
+    /// Sets the maximum acceptable space complexity, measured on the peak memory usage of each pass
+    /// -- encompassing both the retained and the auxiliary memory (see [Self::with_auxiliary_space_measurements()])
     pub fn with_space_measurements(mut self, measure: BigOAlgorithmComplexity) -> Self {
         self.expected_space_complexity = Some(measure);
         self
     }
 
+    /// Takes the expected time & space complexities from the `test_name` entry of `baseline` -- replacing the ones set so far.
+    /// Complexities missing from the baseline keep the ones previously set or, if none were, go unchecked.\
+    /// Requires the `baseline` feature.
+    #[cfg(feature = "baseline")]
+    pub fn with_baseline(mut self, baseline: &ComplexityBaseline) -> Self {
+        if let Some(expectation) = baseline.expectation(&self.test_name) {
+            self.expected_time_complexity = expectation.time_complexity.or(self.expected_time_complexity);
+            self.expected_space_complexity = expectation.space_complexity.or(self.expected_space_complexity);
+        }
+        self
+    }
+
+    /// Sets the maximum acceptable complexity for the *auxiliary space* -- the scratch memory allocated and then freed
+    /// during each pass (`max_used_memory - used_memory_after`), as opposed to the *retained space*, which is what is
+    /// still allocated when the pass finishes (`used_memory_after - used_memory_before`).\
+    /// Use it to assert, for instance, that a sort is done in place: its auxiliary space should be `O(1)` (or `O(log(n))`
+    /// for the recursion stack), even if the algorithm's outputs make the overall space (see [Self::with_space_measurements()]) grow.
     pub fn with_auxiliary_space_measurements(mut self, measure: BigOAlgorithmComplexity) -> Self {
         self.auxiliary_space_measurement = Some(measure);
         self
     }
 
+    /// Adds a custom measurement, taken on each pass: `before_event_measurer_fn` runs just before the pass and its output
+    /// is given to `after_event_measurer_fn`, which runs just after it, yielding the measured value for the pass
+    /// -- neither is accounted for in the time & space measurements.\
+    /// The growth of the values between passes is checked against `expected_complexity` -- retrying on mismatches, like the
+    /// time measurements -- as costs or, for throughput-like measurements, as yields: see [MeasurementOrientation].\
+    /// Only the values measured on the last attempt are reported. See [Self::add_custom_measurement_with_averages()] for
+    /// measurements that may get flaky.
     pub fn add_custom_measurement<BeforeMeasurerOutput:                              Send + 'static,
                                   BeforeFut: Future<Output=BeforeMeasurerOutput>   + Send + 'static,
                                   AfterFut:  Future<Output=PresentableMeasurement> + Send + 'static>
                                  (mut self,
                                   name: impl Into<String>,
                                   expected_complexity: BigOAlgorithmComplexity,
+                                  orientation: MeasurementOrientation,
                                   description: impl Into<String>,
                                   before_event_measurer_fn: impl FnMut(Option<&AlgoDataType>) -> BeforeFut + Send + Sync + 'static,
                                   after_event_measurer_fn:  impl FnMut(Option<&AlgoDataType>, BeforeMeasurerOutput) -> AfterFut + Send + Sync + 'static)
                                  -> Self {
-        let measurer_executor = Box::new(CustomMeasurer::new(name, expected_complexity, description, before_event_measurer_fn, after_event_measurer_fn));
+        let measurer_executor = Box::new(CustomMeasurer::new(name, expected_complexity, orientation, description, before_event_measurer_fn, after_event_measurer_fn));
         self.custom_measurers.push(measurer_executor);
         self
     }
 
+    /// Same as [Self::add_custom_measurement()], but the values measured on each pass are accumulated over the reattempts,
+    /// reporting their mean (and standard deviation) -- so flaky custom measurements stabilise across reattempts, just
+    /// like retrying stabilises the time measurements.
     pub fn add_custom_measurement_with_averages<BeforeMeasurerOutput:                              Send + 'static,
                                                 BeforeFut: Future<Output=BeforeMeasurerOutput>   + Send + 'static,
                                                 AfterFut:  Future<Output=PresentableMeasurement> + Send + 'static>
                                                (mut self,
                                                 name: &str,
                                                 expected_complexity: BigOAlgorithmComplexity,
+                                                orientation: MeasurementOrientation,
                                                 description: &str,
                                                 before_event_measurer_fn: impl FnMut(Option<&AlgoDataType>) -> BeforeFut + Send + Sync + 'static,
                                                 after_event_measurer_fn:  impl FnMut(Option<&AlgoDataType>, BeforeMeasurerOutput) -> AfterFut + Send + Sync + 'static)
                                                -> Self {
-        let measurer_executor = Box::new(CustomMeasurer::new(name, expected_complexity, description, before_event_measurer_fn, after_event_measurer_fn));
+        let measurer_executor = Box::new(CustomMeasurer::new_with_averages(name, expected_complexity, orientation, description, before_event_measurer_fn, after_event_measurer_fn));
         self.custom_measurers.push(measurer_executor);
         self
     }
 }
 
+/// Adapts the synchronous `sync_fn` to the async steps of the builders -- so CPU-bound steps needn't be wrapped in `async` blocks:
+/// ```nocompile
+///     RegularAsyncAnalyzerBuilder::new("MyAlgorithm")
+///         .with_reset_fn(sync_fn(|_previous_pass_data| vec![]))
+///         .first_pass(1000, sync_fn(|data| my_algorithm(data.unwrap(), 1000)))
+///         .second_pass(2000, |data| async move { my_async_algorithm(data.unwrap(), 2000).await })
+/// ```
+/// Works for [RegularAsyncAnalyzerBuilder::with_reset_fn()], [RegularAsyncAnalyzerBuilder::warmup_pass()], the passes and the
+/// [crate::CrudAsyncAnalyzerBuilder] operations. The returned futures are ready right away: `sync_fn` runs when they are created.
+pub fn sync_fn<Input, Output>(sync_fn: impl Fn(Input) -> Output + Send + Sync) -> impl Fn(Input) -> Ready<Output> + Send + Sync {
+    move |input| std::future::ready(sync_fn(input))
+}
+
+/// merges the `custom_measurements` of the current attempt's pass with the ones from the previous attempt -- see
+/// [CustomMeasurement::merge_previous_attempt()] -- keeping the result as the previous attempt for the next one
+fn merge_previous_attempt(mut custom_measurements:       Vec<CustomMeasurement>,
+                          previous_attempt_measurements: &mut Option<Vec<CustomMeasurement>>)
+                         -> Vec<CustomMeasurement> {
+    if let Some(previous_attempt_measurements) = previous_attempt_measurements {
+        for (custom_measurement, previous_attempt_measurement) in custom_measurements.iter_mut().zip(previous_attempt_measurements.iter()) {
+            custom_measurement.merge_previous_attempt(previous_attempt_measurement);
+        }
+    }
+    previous_attempt_measurements.replace(custom_measurements.clone());
+    custom_measurements
+}
+
+
+/// Why a pass was dropped before completing -- see [guard_pass()]
+enum PassInterruption {
+    Cancelled,
+    TimedOut(Duration),
+}
+
+/// Runs the pass `future` to completion -- unless `cancellation_token` is cancelled or `pass_timeout` elapses first,
+/// in which case `future` is dropped & the [PassInterruption] is returned
+async fn guard_pass<T>(cancellation_token: &Option<CancellationToken>, pass_timeout: Option<Duration>, future: impl Future<Output=T>) -> Result<T, PassInterruption> {
+    let timed_future = async {
+        match pass_timeout {
+            Some(pass_timeout) => tokio::time::timeout(pass_timeout, future).await
+                .map_err(|_elapsed| PassInterruption::TimedOut(pass_timeout)),
+            None => Ok(future.await),
+        }
+    };
+    match cancellation_token {
+        Some(cancellation_token) => tokio::select! {
+            biased;
+            _ = cancellation_token.cancelled() => Err(PassInterruption::Cancelled),
+            outcome = timed_future => outcome,
+        },
+        None => timed_future.await,
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use std::future;
+    use std::sync::{Arc, Mutex};
     use std::time::{Duration, Instant};
     use crate::utils::measurements;
     use super::*;
@@ -499,7 +808,7 @@ mod tests {
             .second_pass_assertion(|&algo_data| async move {
                 assert_eq!(algo_data, 2, "Unexpected data was generated in the 2nd pass");
             })
-            .add_custom_measurement("Δt", BigOAlgorithmComplexity::O1, "Elapsed Time",
+            .add_custom_measurement("Δt", BigOAlgorithmComplexity::O1, MeasurementOrientation::LowerIsBetter, "Elapsed Time",
                                     |_algo_data| future::ready(Instant::now()),
                                     |_algo_data, instant| future::ready(measurements::presentable_measurements::duration_measurement(instant.elapsed())));
         s.test_run().await;
@@ -508,11 +817,142 @@ mod tests {
     #[tokio::test]
     async fn minimum_options() {
         let s = RegularAsyncAnalyzerBuilder::new("minimum_options")
-            .first_pass(100, |_: Option<()>| async { () })
-            .second_pass(100, |_: Option<()>| async { () });
+            .first_pass(100, |_: Option<()>| async {})
+            .second_pass(100, |_: Option<()>| async {});
+        s.test_run().await;
+    }
+
+    /// assures the analysis runs when pinned to a core -- where the affinity isn't supported, pinning does nothing
+    #[tokio::test]
+    #[cfg(feature = "core_pinning")]
+    async fn pinned_to_a_core() {
+        let s = RegularAsyncAnalyzerBuilder::new("pinned_to_a_core")
+            .with_cpu_affinity(0)
+            .first_pass(100, |_: Option<()>| async {})
+            .second_pass(100, |_: Option<()>| async {});
         s.test_run().await;
     }
 
+    /// assures synchronous steps -- adapted by [sync_fn()] -- mix with async ones
+    #[tokio::test]
+    async fn sync_steps() {
+        let result = RegularAsyncAnalyzerBuilder::new("sync steps")
+            .with_reset_fn(sync_fn(|_: Option<u32>| 0))
+            .warmup_pass(sync_fn(|data: Option<u32>| data.expect("BUG! No `data`!") + 1))
+            .first_pass(10, sync_fn(|data: Option<u32>| data.expect("BUG! No `data`!") + 10))
+            .second_pass(20, |data| async move { data.expect("BUG! No `data`!") + 20 })
+            .raw_analyse_algorithm(None).await;
+        assert!(matches!(result, RetryResult::Ok { .. }), "The analysis should have run the sync & async steps alike");
+    }
+
+    /// assures the expectations are taken from the baseline entry of the test -- unless it doesn't state them
+    #[cfg(feature = "baseline")]
+    #[tokio::test]
+    async fn baseline() {
+        let baseline = ComplexityBaseline::from_json_str(r#"{ "baselined": { "time": ["O(1)", "O(n)"] } }"#)
+            .expect("The baseline should have been parsed");
+        let s = RegularAsyncAnalyzerBuilder::new("baselined")
+            .with_space_measurements(BigOAlgorithmComplexity::O1)
+            .with_baseline(&baseline)
+            .first_pass(100, |_: Option<()>| async {})
+            .second_pass(100, |_: Option<()>| async {});
+        assert_eq!((s.expected_time_complexity, s.expected_space_complexity), (Some(ComplexityBoundPair::new(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON)), Some(BigOAlgorithmComplexity::O1)),
+                   "The time bounds should have come from the baseline, which doesn't state the space");
+        let s = RegularAsyncAnalyzerBuilder::new("not baselined")
+            .with_baseline(&baseline)
+            .first_pass(100, |_: Option<()>| async {})
+            .second_pass(100, |_: Option<()>| async {});
+        assert_eq!((s.expected_time_complexity, s.expected_space_complexity), (None, None), "Tests missing from the baseline should go unchecked");
+    }
+
+    /// assures passes are repeated, with resets in-between, when [RegularAsyncAnalyzerBuilder::with_repetitions_per_measurement()] is set
+    #[tokio::test]
+    async fn repeated_passes() {
+        let result = RegularAsyncAnalyzerBuilder::new("repeated passes")
+            .with_repetitions_per_measurement(3)
+            .with_reset_fn(|runs: Option<u32>| future::ready(runs.unwrap_or(0)))
+            .first_pass(10, |runs| future::ready(runs.expect("BUG! No `runs`!") + 1))
+            .second_pass(20, |runs| future::ready(runs.expect("BUG! No `runs`!") + 1))
+            .raw_analyse_algorithm(None).await;
+        match result {
+            RetryResult::Ok { output: runs, .. } => assert_eq!(runs, 6, "Each of the 2 passes should have run 3 times"),
+            _ => panic!("algorithm analysis ended with non-ok status"),
+        }
+    }
+
+    /// assures custom measurements are taken around each repetition -- rather than adding up over all of them
+    #[tokio::test]
+    async fn custom_measurements_of_repeated_passes() {
+        let result = RegularAsyncAnalyzerBuilder::new("custom measurements of repeated passes")
+            .with_repetitions_per_measurement(3)
+            .with_reset_fn(|runs: Option<u32>| future::ready(runs.unwrap_or(0)))
+            .first_pass(10, |runs| future::ready(runs.expect("BUG! No `runs`!") + 1))
+            .second_pass(20, |runs| future::ready(runs.expect("BUG! No `runs`!") + 1))
+            .add_custom_measurement("runs", BigOAlgorithmComplexity::O1, MeasurementOrientation::LowerIsBetter, "Runs measured at once",
+                                    |runs| future::ready(runs.copied().unwrap_or(0)),
+                                    |runs, runs_before| {
+                                        let measured_runs = runs.expect("BUG! No `runs`!") - runs_before;
+                                        assert_eq!(measured_runs, 1, "Each measurement should have enclosed a single repetition");
+                                        future::ready(measurements::presentable_measurements::custom_unit_measurement(measured_runs as f64, "runs"))
+                                    })
+            .raw_analyse_algorithm(None).await;
+        assert!(matches!(result, RetryResult::Ok { output: 6, .. }), "Each of the 2 passes should have run 3 times");
+    }
+
+    /// assures the warmup outcome reaches the first pass only if [RegularAsyncAnalyzerBuilder::with_reset_after_warmup()] is disabled
+    #[tokio::test]
+    async fn reset_after_warmup() {
+        let analyse = |reset_after_warmup| RegularAsyncAnalyzerBuilder::new("reset after warmup")
+            .with_reset_fn(|data: Option<u32>| future::ready(data.unwrap_or(0)))
+            .warmup_pass(|data| future::ready(data.expect("BUG! No `data`!") + 100))
+            .with_reset_after_warmup(reset_after_warmup)
+            .first_pass(10, |data| future::ready(data.expect("BUG! No `data`!") + 1))
+            .second_pass(20, |data| future::ready(data.expect("BUG! No `data`!") + 1))
+            .raw_analyse_algorithm(None);
+        let RetryResult::Ok { output: data, .. } = analyse(true).await else { panic!("algorithm analysis ended with non-ok status") };
+        assert_eq!(data, 2, "The warmup outcome should have been reset");
+        let RetryResult::Ok { output: data, .. } = analyse(false).await else { panic!("algorithm analysis ended with non-ok status") };
+        assert_eq!(data, 102, "The warmup outcome should have been kept");
+    }
+
+    /// assures a pass is interrupted when the [CancellationToken] given to [RegularAsyncAnalyzerBuilder::with_cancellation_token()] is cancelled
+    #[tokio::test]
+    async fn cancellation() {
+        let cancellation_token = CancellationToken::new();
+        let deadline_token = cancellation_token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            deadline_token.cancel();
+        });
+        let start = Instant::now();
+        let result = RegularAsyncAnalyzerBuilder::new("cancelled analysis")
+            .with_cancellation_token(cancellation_token)
+            .first_pass(10, |_: Option<()>| future::ready(()))
+            .second_pass(20, |_: Option<()>| tokio::time::sleep(Duration::from_secs(10)))
+            .raw_analyse_algorithm(None).await;
+        match result {
+            RetryResult::Fatal { error, .. } => assert_eq!(error, "Cancelled", "Wrong error for a cancelled analysis"),
+            _ => panic!("A cancelled analysis should end with a fatal status"),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5), "The second pass should have been interrupted -- took {:?}", start.elapsed());
+    }
+
+    /// assures hanging passes are interrupted by [RegularAsyncAnalyzerBuilder::with_timeout()]
+    #[tokio::test]
+    async fn pass_timeout() {
+        let start = Instant::now();
+        let result = RegularAsyncAnalyzerBuilder::new("hanging analysis")
+            .with_timeout(Duration::from_millis(50))
+            .first_pass(10, |_: Option<()>| future::pending())
+            .second_pass(20, |_: Option<()>| future::ready(()))
+            .raw_analyse_algorithm(None).await;
+        match result {
+            RetryResult::Fatal { error, .. } => assert_eq!(error, "Pass 1 timed out after 50ms", "Wrong error for a timed out pass"),
+            _ => panic!("A timed out analysis should end with a fatal status"),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5), "The first pass should have been interrupted -- took {:?}", start.elapsed());
+    }
+
     #[tokio::test]
     async fn raw_analyse_algorithm() {
         let result = RegularAsyncAnalyzerBuilder::new("dummy analysis")
@@ -545,4 +985,68 @@ mod tests {
             .test_algorithm().await;
     }
 
+    /// assures a panicking pass yields a fatal outcome, telling which pass (and `n`) panicked -- and with which message
+    #[tokio::test]
+    async fn panicking_pass() {
+        let result = RegularAsyncAnalyzerBuilder::new("panicking pass")
+            .first_pass(10, |_: Option<u32>| future::ready(1))
+            .second_pass(20, |_: Option<u32>| async { panic!("second pass is broken") })
+            .raw_analyse_algorithm(None).await;
+        match result {
+            RetryResult::Fatal { error, .. } => assert!(error.contains("pass 2 (n=20)") && error.contains("'second pass is broken'"),
+                                                        "The error should tell the pass, its `n` and the panic message: {}", error),
+            _ => panic!("A panicking pass should yield a fatal outcome"),
+        }
+    }
+
+    /// assures "higher is better" custom measurements are checked against their expected complexity inverted:
+    /// a throughput that halves when `n` doubles is `O(n)`
+    #[tokio::test]
+    async fn inverted_custom_measurement() {
+        let analyse = |expected_complexity| RegularAsyncAnalyzerBuilder::new("inverted custom measurement")
+            .first_pass(100, |_: Option<u32>| future::ready(100))
+            .second_pass(200, |_: Option<u32>| future::ready(200))
+            .add_custom_measurement("throughput", expected_complexity, MeasurementOrientation::HigherIsBetter, "Processed bytes per second",
+                                    |_algo_data| future::ready(()),
+                                    |algo_data, _| future::ready(measurements::presentable_measurements::bytes_per_second_measurement(1e6 / *algo_data.expect("BUG! No `algo_data`!") as f64)))
+            .raw_analyse_algorithm(None);
+        analyse(BigOAlgorithmComplexity::ON).await
+            .expect_ok("A throughput halving when `n` doubles should be accepted as O(n)");
+        assert!(matches!(analyse(BigOAlgorithmComplexity::O1).await, RetryResult::Transient { .. }),
+                "A throughput halving when `n` doubles should not be accepted as O(1)");
+    }
+
+    /// checks [RegularAsyncAnalyzerBuilder::with_reporter()]'s reporter is told of matches & mismatches -- and if a reattempt will follow
+    #[tokio::test]
+    async fn reporter() {
+        #[derive(Clone, Default)]
+        struct RecordingReporter(Arc<Mutex<Vec<String>>>);
+        impl Reporter for RecordingReporter {
+            fn report_match(&self, algorithm_name: &str, _observed: BigOAlgorithmComplexity) {
+                self.0.lock().unwrap().push(format!("{algorithm_name}: match"));
+            }
+            fn report_mismatch(&self, algorithm_name: &str, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool) {
+                self.0.lock().unwrap().push(format!("{algorithm_name}: expected {expected:?} got {observed:?}, retrying: {retrying}"));
+            }
+        }
+        let analyse = |expected_complexity, max_reattempts, reporter| RegularAsyncAnalyzerBuilder::new("reported")
+            .with_reporter(reporter)
+            .with_max_reattempts(max_reattempts)
+            .first_pass(100, |_: Option<u32>| future::ready(100))
+            .second_pass(200, |_: Option<u32>| future::ready(200))
+            .add_custom_measurement("latency", expected_complexity, MeasurementOrientation::LowerIsBetter, "Simulated latency",
+                                    |_algo_data| future::ready(()),
+                                    |algo_data, _| future::ready(measurements::presentable_measurements::duration_measurement(Duration::from_millis(*algo_data.expect("BUG! No `algo_data`!") as u64))))
+            .raw_analyse_algorithm(None);
+        let reporter = RecordingReporter::default();
+        let _ = analyse(BigOAlgorithmComplexity::ON, 0, reporter.clone()).await;
+        let _ = analyse(BigOAlgorithmComplexity::O1, 1, reporter.clone()).await;
+        let _ = analyse(BigOAlgorithmComplexity::O1, 0, reporter.clone()).await;
+        assert_eq!(*reporter.0.lock().unwrap(), vec![
+            "reported: match".to_owned(),
+            "reported: expected O1 got ON, retrying: true".to_owned(),
+            "reported: expected O1 got ON, retrying: false".to_owned(),
+        ], "Wrong reports");
+    }
+
 }
\ No newline at end of file
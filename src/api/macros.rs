@@ -0,0 +1,132 @@
+//! Assertion macros for complexities observed by the analysis functions -- see [crate::low_level_analysis].
+
+
+/// Asserts the `$observed` [crate::BigOAlgorithmComplexity] is no worse than the `$expected` maximum one
+/// -- observing a *better* complexity passes.\
+/// This is the usual assertion for preventing performance regressions: use [assert_complexity_eq!()] instead
+/// if an exact complexity should be enforced.
+/// ```
+///     use big_o_test::{assert_complexity, BigOAlgorithmComplexity};
+///     assert_complexity!(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+/// ```
+#[macro_export]
+macro_rules! assert_complexity {
+    ($observed: expr, $expected: expr) => {{
+        let observed: $crate::BigOAlgorithmComplexity = $observed;
+        let expected: $crate::BigOAlgorithmComplexity = $expected;
+        if !observed.is_at_most(expected) {
+            panic!("Complexity assertion failed: expected a maximum of '{}' ({:?}), but '{}' ({:?}) was observed",
+                   expected.as_pretty_str(), expected, observed.as_pretty_str(), observed);
+        }
+    }}
+}
+
+/// Asserts the `$observed` [crate::BigOAlgorithmComplexity] is exactly the `$expected` one
+/// -- observing either a *better* or a *worse* complexity fails.\
+/// Use it when proving an algorithm's complexity (for instance, that a data structure is genuinely O(log(n)) and
+/// neither O(1) nor O(n)); for regression prevention, [assert_complexity!()] should be preferred, as it won't complain
+/// about improvements.
+/// ```
+///     use big_o_test::{assert_complexity_eq, BigOAlgorithmComplexity};
+///     assert_complexity_eq!(BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::OLogN);
+/// ```
+#[macro_export]
+macro_rules! assert_complexity_eq {
+    ($observed: expr, $expected: expr) => {{
+        let observed: $crate::BigOAlgorithmComplexity = $observed;
+        let expected: $crate::BigOAlgorithmComplexity = $expected;
+        if observed != expected {
+            panic!("Complexity assertion failed: expected exactly '{}' ({:?}), but '{}' ({:?}) was observed",
+                   expected.as_pretty_str(), expected, observed.as_pretty_str(), observed);
+        }
+    }}
+}
+
+/// Asserts the `$observed` [crate::BigOAlgorithmComplexity] is within the (inclusive) `$lower` & `$upper` bounds
+/// -- for algorithms with known tight bounds, measured in uncertain environments: observing a complexity better than
+/// `$lower` fails as well, as it may denote a measurement (or algorithm) problem.\
+/// See [ComplexityBoundPair](crate::low_level_analysis::types::ComplexityBoundPair).
+/// ```
+///     use big_o_test::{assert_complexity_in_range, BigOAlgorithmComplexity};
+///     assert_complexity_in_range!(BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+/// ```
+#[macro_export]
+macro_rules! assert_complexity_in_range {
+    ($observed: expr, $lower: expr, $upper: expr) => {{
+        let observed: $crate::BigOAlgorithmComplexity = $observed;
+        let lower: $crate::BigOAlgorithmComplexity = $lower;
+        let upper: $crate::BigOAlgorithmComplexity = $upper;
+        if !observed.is_between(lower, upper) {
+            panic!("Complexity assertion failed: expected between '{}' ({:?}) and '{}' ({:?}), but '{}' ({:?}) was observed",
+                   lower.as_pretty_str(), lower, upper.as_pretty_str(), upper, observed.as_pretty_str(), observed);
+        }
+    }}
+}
+
+/// Asserts the `$analysis` [BigOAlgorithmAnalysis](crate::low_level_analysis::types::BigOAlgorithmAnalysis) didn't regress,
+/// in neither time nor space complexities, when compared to the `$baseline` one -- for instance, when checking a new implementation
+/// of an algorithm against the previous one.\
+/// See [BigOAlgorithmAnalysis::compare()](crate::low_level_analysis::types::BigOAlgorithmAnalysis::compare).
+#[macro_export]
+macro_rules! assert_not_worse_than {
+    ($analysis: expr, $baseline: expr) => {{
+        let (analysis, baseline) = (&$analysis, &$baseline);
+        let comparison = analysis.compare(baseline);
+        if !comparison.is_not_worse() {
+            panic!("Complexity regression detected: time went from '{}' to '{}' ({:?}); space went from '{}' to '{}' ({:?})",
+                   baseline.time_complexity.as_pretty_str(),  analysis.time_complexity.as_pretty_str(),  comparison.time,
+                   baseline.space_complexity.as_pretty_str(), analysis.space_complexity.as_pretty_str(), comparison.space);
+        }
+    }}
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [macros](super) module
+
+    use crate::BigOAlgorithmComplexity;
+
+
+    #[test]
+    fn assert_complexity_allows_better() {
+        assert_complexity!(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON);
+        assert_complexity!(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a maximum of 'O(n)'")]
+    fn assert_complexity_rejects_worse() {
+        assert_complexity!(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON);
+    }
+
+    #[test]
+    fn assert_complexity_eq_allows_equal() {
+        assert_complexity_eq!(BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::OLogN);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected exactly 'O(log(n))'")]
+    fn assert_complexity_eq_rejects_better() {
+        assert_complexity_eq!(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::OLogN);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected exactly 'O(log(n))'")]
+    fn assert_complexity_eq_rejects_worse() {
+        assert_complexity_eq!(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::OLogN);
+    }
+
+    #[test]
+    fn assert_complexity_in_range_allows_bounds() {
+        assert_complexity_in_range!(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+        assert_complexity_in_range!(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected between 'O(1)' (O1) and 'O(n)' (ON), but 'Better than O(1)'")]
+    fn assert_complexity_in_range_rejects_better() {
+        assert_complexity_in_range!(BigOAlgorithmComplexity::BetterThanO1, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON);
+    }
+}
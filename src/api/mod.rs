@@ -1,5 +1,11 @@
 //! Contains the interface externally available to users as well as related internal helpers
 
+#[cfg(feature = "tokio")]
 mod types;
+mod macros;
+#[cfg(feature = "tokio")]
 mod builders;
-pub use builders::*;
\ No newline at end of file
+#[cfg(feature = "tokio")]
+pub use builders::*;
+mod reporters;
+pub use reporters::*;
\ No newline at end of file
@@ -0,0 +1,88 @@
+//! Renders the elapsed times of the analysis passes as ASCII bars -- so their proportion may be seen at a glance.\
+//! Appended to the [crate::low_level_analysis::types::BigOAlgorithmAnalysis] reports when the `ascii_charts` feature is enabled.
+
+use std::time::Duration;
+
+
+/// The width, in characters, of the longest bar
+const BAR_WIDTH: usize = 40;
+/// The character bars are filled with
+const BAR_GLYPH: char = '█';
+
+/// Two-bar chart of the elapsed times of passes 1 & 2:
+/// ```text
+///   pass1: ████████████████████                     100µs
+///   pass2: ████████████████████████████████████████ 200µs
+/// ```
+pub struct AsciiChart;
+
+impl AsciiChart {
+
+    /// Renders the chart for the given elapsed times, in nanoseconds -- bars are scaled against the largest one.\
+    /// If `expected_ratio` is positive, the ideal pass 2 height -- `pass_1_elapsed * expected_ratio` -- is marked with a `|`
+    /// on the pass 2 bar: a bar going past the mark denotes an algorithm performing worse than expected.
+    /// Give `0.0` to leave the mark out.
+    pub fn render(pass_1_elapsed: u64, pass_2_elapsed: u64, expected_ratio: f64) -> String {
+        let expected_pass_2_elapsed = (expected_ratio.is_finite() && expected_ratio > 0.0).then_some(pass_1_elapsed as f64 * expected_ratio);
+        let max_elapsed = (pass_1_elapsed.max(pass_2_elapsed) as f64).max(expected_pass_2_elapsed.unwrap_or(0.0));
+        // one more cell, so the expected mark fits past the longest bar
+        let bar = |elapsed: u64| format!("{} ", Self::bar(elapsed as f64, max_elapsed)).chars().collect::<Vec<_>>();
+        let pass_1_bar = bar(pass_1_elapsed);
+        let mut pass_2_bar = bar(pass_2_elapsed);
+        if let Some(expected_pass_2_elapsed) = expected_pass_2_elapsed {
+            pass_2_bar[Self::cells(expected_pass_2_elapsed, max_elapsed)] = '|';
+        }
+        format!("  pass1: {} {:?}\n\
+                 \x20 pass2: {} {:?}{}\n",
+                pass_1_bar.into_iter().collect::<String>(), Duration::from_nanos(pass_1_elapsed),
+                pass_2_bar.into_iter().collect::<String>(), Duration::from_nanos(pass_2_elapsed),
+                if expected_pass_2_elapsed.is_some() { " (| = expected)" } else { "" })
+    }
+
+    /// A bar with `value`'s proportion of `max` filled -- padded to the full [BAR_WIDTH], so consecutive bars line up.\
+    /// Shared with [crate::low_level_analysis::types::BigOAlgorithmAnalysis::ascii_chart()]
+    pub(crate) fn bar(value: f64, max: f64) -> String {
+        format!("{:<width$}", BAR_GLYPH.to_string().repeat(Self::cells(value, max)), width = BAR_WIDTH)
+    }
+
+    /// how many of the [BAR_WIDTH] cells `value` fills, scaled against `max`
+    fn cells(value: f64, max: f64) -> usize {
+        if max > 0.0 { (((value / max) * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH) } else { 0 }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [ascii_chart](super) module
+
+    use super::*;
+
+
+    /// checks the bars are proportional to the elapsed times & the expected mark is placed at the ideal pass 2 height
+    #[test]
+    fn render() {
+        let chart = AsciiChart::render(100_000, 200_000, 0.0);
+        assert_eq!(chart, format!("  pass1: {}{} 100µs\n  pass2: {}  200µs\n", "█".repeat(20), " ".repeat(21), "█".repeat(40)),
+                   "Wrong chart");
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 2, "The chart should have a bar for each pass");
+        assert_eq!(lines[0].matches(BAR_GLYPH).count(), BAR_WIDTH / 2, "Pass 1 bar should be half of pass 2's");
+        assert_eq!(lines[1].matches(BAR_GLYPH).count(), BAR_WIDTH, "Pass 2 bar should take the full width");
+        assert!(lines[0].ends_with(" 100µs") && lines[1].ends_with(" 200µs"), "The elapsed times should follow the bars");
+        assert!(!chart.contains('|'), "No expected mark was requested");
+
+        let chart = AsciiChart::render(100_000, 400_000, 2.0);
+        assert_eq!(chart, format!("  pass1: {}{} 100µs\n  pass2: {}|{}  400µs (| = expected)\n", "█".repeat(10), " ".repeat(31), "█".repeat(20), "█".repeat(19)),
+                   "Wrong chart");
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[1].chars().position(|c| c == '|'), Some("  pass2: ".len() + BAR_WIDTH / 2), "The mark should be at half the pass 2 bar");
+        assert!(lines[1].ends_with("(| = expected)"), "The mark should be explained");
+
+        let chart = AsciiChart::render(100_000, 100_000, 3.0);
+        assert_eq!(chart.lines().nth(1).unwrap().chars().position(|c| c == '|'), Some("  pass2: ".len() + BAR_WIDTH),
+                   "Taller than the bars, the expected height should set the scale");
+        assert_eq!(AsciiChart::render(0, 0, 0.0).matches(BAR_GLYPH).count(), 0, "Nothing measured should render empty bars");
+    }
+}
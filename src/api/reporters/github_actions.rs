@@ -0,0 +1,112 @@
+//! Reports the analyses as GitHub Actions workflow commands -- which GitHub turns into annotations on the workflow run,
+//! instead of the generic test failures complexity assertions would otherwise appear as.
+
+use std::fmt::Debug;
+use std::time::Duration;
+use std::panic::Location;
+use super::Reporter;
+use crate::BigOAlgorithmComplexity;
+
+
+/// The environment variable GitHub sets to `true` when running workflows
+const GITHUB_ACTIONS_ENV_VAR: &str = "GITHUB_ACTIONS";
+
+/// Emits `::notice` (matches), `::warning` (mismatches to be retried) & `::error` (failures) workflow commands, annotating `file`.\
+/// They are written straight to stdout, where the runner picks them up -- regardless of the crate's configured output.\
+/// Automatically used by the async builders -- [crate::RegularAsyncAnalyzerBuilder] & [crate::CrudAsyncAnalyzerBuilder] -- and by the
+/// [crate::test_algorithm()] family of runners when running in GitHub Actions -- see [Self::detect()]
+pub struct GithubActionsReporter {
+    /// the source file the annotations refer to
+    file: String,
+}
+
+impl GithubActionsReporter {
+
+    /// Annotates the source file of the caller
+    #[track_caller]
+    pub fn new() -> Self {
+        Self::for_file(Location::caller().file())
+    }
+
+    /// Annotates the given source `file`
+    pub fn for_file(file: impl Into<String>) -> Self {
+        Self { file: file.into() }
+    }
+
+    /// A reporter annotating the caller's source file, if running in GitHub Actions -- `None` otherwise
+    #[track_caller]
+    pub fn detect() -> Option<Self> {
+        let file = Location::caller().file();
+        std::env::var(GITHUB_ACTIONS_ENV_VAR).is_ok_and(|value| value == "true")
+            .then(|| Self::for_file(file))
+    }
+
+    fn notice(&self, algorithm_name: &str, observed: BigOAlgorithmComplexity) -> String {
+        format!("::notice file={}::Algorithm {} measured as {:?}", self.file, algorithm_name, observed)
+    }
+
+    fn warning_or_error(&self, algorithm_name: &str, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool) -> String {
+        if retrying {
+            format!("::warning file={}::Algorithm {}: expected {:?} got {:?} -- retrying", self.file, algorithm_name, expected, observed)
+        } else {
+            format!("::error file={}::Algorithm {}: expected {:?} got {:?}", self.file, algorithm_name, expected, observed)
+        }
+    }
+
+    fn coefficient_warning_or_error(&self, algorithm_name: &str, max: Duration, observed: Duration, retrying: bool) -> String {
+        if retrying {
+            format!("::warning file={}::Algorithm {}: expected a time coefficient of at most {:?} got {:?} -- retrying", self.file, algorithm_name, max, observed)
+        } else {
+            format!("::error file={}::Algorithm {}: expected a time coefficient of at most {:?} got {:?}", self.file, algorithm_name, max, observed)
+        }
+    }
+}
+
+impl Default for GithubActionsReporter {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for GithubActionsReporter {
+
+    fn report_match(&self, algorithm_name: &str, observed: BigOAlgorithmComplexity) {
+        println!("{}", self.notice(algorithm_name, observed));
+    }
+
+    fn report_mismatch(&self, algorithm_name: &str, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool) {
+        println!("{}", self.warning_or_error(algorithm_name, expected, observed, retrying));
+    }
+
+    fn report_coefficient_excess(&self, algorithm_name: &str, max: Duration, observed: Duration, retrying: bool) {
+        println!("{}", self.coefficient_warning_or_error(algorithm_name, max, observed, retrying));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [github_actions](super) module
+
+    use super::*;
+
+
+    /// checks the workflow commands emitted for each verdict
+    #[test]
+    fn workflow_commands() {
+        let reporter = GithubActionsReporter::new();
+        assert_eq!(reporter.file, file!(), "The caller's file should be annotated");
+        assert_eq!(reporter.notice("sort", BigOAlgorithmComplexity::ONLogN),
+                   format!("::notice file={}::Algorithm sort measured as ONLogN", file!()));
+        assert_eq!(reporter.warning_or_error("sort", &BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON2, true),
+                   format!("::warning file={}::Algorithm sort: expected ON got ON2 -- retrying", file!()));
+        assert_eq!(reporter.warning_or_error("sort", &BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON2, false),
+                   format!("::error file={}::Algorithm sort: expected ON got ON2", file!()));
+        assert_eq!(reporter.coefficient_warning_or_error("sort", Duration::from_nanos(10), Duration::from_nanos(50), true),
+                   format!("::warning file={}::Algorithm sort: expected a time coefficient of at most 10ns got 50ns -- retrying", file!()));
+        assert_eq!(reporter.coefficient_warning_or_error("sort", Duration::from_nanos(10), Duration::from_nanos(50), false),
+                   format!("::error file={}::Algorithm sort: expected a time coefficient of at most 10ns got 50ns", file!()));
+    }
+}
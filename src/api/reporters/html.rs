@@ -0,0 +1,217 @@
+//! Renders analyses as a standalone HTML page -- with Chart.js charts of the passes' measurements and a table of the observed
+//! complexities -- so they may be shared with people who won't read the text reports.\
+//! Chart.js is loaded from its CDN, so the charts need network access to be displayed; the table doesn't.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use crate::{BigOAlgorithmComplexity, ComplexityBoundPair};
+use crate::low_level_analysis::types::{BigOAlgorithmAnalysis, BigOAlgorithmMeasurements};
+
+
+/// Where Chart.js is loaded from
+const CHART_JS_CDN_URL: &str = "https://cdn.jsdelivr.net/npm/chart.js";
+
+/// Accumulates analyses to be rendered as an HTML page:
+/// ```nocompile
+///     let mut reporter = HtmlReporter::new("Containers");
+///     reporter.add_analysis(&vec_analysis)
+///             .add_analysis_with_expectations(&map_analysis, BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1);
+///     reporter.render_to_file(Path::new("target/big-o-report.html"))?;
+/// ```
+/// The page has bar charts of the passes' elapsed times & used memories for each operation, followed by a table of the observed
+/// complexities -- green if matching the expected ones, red if not.
+pub struct HtmlReporter {
+    title:      String,
+    operations: Vec<OperationReport>,
+}
+
+/// The figures of an analysed operation that get rendered
+struct OperationReport {
+    name:             String,
+    /// the elapsed seconds of passes 1 & 2
+    elapsed_times:    [f64; 2],
+    /// the bytes used by passes 1 & 2 -- see [crate::low_level_analysis::types::BigOSpacePassMeasurements::used_memory()]
+    used_memories:    [usize; 2],
+    time_complexity:  BigOAlgorithmComplexity,
+    space_complexity: BigOAlgorithmComplexity,
+    /// the expected (time, space) complexities -- if given
+    expectations:     Option<(ComplexityBoundPair, ComplexityBoundPair)>,
+}
+
+impl HtmlReporter {
+
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_owned(),
+            operations: vec![],
+        }
+    }
+
+    /// Adds `analysis` to the report -- with no expectations to be checked against
+    pub fn add_analysis<T: BigOAlgorithmMeasurements>(&mut self, analysis: &BigOAlgorithmAnalysis<T>) -> &mut Self {
+        self.add(analysis, None)
+    }
+
+    /// Adds `analysis` to the report, to be checked against the expected complexities -- either maximums or `(lower, upper)` bounds
+    pub fn add_analysis_with_expectations<T: BigOAlgorithmMeasurements>(&mut self,
+                                                                       analysis: &BigOAlgorithmAnalysis<T>,
+                                                                       expected_time_complexity: impl Into<ComplexityBoundPair>,
+                                                                       expected_space_complexity: impl Into<ComplexityBoundPair>)
+                                                                      -> &mut Self {
+        self.add(analysis, Some((expected_time_complexity.into(), expected_space_complexity.into())))
+    }
+
+    /// The HTML page for the analyses added so far
+    pub fn render(&self) -> String {
+        let labels = json_array(self.operations.iter().map(|operation| json_string(&operation.name)));
+        let pass_values = |pass: usize, value: fn(&OperationReport, usize) -> String| json_array(self.operations.iter().map(|operation| value(operation, pass)));
+        let elapsed_times = |pass| pass_values(pass, |operation, pass| format!("{:e}", operation.elapsed_times[pass]));
+        let used_memories = |pass| pass_values(pass, |operation, pass| operation.used_memories[pass].to_string());
+        let mut html = String::new();
+        let _ = write!(html, "\
+<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{title}</title>
+<script src=\"{CHART_JS_CDN_URL}\"></script>
+<style>
+  body {{ font-family: sans-serif; }}
+  table {{ border-collapse: collapse; }}
+  th, td {{ border: 1px solid #ccc; padding: 4px 8px; }}
+  .pass {{ background-color: #c8f7c5; }}
+  .fail {{ background-color: #f7c5c5; }}
+  .chart {{ max-width: 900px; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<div class=\"chart\"><canvas id=\"elapsed_times\"></canvas></div>
+<div class=\"chart\"><canvas id=\"used_memories\"></canvas></div>
+<script>
+  function barChart(id, title, pass1, pass2) {{
+    new Chart(document.getElementById(id), {{
+      type: 'bar',
+      data: {{ labels: {labels}, datasets: [{{ label: 'pass 1', data: pass1 }}, {{ label: 'pass 2', data: pass2 }}] }},
+      options: {{ plugins: {{ title: {{ display: true, text: title }} }} }},
+    }});
+  }}
+  barChart('elapsed_times', 'Elapsed time (s)',  {elapsed_times_1}, {elapsed_times_2});
+  barChart('used_memories', 'Used memory (bytes)', {used_memories_1}, {used_memories_2});
+</script>
+<h2>Complexities</h2>
+<table>
+<tr><th>operation</th><th>expected time</th><th>observed time</th><th>expected space</th><th>observed space</th></tr>
+",
+            title = escape_html(&self.title),
+            elapsed_times_1 = elapsed_times(0), elapsed_times_2 = elapsed_times(1),
+            used_memories_1 = used_memories(0), used_memories_2 = used_memories(1));
+        for operation in &self.operations {
+            let (expected_time, expected_space) = match operation.expectations {
+                Some((expected_time, expected_space)) => (Some(expected_time), Some(expected_space)),
+                None => (None, None),
+            };
+            let _ = writeln!(html, "<tr><td>{}</td>{}{}</tr>",
+                             escape_html(&operation.name),
+                             complexity_cells(expected_time, operation.time_complexity),
+                             complexity_cells(expected_space, operation.space_complexity));
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+
+    /// Writes [Self::render()] to the file at `path` -- overwriting it, if it exists
+    pub fn render_to_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.render())
+    }
+
+    fn add<T: BigOAlgorithmMeasurements>(&mut self, analysis: &BigOAlgorithmAnalysis<T>, expectations: Option<(ComplexityBoundPair, ComplexityBoundPair)>) -> &mut Self {
+        let measurements = &analysis.algorithm_measurements;
+        let time_measurements = measurements.time_measurements();
+        let space_measurements = measurements.space_measurements();
+        self.operations.push(OperationReport {
+            name:             measurements.measurement_name().to_owned(),
+            elapsed_times:    [time_measurements.pass_1_measurements.as_secs_f64(), time_measurements.pass_2_measurements.as_secs_f64()],
+            used_memories:    [space_measurements.pass_1_measurements.used_memory(), space_measurements.pass_2_measurements.used_memory()],
+            time_complexity:  analysis.time_complexity,
+            space_complexity: analysis.space_complexity,
+            expectations,
+        });
+        self
+    }
+}
+
+/// the "expected" & "observed" table cells of a complexity -- the observed one colored by its status, if there are expectations
+fn complexity_cells(expected: Option<ComplexityBoundPair>, observed: BigOAlgorithmComplexity) -> String {
+    match expected {
+        Some(expected) => format!("<td>{}</td><td class=\"{}\">{}</td>",
+                                  expected, if expected.contains(observed) {"pass"} else {"fail"}, observed.as_pretty_str()),
+        None => format!("<td>-</td><td>{}</td>", observed.as_pretty_str()),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// a JavaScript string literal for `text` -- also safe inside the `<script>` element
+fn json_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n").replace('<', "\\u003c"))
+}
+
+fn json_array(items: impl Iterator<Item=String>) -> String {
+    format!("[{}]", items.collect::<Vec<_>>().join(", "))
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [html](super) module
+
+    use super::*;
+    use std::time::Duration;
+    use crate::low_level_analysis::types::{fixtures, AlgorithmMeasurements};
+
+
+    /// an `O(n)` space analysis taking 100ms & 1KiB on the first pass, then the double on the second
+    fn analysis(measurement_name: &'static str, time_complexity: BigOAlgorithmComplexity) -> BigOAlgorithmAnalysis<AlgorithmMeasurements<'static>> {
+        fixtures::analysis(measurement_name, time_complexity, BigOAlgorithmComplexity::ON, Duration::from_millis(100), Duration::from_millis(200), 1024, 2048)
+    }
+
+    /// checks the charts' data & the color coding of the complexities table -- as well as the escaping of the names
+    #[test]
+    fn render() {
+        let mut reporter = HtmlReporter::new("Vec <vs> Map");
+        reporter.add_analysis_with_expectations(&analysis("vec", BigOAlgorithmComplexity::ON), BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON)
+                .add_analysis_with_expectations(&analysis("map", BigOAlgorithmComplexity::ON), BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::ON)
+                .add_analysis(&analysis("\"unchecked\"", BigOAlgorithmComplexity::ON2));
+        let html = reporter.render();
+        assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n"), "The page should start with its head: {}", html);
+        assert!(html.ends_with("</table>\n</body>\n</html>\n"), "The page should end with the complexities table: {}", html);
+        assert!(html.contains("<h1>Vec &lt;vs&gt; Map</h1>"), "The heading should be escaped");
+        assert!(html.contains(&format!("<script src=\"{}\"></script>", CHART_JS_CDN_URL)), "Chart.js should be loaded");
+        assert!(html.contains("<title>Vec &lt;vs&gt; Map</title>"), "The title should be escaped");
+        assert!(html.contains("labels: [\"vec\", \"map\", \"\\\"unchecked\\\"\"]"), "Each analysis should be charted");
+        assert!(html.contains("'Elapsed time (s)',  [1e-1, 1e-1, 1e-1], [2e-1, 2e-1, 2e-1]"), "Wrong elapsed times data");
+        assert!(html.contains("'Used memory (bytes)', [1024, 1024, 1024], [2048, 2048, 2048]"), "Wrong used memories data");
+        let rows = html.lines().filter(|line| line.starts_with("<tr><td>")).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3, "There should be a table row for each analysis");
+        assert!(rows[0].contains("<td class=\"pass\">O(n)</td><td>maximum: ON</td><td class=\"pass\">"), "A matched time should be green: {}", rows[0]);
+        assert!(rows[1].contains("<td>maximum: O1</td><td class=\"fail\">O(n)</td>"), "A mismatched time should be red: {}", rows[1]);
+        assert!(rows[2].starts_with("<tr><td>&quot;unchecked&quot;</td><td>-</td><td>O(n²)</td>"), "Unchecked analyses shouldn't be colored: {}", rows[2]);
+    }
+
+    /// assures the rendered page is written to the given path
+    #[test]
+    fn render_to_file() {
+        let mut reporter = HtmlReporter::new("file");
+        reporter.add_analysis(&analysis("vec", BigOAlgorithmComplexity::ON));
+        let path = std::env::temp_dir().join(format!("big-o-html-reporter-{}.html", std::process::id()));
+        reporter.render_to_file(&path).expect("Rendering to a temporary file should work");
+        let written = std::fs::read_to_string(&path).expect("The rendered file should be readable");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(written, reporter.render(), "The file should contain the rendered page");
+    }
+}
@@ -0,0 +1,38 @@
+//! Presentation of the analyses' outcomes -- besides the standard [crate::OUTPUT] ones
+
+use std::fmt::Debug;
+use std::time::Duration;
+use crate::BigOAlgorithmComplexity;
+
+mod ascii_chart;
+pub use ascii_chart::*;
+mod github_actions;
+pub use github_actions::*;
+mod html;
+pub use html::*;
+#[cfg(feature = "metrics")]
+mod open_metrics;
+#[cfg(feature = "metrics")]
+pub use open_metrics::*;
+#[cfg(feature = "prometheus")]
+mod prometheus_exporter;
+#[cfg(feature = "prometheus")]
+pub use prometheus_exporter::*;
+
+
+/// Receives the verdicts of the analyses -- so they may be surfaced elsewhere (CI annotations, dashboards...).
+/// See [crate::RegularAsyncAnalyzerBuilder::with_reporter()]
+pub trait Reporter: Send + Sync {
+
+    /// `algorithm_name` matched all its expectations, with the `observed` time complexity
+    fn report_match(&self, algorithm_name: &str, observed: BigOAlgorithmComplexity);
+
+    /// `algorithm_name` measured as `observed` -- outside of the `expected` complexity.\
+    /// `retrying` tells if another attempt will follow -- making the mismatch not (yet) a failure
+    fn report_mismatch(&self, algorithm_name: &str, expected: &dyn Debug, observed: BigOAlgorithmComplexity, retrying: bool);
+
+    /// `algorithm_name` matched its expected complexity, but its `observed` time coefficient -- the time taken per unit of the
+    /// complexity's model term -- exceeded the `max` one: see [crate::RegularAsyncAnalyzerBuilder::with_max_time_coefficient()].\
+    /// `retrying` tells if another attempt will follow. Does nothing by default
+    fn report_coefficient_excess(&self, _algorithm_name: &str, _max: Duration, _observed: Duration, _retrying: bool) {}
+}
@@ -0,0 +1,153 @@
+//! Serializes the analyses as OpenMetrics text, enabled by the `metrics` feature -- so benchmark jobs may be scraped by Prometheus
+//! (or any other OpenMetrics compatible backend) without linking to its client libraries.\
+//! Only the already populated measurements are read: nothing is registered nor measured.
+
+use std::io::{self, Write};
+use crate::BigOAlgorithmComplexity;
+use crate::low_level_analysis::types::{BigOAlgorithmAnalysis, BigOAlgorithmMeasurements};
+use crate::runners::crud::CrudAnalyses;
+
+
+/// Serializes analyses into the OpenMetrics text exposition format:
+/// ```text
+///   # TYPE bigo_elapsed_time gauge
+///   # HELP bigo_elapsed_time Elapsed time of the pass, in nanoseconds
+///   bigo_elapsed_time{operation="read",pass="1"} 100
+///   bigo_elapsed_time{operation="read",pass="2"} 200
+///   # TYPE bigo_used_memory gauge
+///   ...
+///   bigo_complexity{operation="read"} 3
+///   ...
+///   # EOF
+/// ```
+/// Complexities are given as the discriminants of [BigOAlgorithmComplexity] -- the higher, the worse.
+pub struct OpenMetricsExporter;
+
+impl OpenMetricsExporter {
+
+    /// The OpenMetrics text for `analysis` -- labeled with `operation="<measurement name>"`
+    pub fn analysis_to_text<T: BigOAlgorithmMeasurements>(analysis: &BigOAlgorithmAnalysis<T>) -> String {
+        to_text(&[OperationMetrics::of(analysis.algorithm_measurements.measurement_name(), analysis)])
+    }
+
+    /// The OpenMetrics text for the analysed operations of [crate::runners::crud::analyse_crud_algorithms()] -- labeled
+    /// `operation="create"`, `"read"`, `"update"` & `"delete"`
+    pub fn crud_analyses_to_text(crud_analyses: &CrudAnalyses<'_>) -> String {
+        let (create_analysis, read_analysis, update_analysis, delete_analysis, _report) = crud_analyses;
+        let operations_metrics = [
+            create_analysis.as_ref().map(|analysis| OperationMetrics::of("create", analysis)),
+            read_analysis.as_ref().map(|analysis| OperationMetrics::of("read", analysis)),
+            update_analysis.as_ref().map(|analysis| OperationMetrics::of("update", analysis)),
+            delete_analysis.as_ref().map(|analysis| OperationMetrics::of("delete", analysis)),
+        ];
+        to_text(&operations_metrics.into_iter().flatten().collect::<Vec<_>>())
+    }
+
+    /// Writes [Self::analysis_to_text()] to `writer`
+    pub fn write_analysis<T: BigOAlgorithmMeasurements>(analysis: &BigOAlgorithmAnalysis<T>, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(Self::analysis_to_text(analysis).as_bytes())
+    }
+
+    /// Writes [Self::crud_analyses_to_text()] to `writer`
+    pub fn write_crud_analyses(crud_analyses: &CrudAnalyses<'_>, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(Self::crud_analyses_to_text(crud_analyses).as_bytes())
+    }
+}
+
+/// The figures of an analysed operation that get serialized
+struct OperationMetrics<'a> {
+    operation:        &'a str,
+    /// the elapsed nanoseconds of passes 1 & 2
+    elapsed_times:    [u128; 2],
+    /// the bytes used by passes 1 & 2 -- see [crate::low_level_analysis::types::BigOSpacePassMeasurements::used_memory()]
+    used_memories:    [usize; 2],
+    time_complexity:  BigOAlgorithmComplexity,
+    space_complexity: BigOAlgorithmComplexity,
+}
+impl<'a> OperationMetrics<'a> {
+    fn of<T: BigOAlgorithmMeasurements>(operation: &'a str, analysis: &BigOAlgorithmAnalysis<T>) -> Self {
+        let time_measurements = analysis.algorithm_measurements.time_measurements();
+        let space_measurements = analysis.algorithm_measurements.space_measurements();
+        Self {
+            operation,
+            elapsed_times:    [time_measurements.pass_1_measurements.as_nanos(), time_measurements.pass_2_measurements.as_nanos()],
+            used_memories:    [space_measurements.pass_1_measurements.used_memory(), space_measurements.pass_2_measurements.used_memory()],
+            time_complexity:  analysis.time_complexity,
+            space_complexity: analysis.space_complexity,
+        }
+    }
+}
+
+/// serializes the metric families of all `operations_metrics` -- each family's samples must be contiguous
+fn to_text(operations_metrics: &[OperationMetrics]) -> String {
+    let mut text = String::new();
+    let mut family = |name: &str, help: &str, samples: &mut dyn Iterator<Item=(String, String)>| {
+        text.push_str(&format!("# TYPE {name} gauge\n# HELP {name} {help}\n"));
+        for (labels, value) in samples {
+            text.push_str(&format!("{name}{{{labels}}} {value}\n"));
+        }
+    };
+    let pass_samples = |values: fn(&OperationMetrics) -> [String; 2]| operations_metrics.iter()
+        .flat_map(move |metrics| values(metrics).into_iter().enumerate()
+            .map(|(pass, value)| (format!("operation=\"{}\",pass=\"{}\"", escape(metrics.operation), pass+1), value)));
+    let operation_samples = |value: fn(&OperationMetrics) -> String| operations_metrics.iter()
+        .map(move |metrics| (format!("operation=\"{}\"", escape(metrics.operation)), value(metrics)));
+    family("bigo_elapsed_time", "Elapsed time of the pass, in nanoseconds",
+           &mut pass_samples(|metrics| metrics.elapsed_times.map(|elapsed| elapsed.to_string())));
+    family("bigo_used_memory", "Memory used by the pass, in bytes",
+           &mut pass_samples(|metrics| metrics.used_memories.map(|used_memory| used_memory.to_string())));
+    family("bigo_complexity", "Observed time complexity -- the BigOAlgorithmComplexity discriminant",
+           &mut operation_samples(|metrics| (metrics.time_complexity as u32).to_string()));
+    family("bigo_space_complexity", "Observed space complexity -- the BigOAlgorithmComplexity discriminant",
+           &mut operation_samples(|metrics| (metrics.space_complexity as u32).to_string()));
+    text.push_str("# EOF\n");
+    text
+}
+
+/// escapes a label value, as required by the OpenMetrics text format
+fn escape(label_value: &str) -> String {
+    label_value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [open_metrics](super) module
+
+    use super::*;
+    use std::time::Duration;
+    use crate::low_level_analysis::types::fixtures;
+
+
+    /// checks the exact lines of the exported text -- for the CRUD analyses & for a single analysis
+    #[test]
+    fn line_format() {
+        let read_analysis = fixtures::constant_set_analysis("Read \"quoted\"", BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::O1,
+                                                            Duration::from_nanos(100), Duration::from_nanos(200), 16, 32);
+        let expected_crud_text = "\
+            # TYPE bigo_elapsed_time gauge\n\
+            # HELP bigo_elapsed_time Elapsed time of the pass, in nanoseconds\n\
+            bigo_elapsed_time{operation=\"read\",pass=\"1\"} 100\n\
+            bigo_elapsed_time{operation=\"read\",pass=\"2\"} 200\n\
+            # TYPE bigo_used_memory gauge\n\
+            # HELP bigo_used_memory Memory used by the pass, in bytes\n\
+            bigo_used_memory{operation=\"read\",pass=\"1\"} 16\n\
+            bigo_used_memory{operation=\"read\",pass=\"2\"} 32\n\
+            # TYPE bigo_complexity gauge\n\
+            # HELP bigo_complexity Observed time complexity -- the BigOAlgorithmComplexity discriminant\n\
+            bigo_complexity{operation=\"read\"} 3\n\
+            # TYPE bigo_space_complexity gauge\n\
+            # HELP bigo_space_complexity Observed space complexity -- the BigOAlgorithmComplexity discriminant\n\
+            bigo_space_complexity{operation=\"read\"} 1\n\
+            # EOF\n";
+        let single_analysis_text = OpenMetricsExporter::analysis_to_text(&read_analysis);
+        assert_eq!(single_analysis_text, expected_crud_text.replace("operation=\"read\"", "operation=\"Read \\\"quoted\\\"\""),
+                   "A single analysis should be labeled with its (escaped) measurement name");
+
+        let crud_analyses: CrudAnalyses = (None, Some(read_analysis), None, None, String::new());
+        let mut written = Vec::new();
+        OpenMetricsExporter::write_crud_analyses(&crud_analyses, &mut written).expect("Writing to a Vec should not fail");
+        assert_eq!(String::from_utf8(written).unwrap(), expected_crud_text, "Only the analysed CRUD operations should be exported");
+    }
+}
@@ -0,0 +1,110 @@
+//! Exposes the analyses as Prometheus gauges, enabled by the `prometheus` feature -- so the complexities measured on each
+//! deployment may be tracked by dashboards & alerts, like any other metric.
+
+use prometheus::{Gauge, Opts, Registry};
+use crate::low_level_analysis::types::{BigOAlgorithmAnalysis, BigOAlgorithmMeasurements};
+
+
+/// The label telling the analysed algorithms apart -- their [BigOAlgorithmMeasurements::measurement_name()]
+const ALGORITHM_LABEL: &str = "algorithm";
+
+/// Registers the outcome of an analysis in a [prometheus::Registry], as the gauges:
+///   * `big_o_time_complexity` -- the ordinal of the observed [crate::BigOAlgorithmComplexity]: the higher, the worse;
+///   * `big_o_ops_per_second` -- the throughput of the second pass: its operations over its elapsed time;
+///   * `big_o_memory_bytes_per_element` -- the memory used by the second pass, over its operations -- the elements it added or processed.
+///
+/// See [BigOAlgorithmMeasurements::pass_2_operations()].
+///
+/// All of them labeled with `algorithm="<measurement name>"`.
+pub struct PrometheusMetricsExporter;
+
+impl PrometheusMetricsExporter {
+
+    /// Registers the gauges for `analysis` in `registry`, set to the measured values.\
+    /// Fails with [prometheus::Error::AlreadyReg] if an analysis with the same name was already exported to `registry`.
+    pub fn export<T: BigOAlgorithmMeasurements>(analysis: &BigOAlgorithmAnalysis<T>, registry: &Registry) -> prometheus::Result<()> {
+        let measurements = &analysis.algorithm_measurements;
+        let algorithm_name = measurements.measurement_name();
+        let pass_2_seconds = measurements.time_measurements().pass_2_measurements.as_secs_f64();
+        let pass_2_operations = measurements.pass_2_operations();
+        let pass_2_used_memory = measurements.space_measurements().pass_2_measurements.used_memory();
+        let gauges = [
+            ("big_o_time_complexity",          "Ordinal of the observed time complexity -- the higher, the worse", analysis.time_complexity as u32 as f64),
+            ("big_o_ops_per_second",           "Operations per second, on the second pass",                         if pass_2_seconds > 0.0 { pass_2_operations / pass_2_seconds } else { 0.0 }),
+            ("big_o_memory_bytes_per_element", "Memory used by the second pass, in bytes, per element",             if pass_2_operations > 0.0 { pass_2_used_memory as f64 / pass_2_operations } else { 0.0 }),
+        ];
+        for (name, help, value) in gauges {
+            let gauge = Gauge::with_opts(Opts::new(name, help).const_label(ALGORITHM_LABEL, algorithm_name))?;
+            gauge.set(value);
+            registry.register(Box::new(gauge))?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [prometheus_exporter](super) module
+
+    use super::*;
+    use std::time::Duration;
+    use crate::BigOAlgorithmComplexity;
+    use crate::low_level_analysis::types::{fixtures, BigOSpaceMeasurements, BigOSpacePassMeasurements, BigOTimeMeasurements,
+                                           SetResizingIteratorAlgorithmMeasurements, SetResizingIteratorAlgorithmPassesInfo};
+
+
+    /// the (name, value) of the gauges in `registry` -- checking they are labeled with the `algorithm_name`
+    fn gauges(registry: &Registry, algorithm_name: &str) -> Vec<(String, f64)> {
+        registry.gather().iter()
+            .map(|family| {
+                let metric = &family.get_metric()[0];
+                assert_eq!(metric.get_label()[0].name(), ALGORITHM_LABEL);
+                assert_eq!(metric.get_label()[0].value(), algorithm_name);
+                (family.name().to_owned(), metric.get_gauge().get_value())
+            })
+            .collect()
+    }
+
+    /// checks the gauges registered for an analysis -- and that the same analysis can't be exported twice
+    #[test]
+    fn export() {
+        let analysis = fixtures::analysis("exported", BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+                                          Duration::from_millis(100), Duration::from_millis(200), 800, 800);
+        let registry = Registry::new();
+        PrometheusMetricsExporter::export(&analysis, &registry).expect("Exporting to an empty registry should work");
+        assert_eq!(gauges(&registry, "exported"), vec![
+            ("big_o_memory_bytes_per_element".to_owned(), 4.0),
+            ("big_o_ops_per_second".to_owned(),           1000.0),
+            ("big_o_time_complexity".to_owned(),          BigOAlgorithmComplexity::ON as u32 as f64),
+        ], "Wrong gauges");
+        assert!(matches!(PrometheusMetricsExporter::export(&analysis, &registry), Err(prometheus::Error::AlreadyReg)),
+                "Exporting the same analysis twice should be refused");
+    }
+
+    /// checks set resizing figures are taken over the elements the second pass added -- not over the set size
+    #[test]
+    fn export_set_resizing() {
+        let space_measurements = BigOSpacePassMeasurements { used_memory_before: 0, used_memory_after: 800, max_used_memory: 800, min_used_memory: 0 };
+        let analysis = BigOAlgorithmAnalysis {
+            time_complexity: BigOAlgorithmComplexity::O1,
+            space_complexity: BigOAlgorithmComplexity::O1,
+            time_coefficient: None,
+            is_near_boundary: false,
+            algorithm_measurements: SetResizingIteratorAlgorithmMeasurements {
+                measurement_name: "inserts",
+                passes_info: SetResizingIteratorAlgorithmPassesInfo { delta_set_size: 100 },
+                time_measurements: BigOTimeMeasurements { pass_1_measurements: Duration::from_millis(10), pass_2_measurements: Duration::from_millis(10) },
+                space_measurements: BigOSpaceMeasurements { pass_1_measurements: space_measurements, pass_2_measurements: space_measurements },
+            },
+        };
+        let registry = Registry::new();
+        PrometheusMetricsExporter::export(&analysis, &registry).expect("Exporting to an empty registry should work");
+        assert_eq!(gauges(&registry, "inserts"), vec![
+            ("big_o_memory_bytes_per_element".to_owned(), 8.0),
+            ("big_o_ops_per_second".to_owned(),           10_000.0),
+            ("big_o_time_complexity".to_owned(),          BigOAlgorithmComplexity::O1 as u32 as f64),
+        ], "Wrong gauges");
+    }
+}
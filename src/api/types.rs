@@ -6,4 +6,7 @@ use std::pin::Pin;
 /// Defines optional operations to be applied on the Algorithm under analysis' data -- such as "Reset" and "Warmup"
 pub type AlgoManipulationAsyncFn<AlgoDataType> = Box<dyn FnMut(Option<AlgoDataType>) -> Pin<Box<dyn Future<Output=AlgoDataType> + Send>> + Send + Sync>;
 /// Defines optional assertions to be applied on the Algorithm's data after a pass is executed -- to allow ensuring the pass worked as expected
-pub type AlgoAssertionAsyncFn<AlgoDataType> = Box<dyn FnMut(&AlgoDataType) -> Pin<Box<dyn Future<Output=()> + Send>> + Send + Sync>;
\ No newline at end of file
+pub type AlgoAssertionAsyncFn<AlgoDataType> = Box<dyn FnMut(&AlgoDataType) -> Pin<Box<dyn Future<Output=()> + Send>> + Send + Sync>;
+/// Defines the async CRUD operations to be analysed: called with the number of the element to operate on, resolving to an `u32`
+/// dependent on the algorithm's execution -- to avoid any 'call removal optimizations'
+pub type AlgoIteratorAsyncFn = Box<dyn Fn(u32) -> Pin<Box<dyn Future<Output=u32> + Send>> + Send + Sync>;
@@ -2,28 +2,43 @@
 #![allow(dead_code)]
 
 use std::io::{stdout,stderr,Write};
+use std::sync::{Mutex, PoisonError};
 use crate::metrics_allocator::MetricsAllocator;
+use crate::runners::output::{self, Verbosity};
 
-#[cfg(any(feature = "tolerance_10_percent", not(any(feature = "tolerance_25_percent"))))]
-/// acceptable proportional variance (acceptable measurement errors) when analysing algorithm's time & space complexities
-pub const PERCENT_TOLERANCE: f64 = 0.10;
-
-#[cfg(feature = "tolerance_25_percent")]
-/// acceptable proportional variance (acceptable measurement errors) when analysing algorithm's time & space complexities
-pub const PERCENT_TOLERANCE: f64 = 0.25;
+/// defined along with the analysis -- which also builds without `std`
+pub use crate::low_level_analysis::configs::PERCENT_TOLERANCE;
 
 #[cfg(feature = "report_stdout")]
 /// Function to output an `&str` -- used to sink analysis reports -- controlled by the crate's features (stdout, stderr, no_output)
+/// & by the installed [crate::OutputConfig]
 pub const OUTPUT: fn(&str) = stdout_write;
 
 #[cfg(feature = "report_stderr")]
 /// Function to output an `&str` -- used to sink analysis reports -- controlled by the crate's features (stdout, stderr, no_output)
+/// & by the installed [crate::OutputConfig]
 pub const OUTPUT: fn(&str) = stderr_write;
 
 #[cfg(not(any(feature = "report_stdout", feature = "report_stderr")))]
 /// Function to output an `&str` -- used to sink analysis reports -- controlled by the crate's features (stdout, stderr, no_output)
+/// & by the installed [crate::OutputConfig]
 pub const OUTPUT: fn(&str) = null_write;
 
+#[cfg(feature = "report_stdout")]
+/// Same as [OUTPUT], but for errors (complexity mismatches & aborted analyses) -- still written when the
+/// [crate::runners::output::Verbosity] only allows errors
+pub(crate) const OUTPUT_ERROR: fn(&str) = stdout_write_error;
+
+#[cfg(feature = "report_stderr")]
+/// Same as [OUTPUT], but for errors (complexity mismatches & aborted analyses) -- still written when the
+/// [crate::runners::output::Verbosity] only allows errors
+pub(crate) const OUTPUT_ERROR: fn(&str) = stderr_write_error;
+
+#[cfg(not(any(feature = "report_stdout", feature = "report_stderr")))]
+/// Same as [OUTPUT], but for errors (complexity mismatches & aborted analyses) -- still written when the
+/// [crate::runners::output::Verbosity] only allows errors
+pub(crate) const OUTPUT_ERROR: fn(&str) = null_write;
+
 #[cfg(not(feature = "no_allocator_metrics"))]
 #[global_allocator]
 /// Allows access to the metrics allocator -- replacing the Global Allocator for tests
@@ -37,15 +52,55 @@ pub static ALLOC: MetricsAllocator<SAVE_POINT_RING_BUFFER_SIZE> = MetricsAllocat
 pub const SAVE_POINT_RING_BUFFER_SIZE: usize = 1024;
 
 
+/// Serializes the writes of [OUTPUT] -- which may be called concurrently, from the measuring threads, as well as from the analysis
+/// thread -- so their reports won't interleave
+static OUTPUT_LOCK: Mutex<()> = Mutex::new(());
+
 fn stdout_write(buf: &str) {
-    sync_outputs();
-    print!("{}", buf);
-    sync_outputs();
+    output::write(Verbosity::Verbose, stdout_print, buf);
 }
 
 fn stderr_write(buf: &str) {
-    sync_outputs();
+    output::write(Verbosity::Verbose, stderr_print, buf);
+}
+
+fn stdout_write_error(buf: &str) {
+    output::write(Verbosity::ErrorsOnly, stdout_print, buf);
+}
+
+fn stderr_write_error(buf: &str) {
+    output::write(Verbosity::ErrorsOnly, stderr_print, buf);
+}
+
+/// prints through [print!] -- rather than writing to [stdout()] -- so the test harness is able to capture the reports
+fn stdout_print(buf: &str) {
+    print!("{}", buf);
+}
+
+/// prints through [eprint!] -- rather than writing to [stderr()] -- so the test harness is able to capture the reports
+fn stderr_print(buf: &str) {
     eprint!("{}", buf);
+}
+
+/// Prints `buf` with `print` -- one of the functions above -- while holding [OUTPUT_LOCK]
+pub(crate) fn locked_print(print: fn(&str), buf: &str) {
+    serialized(|| print(buf));
+}
+
+/// Writes `buf` to `sink` as a single unit -- flushed, so partial lines (like progress reports) show up right away --
+/// while holding [OUTPUT_LOCK]
+pub(crate) fn locked_write(mut sink: impl Write, buf: &str) {
+    serialized(|| {
+        _ = sink.write_all(buf.as_bytes());
+        _ = sink.flush();
+    });
+}
+
+/// Runs `output` while holding [OUTPUT_LOCK] -- syncing stdout & stderr before and after it
+fn serialized(output: impl FnOnce()) {
+    let _serialized = OUTPUT_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    sync_outputs();
+    output();
     sync_outputs();
 }
 
@@ -59,3 +114,48 @@ fn null_write(_buf: &str) {
     // release compilations will optimize out this call for '_buf' is not used
 }
 
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [features](super) module
+
+    use super::*;
+    use std::sync::Arc;
+
+
+    /// a sink that writes a single byte at a time -- yielding in-between, to expose any interleaving
+    #[derive(Clone)]
+    struct BytewiseSink(Arc<Mutex<Vec<u8>>>);
+    impl Write for BytewiseSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let Some(&byte) = buf.first() else { return Ok(0) };
+            self.0.lock().unwrap().push(byte);
+            std::thread::yield_now();
+            Ok(1)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// assures concurrent outputs are written as whole units
+    #[test]
+    fn concurrent_outputs_dont_interleave() {
+        let sink = BytewiseSink(Arc::new(Mutex::new(Vec::new())));
+        let messages = ["first pass: 1ms/0b; ", "second pass: 2ms/0b; ", "third pass: 3ms/0b; "];
+        std::thread::scope(|scope| {
+            for message in messages {
+                let sink = sink.clone();
+                scope.spawn(move || for _ in 0..10 { locked_write(sink.clone(), message) });
+            }
+        });
+        let written = String::from_utf8(sink.0.lock().unwrap().clone()).expect("Garbled UTF-8 output");
+        let mut remaining = written.as_str();
+        while !remaining.is_empty() {
+            let message = messages.iter().find(|&&message| remaining.starts_with(message))
+                .unwrap_or_else(|| panic!("Interleaved output at '{}'", remaining));
+            remaining = &remaining[message.len()..];
+        }
+    }
+}
@@ -1,22 +1,55 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+// unit tests still run with `std` -- its test harness, macros & collections -- even when the crate itself is built without it:
+// as `std`'s inherent float methods then take precedence over `libm`'s, those are tested on their own -- see `low_level_analysis::no_std_math`
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
 
 
+#[cfg(feature = "std")]
 mod api;
+#[cfg(feature = "std")]
 pub use api::*;
+#[cfg(feature = "std")]
 pub mod runners;
 pub mod low_level_analysis;
+#[cfg(feature = "std")]
 pub mod metrics_allocator;
+#[cfg(feature = "std")]
 mod features;
+#[cfg(feature = "std")]
 pub(crate) mod utils;
 
 // exported symbols
 pub use {
-    features::{ALLOC, OUTPUT},
+    low_level_analysis::analyse_complexity,
     low_level_analysis::types::{
-        BigOAlgorithmComplexity
+        BigOAlgorithmComplexity,
+        ComplexityBoundPair,
+        MeasurementAnomaly,
     },
+};
+#[cfg(feature = "std")]
+pub use {
+    features::{ALLOC, OUTPUT},
     runners::{
-        standard::{test_algorithm,test_constant_set_iterator_algorithm,test_set_resizing_iterator_algorithm},
-        crud::test_crud_algorithms,
+        standard::{test_algorithm,test_algorithm_with_setup_teardown,test_algorithm_growing_n,test_algorithm_threaded,test_algorithm_contextual,test_algorithm_no_space,test_algorithm_space_only,
+                   AlgorithmOptions,test_algorithm_with_options,test_algorithm_with_setup_teardown_and_options,test_algorithm_growing_n_with_options,test_algorithm_threaded_with_options,
+                   test_algorithm_contextual_with_options,test_algorithm_no_space_with_options,test_algorithm_space_only_with_options,analyse_parallel_scaling,SpeedupAnalysis,test_constant_set_iterator_algorithm,test_constant_set_iterator_algorithm_with_context,test_set_resizing_iterator_algorithm,test_set_resizing_iterator_algorithm_with_verification},
+        crud::{test_crud_algorithms, test_crud_algorithms_v2, test_crud_algorithms_with_options, test_read_update_algorithms, CrudOp, CrudOptions},
+        specialized::{test_sort_algorithm, test_search_algorithm},
+        containers::{test_crud_algorithms_for, Analyzable},
+        common::{run_iterator_pass_with_backend, try_run_iterator_pass_with_backend, run_iterator_pass_with_iteration_timings, IterationOrder, PassAbort},
+        timing::{TimingBackend, DefaultTimingBackend},
+        workload::Workload,
+        aggregation::Aggregation,
+        output::{set_output_config, reset_output_config, OutputConfig, Verbosity},
+        record::{ComplexityAssertionRecord, ComplexityAssertion},
     },
+    utils::measurements::measurer::MeasurementOrientation,
 };
+#[cfg(all(feature = "std", feature = "testing"))]
+pub use runners::timing::{MockTimingBackend, ScriptedTimingBackend};
+#[cfg(feature = "baseline")]
+pub use runners::baseline::{ComplexityBaseline, BaselineExpectation, BaselineError};
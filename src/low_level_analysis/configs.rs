@@ -1,6 +1,13 @@
-//! configurations for this sub-module, with definitions delegated to the [crate::configs] module
+//! configurations for this sub-module, with definitions delegated to the [crate::configs] module -- except for the ones the
+//! analysis needs on `no_std` builds
 
-pub use crate::features::{
-    PERCENT_TOLERANCE,
-    ALLOC,
-};
\ No newline at end of file
+#[cfg(any(feature = "tolerance_10_percent", not(any(feature = "tolerance_25_percent"))))]
+/// acceptable proportional variance (acceptable measurement errors) when analysing algorithm's time & space complexities
+pub const PERCENT_TOLERANCE: f64 = 0.10;
+
+#[cfg(feature = "tolerance_25_percent")]
+/// acceptable proportional variance (acceptable measurement errors) when analysing algorithm's time & space complexities
+pub const PERCENT_TOLERANCE: f64 = 0.25;
+
+#[cfg(feature = "std")]
+pub use crate::features::ALLOC;
@@ -4,12 +4,106 @@ use super::{
     configs::PERCENT_TOLERANCE,
     types::{BigOAlgorithmComplexity},
 };
+use core::f64::consts::{LN_2, PI};
+#[cfg(not(feature = "std"))]
+#[cfg_attr(test, allow(unused_imports))]    // tests link `std`, whose inherent float methods take precedence
+use super::no_std_math::FloatMath;
 
 
 /// Performs the Algorithm Complexity Analysis on the resource denoted by `u`, where `u1` & `u2` are the resource
 /// utilization on passes 1 & 2 and, likewise, `n1` & `n2` represent the number of element, iterations or computations
-/// -- in other words, represents the `n` in the Big-O notation... `O(n)`, `O(log(n))`, `O(n²)`, etc...
+/// -- in other words, represents the `n` in the Big-O notation... `O(n)`, `O(log(n))`, `O(n²)`, etc...\
+/// This is the classifier behind every analysis in this crate -- stable and public, for those having their own measurements.
+///
+/// # Bands
+/// The observed ratio `r = u2/u1` is compared against each class' reference ratio `m` -- what `r` would be if `u` followed the
+/// class exactly -- with `T` being the [PERCENT_TOLERANCE]. The bands are checked in the order below, the first match winning:
+///
+/// | band                                         | reference ratio `m`                              | accepted when          |
+/// |----------------------------------------------|--------------------------------------------------|------------------------|
+/// | [BetterThanO1](BigOAlgorithmComplexity::BetterThanO1) | `1`                                     | `r/m < 1-T`            |
+/// | [O1](BigOAlgorithmComplexity::O1)            | `1`                                              | `\|r/m - 1\| <= T`     |
+/// | [BetweenO1AndOLogN](BigOAlgorithmComplexity::BetweenO1AndOLogN)             | `log(n2) / log(n1)`               | `r/m < 1-T`            |
+/// | [OLogN](BigOAlgorithmComplexity::OLogN)      | `log(n2) / log(n1)`                              | `\|r/m - 1\| <= T`     |
+/// | [BetweenOLogNAndON](BigOAlgorithmComplexity::BetweenOLogNAndON)             | `n2 / n1`                         | `r/m < 1-T`            |
+/// | [ON](BigOAlgorithmComplexity::ON)            | `n2 / n1`                                        | `\|r/m - 1\| <= T`     |
+/// | [BetweenONAndONLogN](BigOAlgorithmComplexity::BetweenONAndONLogN)           | `n2.log(n2) / n1.log(n1)`         | `r/m < 1-T`            |
+/// | [ONLogN](BigOAlgorithmComplexity::ONLogN)    | `n2.log(n2) / n1.log(n1)`                        | `\|r/m - 1\| <= T`     |
+/// | [BetweenONLogNAndONLog2N](BigOAlgorithmComplexity::BetweenONLogNAndONLog2N) | `n2.log²(n2) / n1.log²(n1)` (*)   | `r/m < 1-T`            |
+/// | [ONLog2N](BigOAlgorithmComplexity::ONLog2N)  | `n2.log²(n2) / n1.log²(n1)` (*)                  | `\|r/m - 1\| <= T`     |
+/// | [BetweenONLogNAndON2](BigOAlgorithmComplexity::BetweenONLogNAndON2)         | `(n2 / n1)²`                      | `r/m < 1-T`            |
+/// | [ON2](BigOAlgorithmComplexity::ON2)          | `(n2 / n1)²`                                     | `\|r/m - 1\| <= T`     |
+/// | [BetweenON2AndON3](BigOAlgorithmComplexity::BetweenON2AndON3)               | `(n2 / n1)³`                      | `r/m < 1-T`            |
+/// | [ON3](BigOAlgorithmComplexity::ON3)          | `(n2 / n1)³`                                     | `\|r/m - 1\| <= T`     |
+/// | [BetweenON3AndON4](BigOAlgorithmComplexity::BetweenON3AndON4)               | `(n2 / n1)⁴`                      | `r/m < 1-T`            |
+/// | [ON4](BigOAlgorithmComplexity::ON4)          | `(n2 / n1)⁴`                                     | `\|r/m - 1\| <= T`     |
+/// | [BetweenON4AndOkN](BigOAlgorithmComplexity::BetweenON4AndOkN)               | (**)                              | `u2 / u1^(n2/n1) < 1-T` |
+/// | [OkN](BigOAlgorithmComplexity::OkN)          | (**)                                             | `\|u2 / u1^(n2/n1) - 1\| <= T` |
+/// | [BetweenOkNAndONFactorial](BigOAlgorithmComplexity::BetweenOkNAndONFactorial) | `n2! / n1!` (***)               | `r/m < 1-T`            |
+/// | [ONFactorial](BigOAlgorithmComplexity::ONFactorial) | `n2! / n1!` (***)                         | `\|r/m - 1\| <= T`     |
+/// | [WorseThanExponential](BigOAlgorithmComplexity::WorseThanExponential)       | --                                | otherwise              |
+///
+/// (*) for small `n`s, `log²(n)` outgrows `n` -- in which case the `O(n.log²(n))` reference is taken halfway (geometrically)
+/// between `O(n.log(n))` and `O(n²)`, so the complexity classes are always checked in increasing order.\
+/// (**) `u = kⁿ` is assumed, so `u2 = u1^(n2/n1)` -- unlike the other bands, this one depends on the magnitude of `u`, not only on `r`.\
+/// (***) the `O(n!)` ratio is computed in log space, through Stirling's series, so big `n`s don't overflow -- and these bands are only
+/// tested while `n2!/n1!` stays observable -- up to `2⁵²`: past that, anything worse than `O(kⁿ)` is `WorseThanExponential`.
+///
+/// As the bands are tested in order and are `±T` wide, close reference ratios -- from `n2/n1` near `1` -- may overlap,
+/// favouring the lesser complexity: prefer `n2` several times greater than `n1`.\
+/// A resource not used at all -- `u1` & `u2` both `0` -- has no ratio to classify, falling through to `WorseThanExponential`: only
+/// the space analyses take it as `O(1)`, see [super::space_analysis].
+///
+/// # Examples
+/// The boundaries of each band -- just inside & just outside of the tolerance -- using `n2/n1` big enough for the bands not to overlap:
+/// ```
+///     use big_o_test::{BigOAlgorithmComplexity::*, low_level_analysis::{analyse_complexity, configs::PERCENT_TOLERANCE as T}};
+///     let (n1, n2) = (2.0_f64, (1u64 << 20) as f64);
+///     let n_ratio = n2 / n1;
+///     let log_ratio = n2.log2() / n1.log2();
+///     let n_log_n_ratio = n_ratio * log_ratio;
+///     let n_log2_n_ratio = n_ratio * log_ratio.powi(2);
+///     // (reference ratio `m`, the band of `m`, the band above it)
+///     let bands = [
+///         (1.0,                O1,     BetweenO1AndOLogN),
+///         (log_ratio,          OLogN,  BetweenOLogNAndON),
+///         (n_ratio,            ON,     BetweenONAndONLogN),
+///         (n_log_n_ratio,      ONLogN, BetweenONLogNAndONLog2N),
+///         (n_log2_n_ratio,     ONLog2N, BetweenONLogNAndON2),
+///         (n_ratio.powi(2),    ON2,    BetweenON2AndON3),
+///         (n_ratio.powi(3),    ON3,    BetweenON3AndON4),
+///     ];
+///     for (m, band, band_above) in bands {
+///         assert_eq!(analyse_complexity(1.0, m * (1.0 - 0.99*T), n1, n2), band);
+///         assert_eq!(analyse_complexity(1.0, m * (1.0 + 0.99*T), n1, n2), band);
+///         assert_eq!(analyse_complexity(1.0, m * (1.0 + 1.01*T), n1, n2), band_above);
+///     }
+///     assert_eq!(analyse_complexity(1.0, 1.0 - 1.01*T, n1, n2), BetterThanO1);
+///     assert_eq!(analyse_complexity(1.0, n_ratio.powi(4) * (1.0 - 0.99*T), n1, n2), ON4);
+///     assert_eq!(analyse_complexity(1.0, n_ratio.powi(4) * (1.0 + 0.99*T), n1, n2), ON4);
+/// ```
+/// The exponential & factorial bands -- with small `n`s, so `n2!` doesn't get too big:
+/// ```
+///     use big_o_test::{BigOAlgorithmComplexity::*, low_level_analysis::{analyse_complexity, configs::PERCENT_TOLERANCE as T}};
+///     let (u1, n1, n2) = (100.0_f64, 10.0, 20.0);
+///     let exponential_u2 = u1.powf(n2 / n1);
+///     assert_eq!(analyse_complexity(u1, exponential_u2 * (1.0 - 1.01*T), n1, n2), BetweenON4AndOkN);
+///     assert_eq!(analyse_complexity(u1, exponential_u2 * (1.0 - 0.99*T), n1, n2), OkN);
+///     assert_eq!(analyse_complexity(u1, exponential_u2 * (1.0 + 0.99*T), n1, n2), OkN);
+///     assert_eq!(analyse_complexity(u1, exponential_u2 * (1.0 + 1.01*T), n1, n2), BetweenOkNAndONFactorial);
+///     let factorial_u2 = u1 * (11..=20).map(f64::from).product::<f64>();
+///     assert_eq!(analyse_complexity(u1, factorial_u2 * (1.0 - 1.01*T), n1, n2), BetweenOkNAndONFactorial);
+///     assert_eq!(analyse_complexity(u1, factorial_u2 * (1.0 - 0.99*T), n1, n2), ONFactorial);
+///     assert_eq!(analyse_complexity(u1, factorial_u2 * (1.0 + 0.99*T), n1, n2), ONFactorial);
+///     assert_eq!(analyse_complexity(u1, factorial_u2 * (1.0 + 1.01*T), n1, n2), WorseThanExponential);
+///     // `70!/10!` is way past what could be measured: no factorial bands
+///     let (u1, n1, n2) = (10.0_f64, 10.0, 70.0);
+///     assert_eq!(analyse_complexity(u1, u1.powf(n2 / n1) * (1.0 + 1.01*T), n1, n2), WorseThanExponential);
+/// ```
 pub fn analyse_complexity(u1: f64, u2: f64, n1: f64, n2: f64) -> BigOAlgorithmComplexity {
+    let n_log_n_ratio = (n2*n2.log2()) / (n1*n1.log2());
+    let n_log2_n_ratio = n_log2_n_ratio(n1, n2);
+    let ln_factorial_ratio = ln_factorial(n2) - ln_factorial(n1);
     if (u2 / u1) < 1.0 - PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::BetterThanO1
     } else if ((u2 / u1) - 1.0).abs() <= PERCENT_TOLERANCE {
@@ -22,10 +116,14 @@ pub fn analyse_complexity(u1: f64, u2: f64, n1: f64, n2: f64) -> BigOAlgorithmCo
         BigOAlgorithmComplexity::BetweenOLogNAndON
     } else if ( ((u2 / u1) / (n2 / n1)) - 1.0 ).abs() <= PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::ON
-    } else if ((u2 / u1) / ( (n2*n2.log2()) / (n1*n1.log2()) )) < 1.0 - PERCENT_TOLERANCE {
+    } else if ((u2 / u1) / n_log_n_ratio) < 1.0 - PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::BetweenONAndONLogN
-    } else if ( ((u2 / u1) / ( (n2*n2.log2()) / (n1*n1.log2()) )) - 1.0 ).abs() <= PERCENT_TOLERANCE {
+    } else if ( ((u2 / u1) / n_log_n_ratio) - 1.0 ).abs() <= PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::ONLogN
+    } else if ((u2 / u1) / n_log2_n_ratio) < 1.0 - PERCENT_TOLERANCE {
+        BigOAlgorithmComplexity::BetweenONLogNAndONLog2N
+    } else if ( ((u2 / u1) / n_log2_n_ratio) - 1.0 ).abs() <= PERCENT_TOLERANCE {
+        BigOAlgorithmComplexity::ONLog2N
     } else if ((u2 / u1) / (n2 / n1).powi(2)) < 1.0 - PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::BetweenONLogNAndON2
     } else if ( ((u2 / u1) / (n2 / n1).powi(2)) - 1.0 ).abs() <= PERCENT_TOLERANCE {
@@ -42,11 +140,108 @@ pub fn analyse_complexity(u1: f64, u2: f64, n1: f64, n2: f64) -> BigOAlgorithmCo
         BigOAlgorithmComplexity::BetweenON4AndOkN
     } else if ( (u2 / u1.powf(n2/n1)) - 1.0 ).abs() <= PERCENT_TOLERANCE {
         BigOAlgorithmComplexity::OkN
+    } else if ln_factorial_ratio <= MAX_LN_FACTORIAL_RATIO && ((u2 / u1).ln() - ln_factorial_ratio) < (1.0 - PERCENT_TOLERANCE).ln() {
+        BigOAlgorithmComplexity::BetweenOkNAndONFactorial
+    } else if ln_factorial_ratio <= MAX_LN_FACTORIAL_RATIO && ( ((u2 / u1).ln() - ln_factorial_ratio).exp() - 1.0 ).abs() <= PERCENT_TOLERANCE {
+        BigOAlgorithmComplexity::ONFactorial
     } else {
         BigOAlgorithmComplexity::WorseThanExponential
     }
 }
 
+/// The biggest `ln(n2!/n1!)` for which [analyse_complexity()] tests the `O(n!)` bands: `ln(2⁵²)`. Even from a 1ns pass 1, a pass 2
+/// `2⁵²` times longer would take ~52 days -- so, for bigger `n`s, no measurement could ever land near `O(n!)` and the whole range above
+/// `O(kⁿ)` is left to `WorseThanExponential` (rather than to a `BetweenOkNAndONFactorial` band no real algorithm would leave)
+const MAX_LN_FACTORIAL_RATIO: f64 = 52.0 * LN_2;
+
+/// How close -- in the normalized ratios `r/m` of [analyse_complexity()] -- a measurement may get to the edge of a band (`1-T` or `1+T`)
+/// before being considered too close to call: 2 percentage points for the default 10% [PERCENT_TOLERANCE].\
+/// Notice a `2*T` margin -- as once sketched -- would be wider than the `±T` bands themselves: every measurement would be near a
+/// boundary. A fifth of `T` flags only the outer fifth of each band (and as much outside of it), keeping the warning meaningful.
+const NEAR_BOUNDARY_MARGIN: f64 = PERCENT_TOLERANCE / 5.0;
+
+/// Tells if the resource utilization `u1` & `u2` -- measured on passes with `n1` & `n2` elements -- lies so close to the edge between
+/// two of the bands of [analyse_complexity()] that a bit of measurement noise could have tipped its classification: the normalized ratio
+/// `r/m` of any class being within `PERCENT_TOLERANCE/5` of `1-T` or `1+T`.\
+/// A `true` outcome doesn't invalidate the classification, but hints it deserves a retry -- or more iterations, for a steadier `u`.
+pub fn is_near_boundary(u1: f64, u2: f64, n1: f64, n2: f64) -> bool {
+    let r = u2 / u1;
+    let n_ratio = n2 / n1;
+    let normalized_ratios = [
+        r,
+        r / (n2.log2() / n1.log2()),
+        r / n_ratio,
+        r / ((n2*n2.log2()) / (n1*n1.log2())),
+        r / n_log2_n_ratio(n1, n2),
+        r / n_ratio.powi(2),
+        r / n_ratio.powi(3),
+        r / n_ratio.powi(4),
+        u2 / u1.powf(n_ratio),
+    ];
+    let ln_factorial_ratio = ln_factorial(n2) - ln_factorial(n1);
+    let factorial_ratio = (ln_factorial_ratio <= MAX_LN_FACTORIAL_RATIO).then(|| (r.ln() - ln_factorial_ratio).exp());
+    normalized_ratios.into_iter().chain(factorial_ratio)
+        .any(|normalized_ratio| [1.0 - PERCENT_TOLERANCE, 1.0 + PERCENT_TOLERANCE].into_iter()
+            .any(|band_edge| (normalized_ratio - band_edge).abs() <= NEAR_BOUNDARY_MARGIN))
+}
+
+/// Same as [is_near_boundary()], for the bands of [analyse_set_resizing_iterator_complexity()] -- with `n` elements added or removed
+/// on each pass: the normalized ratios `r/m` for the references `1`, `log2(3n)/log2(n)` & `3` being close to the edges that function checks.
+pub fn is_set_resizing_near_boundary(u1: f64, u2: f64, n: f64) -> bool {
+    let r = u2 / u1;
+    let log_ratio = (n * 3.0).log2() / n.log2();
+    // (normalized ratio, the edges it is checked against)
+    let band_edges = [
+        (r,             [1.0 / (1.0 + PERCENT_TOLERANCE), 1.0 + PERCENT_TOLERANCE]),
+        (r / log_ratio, [1.0,                             1.0 + PERCENT_TOLERANCE]),
+        (r / 3.0,       [1.0 - PERCENT_TOLERANCE,         1.0 + PERCENT_TOLERANCE]),
+    ];
+    band_edges.into_iter()
+        .any(|(normalized_ratio, edges)| edges.into_iter()
+            .any(|band_edge| (normalized_ratio - band_edge).abs() <= NEAR_BOUNDARY_MARGIN))
+}
+
+/// The `O(n.log²(n))` reference ratio -- see the (*) note on [analyse_complexity()]
+fn n_log2_n_ratio(n1: f64, n2: f64) -> f64 {
+    let n_log_n_ratio = (n2*n2.log2()) / (n1*n1.log2());
+    let n_log2_n_ratio = (n2*n2.log2().powi(2)) / (n1*n1.log2().powi(2));
+    let n2_ratio = (n2 / n1).powi(2);
+    if n_log2_n_ratio < n2_ratio {
+        n_log2_n_ratio
+    } else {
+        (n_log_n_ratio * n2_ratio).sqrt()
+    }
+}
+
+/// Estimates the constant factor (the leading coefficient) of the matched `complexity` model, for a resource utilization of `u`
+/// on a set of `n` elements: `u / log2(n)` for `O(log(n))`, `u / n` for `O(n)`, `u / (n*log2(n))` for `O(n.log(n))` and so on.\
+/// Returns `None` for the complexities without a model to match against -- the ones between two classes, `O(kⁿ)` (whose `k` is unknown)
+/// and the ones better than `O(1)` or worse than `O(n!)`.
+pub fn complexity_coefficient(complexity: BigOAlgorithmComplexity, u: f64, n: f64) -> Option<f64> {
+    let model = match complexity {
+        BigOAlgorithmComplexity::O1          => 1.0,
+        BigOAlgorithmComplexity::OLogN       => n.log2(),
+        BigOAlgorithmComplexity::ON          => n,
+        BigOAlgorithmComplexity::ONLogN      => n * n.log2(),
+        BigOAlgorithmComplexity::ONLog2N     => n * n.log2().powi(2),
+        BigOAlgorithmComplexity::ON2         => n.powi(2),
+        BigOAlgorithmComplexity::ON3         => n.powi(3),
+        BigOAlgorithmComplexity::ON4         => n.powi(4),
+        BigOAlgorithmComplexity::ONFactorial => ln_factorial(n).exp(),
+        _ => return None,
+    };
+    (model > 0.0 && model.is_finite()).then(|| u / model)
+}
+
+/// `ln(n!)`, through Stirling's series -- precise even for small `n`s, without ever computing `n!`
+pub(crate) fn ln_factorial(n: f64) -> f64 {
+    if n < 1.0 {
+        0.0
+    } else {
+        n * n.ln() - n + 0.5 * (2.0 * PI * n).ln() + 1.0 / (12.0 * n) - 1.0 / (360.0 * n.powi(3))
+    }
+}
+
 /// TODO 2022-06-30: fix the math here (and tests) to the same standards as the function above -- and also include the increased complexity levels
 /// Performs the Algorithm Complexity Analysis on an iterator algorithm that alters the elements it operates on as it runs.\
 ///   - `u1` & `u2` are the resource utilization on passes 1 & 2
@@ -78,12 +273,14 @@ mod tests {
     //! Unit tests for [low_level_analysis](super) module -- using 'serial_test' crate in order to make time measurements more reliable.
 
     use super::*;
+    use crate::low_level_analysis::types::BigOAlgorithmComplexity;
+    #[cfg(feature = "std")]
     use crate::{
-        features::{OUTPUT},
+        features::OUTPUT,
         low_level_analysis::{
             types::{
                 BigOIteratorAlgorithmType,
-                BigOAlgorithmComplexity, BigOAlgorithmAnalysis,
+                BigOAlgorithmAnalysis, BigOPassMeasurements,
                 BigOTimeMeasurements, BigOSpaceMeasurements,
                 ConstantSetIteratorAlgorithmPassesInfo, SetResizingIteratorAlgorithmPassesInfo,
                 ConstantSetIteratorAlgorithmMeasurements, SetResizingIteratorAlgorithmMeasurements,
@@ -91,13 +288,11 @@ mod tests {
             time_analysis::*,
             space_analysis::*
         },
-        runners::common::{run_iterator_pass_verbosely},
-    };
-    use std::{
-        time::{Duration},
+        runners::common::{run_iterator_pass_verbosely, IterationOrder},
     };
+    #[cfg(feature = "std")]
+    use std::time::Duration;
     use serial_test::serial;
-    use crate::low_level_analysis::types::BigOPassMeasurements;
 
     /// test algorithm complexity analysis progression when resource utilization increase for regular, non-iterator algorithms
     /// and for constant set iterator algorithms
@@ -105,18 +300,87 @@ mod tests {
     #[serial]
     fn smooth_transitions() {
         let mut last_complexity = BigOAlgorithmComplexity::BetterThanO1;
-        for u2 in 0..11_000_001 {
-            let current_complexity = analyse_complexity(10.0, u2 as f64, 2.0, 14.0);
+        let mut check_transition = |previous_u2: f64, u2: f64| {
+            let current_complexity = analyse_complexity(10.0, u2, 2.0, 14.0);
             let delta = current_complexity as i32 - last_complexity as i32;
-            assert!(delta == 0 || delta == 1, "'analyse_complexity(..., {}, ..., ...)' suddenly went from {:?} to {:?} when `u2` when from {} to {}", u2, last_complexity, current_complexity, u2-1, u2);
+            assert!(delta == 0 || delta == 1, "'analyse_complexity(..., {}, ..., ...)' suddenly went from {:?} to {:?} when `u2` when from {} to {}", u2, last_complexity, current_complexity, previous_u2, u2);
             if delta == 1 {
                 last_complexity = current_complexity;
                 eprintln!("'analyse_complexity(...)' transitioned to {:?} when `u2`={}", current_complexity, u2);
             }
+        };
+        for u2 in 0..11_000_001 {
+            check_transition(u2 as f64 - 1.0, u2 as f64);
+        }
+        // `O(n!)` is only reached at `u2` ~ `10 * 14!/2!` -- so, from now on, increments are geometric
+        let mut u2 = 11_000_000.0;
+        while u2 < 1e12 {
+            check_transition(u2, u2 * 1.0001);
+            u2 *= 1.0001;
         }
         assert_eq!(last_complexity, BigOAlgorithmComplexity::WorseThanExponential, "Please update this test to cycle through all variants of `BigOAlgorithmComplexity`");
     }
 
+    /// assures a resource not used at all isn't taken as `O(1)` -- its `0/0` ratio can't be classified -- see [space_analysis](super::space_analysis)
+    /// for the analyses that do so
+    #[test]
+    fn no_resource_usage() {
+        assert_eq!(analyse_complexity(0.0, 0.0, 1000.0, 2000.0), BigOAlgorithmComplexity::WorseThanExponential, "A `0/0` ratio should fall through every band");
+    }
+
+    /// assures the constant factors are recovered from the models of each complexity -- and not estimated when there is no model
+    #[test]
+    fn coefficients() {
+        let n = 1024.0;
+        for (complexity, u) in [(BigOAlgorithmComplexity::O1,     12.0),
+                                (BigOAlgorithmComplexity::OLogN,  12.0 * 10.0),
+                                (BigOAlgorithmComplexity::ON,     12.0 * 1024.0),
+                                (BigOAlgorithmComplexity::ONLogN, 12.0 * 1024.0 * 10.0),
+                                (BigOAlgorithmComplexity::ON2,    12.0 * 1024.0 * 1024.0)] {
+            let coefficient = complexity_coefficient(complexity, u, n).unwrap_or_else(|| panic!("{:?} should have a coefficient", complexity));
+            assert!((coefficient - 12.0).abs() < 1e-9, "Wrong coefficient for {:?}: {}", complexity, coefficient);
+        }
+        let factorial_coefficient = complexity_coefficient(BigOAlgorithmComplexity::ONFactorial, 3.0 * 40320.0, 8.0).unwrap();
+        assert!((factorial_coefficient - 3.0).abs() < 1e-3, "Wrong O(n!) coefficient: {}", factorial_coefficient);
+        for complexity in [BigOAlgorithmComplexity::BetterThanO1, BigOAlgorithmComplexity::BetweenOLogNAndON, BigOAlgorithmComplexity::OkN, BigOAlgorithmComplexity::WorseThanExponential] {
+            assert_eq!(complexity_coefficient(complexity, 1.0, n), None, "{:?} has no model to estimate a coefficient from", complexity);
+        }
+    }
+
+    /// assures measurements close to the edges of the complexity bands -- and only them -- are flagged as near a boundary
+    #[test]
+    fn near_boundary() {
+        let (n1, n2) = (2.0_f64, (1u64 << 20) as f64);
+        let n_ratio = n2 / n1;
+        for m in [1.0, n2.log2() / n1.log2(), n_ratio, n_ratio.powi(2)] {
+            assert!( is_near_boundary(1.0, m * (1.0 + PERCENT_TOLERANCE),       n1, n2), "A ratio on the upper edge of a band should be near its boundary (m={})", m);
+            assert!( is_near_boundary(1.0, m * (1.0 - PERCENT_TOLERANCE * 1.1), n1, n2), "A ratio just below a band should be near its boundary (m={})", m);
+            assert!(!is_near_boundary(1.0, m,                                   n1, n2), "A ratio right at a band's reference should not be near a boundary (m={})", m);
+            assert!(!is_near_boundary(1.0, m * (1.0 + PERCENT_TOLERANCE * 1.6), n1, n2), "A ratio well past a band's edge should not be near a boundary (m={})", m);
+        }
+        assert!(!is_near_boundary(0.0, 0.0, n1, n2), "A `0/0` ratio isn't near any band");
+    }
+
+    /// assures the set resizing analysis has its own boundaries checked -- its references differ from [analyse_complexity()]'s
+    #[test]
+    fn set_resizing_near_boundary() {
+        let n = 1000.0_f64;
+        let log_ratio = (n * 3.0).log2() / n.log2();
+        for m in [1.0, log_ratio, 3.0] {
+            assert!( is_set_resizing_near_boundary(1.0, m * (1.0 + PERCENT_TOLERANCE), n), "A ratio on the upper edge of a band should be near its boundary (m={})", m);
+        }
+        // O(1)'s & O(log(n))'s bands are close enough, for this `n`, for `1.16` to be on the edge of the latter
+        for m in [log_ratio, 3.0] {
+            assert!(!is_set_resizing_near_boundary(1.0, m * (1.0 + PERCENT_TOLERANCE * 1.6), n), "A ratio well past a band's edge should not be near a boundary (m={})", m);
+        }
+        assert!( is_set_resizing_near_boundary(1.0, log_ratio * 0.99, n), "O(log(n))'s lower edge is its reference itself");
+        assert!(!is_set_resizing_near_boundary(1.0, 3.0, n), "A ratio right at O(n)'s reference should not be near a boundary");
+        assert!(!is_set_resizing_near_boundary(0.0, 0.0, n), "A `0/0` ratio isn't near any band");
+        // the regular analysis' references would have flagged -- or missed -- other ratios
+        assert_ne!(is_set_resizing_near_boundary(1.0, log_ratio * 0.99, n), is_near_boundary(1.0, log_ratio * 0.99, n * 0.5, n * 1.5),
+                   "The set resizing boundaries should differ from the regular ones");
+    }
+
     /// test algorithm complexity analysis progression when resource utilization increase for set resizing iterator algorithms
     #[test]
     #[serial]
@@ -135,9 +399,10 @@ mod tests {
     }
 
 
-    /// tests time & space complexity analysis on real constant set algorithms
+    /// tests time & space complexity analysis on real constant set algorithms -- measured by the runners, which require `std`
     #[test]
     #[serial]
+    #[cfg(feature = "std")]
     fn analyse_constant_set_algorithm_real_test() {
 
         const REPETITIONS: u32 = 1024;
@@ -188,14 +453,14 @@ mod tests {
         let analyze = |measurement_name, select_function: fn(u32) -> u32| {
             OUTPUT(&format!("Real '{}', fetching {} elements on each pass ", measurement_name, REPETITIONS));
 
-            let (_warmup_result               , r1) = run_iterator_pass_verbosely("(warmup: ", "",    &select_function, &BigOIteratorAlgorithmType::ConstantSet, 0 .. REPETITIONS, 1, OUTPUT);
-            let (pass_1_result, r2) = run_iterator_pass_verbosely("; pass1: ", "",    &select_function, &BigOIteratorAlgorithmType::ConstantSet, 0 .. PASS_1_SET_SIZE, 1, OUTPUT);
-            let (pass_2_result, r3) = run_iterator_pass_verbosely("; pass2: ", "): ", &select_function, &BigOIteratorAlgorithmType::ConstantSet, PASS_2_SET_SIZE - REPETITIONS .. PASS_2_SET_SIZE, 1, OUTPUT);
+            let (_warmup_result               , r1) = run_iterator_pass_verbosely("(warmup: ", "",    &select_function, &BigOIteratorAlgorithmType::ConstantSet, 0 .. REPETITIONS, IterationOrder::Descending, 1, OUTPUT);
+            let (pass_1_result, r2) = run_iterator_pass_verbosely("; pass1: ", "",    &select_function, &BigOIteratorAlgorithmType::ConstantSet, 0 .. PASS_1_SET_SIZE, IterationOrder::Descending, 1, OUTPUT);
+            let (pass_2_result, r3) = run_iterator_pass_verbosely("; pass2: ", "): ", &select_function, &BigOIteratorAlgorithmType::ConstantSet, PASS_2_SET_SIZE - REPETITIONS .. PASS_2_SET_SIZE, IterationOrder::Descending, 1, OUTPUT);
 
             let constant_set_passes_info = ConstantSetIteratorAlgorithmPassesInfo {
-                pass_1_set_size: PASS_1_SET_SIZE,
-                pass_2_set_size: PASS_2_SET_SIZE,
-                repetitions: REPETITIONS,
+                pass_1_set_size: PASS_1_SET_SIZE.into(),
+                pass_2_set_size: PASS_2_SET_SIZE.into(),
+                repetitions: REPETITIONS.into(),
             };
 
             let time_measurements = BigOTimeMeasurements {
@@ -214,6 +479,8 @@ mod tests {
             let algorithm_analysis = BigOAlgorithmAnalysis {
                 time_complexity,
                 space_complexity,
+                time_coefficient: None,
+                is_near_boundary: false,
                 algorithm_measurements: ConstantSetIteratorAlgorithmMeasurements {
                     measurement_name,
                     passes_info: constant_set_passes_info,
@@ -255,9 +522,10 @@ mod tests {
 
     }
 
-    /// tests time & space complexity analysis on real set resizing algorithms
+    /// tests time & space complexity analysis on real set resizing algorithms -- measured by the runners, which require `std`
     #[test]
     #[serial]
+    #[cfg(feature = "std")]
     fn analyse_set_resizing_algorithm_real_test() {
 
         const DELTA_SET_SIZE: u32 = 1024;
@@ -274,7 +542,7 @@ mod tests {
             let mut len = if n==DELTA_SET_SIZE-1 {DELTA_SET_SIZE*2/3} else {0};
             while n > 0 {
                 r ^= operation_simulator();
-                n = n/2;
+                n /= 2;
                 len += n;
             }
             let vec = Vec::<u32>::with_capacity(len as usize);
@@ -287,22 +555,22 @@ mod tests {
             let len = if n<=DELTA_SET_SIZE {(n/20)*(n/20)} else {(n/20)*(n/20)-(n/40)*(n/40)};
             while n > 1 {
                 r ^= operation_simulator();
-                n = n-2;
+                n -= 2;
             }
             let vec = Vec::<u32>::with_capacity(len as usize * 400);
-            r ^ (len as u32 + vec.iter().sum::<u32>())
+            r ^ (len + vec.iter().sum::<u32>())
         }
 
         let analyze = |measurement_name, insert_function: fn(u32) -> u32| {
             OUTPUT(&format!("Real '{}' with {} elements on each pass ", measurement_name, DELTA_SET_SIZE));
 
             /* warmup pass -- container / database should be reset before and after this */
-            let (_warmup_result,                r1) = run_iterator_pass_verbosely("(warmup: ", "", &insert_function, &BigOIteratorAlgorithmType::SetResizing, 0 .. DELTA_SET_SIZE, 1, OUTPUT);
+            let (_warmup_result,                r1) = run_iterator_pass_verbosely("(warmup: ", "", &insert_function, &BigOIteratorAlgorithmType::SetResizing, 0 .. DELTA_SET_SIZE, IterationOrder::Descending, 1, OUTPUT);
             /* if we were operating on real data, we would reset the container / database after the warmup, before running pass 1 */
-            let (pass_1_result, r2) = run_iterator_pass_verbosely("; pass1: ", "", &insert_function, &BigOIteratorAlgorithmType::SetResizing, 0 ..DELTA_SET_SIZE, 1, OUTPUT);
-            let (pass_2_result, r3) = run_iterator_pass_verbosely("; pass2: ", "): ", &insert_function, &BigOIteratorAlgorithmType::SetResizing, DELTA_SET_SIZE.. DELTA_SET_SIZE * 2, 1, OUTPUT);
+            let (pass_1_result, r2) = run_iterator_pass_verbosely("; pass1: ", "", &insert_function, &BigOIteratorAlgorithmType::SetResizing, 0 ..DELTA_SET_SIZE, IterationOrder::Descending, 1, OUTPUT);
+            let (pass_2_result, r3) = run_iterator_pass_verbosely("; pass2: ", "): ", &insert_function, &BigOIteratorAlgorithmType::SetResizing, DELTA_SET_SIZE.. DELTA_SET_SIZE * 2, IterationOrder::Descending, 1, OUTPUT);
 
-            let set_resizing_passes_info = SetResizingIteratorAlgorithmPassesInfo { delta_set_size: DELTA_SET_SIZE };
+            let set_resizing_passes_info = SetResizingIteratorAlgorithmPassesInfo { delta_set_size: DELTA_SET_SIZE.into() };
 
             let time_measurements = BigOTimeMeasurements {
                 pass_1_measurements: pass_1_result.time_measurements,
@@ -320,6 +588,8 @@ mod tests {
             let algorithm_analysis = BigOAlgorithmAnalysis {
                 time_complexity,
                 space_complexity,
+                time_coefficient: None,
+                is_near_boundary: false,
                 algorithm_measurements: SetResizingIteratorAlgorithmMeasurements {
                     measurement_name,
                     passes_info: set_resizing_passes_info,
@@ -351,6 +621,7 @@ mod tests {
     }
 
    #[inline]
+   #[cfg(feature = "std")]
    /// simulates a cpu bound operation using precise sleeping --
    /// a random number is returned to avoid any call cancellation optimizations
     fn operation_simulator() -> u32 {
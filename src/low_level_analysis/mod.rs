@@ -1,13 +1,22 @@
 //! Exports time & space algorithm complexity analysis functions, as well as the needed types to operate on them. See:
 //!   - [time_analysis]
 //!   - [space_analysis]
+//!   - [regression]
 //!   - [types]
 //!
 //! ... and, most importantly, tests both analysis on real functions. See [low_level_analysis::tests].
+//!
+//! Without the (default) `std` feature, this is all the crate provides: the analysis of measurements gathered elsewhere -- like on embedded
+//! targets -- minus the reports' renderings (which need `String`s) & the [regression] (which needs `Vec`s).
 
+#[allow(clippy::module_inception)]
 mod low_level_analysis;
 pub use low_level_analysis::*;
 pub mod types;
 pub mod time_analysis;
 pub mod space_analysis;
+#[cfg(feature = "std")]
+pub mod regression;
 pub mod configs;
+#[cfg(not(feature = "std"))]
+mod no_std_math;
@@ -0,0 +1,57 @@
+//! The `f64` & `f32` functions the analysis uses that `core` lacks -- provided by `libm` on `no_std` builds.\
+//! Brought into scope with `use super::no_std_math::FloatMath`, so the analysis code reads the same on both builds.
+
+/// `libm` backed counterparts of the `std`-only float methods
+pub(crate) trait FloatMath {
+    fn log2(self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+}
+impl FloatMath for f64 {
+    fn log2(self) -> Self { libm::log2(self) }
+    fn ln(self) -> Self { libm::log(self) }
+    fn exp(self) -> Self { libm::exp(self) }
+    fn sqrt(self) -> Self { libm::sqrt(self) }
+    fn powf(self, exponent: Self) -> Self { libm::pow(self, exponent) }
+    fn powi(self, exponent: i32) -> Self { libm::pow(self, f64::from(exponent)) }
+}
+impl FloatMath for f32 {
+    fn log2(self) -> Self { libm::log2f(self) }
+    fn ln(self) -> Self { libm::logf(self) }
+    fn exp(self) -> Self { libm::expf(self) }
+    fn sqrt(self) -> Self { libm::sqrtf(self) }
+    fn powf(self, exponent: Self) -> Self { libm::powf(self, exponent) }
+    fn powi(self, exponent: i32) -> Self { libm::powf(self, exponent as f32) }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [no_std_math](super) module
+
+    use super::*;
+
+
+    /// assures the `libm` backed functions -- called explicitly, as `std`'s inherent methods would take precedence -- agree with `std`'s
+    #[test]
+    fn agrees_with_std() {
+        let close = |libm: f64, std: f64| (libm - std).abs() <= 1e-12 * std.abs().max(1.0);
+        for x in [0.5, 1.0, 2.0, 3.0, 1000.0, 1e9] {
+            assert!(close(FloatMath::log2(x), x.log2()), "log2({x}) disagrees");
+            assert!(close(FloatMath::ln(x), x.ln()), "ln({x}) disagrees");
+            assert!(close(FloatMath::sqrt(x), x.sqrt()), "sqrt({x}) disagrees");
+            assert!(close(FloatMath::powf(x, 1.5), x.powf(1.5)), "powf({x}, 1.5) disagrees");
+            assert!(close(FloatMath::powi(x, 3), x.powi(3)), "powi({x}, 3) disagrees");
+        }
+        for x in [-2.0, 0.0, 1.0, 10.0] {
+            assert!(close(FloatMath::exp(x), x.exp()), "exp({x}) disagrees");
+        }
+        assert!((FloatMath::log2(8.0_f32) - 3.0).abs() < 1e-6 && (FloatMath::sqrt(2.0_f32) - 2.0_f32.sqrt()).abs() < 1e-6, "The f32 functions disagree");
+        assert!((FloatMath::ln(1.0_f32)).abs() < 1e-6 && (FloatMath::exp(0.0_f32) - 1.0).abs() < 1e-6 && (FloatMath::powf(2.0_f32, 3.0) - 8.0).abs() < 1e-6
+                && (FloatMath::powi(2.0_f32, 3) - 8.0).abs() < 1e-6, "The f32 functions disagree");
+    }
+}
@@ -0,0 +1,126 @@
+//! Complexity analysis through least-squares regression -- for when more than 2 passes were measured.\
+//! Where [super::analyse_complexity()] classifies the ratio between 2 data points, the functions here fit `u = a + b * f(n)`
+//! to any number of them, for each complexity class' `f(n)`, picking the class that best explains the measurements
+//! -- so a single noisy pass doesn't determine the outcome.
+
+use super::{
+    configs::PERCENT_TOLERANCE,
+    low_level_analysis::ln_factorial,
+    types::BigOAlgorithmComplexity,
+};
+
+
+/// `f(n)` -- the growth function of a complexity class
+type GrowthFn = fn(f64) -> f64;
+
+/// The complexity classes tried by [analyse_complexity_by_regression()], in increasing order, together with their `f(n)`
+/// -- `O(1)` is handled apart, as a constant has no slope to be fitted.\
+/// `O(kⁿ)` is fitted as `2ⁿ`: `k` can't be absorbed by the slope, so exponential growths of other bases fit it less closely
+const MODELS: [(BigOAlgorithmComplexity, GrowthFn); 9] = [
+    (BigOAlgorithmComplexity::OLogN,       |n| n.log2()),
+    (BigOAlgorithmComplexity::ON,          |n| n),
+    (BigOAlgorithmComplexity::ONLogN,      |n| n * n.log2()),
+    (BigOAlgorithmComplexity::ONLog2N,     |n| n * n.log2().powi(2)),
+    (BigOAlgorithmComplexity::ON2,         |n| n.powi(2)),
+    (BigOAlgorithmComplexity::ON3,         |n| n.powi(3)),
+    (BigOAlgorithmComplexity::ON4,         |n| n.powi(4)),
+    (BigOAlgorithmComplexity::OkN,         |n| n.exp2()),
+    (BigOAlgorithmComplexity::ONFactorial, |n| ln_factorial(n).exp()),
+];
+
+/// Performs the Algorithm Complexity Analysis on the `(n, u)` `data_points` -- `u` being the resource utilization (time, space...)
+/// measured for `n` elements -- by fitting `u = a + b * f(n)` through least-squares, for the `f(n)` of each complexity class.\
+/// Returns the class with the minimum sum of squared residuals, together with its coefficient of determination (`R²`):
+/// the fraction of the variance of `u` explained by the fit -- `1.0` being a perfect one.\
+/// If all `u`s are within [PERCENT_TOLERANCE] of their mean, `O(1)` is returned -- with `R²` of `1.0`, as a constant
+/// explains flat measurements completely. Fits with a negative slope are discarded, as are the ones whose `f(n)` overflows.\
+/// Panics if less than 3 data points are given -- any 2 of them are perfectly fitted by every class.
+pub fn analyse_complexity_by_regression(data_points: &[(f64, f64)]) -> (BigOAlgorithmComplexity, f64) {
+    assert!(data_points.len() >= 3, "analyse_complexity_by_regression(): at least 3 data points are needed, but only {} were given", data_points.len());
+    let u_mean = data_points.iter().map(|&(_n, u)| u).sum::<f64>() / data_points.len() as f64;
+    let u_total_squares = data_points.iter().map(|&(_n, u)| (u - u_mean).powi(2)).sum::<f64>();
+    if data_points.iter().all(|&(_n, u)| (u - u_mean).abs() <= u_mean.abs() * PERCENT_TOLERANCE) {
+        return (BigOAlgorithmComplexity::O1, 1.0)
+    }
+    MODELS.iter()
+        .filter_map(|(complexity, f)| sum_of_squared_residuals(data_points, *f).map(|residuals| (*complexity, residuals)))
+        // on ties, the lesser complexity -- the first one -- is kept
+        .min_by(|(_, residuals_a), (_, residuals_b)| residuals_a.total_cmp(residuals_b))
+        .map(|(complexity, residuals)| (complexity, 1.0 - residuals / u_total_squares))
+        // no increasing model fits: the resource utilization decreases as `n` grows
+        .unwrap_or((BigOAlgorithmComplexity::BetterThanO1, 0.0))
+}
+
+/// fits `u = a + b * f(n)` to `data_points`, returning the sum of the squared residuals --
+/// or `None` if the fit can't be done (`f(n)` overflows or is constant) or has a negative slope
+fn sum_of_squared_residuals(data_points: &[(f64, f64)], f: GrowthFn) -> Option<f64> {
+    let xs = data_points.iter().map(|&(n, _u)| f(n)).collect::<Vec<f64>>();
+    if !xs.iter().all(|x| x.is_finite()) {
+        return None
+    }
+    let len = data_points.len() as f64;
+    let x_mean = xs.iter().sum::<f64>() / len;
+    let u_mean = data_points.iter().map(|&(_n, u)| u).sum::<f64>() / len;
+    let x_squares = xs.iter().map(|x| (x - x_mean).powi(2)).sum::<f64>();
+    let xu_products = xs.iter().zip(data_points).map(|(x, &(_n, u))| (x - x_mean) * (u - u_mean)).sum::<f64>();
+    let b = xu_products / x_squares;
+    if !b.is_finite() || b < 0.0 {
+        return None
+    }
+    let a = u_mean - b * x_mean;
+    Some(xs.iter().zip(data_points).map(|(x, &(_n, u))| (u - (a + b * x)).powi(2)).sum())
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [regression](super) module
+
+    use super::*;
+
+
+    /// builds `(n, u)` data points for `u = a + b * f(n)`, with each `u` disturbed by the given relative `noise` pattern
+    fn data_points(f: impl Fn(f64) -> f64, a: f64, b: f64, noise: &[f64]) -> Vec<(f64, f64)> {
+        [1000.0, 2000.0, 4000.0, 8000.0, 16000.0, 32000.0].into_iter()
+            .zip(noise.iter().cycle())
+            .map(|(n, noise)| (n, (a + b * f(n)) * (1.0 + noise)))
+            .collect()
+    }
+
+    /// checks each complexity class is recognized from its own (slightly noisy) model, with a good `R²`
+    #[test]
+    fn regression() {
+        const NOISE: [f64; 3] = [0.01, -0.01, 0.0];
+        let cases: [(BigOAlgorithmComplexity, GrowthFn); 5] = [
+            (BigOAlgorithmComplexity::OLogN,  |n| n.log2()),
+            (BigOAlgorithmComplexity::ON,     |n| n),
+            (BigOAlgorithmComplexity::ONLogN, |n| n * n.log2()),
+            (BigOAlgorithmComplexity::ON2,    |n| n.powi(2)),
+            (BigOAlgorithmComplexity::ON3,    |n| n.powi(3)),
+        ];
+        for (expected_complexity, f) in cases {
+            let (complexity, r_squared) = analyse_complexity_by_regression(&data_points(f, 1e-9, 1e-9, &NOISE));
+            assert_eq!(complexity, expected_complexity, "Wrong complexity fitted");
+            assert!(r_squared > 0.99, "R² for {:?} should be close to 1, but is {}", expected_complexity, r_squared);
+        }
+        let exponential_data_points = [4.0_f64, 8.0, 12.0, 16.0, 20.0, 24.0].into_iter()
+            .zip(NOISE.iter().cycle())
+            .map(|(n, noise)| (n, (1e-9 + 1e-9 * n.exp2()) * (1.0 + noise)))
+            .collect::<Vec<_>>();
+        let (complexity, r_squared) = analyse_complexity_by_regression(&exponential_data_points);
+        assert_eq!(complexity, BigOAlgorithmComplexity::OkN, "Wrong complexity fitted");
+        assert!(r_squared > 0.99, "R² for OkN should be close to 1, but is {}", r_squared);
+        assert_eq!(analyse_complexity_by_regression(&data_points(|_n| 1.0, 1e-6, 0.0, &NOISE)), (BigOAlgorithmComplexity::O1, 1.0),
+                   "Flat measurements should be O(1)");
+        assert_eq!(analyse_complexity_by_regression(&data_points(|n| 1.0 / n, 0.0, 1.0, &[0.0])).0, BigOAlgorithmComplexity::BetterThanO1,
+                   "Decreasing measurements should be better than O(1)");
+    }
+
+    /// assures too few data points are refused
+    #[test]
+    #[should_panic(expected = "at least 3 data points are needed")]
+    fn too_few_data_points() {
+        analyse_complexity_by_regression(&[(1.0, 1.0), (2.0, 2.0)]);
+    }
+}
@@ -22,7 +22,42 @@ pub fn analyse_space_complexity(passes_info:  &AlgorithmPassesInfo,
     let n1 = passes_info.pass1_n as f64;
     let n2 = passes_info.pass2_n as f64;
 
-    analyse_complexity(s1, s2, n1, n2)
+    analyse_memory_complexity(s1, s2, n1, n2)
+}
+
+/// Performs space complexity analysis on the *retained memory* of regular, non-iterator algorithms -- the memory still
+/// allocated when the algorithm finishes (`used_memory_after - used_memory_before`), such as the output of a `map()`.\
+/// See [analyse_auxiliary_space_complexity()] for the transient (scratch) counterpart.
+pub fn analyse_retained_space_complexity(passes_info:  &AlgorithmPassesInfo,
+                                         measurements: &BigOSpaceMeasurements) -> BigOAlgorithmComplexity {
+
+    // memory left allocated
+    let s1 = measurements.pass_1_measurements.net_delta().max(0) as f64;
+    let s2 = measurements.pass_2_measurements.net_delta().max(0) as f64;
+
+    // set sizes
+    let n1 = passes_info.pass1_n as f64;
+    let n2 = passes_info.pass2_n as f64;
+
+    analyse_memory_complexity(s1, s2, n1, n2)
+}
+
+/// Performs space complexity analysis on the *auxiliary memory* of regular, non-iterator algorithms -- the scratch memory
+/// that was allocated but freed before the algorithm finished (`max_used_memory - used_memory_after`), such as the temporary
+/// buffers of a merge sort.\
+/// See [analyse_retained_space_complexity()] for the memory kept after the algorithm finishes.
+pub fn analyse_auxiliary_space_complexity(passes_info:  &AlgorithmPassesInfo,
+                                          measurements: &BigOSpaceMeasurements) -> BigOAlgorithmComplexity {
+
+    // peak memory above what was left allocated
+    let s1 = measurements.pass_1_measurements.max_used_memory.saturating_sub(measurements.pass_1_measurements.used_memory_after) as f64;
+    let s2 = measurements.pass_2_measurements.max_used_memory.saturating_sub(measurements.pass_2_measurements.used_memory_after) as f64;
+
+    // set sizes
+    let n1 = passes_info.pass1_n as f64;
+    let n2 = passes_info.pass2_n as f64;
+
+    analyse_memory_complexity(s1, s2, n1, n2)
 }
 
 /// Perform space complexity analysis for iterator algorithms that do not alter the size of the set they operate on or for
@@ -42,10 +77,9 @@ pub fn analyse_space_complexity_for_constant_set_iterator_algorithm(passes_info:
     let s2 = (measurements.pass_2_measurements.max_used_memory - measurements.pass_2_measurements.used_memory_before) as f64;
 
     // set sizes
-    let n1 = std::cmp::min(passes_info.pass_1_set_size, passes_info.pass_2_set_size) as f64;
-    let n2 = std::cmp::max(passes_info.pass_1_set_size, passes_info.pass_2_set_size) as f64;
+    let (n1, n2) = passes_info.set_sizes();
 
-    analyse_complexity(s1, s2, n1, n2)
+    analyse_memory_complexity(s1, s2, n1, n2)
 }
 
 /// Perform space complexity analysis for iterator algorithms that alter the set size they operate on,
@@ -71,6 +105,47 @@ pub fn analyse_space_complexity_for_set_resizing_iterator_algorithm(passes_info:
     analyse_set_resizing_iterator_complexity(s1, s2, n)
 }
 
+/// Same as [analyse_space_complexity_for_constant_set_iterator_algorithm()], but on the *retained memory* -- the memory left allocated
+/// after the pass ([BigOSpacePassMeasurements::net_delta()]), disregarding any transient allocations: for reads & updates that cache
+/// their results, for instance, while using scratch buffers that would otherwise dominate the analysis
+pub fn analyse_retained_space_complexity_for_constant_set_iterator_algorithm(passes_info:  &ConstantSetIteratorAlgorithmPassesInfo,
+                                                                             measurements: &BigOSpaceMeasurements) -> BigOAlgorithmComplexity {
+
+    // memory left allocated
+    let s1 = measurements.pass_1_measurements.net_delta().max(0) as f64;
+    let s2 = measurements.pass_2_measurements.net_delta().max(0) as f64;
+
+    // set sizes
+    let (n1, n2) = passes_info.set_sizes();
+
+    analyse_memory_complexity(s1, s2, n1, n2)
+}
+
+/// Same as [analyse_space_complexity_for_set_resizing_iterator_algorithm()], but on the *retained memory* -- the memory left allocated
+/// after the pass ([BigOSpacePassMeasurements::net_delta()]): tells the memory each inserted element keeps, regardless of the
+/// transient peaks of the container's growth -- like a vector reallocating to the double of its capacity
+pub fn analyse_retained_space_complexity_for_set_resizing_iterator_algorithm(passes_info:  &SetResizingIteratorAlgorithmPassesInfo,
+                                                                             measurements: &BigOSpaceMeasurements) -> BigOAlgorithmComplexity {
+
+    let n = passes_info.delta_set_size as f64;
+
+    // memory left allocated
+    let s1 = measurements.pass_1_measurements.net_delta().max(0) as f64;
+    let s2 = measurements.pass_2_measurements.net_delta().max(0) as f64;
+
+    analyse_set_resizing_iterator_complexity(s1, s2, n)
+}
+
+/// [analyse_complexity()] for the memory used on passes with `n1` & `n2` elements -- `s1` & `s2` -- taking not using memory at all,
+/// like in-place algorithms, as `O(1)`, rather than as the unclassifiable `0/0`. Behind all the space analyses above.
+pub fn analyse_memory_complexity(s1: f64, s2: f64, n1: f64, n2: f64) -> BigOAlgorithmComplexity {
+    if s1 == 0.0 && s2 == 0.0 {
+        BigOAlgorithmComplexity::O1
+    } else {
+        analyse_complexity(s1, s2, n1, n2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -130,13 +205,13 @@ mod tests {
                    pass_1_measurements: BigOSpacePassMeasurements {
                        used_memory_before: 0,
                        used_memory_after: 0,
-                       max_used_memory: (1000 as f32).ln() as usize,
+                       max_used_memory: 1000_f32.ln() as usize,
                        min_used_memory: 0
                    },
                    pass_2_measurements: BigOSpacePassMeasurements {
                        used_memory_before: 0,
                        used_memory_after: 0,
-                       max_used_memory: (2000 as f32).ln() as usize,
+                       max_used_memory: 2000_f32.ln() as usize,
                        min_used_memory: 0
                    },
                });
@@ -147,13 +222,13 @@ mod tests {
                    pass_1_measurements: BigOSpacePassMeasurements {
                        used_memory_before: 0,
                        used_memory_after: 0,
-                       max_used_memory: 1000 / (1000 as f32).ln() as usize,
+                       max_used_memory: 1000 / 1000_f32.ln() as usize,
                        min_used_memory: 0
                    },
                    pass_2_measurements: BigOSpacePassMeasurements {
                        used_memory_before: 0,
                        used_memory_after: 0,
-                       max_used_memory: 2000 / (2000 as f32).ln() as usize,
+                       max_used_memory: 2000 / 2000_f32.ln() as usize,
                        min_used_memory: 0
                    },
                });
@@ -194,6 +269,82 @@ mod tests {
 
     }
 
+    /// attests retained and auxiliary memory are classified independently for regular algorithms:
+    /// here, the output grows linearly with `n` while the scratch buffer stays constant
+    #[test]
+    #[serial]
+    fn retained_and_auxiliary_space() {
+        let passes_info = AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 };
+        let space_measurements = BigOSpaceMeasurements {
+            pass_1_measurements: BigOSpacePassMeasurements {
+                used_memory_before: 100,
+                used_memory_after: 100 + 1000,
+                max_used_memory: 100 + 1000 + 4096,
+                min_used_memory: 100
+            },
+            pass_2_measurements: BigOSpacePassMeasurements {
+                used_memory_before: 100,
+                used_memory_after: 100 + 2000,
+                max_used_memory: 100 + 2000 + 4096,
+                min_used_memory: 100
+            },
+        };
+        assert_eq!(analyse_retained_space_complexity(&passes_info, &space_measurements), BigOAlgorithmComplexity::ON, "Retained space");
+        assert_eq!(analyse_auxiliary_space_complexity(&passes_info, &space_measurements), BigOAlgorithmComplexity::O1, "Auxiliary space");
+    }
+
+    /// attests algorithms not using memory at all -- like in-place sorts -- are reported as `O(1)`, rather than as the `0/0` ratio
+    /// the general analysis can't classify
+    #[test]
+    #[serial]
+    fn no_memory_usage() {
+        let passes_info = AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 };
+        let unchanged_memory = BigOSpacePassMeasurements {
+            used_memory_before: 100,
+            used_memory_after: 100,
+            max_used_memory: 100,
+            min_used_memory: 100
+        };
+        let space_measurements = BigOSpaceMeasurements {
+            pass_1_measurements: unchanged_memory,
+            pass_2_measurements: unchanged_memory,
+        };
+        assert_eq!(analyse_space_complexity(&passes_info, &space_measurements),           BigOAlgorithmComplexity::O1, "Space");
+        assert_eq!(analyse_retained_space_complexity(&passes_info, &space_measurements),  BigOAlgorithmComplexity::O1, "Retained space");
+        assert_eq!(analyse_auxiliary_space_complexity(&passes_info, &space_measurements), BigOAlgorithmComplexity::O1, "Auxiliary space");
+    }
+
+    /// attests the retained variants of the iterator analyses disregard transient peaks: here, inserting each element retains
+    /// a constant amount of memory, while the container's growth peaks in proportion to its size (3x the elements, 3x the peak)
+    #[test]
+    #[serial]
+    fn retained_space_for_iterator_algorithms() {
+        let space_measurements = BigOSpaceMeasurements {
+            pass_1_measurements: BigOSpacePassMeasurements {
+                used_memory_before: 0,
+                used_memory_after: 1000,
+                max_used_memory: 2000,
+                min_used_memory: 0
+            },
+            pass_2_measurements: BigOSpacePassMeasurements {
+                used_memory_before: 1000,
+                used_memory_after: 2000,
+                max_used_memory: 1000 + 6000,
+                min_used_memory: 1000
+            },
+        };
+        assert_eq!(space_measurements.pass_2_measurements.net_delta(), 1000, "Wrong net delta");
+        let passes_info = SetResizingIteratorAlgorithmPassesInfo { delta_set_size: 1000 };
+        assert_eq!(analyse_space_complexity_for_set_resizing_iterator_algorithm(&passes_info, &space_measurements), BigOAlgorithmComplexity::ON, "Peak space");
+        assert_eq!(analyse_retained_space_complexity_for_set_resizing_iterator_algorithm(&passes_info, &space_measurements), BigOAlgorithmComplexity::O1, "Retained space");
+        let freeing_pass = BigOSpacePassMeasurements { used_memory_before: 1000, used_memory_after: 0, max_used_memory: 1000, min_used_memory: 0 };
+        assert_eq!(freeing_pass.net_delta(), -1000, "Freeing passes should have negative net deltas");
+        let passes_info = ConstantSetIteratorAlgorithmPassesInfo { pass_1_set_size: 1000, pass_2_set_size: 2000, repetitions: 1000 };
+        let space_measurements = BigOSpaceMeasurements { pass_1_measurements: freeing_pass, pass_2_measurements: freeing_pass };
+        assert_eq!(analyse_retained_space_complexity_for_constant_set_iterator_algorithm(&passes_info, &space_measurements), BigOAlgorithmComplexity::O1,
+                   "Freeing memory retains nothing -- constant space");
+    }
+
     /// test the space complexity analysis results based on some known-to-be-correct measurement sizes
     #[test]
     #[serial]
@@ -205,7 +356,7 @@ mod tests {
         };
 
         // ∑(int)log2(1)..log2((2^n)-1) -- deduced from Gauss's arithmetic progression sum
-        let log_sum_bit_values = |n_bits| (1..n_bits).into_iter().fold(0.0, |sum: f32, bit: usize| {
+        let log_sum_bit_values = |n_bits| (1..n_bits).fold(0.0, |sum: f32, bit: usize| {
             let bit_value_start: usize = 1<<bit;
             let bit_value_finish = (1<<(bit+1))-1;
             let bit_sum = (bit_value_start+bit_value_finish) * (bit_value_start/2);
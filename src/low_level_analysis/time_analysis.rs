@@ -1,10 +1,13 @@
 //! Contains functions to perform Algorithm's Time Complexity Analysis.
 
 use crate::low_level_analysis::{
+    configs::PERCENT_TOLERANCE,
     analyse_complexity,
     analyse_set_resizing_iterator_complexity,
+    complexity_coefficient,
     types::*,
 };
+use core::time::Duration;
 
 
 /// Performs time complexity analysis for regular, non-iterator algorithms, such as `fib(n)`, `sort(n)`, `bsearch(e, n)`, ...
@@ -23,12 +26,39 @@ pub fn analyse_time_complexity(passes_info:  &AlgorithmPassesInfo,
     analyse_complexity(t1, t2, n1, n2)
 }
 
+/// Checks the time `measurements` of an analysis whose second pass operates on a greater `n` -- regular, constant set or set resizing algorithms --
+/// for [MeasurementAnomaly]s: the second pass running faster (beyond the tolerance) is reported as [MeasurementAnomaly::DecreasingTime],
+/// telling the measurements should be retaken rather than analysed as [BigOAlgorithmComplexity::BetterThanO1].
+pub fn detect_time_anomaly(measurements: &BigOTimeMeasurements) -> Option<MeasurementAnomaly> {
+    let t1 = measurements.pass_1_measurements.as_secs_f64();
+    let t2 = measurements.pass_2_measurements.as_secs_f64();
+    (t2 < t1 * (1.0 - PERCENT_TOLERANCE)).then_some(MeasurementAnomaly::DecreasingTime)
+}
+
+/// Estimates the constant factor of the already analysed `time_complexity` -- from the second pass' time per operation, see
+/// [BigOAlgorithmMeasurements::time_coefficient_reference()]. For instance, `elapsed / n` for `O(n)` or `elapsed / (n*log2(n))` for
+/// `O(n.log(n))`.\
+/// The returned value, in seconds, is `None` if the complexity has no model to estimate it from -- see [complexity_coefficient()] --
+/// or if the measurements give no reference.
+pub fn analyse_time_coefficient<T: BigOAlgorithmMeasurements>(time_complexity: BigOAlgorithmComplexity, measurements: &T) -> Option<f64> {
+    let (time_per_operation, n) = measurements.time_coefficient_reference()?;
+    complexity_coefficient(time_complexity, time_per_operation, n)
+}
+
+/// Tells if the time `measurements` lie so close to the boundary between two complexities that a bit of noise could have tipped
+/// their classification -- see [BigOAlgorithmMeasurements::is_time_near_boundary()]: [super::is_near_boundary()] for regular & constant set
+/// algorithms; [super::is_set_resizing_near_boundary()] for the set resizing ones.
+pub fn is_time_near_boundary<T: BigOAlgorithmMeasurements>(measurements: &T) -> bool {
+    measurements.is_time_near_boundary()
+}
+
 /// Performs time complexity analysis (based on the 2 passes & measurements given), for an iterator algorithm that does not alter
 /// the size of the set they operate on or for non-iterator algorithms (even if they are growing/shrinking a data set from top to
 /// zero), where iterator algorithms are the ones that operates on a single element (of a huge set) per call.\
 /// Examples: select/update, get, sort, fib...
 ///
-/// To perform the analysis, two passes are required on different set sizes -- see [passes_info]. The number of repetitions & set size
+/// To perform the analysis, two passes are required on different set sizes -- in any ratio: the bigger it is, the better the
+/// complexities stand out of the measurement noise (see [ConstantSetIteratorAlgorithmPassesInfo::with_ratio()]). The number of repetitions & set size
 /// must be carefully chosen in order to generate elapsed times (on each pass) high enough to make OS, IO and network latencies
 /// negligible -- if the operation is CPU bounded, the machine should be idle.
 ///
@@ -43,8 +73,7 @@ pub fn analyse_time_complexity_for_constant_set_iterator_algorithm(passes_info:
     let t2 = measurements.pass_2_measurements.as_secs_f64();
 
     // set sizes
-    let n1 = std::cmp::min(passes_info.pass_1_set_size, passes_info.pass_2_set_size) as f64;
-    let n2 = std::cmp::max(passes_info.pass_1_set_size, passes_info.pass_2_set_size) as f64;
+    let (n1, n2) = passes_info.set_sizes();
 
     analyse_complexity(t1, t2, n1, n2)
 }
@@ -72,17 +101,81 @@ pub fn analyse_time_complexity_for_set_resizing_iterator_algorithm(passes_info:
     analyse_set_resizing_iterator_complexity(t1, t2, n)
 }
 
+/// Same as [analyse_time_complexity_for_set_resizing_iterator_algorithm()], but for algorithms whose per-iteration cost is
+/// only constant when amortized -- like `Vec::push()`, which is usually O(1), but O(n) when a reallocation takes place.\
+/// A pass is considered to contain "spikes" if its slowest iteration took more than `realloc_threshold_ratio` times its
+/// median iteration: the spikes are then excluded from the complexity ratio, using `median * iterations` as the pass time.
+pub fn analyse_amortized_time_complexity(passes_info:             &SetResizingIteratorAlgorithmPassesInfo,
+                                         measurements:            &BigOAmortizedTimeMeasurements,
+                                         realloc_threshold_ratio: f64)
+                                        -> BigOAlgorithmComplexity {
+
+    let n = passes_info.delta_set_size as f64;
+
+    // pass time, without spikes
+    let amortized_time = |pass_time: Duration, iterations: &BigOIterationTimeStatistics| {
+        let median = iterations.median_elapsed_per_iteration.as_secs_f64();
+        if iterations.max_elapsed_per_iteration.as_secs_f64() > median * realloc_threshold_ratio {
+            median * iterations.iterations as f64
+        } else {
+            pass_time.as_secs_f64()
+        }
+    };
+    let t1 = amortized_time(measurements.time_measurements.pass_1_measurements, &measurements.pass_1_iterations);
+    let t2 = amortized_time(measurements.time_measurements.pass_2_measurements, &measurements.pass_2_iterations);
+
+    analyse_set_resizing_iterator_complexity(t1, t2, n)
+}
+
 #[cfg(test)]
 mod tests {
 
     //! Unit tests for [time_analysis](super) module
 
     use super::*;
-    use crate::features::*;
     use std::time::Duration;
     use serial_test::serial;
 
 
+    /// assures set sizes beyond `u32::MAX` are analysed without truncation -- like a truncated `pass2_n` of 0 or `pass2_n` < `pass1_n`
+    #[test]
+    fn set_sizes_beyond_u32() {
+        let pass1_n = 3 * (u32::MAX as u64);
+        let time_measurements = BigOTimeMeasurements { pass_1_measurements: Duration::from_secs(3), pass_2_measurements: Duration::from_secs(6) };
+        assert_eq!(analyse_time_complexity(&AlgorithmPassesInfo { pass1_n, pass2_n: pass1_n * 2 }, &time_measurements), BigOAlgorithmComplexity::ON,
+                   "Doubling a huge `n` & the time should be linear");
+        let passes_info = ConstantSetIteratorAlgorithmPassesInfo { pass_1_set_size: pass1_n, pass_2_set_size: pass1_n * 2, repetitions: 1000 };
+        let time_measurements = BigOTimeMeasurements { pass_1_measurements: Duration::from_secs(3), pass_2_measurements: Duration::from_secs(3) };
+        assert_eq!(analyse_time_complexity_for_constant_set_iterator_algorithm(&passes_info, &time_measurements), BigOAlgorithmComplexity::O1,
+                   "Same times on huge sets should be constant");
+    }
+
+    /// assures constant set iterator algorithms may be analysed in ratios other than `2x` -- and never on equal set sizes
+    #[test]
+    fn constant_set_ratios() {
+        let passes_info = ConstantSetIteratorAlgorithmPassesInfo::with_ratio(1000, 4, 1000);
+        assert_eq!((passes_info.pass_1_set_size, passes_info.pass_2_set_size), (1000, 4000), "Wrong set sizes for a 4x ratio");
+        let time_measurements = BigOTimeMeasurements { pass_1_measurements: Duration::from_secs(5), pass_2_measurements: Duration::from_secs(6) };
+        assert_eq!(analyse_time_complexity_for_constant_set_iterator_algorithm(&passes_info, &time_measurements), BigOAlgorithmComplexity::OLogN,
+                   "log(4000)/log(1000) = 1.2");
+        let reversed_passes_info = ConstantSetIteratorAlgorithmPassesInfo::new(4000, 1000, 1000);
+        assert_eq!(reversed_passes_info.set_sizes(), passes_info.set_sizes(), "`n1` should be the smaller set size, regardless of the pass");
+    }
+
+    /// equal set sizes can't tell any complexity
+    #[test]
+    #[should_panic(expected = "different set sizes")]
+    fn constant_set_of_equal_sizes() {
+        ConstantSetIteratorAlgorithmPassesInfo::with_ratio(1000, 1, 1000);
+    }
+
+    /// a second set too big to be counted can't be described
+    #[test]
+    #[should_panic(expected = "can't be 4 times bigger")]
+    fn constant_set_ratio_overflow() {
+        ConstantSetIteratorAlgorithmPassesInfo::with_ratio(u64::MAX / 2, 4, 1000);
+    }
+
     /// tests the time complexity analysis results based on some known-to-be-correct measurement times
     #[test]
     #[serial]
@@ -134,6 +227,13 @@ mod tests {
                    pass_2_measurements: Duration::from_micros(2220)
                });
 
+        assert("Theoretical O(n.log²(n)) algorithm", BigOAlgorithmComplexity::ONLog2N,
+               AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 8000 },
+               BigOTimeMeasurements {
+                   pass_1_measurements: Duration::from_micros(1000),
+                   pass_2_measurements: Duration::from_micros(13540)
+               });
+
         assert("Theoretical between O(n.log(n)) and O(n²) algorithm", BigOAlgorithmComplexity::BetweenONLogNAndON2,
                AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 },
                BigOTimeMeasurements {
@@ -190,6 +290,43 @@ mod tests {
                    pass_2_measurements: Duration::from_secs_f64(1.0e7 * 1.101)
                });
 
+        // factorials are kept tractable with small `n`s: 8!/5! = 336
+        assert("Theoretical O(n!) algorithm", BigOAlgorithmComplexity::ONFactorial,
+               AlgorithmPassesInfo { pass1_n: 5, pass2_n: 8 },
+               BigOTimeMeasurements {
+                   pass_1_measurements: Duration::from_secs_f64(1.0e1),
+                   pass_2_measurements: Duration::from_secs_f64(1.0e1 * 336.0)
+               });
+
+        assert("O(n!) algorithm (10% lower than the theoretical value)", BigOAlgorithmComplexity::ONFactorial,
+               AlgorithmPassesInfo { pass1_n: 5, pass2_n: 8 },
+               BigOTimeMeasurements {
+                   pass_1_measurements: Duration::from_secs_f64(1.0e1),
+                   pass_2_measurements: Duration::from_secs_f64(1.0e1 * 336.0 * 0.901)
+               });
+
+        assert("Worse than factorial algorithm", BigOAlgorithmComplexity::WorseThanExponential,
+               AlgorithmPassesInfo { pass1_n: 5, pass2_n: 8 },
+               BigOTimeMeasurements {
+                   pass_1_measurements: Duration::from_secs_f64(1.0e1),
+                   pass_2_measurements: Duration::from_secs_f64(1.0e1 * 336.0 * 1.101)
+               });
+
+    }
+
+    /// assures sub-microsecond measurements keep their nanosecond precision through the analysis
+    /// -- with integer microseconds, all of these would be truncated to 0
+    #[test]
+    #[serial]
+    fn sub_microsecond_precision() {
+        let analyse = |pass_1_nanos, pass_2_nanos| analyse_time_complexity(&AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 },
+                                                                             &BigOTimeMeasurements {
+                                                                                 pass_1_measurements: Duration::from_nanos(pass_1_nanos),
+                                                                                 pass_2_measurements: Duration::from_nanos(pass_2_nanos),
+                                                                             });
+        assert_eq!(analyse(300, 300), BigOAlgorithmComplexity::O1,    "Sub-microsecond O(1) check failed");
+        assert_eq!(analyse(300, 333), BigOAlgorithmComplexity::OLogN, "Sub-microsecond O(log(n)) check failed");
+        assert_eq!(analyse(300, 600), BigOAlgorithmComplexity::ON,    "Sub-microsecond O(n) check failed");
     }
 
     /// test the time complexity analysis results based on some known-to-be-correct measurement times
@@ -299,4 +436,45 @@ mod tests {
         });
     }
 
-}
\ No newline at end of file
+    /// assures a second pass running faster than the first is reported as an anomaly -- and only beyond the tolerance
+    #[test]
+    fn decreasing_time_anomaly() {
+        let detect = |pass_1_micros, pass_2_micros| detect_time_anomaly(&BigOTimeMeasurements {
+            pass_1_measurements: Duration::from_micros(pass_1_micros),
+            pass_2_measurements: Duration::from_micros(pass_2_micros),
+        });
+        assert_eq!(detect(1000, 2000), None, "Increasing times are not anomalous");
+        assert_eq!(detect(1000, 1000), None, "Constant times are not anomalous");
+        assert_eq!(detect(1000, 1000 - (PERCENT_TOLERANCE*1000.0) as u64 + 1), None, "Decreases within the tolerance are not anomalous");
+        assert_eq!(detect(1000, 1000 - (PERCENT_TOLERANCE*1000.0) as u64 - 1), Some(MeasurementAnomaly::DecreasingTime), "Decreases beyond the tolerance should be detected");
+    }
+
+    /// test the amortized time complexity analysis, where a reallocation spike on the second pass should be disregarded
+    #[test]
+    #[serial]
+    fn analyse_amortized_time_complexity_theoretical_test() {
+        let passes_info = SetResizingIteratorAlgorithmPassesInfo { delta_set_size: 1000 };
+        let iterations = |median_micros, max_micros| BigOIterationTimeStatistics {
+            iterations:                   1000,
+            min_elapsed_per_iteration:    Duration::from_micros(median_micros),
+            median_elapsed_per_iteration: Duration::from_micros(median_micros),
+            max_elapsed_per_iteration:    Duration::from_micros(max_micros),
+        };
+        // pass 2 is 1000µs above the 1000 * 1µs of the typical iterations, due to a single reallocation
+        let spiky_measurements = BigOAmortizedTimeMeasurements {
+            time_measurements: BigOTimeMeasurements {
+                pass_1_measurements: Duration::from_micros(1000),
+                pass_2_measurements: Duration::from_micros(2000),
+            },
+            pass_1_iterations: iterations(1, 1),
+            pass_2_iterations: iterations(1, 1001),
+        };
+        assert_eq!(analyse_time_complexity_for_set_resizing_iterator_algorithm(&passes_info, &spiky_measurements.time_measurements),
+                   BigOAlgorithmComplexity::BetweenOLogNAndON, "Without amortization, the spike should show up in the analysis");
+        assert_eq!(analyse_amortized_time_complexity(&passes_info, &spiky_measurements, 10.0),
+                   BigOAlgorithmComplexity::O1, "The reallocation spike should have been excluded");
+        assert_eq!(analyse_amortized_time_complexity(&passes_info, &spiky_measurements, 2000.0),
+                   BigOAlgorithmComplexity::BetweenOLogNAndON, "A spike below the threshold should be kept");
+    }
+
+}
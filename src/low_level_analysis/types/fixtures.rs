@@ -0,0 +1,72 @@
+//! Fake analyses shared by this crate's unit tests -- for the ones that don't care about how the measurements were taken
+
+use super::types::*;
+use core::time::Duration;
+
+
+/// builds a regular algorithm analysis for passes of `n` = 100 & 200, with the given complexities & measurements
+pub(crate) fn analysis(measurement_name:       &'static str,
+                       time_complexity:        BigOAlgorithmComplexity,
+                       space_complexity:       BigOAlgorithmComplexity,
+                       pass_1_time:            Duration,
+                       pass_2_time:            Duration,
+                       pass_1_max_used_memory: usize,
+                       pass_2_max_used_memory: usize)
+                      -> BigOAlgorithmAnalysis<AlgorithmMeasurements<'static>> {
+    let (time_measurements, space_measurements, pass1_measurements, pass2_measurements) = measurements(pass_1_time, pass_2_time, pass_1_max_used_memory, pass_2_max_used_memory);
+    BigOAlgorithmAnalysis {
+        time_complexity,
+        space_complexity,
+        time_coefficient: None,
+        is_near_boundary: false,
+        algorithm_measurements: AlgorithmMeasurements {
+            measurement_name,
+            passes_info: AlgorithmPassesInfo { pass1_n: 100, pass2_n: 200 },
+            time_measurements,
+            space_measurements,
+            pass1_measurements,
+            pass2_measurements,
+        },
+    }
+}
+
+/// builds a constant set iterator algorithm analysis for 100 repetitions over sets of 100 & 200 elements, with the given complexities & measurements
+#[cfg(feature = "metrics")]
+pub(crate) fn constant_set_analysis(measurement_name:       &'static str,
+                                    time_complexity:        BigOAlgorithmComplexity,
+                                    space_complexity:       BigOAlgorithmComplexity,
+                                    pass_1_time:            Duration,
+                                    pass_2_time:            Duration,
+                                    pass_1_max_used_memory: usize,
+                                    pass_2_max_used_memory: usize)
+                                   -> BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements<'static>> {
+    let (time_measurements, space_measurements, pass1_measurements, pass2_measurements) = measurements(pass_1_time, pass_2_time, pass_1_max_used_memory, pass_2_max_used_memory);
+    BigOAlgorithmAnalysis {
+        time_complexity,
+        space_complexity,
+        time_coefficient: None,
+        is_near_boundary: false,
+        algorithm_measurements: ConstantSetIteratorAlgorithmMeasurements {
+            measurement_name,
+            passes_info: ConstantSetIteratorAlgorithmPassesInfo { pass_1_set_size: 100, pass_2_set_size: 200, repetitions: 100 },
+            time_measurements,
+            space_measurements,
+            pass1_measurements,
+            pass2_measurements,
+        },
+    }
+}
+
+/// the measurements of both passes -- none of which allocates nor frees anything, peaking at the given `max_used_memory`s
+fn measurements(pass_1_time: Duration, pass_2_time: Duration, pass_1_max_used_memory: usize, pass_2_max_used_memory: usize)
+               -> (BigOTimeMeasurements, BigOSpaceMeasurements, BigOPassMeasurements, BigOPassMeasurements) {
+    let pass_measurements = |time_measurements, max_used_memory| BigOPassMeasurements {
+        time_measurements,
+        space_measurements: BigOSpacePassMeasurements { used_memory_before: 0, used_memory_after: 0, max_used_memory, min_used_memory: 0 },
+        custom_measurements: vec![],
+    };
+    let (pass1, pass2) = (pass_measurements(pass_1_time, pass_1_max_used_memory), pass_measurements(pass_2_time, pass_2_max_used_memory));
+    (BigOTimeMeasurements { pass_1_measurements: pass1.time_measurements, pass_2_measurements: pass2.time_measurements },
+     BigOSpaceMeasurements { pass_1_measurements: pass1.space_measurements, pass_2_measurements: pass2.space_measurements },
+     pass1, pass2)
+}
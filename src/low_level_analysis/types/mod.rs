@@ -1,5 +1,8 @@
 //! Defines traits, enums & structs used by this module's functions.
 
+#[allow(clippy::module_inception)]
 mod types;
 pub use types::*;
-mod types_impl;
\ No newline at end of file
+mod types_impl;
+#[cfg(all(test, feature = "std"))]
+pub(crate) mod fixtures;
@@ -1,15 +1,22 @@
 //! See [super] for docs.\
 //! See [super::types_impl] as well for implementations of the structs/enums defined here.
 
+use core::cmp::Ordering;
+use core::time::Duration;
+#[cfg(feature = "std")]
 use std::fmt::Display;
-use std::time::Duration;
+#[cfg(feature = "std")]
 use crate::utils::measurements::measurer::CustomMeasurement;
+#[cfg(not(feature = "std"))]
+#[cfg_attr(test, allow(unused_imports))]    // tests link `std`, whose inherent float methods take precedence
+use crate::low_level_analysis::no_std_math::FloatMath;
 
 /// Possible time & space complexity analysis results, in big-O notation.
 /// Results are for a single operation -- remember a pass have several operations,
 /// so the time for the analysis should have '* 2 * p' added -- 'p' being the size
-/// for each one of the 2 passes required for the analysis.
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// for each one of the 2 passes required for the analysis.\
+/// Variants are ordered from the best to the worst complexity -- see the [Ord] implementation in [super::types_impl].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BigOAlgorithmComplexity {
     BetterThanO1,
     O1,
@@ -19,6 +26,10 @@ pub enum BigOAlgorithmComplexity {
     ON,
     BetweenONAndONLogN,
     ONLogN,
+    BetweenONLogNAndONLog2N,
+    /// `O(n.log²(n))` -- found in some divide-and-conquer algorithms doing `O(n.log(n))` work on each level, like some parallel merges
+    ONLog2N,
+    /// worse than `O(n.log²(n))`, but better than `O(n²)` -- the name predates [Self::ONLog2N] and was kept for compatibility
     BetweenONLogNAndON2,
     ON2,
     BetweenON2AndON3,
@@ -27,9 +38,67 @@ pub enum BigOAlgorithmComplexity {
     ON4,
     BetweenON4AndOkN,
     OkN,
+    /// worse than `O(kⁿ)`, but better than `O(n!)`
+    BetweenOkNAndONFactorial,
+    /// `O(n!)` -- like naive permutation generation or brute-force TSP
+    ONFactorial,
+    /// worse than `O(n!)` -- the name predates [Self::ONFactorial] and was kept for compatibility
     WorseThanExponential,
 }
 
+/// The complexity of running an `inner` algorithm on each step of an `outer` one -- like nested loops or an `O(log(n))` search
+/// done for each of `n` elements, giving `O(n.log(n))`: the `n` & `log(n)` exponents of both complexities add up.\
+/// "Between" complexities are taken as their upper bounds, so the result is an upper bound as well -- unless one of the
+/// complexities is `O(1)`, in which case the other one is returned as is. Products that aren't a known class fall in the
+/// "between" class containing them: `O(n².log(n))` is [BigOAlgorithmComplexity::BetweenON2AndON3].
+/// Exponential & factorial complexities dominate, getting promoted to the next class when multiplied by a non-constant one.
+pub fn complexity_compose(outer: BigOAlgorithmComplexity, inner: BigOAlgorithmComplexity) -> BigOAlgorithmComplexity {
+    if outer.is_at_most(BigOAlgorithmComplexity::O1) {
+        return inner
+    } else if inner.is_at_most(BigOAlgorithmComplexity::O1) {
+        return outer
+    }
+    if let (Some((outer_n, outer_log)), Some((inner_n, inner_log))) = (outer.polynomial_exponents(), inner.polynomial_exponents()) {
+        return BigOAlgorithmComplexity::from_polynomial_exponents(outer_n + inner_n, outer_log + inner_log)
+    }
+    match BigOAlgorithmComplexity::worse_of(outer, inner) {
+        BigOAlgorithmComplexity::OkN if outer == inner => BigOAlgorithmComplexity::OkN,
+        BigOAlgorithmComplexity::OkN                   => BigOAlgorithmComplexity::BetweenOkNAndONFactorial,
+        BigOAlgorithmComplexity::ONFactorial           => BigOAlgorithmComplexity::WorseThanExponential,
+        worst => worst,
+    }
+}
+
+/// The complexity of running `a` then `b` -- the dominant one. See [BigOAlgorithmComplexity::worse_of()]
+pub fn complexity_add(a: BigOAlgorithmComplexity, b: BigOAlgorithmComplexity) -> BigOAlgorithmComplexity {
+    BigOAlgorithmComplexity::worse_of(a, b)
+}
+
+/// The complexity of a divide-and-conquer algorithm through the Master Theorem: `T(n) = subproblems * T(n / size_divisor) + f(n)`,
+/// with `combine` being the complexity of `f(n)` -- the work of splitting the problem & combining the sub-results.
+/// With `c = log(subproblems) / log(size_divisor)`:
+///   1. if `f(n)` grows slower than `nᶜ`, the recursion dominates: `O(nᶜ)` -- `2` sub-trees of half the size & `O(1)` work is `O(n)`;
+///   2. if `f(n)` is `O(nᶜ.logᵏ(n))`, each recursion level costs the same: `O(nᶜ.logᵏ⁺¹(n))` -- merge sort is `O(n.log(n))`;
+///   3. if `f(n)` grows faster than `nᶜ`, the combining dominates: `O(f(n))`.
+///
+/// Like in [complexity_compose()], "between" complexities are taken as their upper bounds.\
+/// Panics if `subproblems` is `0` or `size_divisor` is less than `2` -- which wouldn't divide the problem.
+pub fn complexity_divide_and_conquer(subproblems: u32, size_divisor: u32, combine: BigOAlgorithmComplexity) -> BigOAlgorithmComplexity {
+    assert!(subproblems >= 1 && size_divisor >= 2, "complexity_divide_and_conquer(): invalid recurrence T(n) = {}*T(n/{}) + f(n)", subproblems, size_divisor);
+    let critical_exponent = (subproblems as f64).ln() / (size_divisor as f64).ln();
+    let Some((combine_n, combine_log)) = combine.polynomial_exponents() else {
+        // exponential or worse: case 3
+        return combine
+    };
+    if (combine_n - critical_exponent).abs() < 1e-9 {
+        BigOAlgorithmComplexity::from_polynomial_exponents(combine_n, combine_log + 1.0)
+    } else if combine_n < critical_exponent {
+        BigOAlgorithmComplexity::from_polynomial_exponents(critical_exponent, 0.0)
+    } else {
+        BigOAlgorithmComplexity::from_polynomial_exponents(combine_n, combine_log)
+    }
+}
+
 /// Specifies if the iterator algorithm under analysis alters the data set it works on or if it has no side effects on it.\
 /// Different math applies on each case, as well as different parameters to the iterator function required by the [crate::runner].
 /// The "Iterator Algorithms" term is used in this crate to distinguish them from "Standard Algorithms". They differ in the sense
@@ -47,9 +116,49 @@ pub enum BigOIteratorAlgorithmType {
     ConstantSet,
 }
 
+/// What [BigOAlgorithmMeasurements] require to be presented: [Display] -- except on `no_std` builds, which can't render the reports
+#[cfg(feature = "std")]
+pub trait PresentableMeasurements: Display {}
+#[cfg(feature = "std")]
+impl<T: Display> PresentableMeasurements for T {}
+#[cfg(not(feature = "std"))]
+pub trait PresentableMeasurements {}
+#[cfg(not(feature = "std"))]
+impl<T> PresentableMeasurements for T {}
+
 /// base trait for [SetResizingIteratorAlgorithmMeasurements] & [ConstantSetIteratorAlgorithmMeasurements].
-pub trait BigOAlgorithmMeasurements: Display {
+pub trait BigOAlgorithmMeasurements: PresentableMeasurements {
+    /// the name given to these measurements, for presentation purposes -- the implementor's type name, by default
+    fn measurement_name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+    fn time_measurements(&self) -> &BigOTimeMeasurements;
     fn space_measurements(&self) -> &BigOSpaceMeasurements;
+    /// the time per operation of the second pass (in seconds) & the `n` it was measured with -- from which the constant factor
+    /// of the time complexity is estimated. See [super::time_analysis::analyse_time_coefficient()].\
+    /// `None`, by default: no constant factor is estimated
+    fn time_coefficient_reference(&self) -> Option<(f64, f64)> {
+        None
+    }
+    /// the number of operations the second pass measured -- calls to the algorithm, for the iterator ones; or the elements the single
+    /// call processed, for the regular ones -- from which throughputs & per element figures are derived.\
+    /// Taken as a single operation if there is no [Self::time_coefficient_reference()]
+    fn pass_2_operations(&self) -> f64 {
+        self.time_coefficient_reference()
+            .map_or(1.0, |(time_per_operation, _n)| self.time_measurements().pass_2_measurements.as_secs_f64() / time_per_operation)
+    }
+    /// the `n`s of passes 1 & 2, as compared by the time complexity analysis -- see [super::time_analysis::is_time_near_boundary()].\
+    /// `None`, by default: the measurements are never taken as near a boundary
+    fn passes_n(&self) -> Option<(f64, f64)> {
+        None
+    }
+    /// tells if the time measurements lie so close to the boundary between two complexities that a bit of noise could have tipped
+    /// their classification -- by default, through [crate::low_level_analysis::is_near_boundary()] on the [Self::passes_n()], if given
+    fn is_time_near_boundary(&self) -> bool {
+        let time_measurements = self.time_measurements();
+        self.passes_n().is_some_and(|(n1, n2)| crate::low_level_analysis::is_near_boundary(time_measurements.pass_1_measurements.as_secs_f64(),
+                                                                                              time_measurements.pass_2_measurements.as_secs_f64(), n1, n2))
+    }
 }
 
 /// Return result for this submodule's functions for analysing the complexity of algorithms.\
@@ -58,11 +167,71 @@ pub trait BigOAlgorithmMeasurements: Display {
 ///   * [ConstantSetIteratorAlgorithmMeasurements]
 ///   * [SetResizingIteratorAlgorithmMeasurements]
 ///
-/// See the functions in [super::time_analysis] & [super::space_analysis].
+/// See the functions in [super::time_analysis] & [super::space_analysis] -- or build it with [Self::new()].
+#[non_exhaustive]
 pub struct BigOAlgorithmAnalysis<T: BigOAlgorithmMeasurements> {
     pub time_complexity:         BigOAlgorithmComplexity,
     pub space_complexity:        BigOAlgorithmComplexity,
     pub algorithm_measurements:  T,
+    /// the estimated constant factor of `time_complexity`, in seconds -- "about 12ns per element" for an `O(n)` algorithm.
+    /// `None` if the time complexity has no model to estimate it from -- see [super::complexity_coefficient()]
+    pub time_coefficient:        Option<f64>,
+    /// `true` if the time measurements are so close to the boundary between two complexities that noise could have tipped the
+    /// `time_complexity` -- see [super::time_analysis::is_time_near_boundary()]
+    pub is_near_boundary:        bool,
+}
+
+/// Tolerant-but-bounded expectation for an observed complexity -- both `lower` & `upper` bounds are inclusive.\
+/// Useful for algorithms with known tight bounds, when the measurement environment introduces some uncertainty.
+/// A single [BigOAlgorithmComplexity] converts into a pair without a lower bound: the usual "maximum complexity" expectation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ComplexityBoundPair {
+    pub lower: BigOAlgorithmComplexity,
+    pub upper: BigOAlgorithmComplexity,
+}
+
+/// Signals measurements that can't be trusted for a complexity analysis -- they should be re-measured rather than analysed.\
+/// See [super::time_analysis::detect_time_anomaly()]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MeasurementAnomaly {
+    /// the second pass -- with the greater `n` -- ran faster than the first: physically impossible for the algorithm, this indicates
+    /// contaminated measurements (a pause or cache effects on the first pass, a busy machine...) -- which would otherwise
+    /// be reported as [BigOAlgorithmComplexity::BetterThanO1]
+    DecreasingTime,
+}
+
+/// Result of comparing the complexities of two [BigOAlgorithmAnalysis] -- see [BigOAlgorithmAnalysis::compare()].\
+/// Each dimension tells how the analysis at hand relates to the other one: [Ordering::Less] means *better* (less complex),
+/// [Ordering::Greater] means *worse*.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ComparisonResult {
+    pub time:  Ordering,
+    pub space: Ordering,
+}
+
+/// How a complexity changed in relation to the baseline's -- see [AnalysisDiff]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComplexityChange {
+    /// the complexity is now lower (better)
+    Improved,
+    Unchanged,
+    /// the complexity is now higher (worse)
+    Regressed,
+}
+
+/// The changes of a [BigOAlgorithmAnalysis] in relation to a baseline one -- usually, of a previous run -- see [BigOAlgorithmAnalysis::diff()].\
+/// Percentages are positive when the figure grew and are `None` if the baseline's figure is zero (or missing)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AnalysisDiff {
+    pub time_complexity:                 ComplexityChange,
+    pub space_complexity:                ComplexityChange,
+    /// the change of the second pass' elapsed time
+    pub pass_2_time_change_percent:      Option<f64>,
+    /// the change of the memory used by the second pass -- see [BigOSpacePassMeasurements::used_memory()]
+    pub pass_2_memory_change_percent:    Option<f64>,
+    /// the change of the estimated constant factor of the time complexity -- see [BigOAlgorithmAnalysis::time_coefficient].
+    /// `None` if the time complexity changed, as the coefficients of different classes are not comparable
+    pub time_coefficient_change_percent: Option<f64>,
 }
 
 /// Contains the measurements for regular, non-iterator algorithms, so that they may have their time & space complexities analysed\
@@ -78,7 +247,9 @@ pub struct AlgorithmMeasurements<'a> {
     /// allocations / de-allocations measured for each pass run
     pub space_measurements: BigOSpaceMeasurements,
     ////////////////////////////
+    #[cfg(feature = "std")]
     pub pass1_measurements: BigOPassMeasurements,
+    #[cfg(feature = "std")]
     pub pass2_measurements: BigOPassMeasurements,
 }
 
@@ -95,10 +266,13 @@ pub struct ConstantSetIteratorAlgorithmMeasurements<'a> {
     /// allocations / de-allocations measured for each pass run
     pub space_measurements: BigOSpaceMeasurements,
     ////////////////////////////
+    #[cfg(feature = "std")]
     pub pass1_measurements: BigOPassMeasurements,
+    #[cfg(feature = "std")]
     pub pass2_measurements: BigOPassMeasurements,
 }
 
+#[cfg(feature = "std")]
 pub struct BigOPassMeasurements {
     pub time_measurements: Duration,
     pub space_measurements: BigOSpacePassMeasurements,
@@ -119,12 +293,33 @@ pub struct SetResizingIteratorAlgorithmMeasurements<'a> {
     pub space_measurements: BigOSpaceMeasurements,
 }
 
-/// represents an algorithm's execution time measurements for passes 1 & 2
+/// represents an algorithm's execution time measurements for passes 1 & 2.\
+/// Times are kept as [Duration]s -- with nanosecond precision -- and only converted to `f64` seconds when computing
+/// the ratios in [super::time_analysis], so no precision is lost to integer truncation, even for sub-microsecond passes.
 pub struct BigOTimeMeasurements {
     pub pass_1_measurements: Duration,
     pub pass_2_measurements: Duration,
 }
 
+/// elapsed times observed for the single iterations of a pass -- allowing occasional spikes (like a `Vec` reallocation)
+/// to be told apart from the typical iteration. See [super::time_analysis::analyse_amortized_time_complexity()]
+#[derive(Debug,Clone,Copy,Default,PartialEq,Eq)]
+pub struct BigOIterationTimeStatistics {
+    /// number of iterations of the pass
+    pub iterations:                   u32,
+    pub min_elapsed_per_iteration:    Duration,
+    pub median_elapsed_per_iteration: Duration,
+    pub max_elapsed_per_iteration:    Duration,
+}
+
+/// represents an algorithm's execution time measurements for passes 1 & 2, along with their single-iteration statistics
+/// -- for the amortized time complexity analysis
+pub struct BigOAmortizedTimeMeasurements {
+    pub time_measurements:  BigOTimeMeasurements,
+    pub pass_1_iterations:  BigOIterationTimeStatistics,
+    pub pass_2_iterations:  BigOIterationTimeStatistics,
+}
+
 /// represents an algorithm's execution memory usage measurements for passes 1 & 2 -- in bytes
 #[derive(Default)]
 pub struct BigOSpaceMeasurements {
@@ -135,13 +330,16 @@ pub struct BigOSpaceMeasurements {
 /// memory usage measurements, in bytes, for a pass execution
 #[derive(Debug,Clone,Copy,Default)]
 pub struct BigOSpacePassMeasurements {
-    /// heap memory in use just before starting the pass execution
+    /// heap memory in use just before starting the pass execution -- the baseline the other fields are compared to
     pub used_memory_before: usize,
-    /// heap memory in use just after the pass execution
+    /// heap memory in use just after the pass execution -- the *retained* memory, see [Self::net_delta()] &
+    /// [crate::low_level_analysis::space_analysis::analyse_retained_space_complexity()]
     pub used_memory_after:  usize,
-    /// maximum heap memory used during the pass execution
+    /// maximum heap memory used during the pass execution -- the peak, including any *auxiliary* memory: what the
+    /// default space analyses use (`max_used_memory - used_memory_before`)
     pub max_used_memory:    usize,
-    /// minimum heap memory used during the pass execution
+    /// minimum heap memory used during the pass execution -- below `used_memory_before` when the pass freed memory allocated
+    /// before it (like deletes do): shown, along with the peak, by [Self::fmt_over_n()]
     pub min_used_memory:    usize,
 }
 
@@ -150,21 +348,22 @@ pub struct BigOSpacePassMeasurements {
 /// Note that *Regular Algorithms* is in opposition to *Iterator Algorithms*
 pub struct AlgorithmPassesInfo {
     /// elements processed on the first pass
-    pub pass1_n: u32,
+    pub pass1_n: u64,
     /// elements processed on the second pass (usually the double of the first)
-    pub pass2_n: u32,
+    pub pass2_n: u64,
 }
 
 /// Represents the pass information for Iterator Algorithms that don't alter the set size of the data they operate on
-/// (Selects / Updates / Sort / Fib...)
+/// (Selects / Updates / Sort / Fib...).\
+/// Built by [Self::new()] or [Self::with_ratio()], which assure the set sizes differ.
 pub struct ConstantSetIteratorAlgorithmPassesInfo {
     /// set size when running "pass 1"
-    pub pass_1_set_size: u32,
+    pub(crate) pass_1_set_size: u64,
     /// set size when running "pass 2"
-    pub pass_2_set_size: u32,
+    pub(crate) pass_2_set_size: u64,
     /// number of times the algorithm should run on each pass,
     /// where each run operates on a single element
-    pub repetitions: u32,
+    pub(crate) repetitions: u64,
 }
 
 /// Represents the pass information for Iterator Algorithms that alter the set size of the data they operate on
@@ -173,5 +372,5 @@ pub struct SetResizingIteratorAlgorithmPassesInfo {
     /// number of elements added / removed on each pass;
     /// each algorithm iteration should either add or remove a single element
     /// and the test set must start (and/or end) with 0 elements
-    pub delta_set_size: u32,
+    pub delta_set_size: u64,
 }
\ No newline at end of file
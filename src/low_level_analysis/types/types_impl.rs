@@ -3,10 +3,174 @@
 //! TODO 2022-06-28: The pursued benefit with this split was to make that module simpler -- maybe this doesn't compensate.
 
 use super::types::*;
-use std::fmt::{Display, Formatter};
-use std::time::Duration;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::time::Duration;
+
+impl BigOIterationTimeStatistics {
+    /// computes the statistics for the given single-iteration elapsed times -- which are sorted in the process
+    pub fn from_elapsed_times(elapsed_times: &mut [Duration]) -> Self {
+        elapsed_times.sort_unstable();
+        Self {
+            iterations:                   elapsed_times.len() as u32,
+            min_elapsed_per_iteration:    elapsed_times.first().copied().unwrap_or_default(),
+            median_elapsed_per_iteration: elapsed_times.get(elapsed_times.len() / 2).copied().unwrap_or_default(),
+            max_elapsed_per_iteration:    elapsed_times.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+impl ConstantSetIteratorAlgorithmPassesInfo {
+    /// Describes passes on sets of `pass_1_set_size` & `pass_2_set_size` elements -- in any ratio, as long as they differ -- running
+    /// the algorithm `repetitions` times on each.\
+    /// Panics if the sizes are equal, as no complexity may be told from them.
+    pub fn new(pass_1_set_size: u64, pass_2_set_size: u64, repetitions: u64) -> Self {
+        assert!(pass_1_set_size != pass_2_set_size, "Constant set iterator algorithms must be analysed on passes with different set sizes -- both have {} elements", pass_1_set_size);
+        Self { pass_1_set_size, pass_2_set_size, repetitions }
+    }
+    /// Same as [Self::new()], with the second set `ratio` times bigger than the first: `2` is enough for steady measurements, while
+    /// `4` or more helps the complexity to stand out of the noise -- of jittery machines or of algorithms whose reference ratios are
+    /// close to each other, like `O(1)` & `O(log(n))`.\
+    /// Panics if the second set size doesn't fit in a `u64`.
+    pub fn with_ratio(pass_1_set_size: u64, ratio: u64, repetitions: u64) -> Self {
+        let pass_2_set_size = pass_1_set_size.checked_mul(ratio)
+            .unwrap_or_else(|| panic!("Constant set iterator algorithm passes: a set of {} elements can't be {} times bigger", pass_1_set_size, ratio));
+        Self::new(pass_1_set_size, pass_2_set_size, repetitions)
+    }
+    /// set size when running "pass 1"
+    pub fn pass_1_set_size(&self) -> u64 {
+        self.pass_1_set_size
+    }
+    /// set size when running "pass 2"
+    pub fn pass_2_set_size(&self) -> u64 {
+        self.pass_2_set_size
+    }
+    /// number of times the algorithm runs on each pass
+    pub fn repetitions(&self) -> u64 {
+        self.repetitions
+    }
+    /// The smaller & the bigger of the set sizes -- the `n1` & `n2` of the analysis, as the passes may run in any order
+    pub fn set_sizes(&self) -> (f64, f64) {
+        (self.pass_1_set_size.min(self.pass_2_set_size) as f64, self.pass_1_set_size.max(self.pass_2_set_size) as f64)
+    }
+}
+
+// each variant of [BigOAlgorithmComplexity::ALL] must be listed in the position of its [BigOAlgorithmComplexity::rank()] --
+// which, with its length, also rules out repeated variants
+const _: () = {
+    let mut i = 0;
+    while i < BigOAlgorithmComplexity::ALL.len() {
+        assert!(BigOAlgorithmComplexity::ALL[i].rank() as usize == i, "`BigOAlgorithmComplexity::ALL` must list the variants in the order of their ranks");
+        i += 1;
+    }
+};
 
 impl BigOAlgorithmComplexity {
+    /// the number of variants -- counted by an exhaustive `match`, so a new variant won't build until it is counted here (and, then,
+    /// listed in [Self::ALL], whose length this is)
+    const COUNT: usize = match Self::O1 {
+        Self::BetterThanO1 |
+        Self::O1 |
+        Self::BetweenO1AndOLogN |
+        Self::OLogN |
+        Self::BetweenOLogNAndON |
+        Self::ON |
+        Self::BetweenONAndONLogN |
+        Self::ONLogN |
+        Self::BetweenONLogNAndONLog2N |
+        Self::ONLog2N |
+        Self::BetweenONLogNAndON2 |
+        Self::ON2 |
+        Self::BetweenON2AndON3 |
+        Self::ON3 |
+        Self::BetweenON3AndON4 |
+        Self::ON4 |
+        Self::BetweenON4AndOkN |
+        Self::OkN |
+        Self::BetweenOkNAndONFactorial |
+        Self::ONFactorial |
+            Self::WorseThanExponential => 21,
+    };
+    /// every variant, from the best to the worst complexity -- see [Self::from_pretty_str()]
+    pub const ALL: [BigOAlgorithmComplexity; Self::COUNT] = [
+        Self::BetterThanO1,
+        Self::O1,
+        Self::BetweenO1AndOLogN,
+        Self::OLogN,
+        Self::BetweenOLogNAndON,
+        Self::ON,
+        Self::BetweenONAndONLogN,
+        Self::ONLogN,
+        Self::BetweenONLogNAndONLog2N,
+        Self::ONLog2N,
+        Self::BetweenONLogNAndON2,
+        Self::ON2,
+        Self::BetweenON2AndON3,
+        Self::ON3,
+        Self::BetweenON3AndON4,
+        Self::ON4,
+        Self::BetweenON4AndOkN,
+        Self::OkN,
+        Self::BetweenOkNAndONFactorial,
+        Self::ONFactorial,
+        Self::WorseThanExponential,
+    ];
+    /// explicit ranking used by the [Ord] implementation -- so the ordering doesn't depend on the variants' declaration order
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::BetterThanO1         => 0,
+            Self::O1                   => 1,
+            Self::BetweenO1AndOLogN    => 2,
+            Self::OLogN                => 3,
+            Self::BetweenOLogNAndON    => 4,
+            Self::ON                   => 5,
+            Self::BetweenONAndONLogN   => 6,
+            Self::ONLogN               => 7,
+            Self::BetweenONLogNAndONLog2N => 8,
+            Self::ONLog2N              => 9,
+            Self::BetweenONLogNAndON2  => 10,
+            Self::ON2                  => 11,
+            Self::BetweenON2AndON3     => 12,
+            Self::ON3                  => 13,
+            Self::BetweenON3AndON4     => 14,
+            Self::ON4                  => 15,
+            Self::BetweenON4AndOkN     => 16,
+            Self::OkN                  => 17,
+            Self::BetweenOkNAndONFactorial => 18,
+            Self::ONFactorial          => 19,
+            Self::WorseThanExponential => 20,
+        }
+    }
+    /// The number of elements assumed for the first pass by [Self::from_ratio()] -- as, unlike the polynomial ones, the logarithmic &
+    /// factorial reference ratios depend on the absolute sizes, not only on their ratio
+    pub const RATIO_REFERENCE_N: f64 = 1000.0;
+    /// Classifies the complexity of an algorithm taking `t_ratio` times longer (`t2/t1`) on a pass with `n_ratio` times more elements
+    /// (`n2/n1`) -- with the same bands as [crate::low_level_analysis::analyse_complexity()], for those computing their ratios from
+    /// external benchmarks, profilers or logs.\
+    /// `n1` is taken to be [Self::RATIO_REFERENCE_N] -- prefer [crate::low_level_analysis::analyse_complexity()] if the actual sizes
+    /// are known. Also note that the `O(kⁿ)` bands, depending on the magnitude of the measurements, can't be told from ratios.
+    pub fn from_ratio(t_ratio: f64, n_ratio: f64) -> Self {
+        let n1 = Self::RATIO_REFERENCE_N;
+        crate::low_level_analysis::analyse_complexity(1.0, t_ratio, n1, n1 * n_ratio)
+    }
+    /// Classifies the complexity of a set-resizing algorithm (like inserts & deletes) taking `t_ratio` times longer (`t2/t1`) on its
+    /// second pass, each pass adding (or removing) `n` elements -- with the same bands as
+    /// [crate::low_level_analysis::analyse_set_resizing_iterator_complexity()]
+    pub fn from_set_resizing_ratio(t_ratio: f64, n: f64) -> Self {
+        crate::low_level_analysis::analyse_set_resizing_iterator_complexity(1.0, t_ratio, n)
+    }
+    /// `true` if this (observed) complexity is no worse than the given `max` (expected) one
+    pub fn is_at_most(self, max: BigOAlgorithmComplexity) -> bool {
+        self <= max
+    }
+    /// `true` if this (observed) complexity is within the given (inclusive) bounds -- see [ComplexityBoundPair]
+    pub fn is_between(self, lower: BigOAlgorithmComplexity, upper: BigOAlgorithmComplexity) -> bool {
+        lower <= self && self <= upper
+    }
+    /// returns the worse of the two given complexities -- useful for combining several verdicts (like the CRUD ones) into an overall rating
+    pub fn worse_of(a: BigOAlgorithmComplexity, b: BigOAlgorithmComplexity) -> BigOAlgorithmComplexity {
+        core::cmp::max(a, b)
+    }
     /// verbose description for each enum element
     pub fn as_pretty_str(&self) -> &'static str {
         match self {
@@ -18,7 +182,9 @@ impl BigOAlgorithmComplexity {
             Self::ON                  => "O(n)",
             Self::BetweenONAndONLogN  => "Worse than O(n), but better than O(n.log(n))",
             Self::ONLogN              => "O(n.log(n))",
-            Self::BetweenONLogNAndON2 => "Worse than O(n.log(n)), but better than O(n²)",
+            Self::BetweenONLogNAndONLog2N => "Worse than O(n.log(n)), but better than O(n.log²(n))",
+            Self::ONLog2N             => "O(n.log²(n))",
+            Self::BetweenONLogNAndON2 => "Worse than O(n.log²(n)), but better than O(n²)",
             Self::ON2                 => "O(n²)",
             Self::BetweenON2AndON3    => "Worse than O(n²), but better than O(n³)",
             Self::ON3                 => "O(n³)",
@@ -26,14 +192,39 @@ impl BigOAlgorithmComplexity {
             Self::ON4                 => "O(n⁴)",
             Self::BetweenON4AndOkN    => "Worse than O(n⁴), but better than O(kⁿ)",
             Self::OkN                 => "O(kⁿ)",
-            Self::WorseThanExponential => "Worse than O(kⁿ)",
+            Self::BetweenOkNAndONFactorial => "Worse than O(kⁿ), but better than O(n!)",
+            Self::ONFactorial         => "O(n!)",
+            Self::WorseThanExponential => "Worse than O(n!)",
+        }
+    }
+    /// the inverse of [Self::as_pretty_str()] -- for reading back serialized complexities, like the ones in a baseline file.\
+    /// Surrounding whitespaces are ignored; `None` if `pretty_str` doesn't name any complexity.
+    pub fn from_pretty_str(pretty_str: &str) -> Option<Self> {
+        let pretty_str = pretty_str.trim();
+        Self::ALL.into_iter()
+            .find(|complexity| complexity.as_pretty_str() == pretty_str)
+    }
+    /// the term multiplying the constant factor on this complexity's model -- `n.log(n)` for `O(n.log(n))` -- or `None` if there is
+    /// no model to match against. See [crate::low_level_analysis::complexity_coefficient()]
+    pub fn model_term(&self) -> Option<&'static str> {
+        match self {
+            Self::O1          => Some(""),
+            Self::OLogN       => Some("log(n)"),
+            Self::ON          => Some("n"),
+            Self::ONLogN      => Some("n.log(n)"),
+            Self::ONLog2N     => Some("n.log²(n)"),
+            Self::ON2         => Some("n²"),
+            Self::ON3         => Some("n³"),
+            Self::ON4         => Some("n⁴"),
+            Self::ONFactorial => Some("n!"),
+            _ => None,
         }
     }
     /// same as [as_pretty_str()], with additional info for time analysis
     pub fn as_time_pretty_str(&self) -> &'static str {
         match self {
             Self::BetterThanO1      => "Better than O(1) -- aren't the machines idle? too many threads? too little RAM?",
-            Self::WorseThanExponential => "Worse than Exponential!! -- worse than O(kⁿ) -- really, really bad algorithm, too short execution times or is there a hidden bug?",
+            Self::WorseThanExponential => "Worse than Factorial!! -- worse than O(n!) -- really, really bad algorithm, too short execution times or is there a hidden bug?",
             _ => self.as_pretty_str(),
         }
     }
@@ -41,38 +232,345 @@ impl BigOAlgorithmComplexity {
     pub fn as_space_pretty_str(&self) -> &'static str {
         match self {
             Self::BetterThanO1      => "Better than O(1) -- are initialization allocations involved? Consider using a warm up pass",
-            Self::WorseThanExponential => "Worse than Exponential!! -- worse than O(kⁿ) -- really, really bad algorithm or is there a hidden bug?",
+            Self::WorseThanExponential => "Worse than Factorial!! -- worse than O(n!) -- really, really bad algorithm or is there a hidden bug?",
             _ => self.as_pretty_str(),
         }
     }
+
+    /// the `(n, log(n))` exponents of this complexity, if polynomial -- `O(n.log²(n))` being `(1, 2)` -- or `None` for worse than
+    /// `O(n⁴)`. "Between" complexities are taken as their upper bounds. See [complexity_compose()]
+    pub(super) fn polynomial_exponents(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::BetterThanO1 | Self::O1                 => Some((0.0, 0.0)),
+            Self::BetweenO1AndOLogN | Self::OLogN         => Some((0.0, 1.0)),
+            Self::BetweenOLogNAndON | Self::ON            => Some((1.0, 0.0)),
+            Self::BetweenONAndONLogN | Self::ONLogN       => Some((1.0, 1.0)),
+            Self::BetweenONLogNAndONLog2N | Self::ONLog2N => Some((1.0, 2.0)),
+            Self::BetweenONLogNAndON2 | Self::ON2         => Some((2.0, 0.0)),
+            Self::BetweenON2AndON3 | Self::ON3            => Some((3.0, 0.0)),
+            Self::BetweenON3AndON4 | Self::ON4            => Some((4.0, 0.0)),
+            _ => None,
+        }
+    }
+    /// the complexity of `O(nⁿ_exponent.logˡᵒᵍ_exponent(n))`: the exact class, if there is one, or the "between" class containing it
+    pub(super) fn from_polynomial_exponents(n_exponent: f64, log_exponent: f64) -> Self {
+        // exact classes, in increasing order, together with the "between" class just below them
+        const CLASSES: [(BigOAlgorithmComplexity, (f64, f64), BigOAlgorithmComplexity); 8] = [
+            (BigOAlgorithmComplexity::O1,      (0.0, 0.0), BigOAlgorithmComplexity::BetterThanO1),
+            (BigOAlgorithmComplexity::OLogN,   (0.0, 1.0), BigOAlgorithmComplexity::BetweenO1AndOLogN),
+            (BigOAlgorithmComplexity::ON,      (1.0, 0.0), BigOAlgorithmComplexity::BetweenOLogNAndON),
+            (BigOAlgorithmComplexity::ONLogN,  (1.0, 1.0), BigOAlgorithmComplexity::BetweenONAndONLogN),
+            (BigOAlgorithmComplexity::ONLog2N, (1.0, 2.0), BigOAlgorithmComplexity::BetweenONLogNAndONLog2N),
+            (BigOAlgorithmComplexity::ON2,     (2.0, 0.0), BigOAlgorithmComplexity::BetweenONLogNAndON2),
+            (BigOAlgorithmComplexity::ON3,     (3.0, 0.0), BigOAlgorithmComplexity::BetweenON2AndON3),
+            (BigOAlgorithmComplexity::ON4,     (4.0, 0.0), BigOAlgorithmComplexity::BetweenON3AndON4),
+        ];
+        // `n` exponents may come from logarithms (see [complexity_divide_and_conquer()]), so they are compared with some slack
+        let n_cmp = |class_n_exponent: f64| if (class_n_exponent - n_exponent).abs() < 1e-9 { Ordering::Equal } else { class_n_exponent.total_cmp(&n_exponent) };
+        CLASSES.into_iter()
+            .find_map(|(class, (class_n_exponent, class_log_exponent), class_below)|
+                match n_cmp(class_n_exponent).then(class_log_exponent.total_cmp(&log_exponent)) {
+                    Ordering::Less    => None,
+                    Ordering::Equal   => Some(class),
+                    Ordering::Greater => Some(class_below),
+                })
+            .unwrap_or(Self::BetweenON4AndOkN)
+    }
+}
+
+/// Less complex is lesser: `O(1) < O(n)`
+impl Ord for BigOAlgorithmComplexity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+impl PartialOrd for BigOAlgorithmComplexity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+#[cfg(feature = "std")]
+/// shows the given time in the most readable unit, down to picoseconds
+fn to_human_readable_time(seconds: f64) -> String {
+    let (unit, scale) = [("s", 1.0), ("ms", 1e-3), ("µs", 1e-6), ("ns", 1e-9)].into_iter()
+        .find(|(_unit, scale)| seconds.abs() >= *scale)
+        .unwrap_or(("ps", 1e-12));
+    format!("{:.2}{}", seconds / scale, unit)
 }
 
+#[cfg(feature = "std")]
+/// shows the given amount of memory (in bytes) with b, KiB, MiB or GiB unit suffixes
+fn to_human_readable_memory(used_memory: f32) -> String {
+    let memory_unit = if used_memory > (1<<30) as f32 {"GiB"}                        else if used_memory > (1<<20) as f32 {"MiB"}                        else if used_memory > (1<<10) as f32 {"KiB"}                        else {"b"};
+    let memory_delta = if used_memory > (1<<30) as f32 {used_memory / (1<<30) as f32} else if used_memory > (1<<20) as f32 {used_memory / (1<<20) as f32} else if used_memory > (1<<10) as f32 {used_memory / (1<<10) as f32} else {used_memory};
+    format!("{:.2}{}", memory_delta, memory_unit)
+}
 
+#[cfg(feature = "std")]
 impl<T: BigOAlgorithmMeasurements> Display for BigOAlgorithmAnalysis<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let time_coefficient = self.fmt_time_coefficient()
+            .map(|time_coefficient| format!(" ({})", time_coefficient))
+            .unwrap_or_default();
+        let near_boundary_warning = if self.is_near_boundary { " [WARN: measurement near complexity boundary, consider retrying or increasing iterations]" } else { "" };
         write!(f, "{}\
-                   --> Algorithm  Time Analysis: {}\n\
+                   --> Algorithm  Time Analysis: {}{}{}\n\
                    --> Algorithm Space Analysis: {} ({space_measurements})\n",
                self.algorithm_measurements,
-               self.time_complexity.as_time_pretty_str(),
-               self.space_complexity.as_space_pretty_str(), space_measurements=self.algorithm_measurements.space_measurements())
+               self.time_complexity.as_time_pretty_str(), time_coefficient, near_boundary_warning,
+               self.space_complexity.as_space_pretty_str(), space_measurements=self.algorithm_measurements.space_measurements())?;
+        // no expected mark: the expectations aren't known here
+        #[cfg(feature = "ascii_charts")]
+        {
+            let time_measurements = self.algorithm_measurements.time_measurements();
+            write!(f, "{}", crate::AsciiChart::render(time_measurements.pass_1_measurements.as_nanos() as u64, time_measurements.pass_2_measurements.as_nanos() as u64, 0.0))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: BigOAlgorithmMeasurements> BigOAlgorithmAnalysis<T> {
+    /// Builds the analysis of the given `algorithm_measurements`, whose complexities were already found -- estimating the
+    /// [Self::time_coefficient] & [Self::is_near_boundary] from the measurements
+    pub fn new(time_complexity: BigOAlgorithmComplexity, space_complexity: BigOAlgorithmComplexity, algorithm_measurements: T) -> Self {
+        let time_coefficient = crate::low_level_analysis::time_analysis::analyse_time_coefficient(time_complexity, &algorithm_measurements);
+        let is_near_boundary = crate::low_level_analysis::time_analysis::is_time_near_boundary(&algorithm_measurements);
+        Self { time_complexity, space_complexity, algorithm_measurements, time_coefficient, is_near_boundary }
+    }
+    /// `true` if the observed *time* complexity is within the given (inclusive) bounds -- time being the one subject to
+    /// the measurement environment's uncertainties. See [ComplexityBoundPair]
+    pub fn is_between(&self, lower: BigOAlgorithmComplexity, upper: BigOAlgorithmComplexity) -> bool {
+        self.time_complexity.is_between(lower, upper)
+    }
+    /// Compares both time & space complexities of this analysis against `other`'s -- useful when comparing two implementations of the same algorithm
+    pub fn compare<U: BigOAlgorithmMeasurements>(&self, other: &BigOAlgorithmAnalysis<U>) -> ComparisonResult {
+        ComparisonResult {
+            time:  self.time_complexity.cmp(&other.time_complexity),
+            space: self.space_complexity.cmp(&other.space_complexity),
+        }
+    }
+    /// `true` if this analysis is better than `other`'s in at least one dimension (time or space) and not worse in the other one
+    pub fn better_than<U: BigOAlgorithmMeasurements>(&self, other: &BigOAlgorithmAnalysis<U>) -> bool {
+        self.compare(other).is_better()
+    }
+    /// `true` if this analysis is worse than `other`'s in at least one dimension (time or space) and not better in the other one
+    pub fn worse_than<U: BigOAlgorithmMeasurements>(&self, other: &BigOAlgorithmAnalysis<U>) -> bool {
+        self.compare(other).is_worse()
+    }
+    /// `true` if both time & space complexities are the same as `other`'s
+    pub fn equivalent_to<U: BigOAlgorithmMeasurements>(&self, other: &BigOAlgorithmAnalysis<U>) -> bool {
+        self.compare(other).is_equivalent()
+    }
+    /// `true` if the estimated [Self::time_coefficient] exceeds `max_time_coefficient` -- the time taken per unit of the complexity's
+    /// model term (per element, for `O(n)`): catching "still `O(n)`, but 5x slower" regressions the complexity class alone can't.\
+    /// `false` if no coefficient could be estimated -- see [BigOAlgorithmComplexity::model_term()]
+    pub fn exceeds_time_coefficient(&self, max_time_coefficient: Duration) -> bool {
+        self.time_coefficient.is_some_and(|time_coefficient| time_coefficient > max_time_coefficient.as_secs_f64())
+    }
+    /// Tells how this analysis changed in relation to the `baseline` one -- for CI gating against a stored previous run: see
+    /// [AnalysisDiff::is_regression()]
+    pub fn diff<U: BigOAlgorithmMeasurements>(&self, baseline: &BigOAlgorithmAnalysis<U>) -> AnalysisDiff {
+        let comparison = self.compare(baseline);
+        let (time_measurements, baseline_time_measurements) = (self.algorithm_measurements.time_measurements(), baseline.algorithm_measurements.time_measurements());
+        let (space_measurements, baseline_space_measurements) = (self.algorithm_measurements.space_measurements(), baseline.algorithm_measurements.space_measurements());
+        AnalysisDiff {
+            time_complexity:                 comparison.time.into(),
+            space_complexity:                comparison.space.into(),
+            pass_2_time_change_percent:      percent_change(time_measurements.pass_2_measurements.as_secs_f64(), baseline_time_measurements.pass_2_measurements.as_secs_f64()),
+            pass_2_memory_change_percent:    percent_change(space_measurements.pass_2_measurements.used_memory() as f64, baseline_space_measurements.pass_2_measurements.used_memory() as f64),
+            // coefficients of different complexity classes are not comparable
+            time_coefficient_change_percent: self.time_coefficient.zip(baseline.time_coefficient)
+                                                 .filter(|_| comparison.time == Ordering::Equal)
+                                                 .and_then(|(time_coefficient, baseline_time_coefficient)| percent_change(time_coefficient, baseline_time_coefficient)),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Renders a small, aligned, multi-line summary of this analysis -- for pasting into PRs and the like: the operation name, the time
+    /// complexity with the pass 2 / pass 1 time ratio it was fitted from, the space complexity with its retained / auxiliary split,
+    /// the estimated time coefficient and whether both complexities meet the given expectations.\
+    /// This is opt-in: the default [Display] report is not affected.
+    pub fn report_card(&self, expected_time_complexity: impl Into<ComplexityBoundPair>, expected_space_complexity: impl Into<ComplexityBoundPair>) -> String {
+        fn verdict(passed: bool) -> &'static str {
+            if passed { "PASS" } else { "FAIL" }
+        }
+        let (expected_time_complexity, expected_space_complexity) = (expected_time_complexity.into(), expected_space_complexity.into());
+        let time_measurements = self.algorithm_measurements.time_measurements();
+        let space_measurements = self.algorithm_measurements.space_measurements();
+        let time_ratio = time_measurements.pass_2_measurements.as_secs_f64() / time_measurements.pass_1_measurements.as_secs_f64();
+        let retained_memory = space_measurements.used_memory_delta();
+        let time_passed = expected_time_complexity.contains(self.time_complexity);
+        let space_passed = expected_space_complexity.contains(self.space_complexity);
+        format!("operation    '{}'\n\
+                 time         {:<16}  t₂/t₁: {:.2}\n\
+                 space        {:<16}  retained: {}{}; auxiliary: {}\n\
+                 coefficient  {}\n\
+                 expected     time {} ({}); space {} ({})\n\
+                 verdict      {}\n",
+                self.algorithm_measurements.measurement_name(),
+                self.time_complexity.as_pretty_str(), time_ratio,
+                self.space_complexity.as_pretty_str(), if retained_memory < 0 { "-" } else { "" }, to_human_readable_memory(retained_memory.unsigned_abs() as f32),
+                to_human_readable_memory(space_measurements.used_auxiliary_space() as f32),
+                self.fmt_time_coefficient().unwrap_or_else(|| String::from("n/a")),
+                expected_time_complexity, verdict(time_passed), expected_space_complexity, verdict(space_passed),
+                verdict(time_passed && space_passed))
+    }
+
+    #[cfg(feature = "std")]
+    /// the estimated time coefficient, in the most readable unit, together with its complexity model term -- `≈12.00ns × n`
+    pub(crate) fn fmt_time_coefficient(&self) -> Option<String> {
+        match (self.time_coefficient, self.time_complexity.model_term()) {
+            (Some(coefficient), Some(""))   => Some(format!("≈{}", to_human_readable_time(coefficient))),
+            (Some(coefficient), Some(term)) => Some(format!("≈{} × {}", to_human_readable_time(coefficient), term)),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Renders pass 1 & pass 2 time and space measurements as side-by-side ASCII bars, annotated with the detected complexities
+    /// -- for a quick visual sanity check in the terminal. The bars are scaled against the largest pass, so a longer second
+    /// pass bar is what one would expect for anything worse than O(1). Drawn like the [crate::AsciiChart] ones.\
+    /// This is opt-in: the default [Display] report is not affected.
+    pub fn ascii_chart(&self) -> String {
+        let bar = crate::AsciiChart::bar;
+        let time_measurements = self.algorithm_measurements.time_measurements();
+        let space_measurements = self.algorithm_measurements.space_measurements();
+        let (pass_1_time, pass_2_time) = (time_measurements.pass_1_measurements.as_secs_f64(), time_measurements.pass_2_measurements.as_secs_f64());
+        let (pass_1_space, pass_2_space) = (space_measurements.pass_1_measurements.used_memory() as f64, space_measurements.pass_2_measurements.used_memory() as f64);
+        let max_time = pass_1_time.max(pass_2_time);
+        let max_space = pass_1_space.max(pass_2_space);
+        format!("time  1) {} {:>13}\n\
+                 \x20     2) {} {:>13}  --> {}\n\
+                 space 1) {} {:>13}\n\
+                 \x20     2) {} {:>13}  --> {}\n",
+                bar(pass_1_time, max_time), format!("{:?}", time_measurements.pass_1_measurements),
+                bar(pass_2_time, max_time), format!("{:?}", time_measurements.pass_2_measurements), self.time_complexity.as_pretty_str(),
+                bar(pass_1_space, max_space), format!("{}", space_measurements.pass_1_measurements),
+                bar(pass_2_space, max_space), format!("{}", space_measurements.pass_2_measurements), self.space_complexity.as_pretty_str())
+    }
+}
+
+
+impl ComplexityBoundPair {
+    /// builds the bounds, checking `lower` is not worse than `upper`
+    pub fn new(lower: BigOAlgorithmComplexity, upper: BigOAlgorithmComplexity) -> Self {
+        assert!(lower <= upper, "ComplexityBoundPair: the lower bound {:?} is worse than the upper bound {:?}", lower, upper);
+        Self { lower, upper }
+    }
+    /// bounds with no lower limit -- anything better than `upper` is accepted
+    pub fn at_most(upper: BigOAlgorithmComplexity) -> Self {
+        Self { lower: BigOAlgorithmComplexity::BetterThanO1, upper }
+    }
+    /// `true` if the given (observed) `complexity` is within these bounds
+    pub fn contains(&self, complexity: BigOAlgorithmComplexity) -> bool {
+        complexity.is_between(self.lower, self.upper)
+    }
+}
+impl From<BigOAlgorithmComplexity> for ComplexityBoundPair {
+    fn from(upper: BigOAlgorithmComplexity) -> Self {
+        Self::at_most(upper)
+    }
+}
+impl From<(BigOAlgorithmComplexity, BigOAlgorithmComplexity)> for ComplexityBoundPair {
+    fn from((lower, upper): (BigOAlgorithmComplexity, BigOAlgorithmComplexity)) -> Self {
+        Self::new(lower, upper)
+    }
+}
+impl Display for ComplexityBoundPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.lower == BigOAlgorithmComplexity::BetterThanO1 {
+            write!(f, "maximum: {:?}", self.upper)
+        } else {
+            write!(f, "between {:?} and {:?}", self.lower, self.upper)
+        }
+    }
+}
+
+impl Display for MeasurementAnomaly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DecreasingTime => write!(f, "decreasing time -- the pass with the greater `n` ran faster"),
+        }
+    }
+}
+
+/// the percentage `value` grew (or, if negative, shrank) from `baseline` -- `None` if the baseline is zero
+fn percent_change(value: f64, baseline: f64) -> Option<f64> {
+    (baseline != 0.0).then(|| (value - baseline) / baseline * 100.0)
+}
+
+impl ComparisonResult {
+    /// better in at least one dimension and not worse in the other
+    pub fn is_better(&self) -> bool {
+        self.time != Ordering::Greater && self.space != Ordering::Greater && (self.time == Ordering::Less || self.space == Ordering::Less)
+    }
+    /// worse in at least one dimension and not better in the other
+    pub fn is_worse(&self) -> bool {
+        self.time != Ordering::Less && self.space != Ordering::Less && (self.time == Ordering::Greater || self.space == Ordering::Greater)
+    }
+    /// same complexities in both dimensions
+    pub fn is_equivalent(&self) -> bool {
+        self.time == Ordering::Equal && self.space == Ordering::Equal
+    }
+    /// `true` if neither time nor space got worse -- see [assert_not_worse_than!()](crate::assert_not_worse_than)
+    pub fn is_not_worse(&self) -> bool {
+        self.time != Ordering::Greater && self.space != Ordering::Greater
+    }
+}
+
+impl From<Ordering> for ComplexityChange {
+    /// from the ordering of a complexity in relation to the baseline's -- see [ComparisonResult]
+    fn from(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Less    => ComplexityChange::Improved,
+            Ordering::Equal   => ComplexityChange::Unchanged,
+            Ordering::Greater => ComplexityChange::Regressed,
+        }
+    }
+}
+
+impl AnalysisDiff {
+    /// `true` if either the time or space complexity regressed or if the time coefficient grew more than `max_coefficient_growth_percent`
+    /// -- the coefficient telling an algorithm slower than the baseline's, yet in the same complexity class
+    pub fn is_regression(&self, max_coefficient_growth_percent: f64) -> bool {
+        self.time_complexity == ComplexityChange::Regressed ||
+        self.space_complexity == ComplexityChange::Regressed ||
+        self.time_coefficient_change_percent.is_some_and(|change_percent| change_percent > max_coefficient_growth_percent)
     }
 }
 
 
 impl BigOAlgorithmMeasurements for AlgorithmMeasurements<'_> {
+    fn measurement_name(&self) -> &str {
+        self.measurement_name
+    }
+    fn time_measurements(&self) -> &BigOTimeMeasurements {
+        &self.time_measurements
+    }
     fn space_measurements(&self) -> &BigOSpaceMeasurements {
         &self.space_measurements
     }
+    fn time_coefficient_reference(&self) -> Option<(f64, f64)> {
+        Some((self.time_measurements.pass_2_measurements.as_secs_f64(), self.passes_info.pass2_n as f64))
+    }
+    fn pass_2_operations(&self) -> f64 {
+        self.passes_info.pass2_n as f64
+    }
+    fn passes_n(&self) -> Option<(f64, f64)> {
+        Some((self.passes_info.pass1_n as f64, self.passes_info.pass2_n as f64))
+    }
 }
+#[cfg(feature = "std")]
 impl Display for AlgorithmMeasurements<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let pass_1_time  = format!("{:?}", self.time_measurements.pass_1_measurements);
         let pass_2_time  = format!("{:?}", self.time_measurements.pass_2_measurements);
         let pass_1_space = format!("{}", self.space_measurements.pass_1_measurements);
         let pass_2_space = format!("{}", self.space_measurements.pass_2_measurements);
-        let pass_1_space_per_n = format!("{}", self.space_measurements.pass_1_measurements.fmt_over_n(self.passes_info.pass1_n));
-        let pass_2_space_per_n = format!("{}", self.space_measurements.pass_2_measurements.fmt_over_n(self.passes_info.pass2_n));
+        let pass_1_space_per_n = self.space_measurements.pass_1_measurements.fmt_over_n(self.passes_info.pass1_n);
+        let pass_2_space_per_n = self.space_measurements.pass_2_measurements.fmt_over_n(self.passes_info.pass2_n);
         write!(f, "'{}' regular-algorithm measurements:\n\
                    pass          Δt              Δs             n            s⁻           t⁻\n\
                    1) {:>13}  {:>14}  {:>12}  {:>12}  {:>12.3?}\n\
@@ -93,12 +591,28 @@ impl Display for AlgorithmMeasurements<'_> {
 
 
 impl BigOAlgorithmMeasurements for ConstantSetIteratorAlgorithmMeasurements<'_> {
+    fn measurement_name(&self) -> &str {
+        self.measurement_name
+    }
+    fn time_measurements(&self) -> &BigOTimeMeasurements {
+        &self.time_measurements
+    }
     fn space_measurements(&self) -> &BigOSpaceMeasurements {
         &self.space_measurements
     }
+    fn time_coefficient_reference(&self) -> Option<(f64, f64)> {
+        Some((self.time_measurements.pass_2_measurements.as_secs_f64() / self.passes_info.repetitions as f64, self.passes_info.pass_2_set_size as f64))
+    }
+    fn pass_2_operations(&self) -> f64 {
+        self.passes_info.repetitions as f64
+    }
+    fn passes_n(&self) -> Option<(f64, f64)> {
+        Some(self.passes_info.set_sizes())
+    }
 }
+#[cfg(feature = "std")]
 impl Display for ConstantSetIteratorAlgorithmMeasurements<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let pass_1_time  = format!("{:?}", self.time_measurements.pass_1_measurements);
         let pass_2_time  = format!("{:?}", self.time_measurements.pass_2_measurements);
         let pass_1_space = format!("{}", self.space_measurements.pass_1_measurements);
@@ -123,12 +637,33 @@ impl Display for ConstantSetIteratorAlgorithmMeasurements<'_> {
 
 
 impl BigOAlgorithmMeasurements for SetResizingIteratorAlgorithmMeasurements<'_> {
+    fn measurement_name(&self) -> &str {
+        self.measurement_name
+    }
+    fn time_measurements(&self) -> &BigOTimeMeasurements {
+        &self.time_measurements
+    }
     fn space_measurements(&self) -> &BigOSpaceMeasurements {
         &self.space_measurements
     }
+    /// the second pass grows the set from `delta_set_size` to `2*delta_set_size` elements: its midpoint is taken as `n`
+    fn time_coefficient_reference(&self) -> Option<(f64, f64)> {
+        Some((self.time_measurements.pass_2_measurements.as_secs_f64() / self.passes_info.delta_set_size as f64, self.passes_info.delta_set_size as f64 * 1.5))
+    }
+    /// the elements the second pass added to -- or removed from -- the set
+    fn pass_2_operations(&self) -> f64 {
+        self.passes_info.delta_set_size as f64
+    }
+    /// checked against the bands of the set resizing analysis -- see [crate::low_level_analysis::is_set_resizing_near_boundary()]
+    fn is_time_near_boundary(&self) -> bool {
+        crate::low_level_analysis::is_set_resizing_near_boundary(self.time_measurements.pass_1_measurements.as_secs_f64(),
+                                                                 self.time_measurements.pass_2_measurements.as_secs_f64(),
+                                                                 self.passes_info.delta_set_size as f64)
+    }
 }
+#[cfg(feature = "std")]
 impl Display for SetResizingIteratorAlgorithmMeasurements<'_> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let pass_1_time  = format!("{:?}", self.time_measurements.pass_1_measurements);
         let pass_2_time  = format!("{:?}", self.time_measurements.pass_2_measurements);
         let pass_1_space = format!("{}", self.space_measurements.pass_1_measurements);
@@ -154,30 +689,41 @@ impl BigOSpaceMeasurements {
     /// auxiliary space refers to the memory used during the computation and then freed before ending the computation --
     /// returns max_used_ram - max(used_memory_delta, used_memory_start), meaning: >0 if auxiliary memory was allocated
     pub fn used_auxiliary_space(&self) -> usize {
-        self.pass_2_measurements.max_used_memory - std::cmp::max(self.pass_2_measurements.used_memory_after, self.pass_2_measurements.used_memory_before) +
-            self.pass_1_measurements.max_used_memory - std::cmp::max(self.pass_1_measurements.used_memory_after, self.pass_1_measurements.used_memory_before)
+        self.pass_2_measurements.max_used_memory - core::cmp::max(self.pass_2_measurements.used_memory_after, self.pass_2_measurements.used_memory_before) +
+            self.pass_1_measurements.max_used_memory - core::cmp::max(self.pass_1_measurements.used_memory_after, self.pass_1_measurements.used_memory_before)
     }
 }
+#[cfg(feature = "std")]
 impl Display for BigOSpaceMeasurements {
     // shows allocated / deallocated amount + any used auxiliary space
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        fn to_human_readable_string(used_memory: f32) -> String {
-            let memory_unit = if used_memory > (1<<30) as f32 {"GiB"}                        else if used_memory > (1<<20) as f32 {"MiB"}                        else if used_memory > (1<<10) as f32 {"KiB"}                        else {"b"};
-            let memory_delta = if used_memory > (1<<30) as f32 {used_memory / (1<<30) as f32} else if used_memory > (1<<20) as f32 {used_memory / (1<<20) as f32} else if used_memory > (1<<10) as f32 {used_memory / (1<<10) as f32} else {used_memory};
-            format!("{:.2}{}", memory_delta, memory_unit)
-        }
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let used_or_freed = self.used_memory_delta();
         let alloc_op = if used_or_freed >= 0 { "allocated" } else { "freed" };
         let used_auxiliary_space = self.used_auxiliary_space();
         write!(f, "{}: {}; auxiliary used space: {}",
                alloc_op,
-               to_human_readable_string(used_or_freed.abs() as f32),
-               to_human_readable_string(used_auxiliary_space as f32))
+               to_human_readable_memory(used_or_freed.abs() as f32),
+               to_human_readable_memory(used_auxiliary_space as f32))
     }
 }
 
 
 impl BigOSpacePassMeasurements {
+    /// The memory the pass left allocated -- `used_memory_after - used_memory_before` -- negative if it freed more than it allocated.\
+    /// This is the *retained* memory (like the elements inserted in a container or the output of a `map()`), as opposed to the
+    /// peak (`max_used_memory - used_memory_before`) that also includes the scratch memory freed before the pass finished
+    pub fn net_delta(&self) -> isize {
+        self.used_memory_after as isize - self.used_memory_before as isize
+    }
+
+    /// The memory the pass used -- either the peak allocated (`max_used_memory - used_memory_before`) or the freed
+    /// (`used_memory_before - min_used_memory`) bytes, whichever is bigger: the figure [Self::fmt_over_n()] presents
+    pub fn used_memory(&self) -> usize {
+        core::cmp::max(self.max_used_memory.saturating_sub(self.used_memory_before),
+                      self.used_memory_before.saturating_sub(self.min_used_memory))
+    }
+
+    #[cfg(feature = "std")]
     /// Presents either the used or freed memory, with b, KiB, MiB or GiB unit suffixes -- and with the optional `-`, `+` or a null prefix:
     ///  * `-` denotes RAM was freed instead of allocated
     ///  * `+` means RAM was allocated (and remained so)
@@ -185,10 +731,10 @@ impl BigOSpacePassMeasurements {
     ///
     /// When `n` is 1.0, shows the absolute RAM usage;
     /// otherwise, `n` should be the number of elements and the output will represent the memory usage per element
-    pub fn fmt_over_n(&self, n: u32) -> String {
+    pub fn fmt_over_n(&self, n: u64) -> String {
         let used_memory = (self.used_memory_after as f32 - self.used_memory_before as f32) / n as f32;
         let sign = if used_memory > 0.0 {"+"} else if used_memory < 0.0 {"-"} else {""};
-        let used_memory = std::cmp::max( self.max_used_memory    - self.used_memory_before,
+        let used_memory = core::cmp::max( self.max_used_memory    - self.used_memory_before,
                                               self.used_memory_before - self.min_used_memory ) as f32 / n as f32;
         let memory_unit = if used_memory.abs() > (1<<30) as f32 {"GiB"}                        else if used_memory.abs() > (1<<20) as f32 {"MiB"}                              else if used_memory.abs() > (1<<10) as f32 {"KiB"}                              else {"b"};
         let memory_delta = if used_memory.abs() > (1<<30) as f32 {used_memory / (1<<30) as f32} else if used_memory.abs() > (1<<20) as f32 {used_memory.abs() / (1<<20) as f32} else if used_memory.abs() > (1<<10) as f32 {used_memory.abs() / (1<<10) as f32} else {used_memory.abs()};
@@ -200,8 +746,9 @@ impl BigOSpacePassMeasurements {
         }
     }
 }
+#[cfg(feature = "std")]
 impl Display for BigOSpacePassMeasurements {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.fmt_over_n(1))
     }
 }
@@ -215,10 +762,14 @@ mod tests {
 
     use crate::{
         low_level_analysis::{
-            types::BigOAlgorithmComplexity,
+            types::*,
         },
     };
+    #[cfg(feature = "std")]
+    use crate::low_level_analysis::types::fixtures;
     use serial_test::serial;
+    #[cfg(feature = "std")]
+    use std::{cmp::Ordering, time::Duration};
 
 
     /// assures serializations & implementors of *Display* from [types] work without panics
@@ -227,14 +778,56 @@ mod tests {
     #[serial]
     fn serialization() {
         println!("BigOAlgorithmComplexity enum members, as strings:");
-        let enum_members = [
+        let enum_members = BigOAlgorithmComplexity::ALL;
+        for enum_member in enum_members {
+            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
+        }
+        println!("\n");
+
+        let special_enum_members = [
+            BigOAlgorithmComplexity::BetterThanO1,
+            BigOAlgorithmComplexity::WorseThanExponential,
+        ];
+
+        println!(" .as_time_pretty_str():");
+        for enum_member in special_enum_members {
+            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
+        }
+        println!("\n");
+
+        println!(" .as_space_pretty_str():");
+        for enum_member in special_enum_members {
+            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
+        }
+        println!("\n");
+    }
+
+    /// assures [BigOAlgorithmComplexity::from_pretty_str()] reads back what [BigOAlgorithmComplexity::as_pretty_str()] wrote, for every variant
+    #[test]
+    fn pretty_str_round_trip() {
+        for (rank, complexity) in BigOAlgorithmComplexity::ALL.into_iter().enumerate() {
+            assert_eq!(complexity.rank() as usize, rank, "`ALL` should list every variant, in order -- {:?} is misplaced", complexity);
+            assert_eq!(BigOAlgorithmComplexity::from_pretty_str(complexity.as_pretty_str()), Some(complexity), "Round trip failed for {:?}", complexity);
+        }
+        assert_eq!(BigOAlgorithmComplexity::from_pretty_str("  O(n.log(n))\n"), Some(BigOAlgorithmComplexity::ONLogN), "Surrounding whitespaces should be ignored");
+        assert_eq!(BigOAlgorithmComplexity::from_pretty_str("O(n^2)"), None, "Unknown notations should not be parsed");
+        assert_eq!(BigOAlgorithmComplexity::from_pretty_str(""), None);
+    }
+
+    /// assures the [Ord] implementation ranks complexities monotonically, from the best to the worst
+    #[test]
+    fn ordering() {
+        let ordered_members = [
             BigOAlgorithmComplexity::BetterThanO1,
             BigOAlgorithmComplexity::O1,
+            BigOAlgorithmComplexity::BetweenO1AndOLogN,
             BigOAlgorithmComplexity::OLogN,
             BigOAlgorithmComplexity::BetweenOLogNAndON,
             BigOAlgorithmComplexity::ON,
             BigOAlgorithmComplexity::BetweenONAndONLogN,
             BigOAlgorithmComplexity::ONLogN,
+            BigOAlgorithmComplexity::BetweenONLogNAndONLog2N,
+            BigOAlgorithmComplexity::ONLog2N,
             BigOAlgorithmComplexity::BetweenONLogNAndON2,
             BigOAlgorithmComplexity::ON2,
             BigOAlgorithmComplexity::BetweenON2AndON3,
@@ -243,28 +836,280 @@ mod tests {
             BigOAlgorithmComplexity::ON4,
             BigOAlgorithmComplexity::BetweenON4AndOkN,
             BigOAlgorithmComplexity::OkN,
+            BigOAlgorithmComplexity::BetweenOkNAndONFactorial,
+            BigOAlgorithmComplexity::ONFactorial,
             BigOAlgorithmComplexity::WorseThanExponential,
         ];
-        for enum_member in enum_members {
-            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
+        for pair in ordered_members.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} should be less complex than {:?}", pair[0], pair[1]);
         }
-        println!("\n");
+        let mut shuffled = ordered_members;
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(shuffled, ordered_members, "Sorting should yield the best-to-worst order");
+    }
 
-        let special_enum_members = [
-            BigOAlgorithmComplexity::BetterThanO1,
-            BigOAlgorithmComplexity::WorseThanExponential,
-        ];
+    /// checks [BigOAlgorithmComplexity::is_at_most()] & [BigOAlgorithmComplexity::worse_of()] on the boundaries
+    #[test]
+    fn is_at_most_and_worse_of() {
+        use BigOAlgorithmComplexity::*;
+        assert!(O1.is_at_most(O1), "A complexity should be at most itself");
+        assert!(BetterThanO1.is_at_most(O1));
+        assert!(!BetweenO1AndOLogN.is_at_most(O1));
+        assert!(!O1.is_at_most(BetterThanO1));
+        assert!(WorseThanExponential.is_at_most(WorseThanExponential));
+        assert!(OkN.is_at_most(WorseThanExponential));
+        assert!(!WorseThanExponential.is_at_most(OkN));
 
-        println!(" .as_time_pretty_str():");
-        for enum_member in special_enum_members {
-            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
-        }
-        println!("\n");
+        assert_eq!(BigOAlgorithmComplexity::worse_of(O1, BetterThanO1), O1);
+        assert_eq!(BigOAlgorithmComplexity::worse_of(O1, O1), O1);
+        assert_eq!(BigOAlgorithmComplexity::worse_of(WorseThanExponential, BetterThanO1), WorseThanExponential);
+        assert_eq!(BigOAlgorithmComplexity::worse_of(OkN, WorseThanExponential), WorseThanExponential);
+        let crud_verdicts = [O1, OLogN, ON, O1];
+        assert_eq!(crud_verdicts.into_iter().fold(BetterThanO1, BigOAlgorithmComplexity::worse_of), ON, "Combining CRUD verdicts failed");
+    }
 
-        println!(" .as_space_pretty_str():");
-        for enum_member in special_enum_members {
-            println!("\t{:?}:\n\t\t=> '{}'", enum_member, enum_member.as_pretty_str());
+    /// checks [ComplexityBoundPair] & the `is_between()` functions on the boundaries
+    #[test]
+    #[cfg(feature = "std")]
+    fn complexity_bounds() {
+        use BigOAlgorithmComplexity::*;
+        assert!(OLogN.is_between(O1, ON), "Inside the bounds");
+        assert!(O1.is_between(O1, ON) && ON.is_between(O1, ON), "Bounds are inclusive");
+        assert!(!BetterThanO1.is_between(O1, ON) && !BetweenONAndONLogN.is_between(O1, ON), "Outside the bounds");
+
+        let bounds = ComplexityBoundPair::from((O1, OLogN));
+        assert!(bounds.contains(BetweenO1AndOLogN) && !bounds.contains(BetterThanO1) && !bounds.contains(ON));
+        assert_eq!(bounds.to_string(), "between O1 and OLogN");
+        let maximum = ComplexityBoundPair::from(ON);
+        assert!(maximum.contains(BetterThanO1) && maximum.contains(ON) && !maximum.contains(ONLogN));
+        assert_eq!(maximum.to_string(), "maximum: ON");
+
+        let analysis = analysis(OLogN, ON, 10, 20, 1024);
+        assert!(analysis.is_between(O1, OLogN), "Only the time complexity should be considered");
+        assert!(!analysis.is_between(ON, ON2));
+    }
+
+    /// checks the classification of externally computed ratios matches the one of the measurements they came from
+    #[test]
+    fn from_ratios() {
+        use BigOAlgorithmComplexity::*;
+        let n1 = BigOAlgorithmComplexity::RATIO_REFERENCE_N;
+        let n2 = n1 * 8.0;
+        assert_eq!(BigOAlgorithmComplexity::from_ratio(1.02, 8.0), O1);
+        assert_eq!(BigOAlgorithmComplexity::from_ratio(n2.log2() / n1.log2(), 8.0), OLogN);
+        assert_eq!(BigOAlgorithmComplexity::from_ratio(8.3, 8.0), ON);
+        assert_eq!(BigOAlgorithmComplexity::from_ratio((n2 * n2.log2()) / (n1 * n1.log2()), 8.0), ONLogN);
+        assert_eq!(BigOAlgorithmComplexity::from_ratio(64.0, 8.0), ON2);
+        assert_eq!(BigOAlgorithmComplexity::from_ratio(0.5, 8.0), BetterThanO1);
+        assert_eq!(BigOAlgorithmComplexity::from_set_resizing_ratio(1.0, 1000.0), O1);
+        assert_eq!(BigOAlgorithmComplexity::from_set_resizing_ratio(3.0, 1000.0), ON);
+        assert_eq!(BigOAlgorithmComplexity::from_set_resizing_ratio(3000.0_f64.log2() / 1000.0_f64.log2(), 1000.0), OLogN);
+    }
+
+    /// checks [complexity_compose()], [complexity_add()] & [complexity_divide_and_conquer()] against well known algorithms
+    #[test]
+    #[serial]
+    fn complexity_composition() {
+        use BigOAlgorithmComplexity::*;
+        assert_eq!(complexity_compose(ON, OLogN), ONLogN, "n binary searches");
+        assert_eq!(complexity_compose(ONLogN, OLogN), ONLog2N);
+        assert_eq!(complexity_compose(ON, ON), ON2, "Nested loops");
+        assert_eq!(complexity_compose(ON2, ON2), ON4);
+        assert_eq!(complexity_compose(O1, BetweenO1AndOLogN), BetweenO1AndOLogN, "O(1) should be neutral");
+        assert_eq!(complexity_compose(OLogN, OLogN), BetweenOLogNAndON, "log²(n) isn't a known class");
+        assert_eq!(complexity_compose(ON2, OLogN), BetweenON2AndON3, "n².log(n) isn't a known class");
+        assert_eq!(complexity_compose(ON4, ON), BetweenON4AndOkN, "n⁵ isn't a known class");
+        assert_eq!(complexity_compose(BetweenO1AndOLogN, ON), ONLogN, "\"Between\" complexities should be taken as their upper bounds");
+        assert_eq!(complexity_compose(OkN, ON), BetweenOkNAndONFactorial);
+        assert_eq!(complexity_compose(OkN, OkN), OkN);
+        assert_eq!(complexity_compose(ON, ONFactorial), WorseThanExponential);
+
+        assert_eq!(complexity_add(ON, OLogN), ON);
+        assert_eq!(complexity_add(O1, ON2), ON2);
+
+        assert_eq!(complexity_divide_and_conquer(1, 2, O1),     OLogN,  "Binary search");
+        assert_eq!(complexity_divide_and_conquer(2, 2, O1),     ON,     "Tree traversal");
+        assert_eq!(complexity_divide_and_conquer(2, 2, ON),     ONLogN, "Merge sort");
+        assert_eq!(complexity_divide_and_conquer(2, 2, ONLogN), ONLog2N);
+        assert_eq!(complexity_divide_and_conquer(1, 2, ON),     ON,     "Quickselect (best case)");
+        assert_eq!(complexity_divide_and_conquer(4, 2, ON),     ON2);
+        assert_eq!(complexity_divide_and_conquer(3, 2, ON),     BetweenONLogNAndON2, "Karatsuba is O(n^1.585)");
+        assert_eq!(complexity_divide_and_conquer(7, 2, ON2),    BetweenON2AndON3,    "Strassen is O(n^2.807)");
+    }
+
+    /// builds an analysis with the given complexities & fake measurements -- for tests that don't care about the measurements themselves
+    #[cfg(feature = "std")]
+    fn analysis(time_complexity: BigOAlgorithmComplexity, space_complexity: BigOAlgorithmComplexity,
+                pass_1_millis: u64, pass_2_millis: u64, max_used_memory: usize) -> BigOAlgorithmAnalysis<AlgorithmMeasurements<'static>> {
+        fixtures::analysis("fake analysis", time_complexity, space_complexity,
+                           Duration::from_millis(pass_1_millis), Duration::from_millis(pass_2_millis), max_used_memory, max_used_memory)
+    }
+
+    /// assures [BigOAlgorithmAnalysis::ascii_chart()] scales the bars against the largest pass and annotates the complexities
+    #[test]
+    #[serial]
+    #[cfg(feature = "std")]
+    fn ascii_chart() {
+        let analysis = analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, 10, 20, 1024);
+        let chart = analysis.ascii_chart();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 4, "Chart should have 2 time & 2 space lines");
+        assert_eq!(lines[0], format!("time  1) {}{} {:>13}", "█".repeat(20), " ".repeat(20), "10ms"), "Wrong pass 1 time line");
+        assert_eq!(lines[1], format!("      2) {} {:>13}  --> {}", "█".repeat(40), "20ms", BigOAlgorithmComplexity::ON.as_pretty_str()), "Wrong pass 2 time line");
+        assert_eq!(lines[0].matches('█').count(), 20, "Pass 1 time bar should be half of pass 2's");
+        assert_eq!(lines[1].matches('█').count(), 40, "Pass 2 time bar should take the full width");
+        assert!(lines[1].ends_with(BigOAlgorithmComplexity::ON.as_pretty_str()), "Time complexity annotation is missing");
+        assert_eq!(lines[2].matches('█').count(), lines[3].matches('█').count(), "Equal space usages should render equal bars");
+        assert!(lines[3].ends_with(BigOAlgorithmComplexity::O1.as_pretty_str()), "Space complexity annotation is missing");
+    }
+
+    /// assures implementors of [BigOAlgorithmMeasurements] outside of this crate only need to provide their measurements:
+    /// the other methods have sensible defaults -- and [BigOAlgorithmAnalysis::new()] copes with them
+    #[test]
+    #[cfg(feature = "std")]
+    fn measurements_defaults() {
+        struct MinimalMeasurements {
+            time_measurements:  BigOTimeMeasurements,
+            space_measurements: BigOSpaceMeasurements,
         }
-        println!("\n");
+        impl core::fmt::Display for MinimalMeasurements {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:?} & {:?}", self.time_measurements.pass_1_measurements, self.time_measurements.pass_2_measurements)
+            }
+        }
+        impl BigOAlgorithmMeasurements for MinimalMeasurements {
+            fn time_measurements(&self) -> &BigOTimeMeasurements {
+                &self.time_measurements
+            }
+            fn space_measurements(&self) -> &BigOSpaceMeasurements {
+                &self.space_measurements
+            }
+        }
+        let no_space = BigOSpacePassMeasurements { used_memory_before: 0, used_memory_after: 0, max_used_memory: 0, min_used_memory: 0 };
+        let measurements = MinimalMeasurements {
+            time_measurements:  BigOTimeMeasurements { pass_1_measurements: Duration::from_millis(10), pass_2_measurements: Duration::from_millis(20) },
+            space_measurements: BigOSpaceMeasurements { pass_1_measurements: no_space, pass_2_measurements: no_space },
+        };
+        assert!(measurements.measurement_name().ends_with("MinimalMeasurements"), "The type name should be the default name -- not '{}'", measurements.measurement_name());
+        assert_eq!(measurements.time_coefficient_reference(), None, "No reference is given by default");
+        assert_eq!(measurements.pass_2_operations(), 1.0, "Without a reference, the second pass should be taken as a single operation");
+        let analysis = BigOAlgorithmAnalysis::new(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, measurements);
+        assert_eq!(analysis.time_coefficient, None, "No constant factor may be estimated without a reference");
+        assert!(!analysis.is_near_boundary, "Without the passes' `n`s, no boundary may be approached");
+    }
+
+    /// checks [BigOAlgorithmAnalysis::exceeds_time_coefficient()] catches constant factor regressions within the same complexity class
+    #[test]
+    #[cfg(feature = "std")]
+    fn time_coefficient_ceiling() {
+        let mut analysis = analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, 10, 20, 1024);
+        analysis.time_coefficient = Some(50e-9);
+        assert!(analysis.exceeds_time_coefficient(Duration::from_nanos(10)), "A 5x slower O(n) should exceed the ceiling");
+        assert!(!analysis.exceeds_time_coefficient(Duration::from_nanos(50)), "The ceiling is inclusive");
+        analysis.time_coefficient = None;
+        assert!(!analysis.exceeds_time_coefficient(Duration::ZERO), "Without an estimated coefficient, there is nothing to exceed");
+    }
+
+    /// assures the report warns about measurements near a complexity boundary -- and only then
+    #[test]
+    #[cfg(feature = "std")]
+    fn near_boundary_warning() {
+        let warning = "[WARN: measurement near complexity boundary, consider retrying or increasing iterations]";
+        let mut analysis = analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, 10, 20, 1024);
+        assert!(!analysis.to_string().contains(warning), "No warning should be issued for measurements far from any boundary");
+        analysis.is_near_boundary = true;
+        assert!(analysis.to_string().contains(&format!("Time Analysis: {} {}", BigOAlgorithmComplexity::ON.as_time_pretty_str(), warning)),
+                "The warning should follow the time analysis: '{}'", analysis);
+    }
+
+    /// assures [BigOAlgorithmAnalysis::report_card()] lines up its fields and judges each complexity against its expectation
+    #[test]
+    #[serial]
+    #[cfg(feature = "std")]
+    fn report_card() {
+        let mut analysis = analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, 10, 20, 1024);
+        analysis.time_coefficient = Some(12e-9);
+        let report_card = analysis.report_card(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1);
+        println!("{}", report_card);
+        let lines: Vec<&str> = report_card.lines().collect();
+        assert_eq!(lines.len(), 6, "Unexpected number of lines in the report card");
+        assert!(lines.iter().all(|line| line.chars().nth(13).is_some_and(|c| !c.is_whitespace()) && line.chars().nth(12) == Some(' ')),
+                "Values should be aligned in a single column");
+        assert_eq!(lines[0], "operation    'fake analysis'");
+        assert!(lines[1].contains(BigOAlgorithmComplexity::ON.as_pretty_str()) && lines[1].ends_with("t₂/t₁: 2.00"), "Wrong time line: '{}'", lines[1]);
+        assert!(lines[2].ends_with("retained: 0.00b; auxiliary: 2.00KiB"), "Wrong space line: '{}'", lines[2]);
+        assert_eq!(lines[3], "coefficient  ≈12.00ns × n");
+        assert_eq!(lines[5], "verdict      PASS");
+
+        analysis.time_coefficient = None;
+        let report_card = analysis.report_card(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1);
+        assert!(report_card.contains("coefficient  n/a\n"), "A missing coefficient should be reported as such");
+        assert!(report_card.contains("time maximum: O1 (FAIL); space maximum: O1 (PASS)"), "The time mismatch should be pointed out");
+        assert!(report_card.ends_with("verdict      FAIL\n"), "Any mismatch should fail the verdict");
+    }
+
+    /// checks [BigOAlgorithmAnalysis::diff()] & [AnalysisDiff::is_regression()] -- for improved, unchanged & regressed analyses
+    #[test]
+    #[serial]
+    #[cfg(feature = "std")]
+    fn diff() {
+        let approximately = |change_percent: Option<f64>, expected_percent: f64| change_percent.is_some_and(|change_percent| (change_percent - expected_percent).abs() < 1e-9);
+        let mut baseline = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON, 10, 20, 1000);
+        baseline.time_coefficient = Some(10e-9);
+
+        // improved
+        let mut improved = analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1, 10, 15, 500);
+        improved.time_coefficient = Some(20e-9);
+        let diff = improved.diff(&baseline);
+        assert_eq!((diff.time_complexity, diff.space_complexity), (ComplexityChange::Improved, ComplexityChange::Improved), "Both complexities should have improved");
+        assert!(approximately(diff.pass_2_time_change_percent, -25.0), "Wrong pass 2 time change: {:?}", diff.pass_2_time_change_percent);
+        assert!(approximately(diff.pass_2_memory_change_percent, -50.0), "Wrong pass 2 memory change: {:?}", diff.pass_2_memory_change_percent);
+        assert_eq!(diff.time_coefficient_change_percent, None, "Coefficients of different complexity classes shouldn't be compared");
+        assert!(!diff.is_regression(10.0), "Improved complexities are not a regression");
+
+        // unchanged
+        let mut unchanged = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON, 10, 22, 1000);
+        unchanged.time_coefficient = Some(11e-9);
+        let diff = unchanged.diff(&baseline);
+        assert_eq!((diff.time_complexity, diff.space_complexity), (ComplexityChange::Unchanged, ComplexityChange::Unchanged), "Complexities should be unchanged");
+        assert!(approximately(diff.pass_2_time_change_percent, 10.0), "Wrong pass 2 time change: {:?}", diff.pass_2_time_change_percent);
+        assert_eq!(diff.pass_2_memory_change_percent, Some(0.0), "Wrong pass 2 memory change");
+        assert!(!diff.is_regression(20.0), "A 10% coefficient growth is within a 20% threshold");
+        assert!(diff.is_regression(5.0), "A 10% coefficient growth is beyond a 5% threshold");
+        unchanged.time_coefficient = None;
+        assert_eq!(unchanged.diff(&baseline).time_coefficient_change_percent, None, "Missing coefficients can't be compared");
+
+        // regressed
+        let regressed = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON2, 10, 20, 0);
+        let diff = regressed.diff(&baseline);
+        assert_eq!((diff.time_complexity, diff.space_complexity), (ComplexityChange::Unchanged, ComplexityChange::Regressed), "The space complexity should have regressed");
+        assert!(diff.is_regression(f64::INFINITY), "Regressed complexities are always a regression");
+        assert_eq!(regressed.diff(&analysis(BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON, 10, 0, 0)).pass_2_time_change_percent, None,
+                   "Changes from a zero baseline are undefined");
+    }
+
+    /// checks [BigOAlgorithmAnalysis::better_than()], [BigOAlgorithmAnalysis::worse_than()] & [BigOAlgorithmAnalysis::equivalent_to()],
+    /// as well as the [assert_not_worse_than!()](crate::assert_not_worse_than) macro
+    #[test]
+    #[serial]
+    #[cfg(feature = "std")]
+    fn comparison() {
+        let baseline      = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON, 10, 20, 1024);
+        let better        = analysis(BigOAlgorithmComplexity::ON,     BigOAlgorithmComplexity::ON, 10, 20, 1024);
+        let worse         = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON2, 10, 20, 1024);
+        let trade_off     = analysis(BigOAlgorithmComplexity::ON,     BigOAlgorithmComplexity::ON2, 10, 20, 1024);
+        let equivalent    = analysis(BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::ON, 30, 60, 4096);
+
+        assert_eq!(better.compare(&baseline), ComparisonResult { time: Ordering::Less, space: Ordering::Equal });
+        assert!(better.better_than(&baseline) && !better.worse_than(&baseline) && !better.equivalent_to(&baseline));
+        assert!(worse.worse_than(&baseline) && !worse.better_than(&baseline));
+        assert!(!trade_off.better_than(&baseline) && !trade_off.worse_than(&baseline), "A trade-off is neither better nor worse");
+        assert!(equivalent.equivalent_to(&baseline), "Only complexities -- not measurements -- should be compared");
+
+        crate::assert_not_worse_than!(better, baseline);
+        crate::assert_not_worse_than!(equivalent, baseline);
+        let regression = std::panic::catch_unwind(|| crate::assert_not_worse_than!(trade_off, baseline));
+        assert!(regression.is_err(), "A regression in space should have been caught, even if time got better");
     }
 }
@@ -1,10 +1,12 @@
 //! See [super]
 
 use std::fmt::{Formatter, Display};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 use std::alloc::{System, GlobalAlloc, Layout};
 
 use crate::metrics_allocator::ring_buffer::{RingBuffer, RingBufferConsumer};
+#[cfg(feature = "typed_tracking")]
+use crate::metrics_allocator::typed_tracking::TypedAllocations;
 
 /// struct returned by [MetricsAllocator::delta_statistics()]
 pub struct MetricsAllocatorStatistics<NumericType> {
@@ -95,6 +97,16 @@ pub struct MetricsAllocator<'a, const RING_BUFFER_SIZE: usize> {
     system_allocator:        &'a System,
     statistics:              MetricsAllocatorStatistics<AtomicUsize>,
     used_memory_ring_buffer: RingBuffer<SavePointRingBufferSlot<usize>, RING_BUFFER_SIZE>,
+    /// only 1 in every `sampling_rate` operations of each kind has its bytes tracked -- see [Self::set_sampling_rate()]
+    sampling_rate:           AtomicU32,
+    /// how many [Self::pause()]s are still to be [Self::resume()]d -- no metrics are tracked while this is > 0
+    pause_depth:             AtomicU32,
+    /// tells if some allocations may have gone untracked -- due to [Self::pause()] or [Self::set_sampling_rate()] -- since the last
+    /// [Self::save_point()], so their de-allocations may exceed the tracked used memory
+    untracked_allocations:   AtomicBool,
+    /// see [Self::tag_next_allocation()]
+    #[cfg(feature = "typed_tracking")]
+    typed_allocations:       TypedAllocations,
 }
 impl<const RING_BUFFER_SIZE: usize>
 Default
@@ -125,11 +137,76 @@ impl<const RING_BUFFER_SIZE: usize> MetricsAllocator<'_, RING_BUFFER_SIZE> {
                 max_used_memory:             AtomicUsize::new(0),
             },
             used_memory_ring_buffer: RingBuffer::new(),
+            sampling_rate:           AtomicU32::new(1),
+            pause_depth:             AtomicU32::new(0),
+            untracked_allocations:   AtomicBool::new(false),
+            #[cfg(feature = "typed_tracking")]
+            typed_allocations:       TypedAllocations::new(),
         }
     }
 
-    /// Prepares a new measurement for future allocations, to be inferred by [delta_statistics()](MetricsAllocator::delta_statistics()).
-    pub fn save_point(&self) -> MetricsAllocatorSavePoint<RING_BUFFER_SIZE> {
+    /// Has only 1 in every `every_nth` allocations (and, independently, de-allocations, zeroed allocations & re-allocations)
+    /// tracking its bytes & the used memory -- the others are passed through to the System allocator with just their counts incremented.\
+    /// Sampled operations account for `every_nth` times their bytes, so the byte metrics become estimates: this reduces the tracking
+    /// overhead -- which may perturb the measurements of algorithms doing many tiny allocations -- at the cost of proportionally
+    /// less accurate absolute byte counts. Counts are always exact. The default, `1`, tracks every operation.
+    pub fn set_sampling_rate(&self, every_nth: u32) {
+        assert!(every_nth > 0, "MetricsAllocator: the sampling rate must be at least 1 -- {} was given", every_nth);
+        if every_nth > 1 {
+            self.untracked_allocations.store(true, Ordering::Relaxed);
+        }
+        self.sampling_rate.store(every_nth, Ordering::Relaxed);
+    }
+
+    /// Stops tracking any metrics -- for all threads -- until [Self::resume()] is called: allocations, de-allocations & re-allocations
+    /// are passed through to the System allocator, so unrelated code (like building reports) won't pollute the space analysis.\
+    /// Pauses may be nested: tracking resumes when every `pause()` has been matched by a `resume()`.\
+    /// Memory allocated while paused, but freed after resuming, is subtracted from the used memory it never added to: the used
+    /// memory saturates at zero, but is under-reported from then on -- harmless to the deltas of [Self::delta_statistics()], provided
+    /// it doesn't happen in the middle of a measurement.
+    pub fn pause(&self) {
+        self.untracked_allocations.store(true, Ordering::Relaxed);
+        self.pause_depth.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Resumes tracking the metrics stopped by [Self::pause()]
+    pub fn resume(&self) {
+        let resumed = self.pause_depth.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |depth| depth.checked_sub(1));
+        assert!(resumed.is_ok(), "MetricsAllocator: `resume()` called without a matching `pause()`");
+    }
+
+    /// Tells if metrics tracking is currently paused -- see [Self::pause()]
+    pub fn is_paused(&self) -> bool {
+        self.pause_depth.load(Ordering::Relaxed) > 0
+    }
+
+    /// The current sampling rate -- see [Self::set_sampling_rate()]
+    pub fn sampling_rate(&self) -> u32 {
+        self.sampling_rate.load(Ordering::Relaxed)
+    }
+
+    /// Attributes the next allocation (or zeroed allocation, or re-allocation -- by its new size) made by the calling thread
+    /// to type `T`, for [Self::typed_statistics()] -- as [GlobalAlloc] doesn't receive type information, allocation sites
+    /// must be annotated. Requires the `typed_tracking` feature. See [super::typed_tracking].
+    #[cfg(feature = "typed_tracking")]
+    pub fn tag_next_allocation<T: ?Sized + 'static>(&self) {
+        self.typed_allocations.tag_next_allocation::<T>();
+    }
+
+    /// Returns the (bytes, count) of the allocations tagged, with [Self::tag_next_allocation()], for each type -- since the program started.\
+    /// Unaffected by the [sampling rate](Self::set_sampling_rate()). Requires the `typed_tracking` feature.
+    #[cfg(feature = "typed_tracking")]
+    pub fn typed_statistics(&self) -> std::collections::HashMap<std::any::TypeId, (u64, u64)> {
+        self.typed_allocations.statistics()
+    }
+
+    /// Prepares a new measurement for future allocations, to be inferred by [delta_statistics()](MetricsAllocator::delta_statistics()).\
+    /// When the [sampling rate](Self::set_sampling_rate()) is greater than `1`, the byte & used memory metrics are estimates.
+    pub fn save_point(&self) -> MetricsAllocatorSavePoint<'_, RING_BUFFER_SIZE> {
+        // a new measurement starts tracking all operations again -- provided it isn't paused nor sampled
+        if !self.is_paused() && self.sampling_rate() == 1 {
+            self.untracked_allocations.store(false, Ordering::Relaxed);
+        }
         // add the current (min,max) to the ring buffer and start a new counter
         // the new consumer will consume any further saved_points + the current (min,max)
         self.used_memory_ring_buffer.enqueue(SavePointRingBufferSlot {
@@ -159,7 +236,9 @@ impl<const RING_BUFFER_SIZE: usize> MetricsAllocator<'_, RING_BUFFER_SIZE> {
     }
 
     /// Returns the allocation statistics between now and the point in time when `save_point` was generated
-    /// (with a call to [save_point()](MetricsAllocator::save_point())).
+    /// (with a call to [save_point()](MetricsAllocator::save_point())).\
+    /// If the [sampling rate](Self::set_sampling_rate()) is greater than `1`, only the counts are exact: bytes, as well as the
+    /// current, min & max used memories, are extrapolated from the sampled operations -- less accurate for fewer operations.
     pub fn delta_statistics(&self, save_point: &MetricsAllocatorSavePoint<RING_BUFFER_SIZE>) -> MetricsAllocatorStatistics<usize> {
         let mut min = usize::MAX;
         let mut max = usize::MIN;
@@ -191,41 +270,87 @@ impl<const RING_BUFFER_SIZE: usize> MetricsAllocator<'_, RING_BUFFER_SIZE> {
 
     /// compute metrics for allocation
     fn compute_alloc_metrics(&self, layout: &Layout) {
-        self.statistics.allocations_count.fetch_add(1, Ordering::Relaxed);
-        self.statistics.allocated_bytes.fetch_add(layout.size(), Ordering::Relaxed);
-        self.statistics.current_used_memory.fetch_add(layout.size(), Ordering::Relaxed);
+        if self.is_paused() {
+            return
+        }
+        #[cfg(feature = "typed_tracking")]
+        self.typed_allocations.track_allocation(layout.size());
+        let previous_count = self.statistics.allocations_count.fetch_add(1, Ordering::Relaxed);
+        let Some(weight) = self.sampling_weight(previous_count) else { return };
+        self.statistics.allocated_bytes.fetch_add(layout.size() * weight, Ordering::Relaxed);
+        self.statistics.current_used_memory.fetch_add(layout.size() * weight, Ordering::Relaxed);
         self.compute_min_and_max_used_memories();
     }
 
     /// compute metrics for de-allocation
     fn compute_dealloc_metrics(&self, layout: &Layout) {
-        self.statistics.deallocations_count.fetch_add(1, Ordering::Relaxed);
-        self.statistics.deallocated_bytes.fetch_add(layout.size(), Ordering::Relaxed);
-        self.statistics.current_used_memory.fetch_sub(layout.size(), Ordering::Relaxed);
+        if self.is_paused() {
+            return
+        }
+        let previous_count = self.statistics.deallocations_count.fetch_add(1, Ordering::Relaxed);
+        let Some(weight) = self.sampling_weight(previous_count) else { return };
+        self.statistics.deallocated_bytes.fetch_add(layout.size() * weight, Ordering::Relaxed);
+        self.decrease_used_memory(layout.size() * weight);
         self.compute_min_and_max_used_memories();
     }
 
     /// compute metrics for zeroed allocation
     fn compute_alloc_zeroed_metrics(&self, layout: &Layout) {
-        self.statistics.zeroed_allocations_count.fetch_add(1, Ordering::Relaxed);
-        self.statistics.zeroed_allocated_bytes.fetch_add(layout.size(), Ordering::Relaxed);
-        self.statistics.current_used_memory.fetch_add(layout.size(), Ordering::Relaxed);
+        if self.is_paused() {
+            return
+        }
+        #[cfg(feature = "typed_tracking")]
+        self.typed_allocations.track_allocation(layout.size());
+        let previous_count = self.statistics.zeroed_allocations_count.fetch_add(1, Ordering::Relaxed);
+        let Some(weight) = self.sampling_weight(previous_count) else { return };
+        self.statistics.zeroed_allocated_bytes.fetch_add(layout.size() * weight, Ordering::Relaxed);
+        self.statistics.current_used_memory.fetch_add(layout.size() * weight, Ordering::Relaxed);
         self.compute_min_and_max_used_memories();
     }
 
     /// compute metrics for re-allocation
     fn compute_realloc_metrics(&self, layout: &Layout, new_size: usize) {
-        self.statistics.reallocations_count.fetch_add(1, Ordering::Relaxed);
-        self.statistics.reallocated_originals_bytes.fetch_add(layout.size(), Ordering::Relaxed);
-        self.statistics.reallocated_news_bytes.fetch_add(new_size, Ordering::Relaxed);
+        if self.is_paused() {
+            return
+        }
+        #[cfg(feature = "typed_tracking")]
+        self.typed_allocations.track_allocation(new_size);
+        let previous_count = self.statistics.reallocations_count.fetch_add(1, Ordering::Relaxed);
+        let Some(weight) = self.sampling_weight(previous_count) else { return };
+        self.statistics.reallocated_originals_bytes.fetch_add(layout.size() * weight, Ordering::Relaxed);
+        self.statistics.reallocated_news_bytes.fetch_add(new_size * weight, Ordering::Relaxed);
         if new_size > layout.size() {
-            self.statistics.current_used_memory.fetch_add(new_size-layout.size(), Ordering::Relaxed);
+            self.statistics.current_used_memory.fetch_add((new_size-layout.size()) * weight, Ordering::Relaxed);
         } else if new_size < layout.size() {
-            self.statistics.current_used_memory.fetch_sub(layout.size()-new_size, Ordering::Relaxed);
+            self.decrease_used_memory((layout.size()-new_size) * weight);
         }
         self.compute_min_and_max_used_memories();
     }
 
+    /// tells if the operation numbered `previous_count` (for its kind) should be tracked, returning the weight
+    /// its bytes should be accounted with -- see [Self::set_sampling_rate()]
+    #[inline(always)]
+    fn sampling_weight(&self, previous_count: usize) -> Option<usize> {
+        let sampling_rate = self.sampling_rate.load(Ordering::Relaxed) as usize;
+        previous_count.is_multiple_of(sampling_rate).then_some(sampling_rate)
+    }
+
+    /// decreases the used memory by `bytes` -- saturating at zero if some allocations went untracked (when sampling or after pausing),
+    /// as the tracked allocated & de-allocated bytes may disagree. Memory allocated while untracked before the last save point
+    /// may still be freed after it: if that would wrap the used memory, the decrease is undone and redone saturating
+    #[inline(always)]
+    fn decrease_used_memory(&self, bytes: usize) {
+        if !self.untracked_allocations.load(Ordering::Relaxed) {
+            let used = self.statistics.current_used_memory.fetch_sub(bytes, Ordering::Relaxed);
+            if used >= bytes {
+                return
+            }
+            self.untracked_allocations.store(true, Ordering::Relaxed);
+            self.statistics.current_used_memory.fetch_add(bytes, Ordering::Relaxed);
+        }
+        let _ = self.statistics.current_used_memory.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| Some(used.saturating_sub(bytes)));
+    }
+
     /// helper functions for metrics computation
     fn compute_min_and_max_used_memories(&self) {
         let current_used_memory = self.statistics.current_used_memory.load(Ordering::Relaxed);
@@ -344,13 +469,13 @@ mod tests {
         // new save_point
         let save_point2 = save_point(&used_mem, &mut min_mem, &mut max_mem);
         allocate_and_check(30303, &save_point2, &mut used_mem, &mut min_mem, &mut max_mem);
-        allocate_and_check(03030, &save_point2, &mut used_mem, &mut min_mem, &mut max_mem);
+        allocate_and_check(3030,  &save_point2, &mut used_mem, &mut min_mem, &mut max_mem);
         assert_current_min_and_max_memory(&save_point2, 99999, 66666, 99999);
         assert_current_min_and_max_memory(&save_point1, 99999, 0,     99999);
 
         // new save_point
         let save_point3 = save_point(&used_mem, &mut min_mem, &mut max_mem);
-        deallocate_and_check(05050, &save_point3, &mut used_mem, &mut min_mem, &mut max_mem);
+        deallocate_and_check(5050,  &save_point3, &mut used_mem, &mut min_mem, &mut max_mem);
         deallocate_and_check(50505, &save_point3, &mut used_mem, &mut min_mem, &mut max_mem);
         assert_current_min_and_max_memory(&save_point3, 44444, 44444, 99999);
         assert_current_min_and_max_memory(&save_point2, 44444, 44444, 99999);
@@ -358,7 +483,7 @@ mod tests {
 
         // new save_point
         let save_point4 = save_point(&used_mem, &mut min_mem, &mut max_mem);
-        deallocate_and_check(01010, &save_point4, &mut used_mem, &mut min_mem, &mut max_mem);
+        deallocate_and_check(1010,  &save_point4, &mut used_mem, &mut min_mem, &mut max_mem);
         deallocate_and_check(10101, &save_point4, &mut used_mem, &mut min_mem, &mut max_mem);
         assert_current_min_and_max_memory(&save_point4, 33333, 33333, 44444);
         assert_current_min_and_max_memory(&save_point3, 33333, 33333, 99999);
@@ -371,4 +496,92 @@ mod tests {
         eprintln!("Final metrics for 'save_point3': {}", allocator.delta_statistics(&save_point3));
         eprintln!("Final metrics for 'save_point4': {}", allocator.delta_statistics(&save_point4));
     }
+
+    /// attests [MetricsAllocator::set_sampling_rate()] keeps exact counts while extrapolating the bytes from the sampled operations
+    #[test]
+    fn sampling_rate() {
+        let allocator = MetricsAllocator::<16>::new();
+        assert_eq!(allocator.sampling_rate(), 1, "Every operation should be tracked by default");
+        allocator.set_sampling_rate(4);
+        let save_point = allocator.save_point();
+        let small = Layout::from_size_align(100, 4).unwrap();
+        let large = Layout::from_size_align(1000, 4).unwrap();
+        // only the 1st & 5th allocations are sampled
+        for layout in [small, large, large, large, small, large, large, large] {
+            allocator.compute_alloc_metrics(&layout);
+        }
+        let metrics = allocator.delta_statistics(&save_point);
+        assert_eq!(metrics.allocations_count,   8,       "Counts should be exact, regardless of the sampling rate");
+        assert_eq!(metrics.allocated_bytes,     2*100*4, "Bytes should be extrapolated from the sampled allocations only");
+        assert_eq!(metrics.current_used_memory, 2*100*4, "Used memory should be extrapolated from the sampled allocations only");
+        // de-allocating more than the estimate saturates at zero
+        for layout in [large, small] {
+            allocator.compute_dealloc_metrics(&layout);
+        }
+        let metrics = allocator.delta_statistics(&save_point);
+        assert_eq!(metrics.deallocations_count, 2,        "Counts should be exact, regardless of the sampling rate");
+        assert_eq!(metrics.deallocated_bytes,   1000*4,   "Bytes should be extrapolated from the sampled de-allocations only");
+        assert_eq!(metrics.current_used_memory, 0,        "Used memory should saturate at zero when the sampled estimates disagree");
+        assert_eq!(metrics.max_used_memory,     2*100*4,  "Wrong max used memory estimate");
+    }
+
+    /// attests nothing is tracked while [MetricsAllocator::pause()]d -- and that de-allocating paused allocations don't underflow the used memory
+    #[test]
+    fn pause_and_resume() {
+        let allocator = MetricsAllocator::<16>::new();
+        let layout = Layout::from_size_align(100, 4).unwrap();
+        let save_point = allocator.save_point();
+        allocator.compute_alloc_metrics(&layout);
+        allocator.pause();
+        allocator.pause();
+        allocator.compute_alloc_metrics(&layout);
+        allocator.compute_alloc_metrics(&layout);
+        allocator.resume();
+        assert!(allocator.is_paused(), "Nested pauses should only resume when all of them are resumed");
+        allocator.compute_alloc_metrics(&layout);
+        allocator.resume();
+        assert!(!allocator.is_paused(), "Tracking should have been resumed");
+        let metrics = allocator.delta_statistics(&save_point);
+        assert_eq!((metrics.allocations_count, metrics.allocated_bytes), (1, 100), "Allocations made while paused should not be tracked");
+        // frees all 4 allocations
+        for _ in 0..4 {
+            allocator.compute_dealloc_metrics(&layout);
+        }
+        let metrics = allocator.delta_statistics(&save_point);
+        assert_eq!((metrics.deallocations_count, metrics.current_used_memory), (4, 0), "Freeing the paused allocations should saturate the used memory at zero");
+        // the next measurement no longer saturates -- unless it frees memory allocated while paused before it
+        allocator.pause();
+        allocator.compute_alloc_metrics(&layout);
+        allocator.resume();
+        let save_point = allocator.save_point();
+        assert!(!allocator.untracked_allocations.load(Ordering::Relaxed), "A save point should reset the untracked allocations flag");
+        allocator.compute_alloc_metrics(&layout);
+        allocator.compute_dealloc_metrics(&layout);
+        allocator.compute_dealloc_metrics(&layout);
+        let metrics = allocator.delta_statistics(&save_point);
+        assert_eq!(metrics.current_used_memory, 0, "Freeing an allocation paused before the save point should still saturate the used memory at zero");
+        assert!(allocator.untracked_allocations.load(Ordering::Relaxed), "Saturating should set the untracked allocations flag back");
+    }
+
+    /// attests [MetricsAllocator::tag_next_allocation()] attributes only the next allocation to the tagged type
+    #[cfg(feature = "typed_tracking")]
+    #[test]
+    fn typed_tracking() {
+        use std::any::TypeId;
+        struct Node;
+        struct BackingArray;
+        let allocator = MetricsAllocator::<16>::new();
+        let layout = |size| Layout::from_size_align(size, 4).unwrap();
+        allocator.tag_next_allocation::<Node>();
+        allocator.compute_alloc_metrics(&layout(24));
+        allocator.compute_alloc_metrics(&layout(1000));     // untagged
+        allocator.tag_next_allocation::<Node>();
+        allocator.compute_alloc_zeroed_metrics(&layout(24));
+        allocator.tag_next_allocation::<BackingArray>();
+        allocator.compute_realloc_metrics(&layout(100), 200);
+        let statistics = allocator.typed_statistics();
+        assert_eq!(statistics.len(), 2, "Only the tagged types should be tracked");
+        assert_eq!(statistics[&TypeId::of::<Node>()],         (48, 2),  "Wrong (bytes, count) for `Node`");
+        assert_eq!(statistics[&TypeId::of::<BackingArray>()], (200, 1), "Re-allocations should be tracked by their new sizes");
+    }
 }
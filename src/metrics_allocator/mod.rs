@@ -16,6 +16,9 @@
 //!     let metrics = ALLOC.delta_statistics(&save_point);
 //!     println!("Allocator Metrics for the Vec allocation: {}", metrics);
 
+#[allow(clippy::module_inception)]
 mod metrics_allocator;
 pub use metrics_allocator::*;
 pub mod ring_buffer;
+#[cfg(feature = "typed_tracking")]
+pub mod typed_tracking;
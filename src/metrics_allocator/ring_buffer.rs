@@ -4,24 +4,50 @@
 //!   3) Each consumer holds their own state (their 'head' pointer), therefore access should be done through a special structure [RingBufferConsumer]
 //!   4) Due to (1), any buffer overflows happens silently in the producer, when enqueueing -- overflows are only detectable by the consumers.
 //!      Please see more on [RingBufferConsumer] docs;
-use std::sync::atomic::{AtomicU32, Ordering};
+//!   5) For work-queue semantics -- each element going to a single one of many consuming threads, owned rather than referenced -- see [RingBufferMpmc].
+use std::sync::{Mutex, OnceLock, PoisonError, atomic::{fence, AtomicU32, Ordering}};
 use std::mem::MaybeUninit;
 use std::io::ErrorKind;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 
 
+/// `true` if the wrapping tail or head `position` comes after `reference` -- positions are compared by their wrapping distance,
+/// so the comparison holds across the wrapping of the `u32` counters (as long as they are less than `i32::MAX` apart)
+#[inline(always)]
+const fn is_after(position: u32, reference: u32) -> bool {
+    (position.wrapping_sub(reference) as i32) > 0
+}
+
+/// the latest of the wrapping positions `a` & `b` -- see [is_after()]
+#[inline(always)]
+const fn latest(a: u32, b: u32) -> u32 {
+    if is_after(b, a) { b } else { a }
+}
+
+/// advances the wrapping `position` to `target` -- unless it is already at or after it: the wrap-aware equivalent of `fetch_max()`
+#[inline(always)]
+fn advance_to(position: &AtomicU32, target: u32) {
+    let _advanced = position.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| is_after(target, current).then_some(target));
+}
+
 /// Represents a concurrent, zero-copy, zero-cost multiple-consumers Ringer buffer.\
 /// Create a new ring buffer with 1024 [u32] slots with:
 /// ```
 ///   let ring_buffer = big_o_test::metrics_allocator::ring_buffer::RingBuffer::<u32, 1024>::new();
 /// ```
-/// Note: for optimization purposes, make the ring buffer size a power of 2 -- so that the modulus operation gets optimized to a bit shift instruction.\
+/// Note: for optimization purposes, make the ring buffer size a power of 2 -- so that slot indexes are computed with a bit mask
+/// rather than with the modulus operation. Sizes that are not a power of 2 still work, but [RingBuffer::new_checked()]
+/// may be used to have them rejected at compile time.\
 /// See [self] for more info.
 pub struct RingBuffer<Slot, const RING_BUFFER_SIZE: usize> {
     /// if ahead of [published_tail], indicates new slots is being filled in, to soon be published
     reserved_tail: AtomicU32,
     /// once the slot data is set in place, this counter increases to indicate a new element is ready to be consumed
     published_tail: AtomicU32,
+    /// elements published before this position were discarded by [Self::clear_published()] -- consumers lagging behind it skip to it
+    cleared_tail: AtomicU32,
+    /// the greatest lag of [published_tail] behind [reserved_tail] a producer had to wait for, to publish its element -- see [Self::max_publish_lag()]
+    max_publish_lag: AtomicU32,
     /// the data
     buffer: MaybeUninit<[Slot; RING_BUFFER_SIZE]>,
 }
@@ -34,21 +60,84 @@ for RingBuffer<Slot, RING_BUFFER_SIZE> {
     }
 }
 
+impl<Slot: Debug, const RING_BUFFER_SIZE: usize>
+Debug
+for RingBuffer<Slot, RING_BUFFER_SIZE> {
+    /// shows the tails & the occupancy -- see [Self::len()] -- plus the held elements, if the `debug_ring_buffer_contents` feature is enabled
+    /// (as printing all the slots might be expensive). Elements being enqueued concurrently may show up overwritten.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut debug_struct = f.debug_struct("RingBuffer");
+        debug_struct
+            .field("reserved_tail",  &self.reserved_tail.load(Ordering::Relaxed))
+            .field("published_tail", &self.published_tail.load(Ordering::Relaxed))
+            .field("buffer_size",    &RING_BUFFER_SIZE)
+            .field("occupancy",      &self.len());
+        #[cfg(feature = "debug_ring_buffer_contents")]
+        {
+            // only the held slots were initialized
+            let published_tail = self.published_tail.load(Ordering::Relaxed);
+            let first_held = published_tail.wrapping_sub(self.len() as u32);
+            let array = unsafe { &*self.buffer.as_ptr() };
+            let contents = (first_held..published_tail).map(|position| &array[Self::slot_index(position)]).collect::<Vec<_>>();
+            debug_struct.field("contents", &contents);
+        }
+        debug_struct.finish()
+    }
+}
+
 impl<Slot, const RING_BUFFER_SIZE: usize> RingBuffer<Slot, RING_BUFFER_SIZE> {
 
+    /// evaluated at compile time by [Self::new_checked()], refusing to build if the ring buffer size is not a power of 2
+    const SIZE_IS_POWER_OF_TWO: () = assert!(RING_BUFFER_SIZE.is_power_of_two(), "RingBuffer: RING_BUFFER_SIZE must be a power of 2");
+
     pub const fn new() -> Self {
         Self {
             reserved_tail: AtomicU32::new(0),
             published_tail: AtomicU32::new(0),
+            cleared_tail: AtomicU32::new(0),
+            max_publish_lag: AtomicU32::new(0),
             buffer: MaybeUninit::uninit(),
         }
     }
 
+    /// Same as [Self::new()], but fails to compile if `RING_BUFFER_SIZE` is not a power of 2:
+    /// ```compile_fail
+    ///   let ring_buffer = big_o_test::metrics_allocator::ring_buffer::RingBuffer::<u32, 1000>::new_checked();
+    /// ```
+    /// whereas the following builds fine:
+    /// ```
+    ///   let ring_buffer = big_o_test::metrics_allocator::ring_buffer::RingBuffer::<u32, 1024>::new_checked();
+    /// ```
+    pub const fn new_checked() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::SIZE_IS_POWER_OF_TWO;
+        Self::new()
+    }
+
+    /// maps a (wrapping) tail or head position to its slot in [Self::buffer] -- using a bit mask if the size is a power of 2
+    /// (decided at compile time) or the slower modulus operation otherwise
+    #[inline(always)]
+    const fn slot_index(position: u32) -> usize {
+        if RING_BUFFER_SIZE.is_power_of_two() {
+            position as usize & (RING_BUFFER_SIZE - 1)
+        } else {
+            position as usize % RING_BUFFER_SIZE
+        }
+    }
+
+    /// debug builds' guard for the unsafe accesses to the slot at `index` -- which [Self::slot_index()] must have kept within the
+    /// buffer. Compiled out in release builds
+    #[inline(always)]
+    fn debug_assert_slot_index(index: usize) {
+        debug_assert!(index < RING_BUFFER_SIZE, "BUG: Ring-Buffer slot index {} is out of its {} slots. Please fix.", index, RING_BUFFER_SIZE);
+    }
+
     /// creates a consumer able to consume elements produced after this call
     pub fn consumer(&self) -> RingBufferConsumer<'_, Slot, RING_BUFFER_SIZE> {
         RingBufferConsumer {
             head: AtomicU32::new(self.published_tail.load(Ordering::Relaxed)),
             ring_buffer: self,
+            poisoned: OnceLock::new(),
         }
     }
 
@@ -57,29 +146,83 @@ impl<Slot, const RING_BUFFER_SIZE: usize> RingBuffer<Slot, RING_BUFFER_SIZE> {
 
         // reserve the slot
         let reserved_tail = self.reserved_tail.fetch_add(1, Ordering::Relaxed);
+        debug_assert!(!is_after(self.published_tail.load(Ordering::Relaxed), reserved_tail),
+                      "BUG: Ring-Buffer slot {} was reserved after being published. Please fix.", reserved_tail);
         // set the reserved slot contents
         let mutable_buffer = unsafe {
             let const_ptr = self.buffer.as_ptr();
             let mut_ptr = const_ptr as *mut [Slot; RING_BUFFER_SIZE];
             &mut *mut_ptr
         };
-        mutable_buffer[reserved_tail as usize % RING_BUFFER_SIZE] = element;
+        let index = Self::slot_index(reserved_tail);
+        Self::debug_assert_slot_index(index);
+        mutable_buffer[index] = element;
 
         // publish the new element for consumption
         loop {
-            match self.published_tail.compare_exchange_weak(reserved_tail, reserved_tail+1, Ordering::Release, Ordering::Relaxed) {
+            match self.published_tail.compare_exchange_weak(reserved_tail, reserved_tail.wrapping_add(1), Ordering::Release, Ordering::Relaxed) {
                 Ok(_) => return,
-                Err(reloaded_val) => if reloaded_val > reserved_tail {
+                Err(reloaded_val) => if is_after(reloaded_val, reserved_tail) {
                     panic!("BUG: Infinite loop detected in Ring-Buffer. Please fix.");
+                } else {
+                    // contention: the elements reserved before ours -- and ours -- are still to be published
+                    self.max_publish_lag.fetch_max(reserved_tail.wrapping_sub(reloaded_val) + 1, Ordering::Relaxed);
                 },
             }
         }
     }
 
+    /// How many elements are currently reserved but not yet published -- being filled in by concurrent [Self::enqueue()] calls.
+    /// Diagnostics only: the value may be outdated as soon as it is returned
+    pub fn publish_lag(&self) -> u32 {
+        // loading the published tail first -- with `Acquire`, so the reserved tail can't be loaded before it -- so it is never ahead of
+        // the loaded reserved tail: their wrapping distance is the lag, even across the wrapping of the counters
+        let published_tail = self.published_tail.load(Ordering::Acquire);
+        self.reserved_tail.load(Ordering::Relaxed).wrapping_sub(published_tail)
+    }
+
+    /// The greatest lag (see [Self::publish_lag()]) a producer ever had to wait on before publishing its element -- `0` if
+    /// the publishing never contended. Never decreases, being bound to the number of concurrent producers
+    pub fn max_publish_lag(&self) -> u32 {
+        self.max_publish_lag.load(Ordering::Relaxed)
+    }
+
     pub fn get_buffer_size(&self) -> usize {
         RING_BUFFER_SIZE
     }
 
+    /// The number of published elements still held in the buffer -- available to consumers that didn't consume them yet.\
+    /// As consumers are unknown to the ring buffer, the oldest of their heads is approximated as `published_tail - RING_BUFFER_SIZE`:
+    /// see [Self::consumer_pending()] for the number of elements a specific consumer may still consume.
+    pub fn len(&self) -> usize {
+        (self.published_tail.load(Ordering::Relaxed).wrapping_sub(self.cleared_tail.load(Ordering::Relaxed)) as usize).min(RING_BUFFER_SIZE)
+    }
+
+    /// `true` if no element was published since this ring buffer was created or last cleared -- see [Self::len()]
+    pub fn is_empty(&self) -> bool {
+        self.published_tail.load(Ordering::Relaxed).wrapping_sub(self.cleared_tail.load(Ordering::Relaxed)) == 0
+    }
+
+    /// Discards all published elements -- for one-shot captures: capture some events, process them, discard --
+    /// advancing the heads of all consumers to the current tail, as if they had dequeued everything (see also [RingBufferConsumer::skip_to_current()]).\
+    /// Elements being concurrently enqueued are waited for, then discarded as well: their slots might not be filled in yet, so `published_tail`
+    /// is never forcibly set to `reserved_tail`. Consumers apply the clearing on their next operation -- no iteration over the elements happens.
+    pub fn clear_published(&self) {
+        let reserved_tail = self.reserved_tail.load(Ordering::Relaxed);
+        while is_after(reserved_tail, self.published_tail.load(Ordering::Relaxed)) {
+            std::hint::spin_loop();
+        }
+        advance_to(&self.cleared_tail, reserved_tail);
+    }
+
+    /// The number of published elements the given `consumer` -- created by this ring buffer -- has yet to consume.\
+    /// If greater than `RING_BUFFER_SIZE`, the consumer overflowed: its next operation will yield a [RingBufferOverflowError].
+    pub fn consumer_pending(&self, consumer: &RingBufferConsumer<'_, Slot, RING_BUFFER_SIZE>) -> usize {
+        debug_assert!(std::ptr::eq(consumer.ring_buffer, self), "RingBuffer::consumer_pending(): the given consumer belongs to another ring buffer");
+        let head = latest(consumer.head.load(Ordering::Relaxed), self.cleared_tail.load(Ordering::Relaxed));
+        self.published_tail.load(Ordering::Relaxed).wrapping_sub(head) as usize
+    }
+
 }
 
 
@@ -104,33 +247,76 @@ impl<Slot, const RING_BUFFER_SIZE: usize> RingBuffer<Slot, RING_BUFFER_SIZE> {
 pub struct RingBufferConsumer<'a, Slot, const RING_BUFFER_SIZE: usize> {
     head: AtomicU32,
     ring_buffer: &'a RingBuffer<Slot, RING_BUFFER_SIZE>,
+    /// set -- with the error message -- when an overflow is first detected: from then on, this consumer only yields that same error,
+    /// as `head` no longer points to valid data (even if the producer goes idle)
+    poisoned: OnceLock<RingBufferOverflowError>,
 }
+impl<Slot, const RING_BUFFER_SIZE: usize> Debug for RingBufferConsumer<'_, Slot, RING_BUFFER_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RingBufferConsumer")
+            .field("head",     &self.head.load(Ordering::Relaxed))
+            .field("pending",  &self.ring_buffer.consumer_pending(self))
+            .field("poisoned", &self.poisoned.get().is_some())
+            .finish()
+    }
+}
+
 impl<Slot, const RING_BUFFER_SIZE: usize> RingBufferConsumer<'_, Slot, RING_BUFFER_SIZE> {
 
+    /// poisons this consumer with an overflow error for the given `published_tail` & `head` -- unless it was already poisoned --
+    /// returning the error for the first overflow
+    fn overflow_error(&self, published_tail: u32, head: u32) -> RingBufferOverflowError {
+        self.poisoned.get_or_init(|| RingBufferOverflowError { published_tail, head, ring_buffer_size: RING_BUFFER_SIZE }).clone()
+    }
+
+    /// returns the error for the first detected overflow, if this consumer was poisoned by it
+    fn check_poisoned(&self) -> Result<(), RingBufferOverflowError> {
+        match self.poisoned.get() {
+            Some(overflow_error) => Err(overflow_error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// advances `head` past the elements discarded by [RingBuffer::clear_published()], if it lags behind them
+    fn skip_cleared(&self) {
+        advance_to(&self.head, self.ring_buffer.cleared_tail.load(Ordering::Relaxed));
+    }
+
+    /// Discards all elements pending for this consumer -- without iterating over them -- by advancing its head to the ring buffer's
+    /// current `published_tail`. Other consumers are unaffected: see [RingBuffer::clear_published()] to discard the elements for all of them.
+    pub fn skip_to_current(&self) {
+        advance_to(&self.head, self.ring_buffer.published_tail.load(Ordering::Relaxed));
+    }
+
     /// Zero-copy dequeueing -- returns a reference to the ring-buffer slot containing the dequeued element.
     /// Please note a silent race condition may happen if the ring-buffer's enqueueing operation keeps happening
     /// before this method's caller uses the returned reference. See more on the [RingBufferConsumer] docs.\
     /// Might fail with [RingBufferOverflowError] if the ring buffer had cycled over the element to be dequeued.
     /// Otherwise, returns a reference (if there is some slot to dequeue) or *None* (if there isn't).
     pub fn dequeue(&self) -> Result<Option<&Slot>, RingBufferOverflowError> {
+        self.check_poisoned()?;
+        self.skip_cleared();
         let mut head = self.head.load(Ordering::Relaxed);
         loop {
             let published_tail = self.ring_buffer.published_tail.load(Ordering::Relaxed);
-            if head > published_tail {
+            if is_after(head, published_tail) {
                 head = self.head.load(Ordering::Relaxed);
                 continue;
             }
             if head == published_tail {
                 return Ok(None);
             }
-            match self.head.compare_exchange_weak(head, head + 1, Ordering::Acquire, Ordering::Relaxed) {
+            match self.head.compare_exchange_weak(head, head.wrapping_add(1), Ordering::Acquire, Ordering::Relaxed) {
                 Ok(_) => unsafe {
                     let ptr = self.ring_buffer.buffer.as_ptr();
                     let array = &*ptr;
-                    if self.ring_buffer.reserved_tail.load(Ordering::Relaxed) - head > RING_BUFFER_SIZE as u32 {
-                        return Err(RingBufferOverflowError { msg: format!("Ring-Buffer overflow: published_tail={}, head={} -- tail could not be farther from head than the ring buffer size of {}", published_tail, head, RING_BUFFER_SIZE) });
+                    if self.ring_buffer.reserved_tail.load(Ordering::Relaxed).wrapping_sub(head) > RING_BUFFER_SIZE as u32 {
+                        return Err(self.overflow_error(published_tail, head));
                     }
-                    return Ok(Some(&array[head as usize % RING_BUFFER_SIZE]))
+                    debug_assert!(is_after(published_tail, head), "BUG: Ring-Buffer slot {} was dequeued before being published (published tail: {}). Please fix.", head, published_tail);
+                    let index = RingBuffer::<Slot, RING_BUFFER_SIZE>::slot_index(head);
+                    RingBuffer::<Slot, RING_BUFFER_SIZE>::debug_assert_slot_index(index);
+                    return Ok(Some(&array[index]))
                 },
                 Err(reloaded_head) => head = reloaded_head,
             }
@@ -159,27 +345,42 @@ impl<Slot, const RING_BUFFER_SIZE: usize> RingBufferConsumer<'_, Slot, RING_BUFF
     ///   }
     ///   # Ok(())}
     pub fn peek_all(&self) -> Result<[&[Slot];2], RingBufferOverflowError> {
+        self.peek_n(RING_BUFFER_SIZE)
+    }
+
+    /// Same as [Self::peek_all()], but returning at most the next `n` slot references -- to look ahead, without committing to consume them:
+    /// the head is not advanced. Callers deciding to consume them may, then, call [Self::dequeue()] `n` times.
+    pub fn peek_n(&self, n: usize) -> Result<[&[Slot];2], RingBufferOverflowError> {
+        self.check_poisoned()?;
+        self.skip_cleared();
         let head = self.head.load(Ordering::Relaxed);
         let published_tail = self.ring_buffer.published_tail.load(Ordering::Relaxed);
-        let head_index           = head as usize % RING_BUFFER_SIZE;
-        let published_tail_index = published_tail as usize % RING_BUFFER_SIZE;
         if head == published_tail {
+            return Ok([&[],&[]])
+        } else if published_tail.wrapping_sub(head) > RING_BUFFER_SIZE as u32 {
+            return Err(self.overflow_error(published_tail, head))
+        }
+        let peek_tail = head.wrapping_add(published_tail.wrapping_sub(head).min(n.min(RING_BUFFER_SIZE) as u32));
+        debug_assert!(!is_after(peek_tail, published_tail), "BUG: Ring-Buffer slots up to {} were peeked before being published (published tail: {}). Please fix.", peek_tail, published_tail);
+        let head_index      = RingBuffer::<Slot, RING_BUFFER_SIZE>::slot_index(head);
+        let peek_tail_index = RingBuffer::<Slot, RING_BUFFER_SIZE>::slot_index(peek_tail);
+        RingBuffer::<Slot, RING_BUFFER_SIZE>::debug_assert_slot_index(head_index);
+        RingBuffer::<Slot, RING_BUFFER_SIZE>::debug_assert_slot_index(peek_tail_index);
+        if peek_tail == head {
             Ok([&[],&[]])
-        } else if published_tail - head > RING_BUFFER_SIZE as u32 {
-            Err(RingBufferOverflowError { msg: format!("Ring-Buffer overflow: published_tail={}, head={} -- tail could not be farther from head than the ring buffer size of {}", published_tail, head, RING_BUFFER_SIZE) })
-        } else if head_index < published_tail_index {
+        } else if head_index < peek_tail_index {
             unsafe {
                 // sorcery to get back an array from a MaybeUninit using only const stable functions (as of Rust 1.55)
                 let ptr = self.ring_buffer.buffer.as_ptr();
                 let array = &*ptr;
-                Ok([&array[head_index .. published_tail_index], &[]])
+                Ok([&array[head_index .. peek_tail_index], &[]])
             }
         } else {
             unsafe {
                 // sorcery to get back an array from a MaybeUninit using only const stable functions (as of Rust 1.55)
                 let ptr = self.ring_buffer.buffer.as_ptr();
                 let array = &*ptr;
-                Ok([&array[head_index..RING_BUFFER_SIZE], &array[0..published_tail_index]])
+                Ok([&array[head_index..RING_BUFFER_SIZE], &array[0..peek_tail_index]])
             }
         }
     }
@@ -187,19 +388,136 @@ impl<Slot, const RING_BUFFER_SIZE: usize> RingBufferConsumer<'_, Slot, RING_BUFF
 }
 
 
+/// A multi-producer, multi-consumer ring-buffer: each element is dequeued by a single one of the threads sharing it -- as a copy,
+/// rather than as a reference to its slot. Create one with 1024 [u32] slots with:
+/// ```
+///   let ring_buffer = big_o_test::metrics_allocator::ring_buffer::RingBufferMpmc::<u32, 1024>::new();
+///   ring_buffer.enqueue(7);
+///   assert_eq!(ring_buffer.dequeue(), Ok(Some(7)));
+/// ```
+/// Trade-offs against [RingBufferConsumer]s: dequeueing takes a lock (around the head update) & copies the element out of its slot
+/// -- so elements must be [Copy].
+/// In exchange, the copy is validated before being handed out -- if the producers cycled over the slot while it was being copied,
+/// a [RingBufferOverflowError] is returned instead -- closing the silent race condition of the zero-copy consumers.\
+/// Prefer separate per-thread [RingBufferConsumer]s (each one seeing all the elements) when every thread must process every element,
+/// or when dequeueing is too hot for a lock; prefer this one when the elements are work to be split among the threads.
+pub struct RingBufferMpmc<Slot, const RING_BUFFER_SIZE: usize> {
+    ring_buffer: RingBuffer<Slot, RING_BUFFER_SIZE>,
+    /// the head shared by all the consuming threads -- the lock is held while the element at it is copied
+    head: Mutex<u32>,
+    /// see [RingBufferConsumer::poisoned]
+    poisoned: OnceLock<RingBufferOverflowError>,
+}
+impl<Slot, const RING_BUFFER_SIZE: usize> Default for RingBufferMpmc<Slot, RING_BUFFER_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<Slot, const RING_BUFFER_SIZE: usize> RingBufferMpmc<Slot, RING_BUFFER_SIZE> {
+
+    pub const fn new() -> Self {
+        Self {
+            ring_buffer: RingBuffer::new(),
+            head: Mutex::new(0),
+            poisoned: OnceLock::new(),
+        }
+    }
+
+    /// The wrapped ring buffer -- for its statistics or for additional zero-copy consumers, which will see all the elements
+    /// enqueued after their creation, regardless of this ring buffer's [Self::dequeue()]s
+    pub fn ring_buffer(&self) -> &RingBuffer<Slot, RING_BUFFER_SIZE> {
+        &self.ring_buffer
+    }
+
+    /// See [RingBuffer::enqueue()] -- producers never take the lock
+    pub fn enqueue(&self, element: Slot) {
+        self.ring_buffer.enqueue(element)
+    }
+
+    /// Dequeues a copy of the next element -- or *None* if there isn't any -- handing each element to a single caller, even if
+    /// called concurrently.\
+    /// Fails with [RingBufferOverflowError] if the ring buffer had cycled over the element -- before or while it was copied -- after
+    /// which this ring buffer is poisoned: see [RingBufferConsumer::dequeue()].\
+    /// Elements are required to be [Copy]: as producers don't take the lock, a slot might be overwritten while being read -- a torn
+    /// copy is, then, discarded (seqlock style) rather than handed out, which would not be sound for elements with drop glue or clone logic
+    pub fn dequeue(&self) -> Result<Option<Slot>, RingBufferOverflowError> where Slot: Copy {
+        if let Some(overflow_error) = self.poisoned.get() {
+            return Err(overflow_error.clone());
+        }
+        let mut head = self.head.lock().unwrap_or_else(PoisonError::into_inner);
+        *head = latest(*head, self.ring_buffer.cleared_tail.load(Ordering::Relaxed));
+        let published_tail = self.ring_buffer.published_tail.load(Ordering::Acquire);
+        let overflow_error = |head| self.poisoned.get_or_init(|| RingBufferOverflowError { published_tail, head, ring_buffer_size: RING_BUFFER_SIZE }).clone();
+        // the producers don't take the lock: the slot at `head` is only valid while they didn't reserve it again
+        let slot_reserved_again = |head: u32| self.ring_buffer.reserved_tail.load(Ordering::Relaxed).wrapping_sub(head) > RING_BUFFER_SIZE as u32;
+        if *head == published_tail {
+            return Ok(None);
+        } else if published_tail.wrapping_sub(*head) > RING_BUFFER_SIZE as u32 || slot_reserved_again(*head) {
+            return Err(overflow_error(*head));
+        }
+        let index = RingBuffer::<Slot, RING_BUFFER_SIZE>::slot_index(*head);
+        RingBuffer::<Slot, RING_BUFFER_SIZE>::debug_assert_slot_index(index);
+        let element = unsafe {
+            let slots = self.ring_buffer.buffer.as_ptr() as *const Slot;
+            std::ptr::read_volatile(slots.add(index))
+        };
+        // orders the copy before the validation below -- if the slot was reserved again meanwhile, the copy may be torn
+        fence(Ordering::Acquire);
+        if slot_reserved_again(*head) {
+            return Err(overflow_error(*head));
+        }
+        *head = head.wrapping_add(1);
+        Ok(Some(element))
+    }
+
+}
+
+
 /// Indicates the result of a [RingBufferConsumer::dequeue()] or [RingBufferConsumer::peek_all()] operation
 /// can no longer be retrieved due to the number of calls to [RingBuffer::enqueue()] causing the ring-buffer
 /// to cycle over, overwriting still-unconsumed slot positions in the buffer.\
-/// In this case, the consumer instance is no longer valid -- any further operations on it will yield this same error.\
-/// A descriptive message is returned in [RingBufferOverflowError::msg].
-#[derive(Debug)]
+/// In this case, the consumer instance is no longer valid -- it gets poisoned: any further operations on it will yield this same error.\
+/// The positions at the moment the overflow was detected are available through the accessors -- see [RingBufferOverflowError::lag()]
+/// -- and a descriptive message is built by [Display].
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RingBufferOverflowError {
-    /// Contains details on the error
-    msg: String,
+    /// the producer's position -- counting all elements ever published
+    published_tail:   u32,
+    /// the consumer's position -- counting all elements it ever consumed
+    head:             u32,
+    /// the number of slots in the ring buffer
+    ring_buffer_size: usize,
+}
+impl RingBufferOverflowError {
+
+    /// the producer's position when the overflow was detected -- counting all elements ever published
+    pub fn published_tail(&self) -> u32 {
+        self.published_tail
+    }
+
+    /// the consumer's position when the overflow was detected -- counting all elements it ever consumed
+    pub fn head(&self) -> u32 {
+        self.head
+    }
+
+    /// the number of slots in the ring buffer
+    pub fn ring_buffer_size(&self) -> usize {
+        self.ring_buffer_size
+    }
+
+    /// how many elements the consumer fell behind the producer -- only the last `ring_buffer_size` of them were still available
+    pub fn lag(&self) -> u32 {
+        self.published_tail.wrapping_sub(self.head)
+    }
+
+    /// the error description, without the error name
+    fn msg(&self) -> String {
+        format!("Ring-Buffer overflow: published_tail={}, head={} -- tail could not be farther from head than the ring buffer size of {}", self.published_tail, self.head, self.ring_buffer_size)
+    }
 }
 impl Display for RingBufferOverflowError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "RingBufferOverflowError: {}", self.msg)
+        write!(f, "RingBufferOverflowError: {}", self.msg())
     }
 }
 impl std::error::Error for RingBufferOverflowError {}
@@ -273,6 +591,279 @@ mod tests {
         }
     }
 
+    /// assures [RingBufferMpmc] hands each element to a single one of the consuming threads
+    #[test]
+    fn mpmc_dequeues_each_element_once() {
+        let ring_buffer = RingBufferMpmc::<u32, 1024>::new();
+        for i in 0..1000 {
+            ring_buffer.enqueue(i);
+        }
+        let mut dequeued = std::thread::scope(|scope| {
+            let consumers = (0..4).map(|_| scope.spawn(|| {
+                let mut dequeued = Vec::new();
+                while let Some(element) = ring_buffer.dequeue().expect("No overflow was expected") {
+                    dequeued.push(element);
+                    std::thread::yield_now();
+                }
+                dequeued
+            })).collect::<Vec<_>>();
+            consumers.into_iter().flat_map(|consumer| consumer.join().unwrap()).collect::<Vec<_>>()
+        });
+        dequeued.sort_unstable();
+        assert_eq!(dequeued, (0..1000).collect::<Vec<_>>(), "Every element should have been dequeued exactly once");
+    }
+
+    /// assures [RingBufferMpmc] detects -- and stays poisoned by -- overflows, like [RingBufferConsumer]s do
+    #[test]
+    fn mpmc_overflow() {
+        let ring_buffer = RingBufferMpmc::<u32, 4>::new();
+        ring_buffer.enqueue(0);
+        assert_eq!(ring_buffer.dequeue(), Ok(Some(0)), "Wrong element dequeued");
+        for i in 1..7 {
+            ring_buffer.enqueue(i);
+        }
+        let overflow_error = ring_buffer.dequeue().expect_err("The ring buffer cycled over element 1: an overflow was expected");
+        assert_eq!((overflow_error.head(), overflow_error.lag()), (1, 6), "Wrong overflow positions");
+        ring_buffer.ring_buffer().clear_published();
+        assert_eq!(ring_buffer.dequeue(), Err(overflow_error), "The ring buffer should stay poisoned");
+    }
+
+    /// assures sizes that are not a power of 2 still work -- through the modulus fallback -- when cycling around the buffer
+    #[test]
+    fn non_power_of_two_size() {
+        let ring_buffer = RingBuffer::<u32, 10>::new();
+        let consumer = ring_buffer.consumer();
+        for i in 0..3*ring_buffer.get_buffer_size() as u32 {
+            ring_buffer.enqueue(i);
+            assert_eq!(consumer.dequeue().unwrap(), Some(&i), "Wrong element dequeued");
+        }
+        for i in 0..7 {
+            ring_buffer.enqueue(i);
+        }
+        assert_eq!(consumer.peek_all().unwrap().concat(), (0..7).collect::<Vec<u32>>(), "Wrong elements peeked across the buffer's end");
+    }
+
+    /// assures the debug guards of the unsafe slot accesses hold while the public API cycles through the buffer several times -- and
+    /// that they trip on the positions that would be used as indexes without the modulus: the ones past the end of the buffer
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "slot index 10 is out of its 10 slots")]
+    fn unwrapped_slot_index() {
+        let ring_buffer = RingBuffer::<u32, 10>::new();
+        let consumer = ring_buffer.consumer();
+        let operations = 3 * ring_buffer.get_buffer_size() as u32 + 3;
+        for e in 0..operations {
+            ring_buffer.enqueue(e);
+            assert_eq!(consumer.peek_n(1).map(|chunks| chunks.concat()), Ok(vec![e]), "Wrong element peeked after {} operations", e);
+            assert_eq!(consumer.dequeue(), Ok(Some(&e)), "Wrong element dequeued after {} operations", e);
+        }
+        // the position of the 11th element, as if `slot_index()` didn't wrap it around
+        RingBuffer::<u32, 10>::debug_assert_slot_index(ring_buffer.get_buffer_size());
+    }
+
+    /// [RingBuffer::len()], [RingBuffer::is_empty()] & [RingBuffer::consumer_pending()] specification & assertions
+    #[test]
+    fn len_and_pending() {
+        let ring_buffer = RingBuffer::<u32, 16>::new();
+        let early_consumer = ring_buffer.consumer();
+        assert!(ring_buffer.is_empty(), "A new ring buffer should be empty");
+        assert_eq!(ring_buffer.len(), 0, "A new ring buffer should have no elements");
+        assert_eq!(ring_buffer.consumer_pending(&early_consumer), 0, "Nothing should be pending on a new ring buffer");
+
+        for e in 0..10 {
+            ring_buffer.enqueue(e);
+        }
+        let late_consumer = ring_buffer.consumer();
+        assert!(!ring_buffer.is_empty(), "The ring buffer should no longer be empty");
+        assert_eq!(ring_buffer.len(), 10, "Wrong number of elements held");
+        assert_eq!(ring_buffer.consumer_pending(&early_consumer), 10, "The early consumer should have all elements pending");
+        assert_eq!(ring_buffer.consumer_pending(&late_consumer),  0,  "The late consumer should only see elements published after its creation");
+
+        early_consumer.dequeue().unwrap();
+        assert_eq!(ring_buffer.consumer_pending(&early_consumer), 9, "Dequeueing should reduce the pending elements");
+
+        for e in 10..20 {
+            ring_buffer.enqueue(e);
+        }
+        assert_eq!(ring_buffer.len(), 16, "No more than the ring buffer size may be held");
+        assert_eq!(ring_buffer.consumer_pending(&late_consumer),  10, "Wrong pending elements for the late consumer");
+        assert_eq!(ring_buffer.consumer_pending(&early_consumer), 19, "Overflowed consumers should have more pending elements than the ring buffer size");
+        assert!(early_consumer.dequeue().is_err(), "The early consumer should have overflowed");
+    }
+
+    /// places all the tails of `ring_buffer` at `position` -- as if that many elements were already enqueued & cleared -- so tests may
+    /// cross the wrapping of the `u32` positions
+    fn position_tails<const RING_BUFFER_SIZE: usize>(ring_buffer: &RingBuffer<u32, RING_BUFFER_SIZE>, position: u32) {
+        ring_buffer.reserved_tail.store(position, Ordering::Relaxed);
+        ring_buffer.published_tail.store(position, Ordering::Relaxed);
+        ring_buffer.cleared_tail.store(position, Ordering::Relaxed);
+    }
+
+    /// assures [RingBuffer::len()] & [RingBuffer::is_empty()] hold when the tails wrap around `u32::MAX`
+    #[test]
+    fn len_across_wrapping() {
+        let ring_buffer = RingBuffer::<u32, 16>::new();
+        position_tails(&ring_buffer, u32::MAX - 2);
+        assert!(ring_buffer.is_empty(), "No element was published after the positioning");
+        for e in 0..5 {
+            ring_buffer.enqueue(e);
+        }
+        assert_eq!(ring_buffer.published_tail.load(Ordering::Relaxed), 2, "The published tail should have wrapped");
+        assert!(!ring_buffer.is_empty(), "Elements published across the wrapping should be held");
+        assert_eq!(ring_buffer.len(), 5, "Wrong number of elements held across the wrapping");
+        for e in 5..40 {
+            ring_buffer.enqueue(e);
+        }
+        assert_eq!(ring_buffer.len(), 16, "No more than the ring buffer size may be held across the wrapping");
+    }
+
+    /// [RingBuffer::clear_published()] & [RingBufferConsumer::skip_to_current()] specification & assertions
+    #[test]
+    fn clearing() {
+        let ring_buffer = RingBuffer::<u32, 16>::new();
+        let consumer_1 = ring_buffer.consumer();
+        let consumer_2 = ring_buffer.consumer();
+        for e in 0..10 {
+            ring_buffer.enqueue(e);
+        }
+
+        consumer_1.skip_to_current();
+        assert_eq!(consumer_1.dequeue(), Ok(None), "Skipping should discard all pending elements");
+        assert_eq!(ring_buffer.consumer_pending(&consumer_2), 10, "Skipping should not affect other consumers");
+
+        ring_buffer.enqueue(10);
+        ring_buffer.clear_published();
+        assert!(ring_buffer.is_empty(), "A cleared ring buffer should be empty");
+        assert_eq!(ring_buffer.len(), 0, "A cleared ring buffer should hold no elements");
+        assert_eq!(ring_buffer.consumer_pending(&consumer_2), 0, "Clearing should discard the pending elements of all consumers");
+        assert_eq!(consumer_1.dequeue(), Ok(None), "Clearing should discard the elements of the first consumer");
+        assert_eq!(consumer_2.peek_all().map(|chunks| chunks.concat()), Ok(vec![]), "Clearing should discard the elements of the second consumer");
+
+        // a lagging consumer is rescued from an overflow it didn't detect yet
+        for e in 11..40 {
+            ring_buffer.enqueue(e);
+        }
+        ring_buffer.clear_published();
+        ring_buffer.enqueue(40);
+        assert_eq!(consumer_2.dequeue(), Ok(Some(&40)), "Only the elements published after clearing should be seen");
+        assert_eq!(consumer_1.peek_all().map(|chunks| chunks.concat()), Ok(vec![40]), "Only the elements published after clearing should be seen");
+    }
+
+    /// assures clearing, skipping & consuming hold when the tails & heads wrap around `u32::MAX` -- see [clearing()]
+    #[test]
+    fn clearing_across_wrapping() -> Result<(), RingBufferOverflowError> {
+        let ring_buffer = RingBuffer::<u32, 16>::new();
+        position_tails(&ring_buffer, u32::MAX - 4);
+        let consumer_1 = ring_buffer.consumer();
+        let consumer_2 = ring_buffer.consumer();
+        for e in 0..10 {
+            ring_buffer.enqueue(e);
+        }
+        assert_eq!(consumer_1.peek_n(3)?.concat(), vec![0, 1, 2], "Wrong elements peeked across the wrapping");
+        assert_eq!(consumer_1.dequeue()?, Some(&0), "Wrong element dequeued before the wrapping");
+        consumer_1.skip_to_current();
+        assert_eq!(consumer_1.dequeue()?, None, "Skipping across the wrapping should discard all pending elements");
+        assert_eq!(ring_buffer.consumer_pending(&consumer_2), 10, "Wrong pending elements across the wrapping");
+
+        ring_buffer.clear_published();
+        assert!(ring_buffer.is_empty(), "A ring buffer cleared across the wrapping should be empty");
+        assert_eq!(ring_buffer.consumer_pending(&consumer_2), 0, "Clearing across the wrapping should discard the pending elements");
+        ring_buffer.enqueue(10);
+        assert_eq!(consumer_2.dequeue()?, Some(&10), "Only the elements published after clearing should be seen");
+        assert_eq!(consumer_1.dequeue()?, Some(&10), "Only the elements published after clearing should be seen");
+
+        let mpmc_ring_buffer = RingBufferMpmc::<u32, 16>::new();
+        position_tails(mpmc_ring_buffer.ring_buffer(), u32::MAX - 4);
+        *mpmc_ring_buffer.head.lock().unwrap() = u32::MAX - 4;
+        for e in 0..10 {
+            mpmc_ring_buffer.enqueue(e);
+        }
+        mpmc_ring_buffer.ring_buffer().clear_published();
+        mpmc_ring_buffer.enqueue(10);
+        assert_eq!(mpmc_ring_buffer.dequeue()?, Some(10), "Clearing across the wrapping should discard the elements of the shared head");
+        Ok(())
+    }
+
+    /// [RingBufferConsumer::peek_n()] specification & assertions
+    #[test]
+    fn peek_n() -> Result<(), RingBufferOverflowError> {
+        let ring_buffer = RingBuffer::<u32, 8>::new();
+        let consumer = ring_buffer.consumer();
+        assert_eq!(consumer.peek_n(3)?.concat(), Vec::<u32>::new(), "Nothing should be peeked on an empty ring buffer");
+
+        // make the elements cycle through the end of the buffer
+        for e in 0..6 {
+            ring_buffer.enqueue(e);
+            consumer.dequeue()?;
+        }
+        for e in 6..12 {
+            ring_buffer.enqueue(e);
+        }
+        assert_eq!(consumer.peek_n(0)?.concat(), Vec::<u32>::new(),  "Peeking 0 elements should yield nothing");
+        assert_eq!(consumer.peek_n(1)?.concat(), vec![6],            "Wrong single element peek");
+        assert_eq!(consumer.peek_n(2)?,          [&[6, 7][..], &[]], "Elements before the end of the buffer should be in the first slice");
+        assert_eq!(consumer.peek_n(3)?,          [&[6, 7][..], &[8]], "Elements cycling through the buffer should be split into both slices");
+        assert_eq!(consumer.peek_n(100)?.concat(), vec![6, 7, 8, 9, 10, 11], "Peeking more than the pending elements should yield only the pending ones");
+        assert_eq!(ring_buffer.consumer_pending(&consumer), 6, "Peeking should not advance the head");
+        assert_eq!(consumer.dequeue()?, Some(&6), "Peeking should not consume");
+
+        for e in 12..20 {
+            ring_buffer.enqueue(e);
+        }
+        assert!(consumer.peek_n(1).is_err(), "Overflows should be detected even when peeking fewer elements than the pending ones");
+        Ok(())
+    }
+
+    /// assures [RingBuffer::max_publish_lag()] never decreases and is bound to the number of concurrent producers
+    #[test]
+    #[serial]
+    fn publish_lag() {
+        const THREADS: u32 = 8;
+        const ELEMENTS_PER_THREAD: u32 = 10_000;
+        let ring_buffer = RingBuffer::<u32, 1024>::new();
+        assert_eq!((ring_buffer.publish_lag(), ring_buffer.max_publish_lag()), (0, 0), "A new ring buffer has no lag");
+        let producers_done = AtomicU32::new(0);
+        crossbeam::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|_| {
+                    // yielding outside of `enqueue()`, so single core machines don't spend whole time slices on producers
+                    // waiting for a preempted one to publish
+                    (0..ELEMENTS_PER_THREAD).for_each(|e| { ring_buffer.enqueue(e); std::thread::yield_now() });
+                    producers_done.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+            let mut previous_max_lag = 0;
+            while producers_done.load(Ordering::Relaxed) < THREADS {
+                let max_lag = ring_buffer.max_publish_lag();
+                assert!(max_lag >= previous_max_lag, "The max lag decreased from {} to {}", previous_max_lag, max_lag);
+                previous_max_lag = max_lag;
+                std::thread::yield_now();
+            }
+        }).unwrap();
+        assert_eq!(ring_buffer.publish_lag(), 0, "Every reserved element should have been published");
+        assert!(ring_buffer.max_publish_lag() <= THREADS, "The max lag ({}) can't exceed the number of producers ({})", ring_buffer.max_publish_lag(), THREADS);
+
+        // across the wrapping of the tails
+        position_tails(&ring_buffer, u32::MAX);
+        ring_buffer.reserved_tail.fetch_add(2, Ordering::Relaxed);
+        assert_eq!(ring_buffer.publish_lag(), 2, "Wrong lag across the wrapping of the tails");
+    }
+
+    /// [Debug] outputs for [RingBuffer] & [RingBufferConsumer]
+    #[test]
+    fn debug() {
+        let ring_buffer = RingBuffer::<u32, 4>::new();
+        let consumer = ring_buffer.consumer();
+        for e in 0..6 {
+            ring_buffer.enqueue(e);
+        }
+        let expected_ring_buffer_debug = "RingBuffer { reserved_tail: 6, published_tail: 6, buffer_size: 4, occupancy: 4";
+        #[cfg(feature = "debug_ring_buffer_contents")]
+        let expected_ring_buffer_debug = format!("{expected_ring_buffer_debug}, contents: [2, 3, 4, 5]");
+        assert_eq!(format!("{:?}", ring_buffer), format!("{expected_ring_buffer_debug} }}"), "Wrong ring buffer debug output");
+        assert_eq!(format!("{:?}", consumer), "RingBufferConsumer { head: 0, pending: 6, poisoned: false }", "Wrong consumer debug output");
+    }
+
     /// [RingBufferConsumer::peek_all()] specification & assertions
     #[test]
     fn peek() -> Result<(), RingBufferOverflowError> {
@@ -280,7 +871,7 @@ mod tests {
         let consumer = ring_buffer.consumer();
 
         let check_name = "empty peek";
-        let expected_elements = &[];
+        let expected_elements: &[u32] = &[];
         assert_eq!(consumer.peek_all()?.concat(), expected_elements, "{} failed", check_name);
 
         let check_name = "peek for a single element";
@@ -297,7 +888,7 @@ mod tests {
         for e in 3..1+ring_buffer.get_buffer_size() as u32 {
             ring_buffer.enqueue(e);
         }
-        let expected_elements: Vec<u32> = (1..1+ring_buffer.get_buffer_size() as u32).into_iter().collect();
+        let expected_elements: Vec<u32> = (1..1+ring_buffer.get_buffer_size() as u32).collect();
         assert_eq!(consumer.peek_all()?.concat(), expected_elements, "{} failed", check_name);
 
         let check_name = "ring goes round";
@@ -342,18 +933,50 @@ mod tests {
         let element = consumer.dequeue();
         assert_buffer_overflow("Dequeueing", element, "Ring-Buffer overflow: published_tail=17, head=0 -- tail could not be farther from head than the ring buffer size of 16");
 
+        // structured fields
+        let overflow_error = consumer.peek_all().expect_err("Overflow should have been reported");
+        assert_eq!((overflow_error.published_tail(), overflow_error.head(), overflow_error.ring_buffer_size()), (17, 0, 16), "Wrong overflow positions");
+        assert_eq!(overflow_error.lag(), 17, "Wrong lag");
+        assert_eq!(overflow_error.to_string(), format!("RingBufferOverflowError: {}", overflow_error.msg()), "Wrong Display");
+        let io_error: std::io::Error = overflow_error.into();
+        assert_eq!(io_error.kind(), ErrorKind::InvalidInput, "Wrong io::Error kind");
+
         /// asserts the right error was returned
         fn assert_buffer_overflow<E: Debug>(operation: &str, result: Result<E, RingBufferOverflowError>, expected_error_message: &str) {
-            if result.is_ok() {
-                panic!("{} from an overflowed ring buffer was allowed, when it shouldn't. Returned element was {:?} -- if overflow didn't happen, it would be 0", operation, result);
-            } else {
-                let observed_error_message = result.unwrap_err().msg;
-                assert_eq!(observed_error_message, expected_error_message, "Wrong error message received");
+            match result {
+                Ok(_) => panic!("{} from an overflowed ring buffer was allowed, when it shouldn't. Returned element was {:?} -- if overflow didn't happen, it would be 0", operation, result),
+                Err(err) => assert_eq!(err.msg(), expected_error_message, "Wrong error message received"),
             }
         }
 
     }
 
+    /// assures an overflowed consumer stays errored -- even after it caught up with the producer, which went idle
+    #[test]
+    fn overflowed_consumer_stays_poisoned() {
+        let ring_buffer = RingBuffer::<u32, 16>::new();
+        let consumer = ring_buffer.consumer();
+        for i in 0..1+ring_buffer.get_buffer_size() as u32 {
+            ring_buffer.enqueue(i);
+        }
+        let overflow_error = consumer.dequeue().expect_err("The first dequeue should have detected the overflow");
+        // keep dequeueing until `head` reaches the idle producer's tail -- where the overflow would no longer be detectable
+        for _ in 0..2*ring_buffer.get_buffer_size() {
+            match consumer.dequeue() {
+                Ok(element) => panic!("An overflowed consumer returned Ok({:?}) -- it should have been poisoned", element),
+                Err(err) => assert_eq!(err, overflow_error, "Poisoned consumers should keep returning the first overflow error"),
+            }
+        }
+        match consumer.peek_all() {
+            Ok(peeked) => panic!("An overflowed consumer peeked {:?} -- it should have been poisoned", peeked),
+            Err(err) => assert_eq!(err, overflow_error, "Poisoned consumers should keep returning the first overflow error"),
+        }
+        // other consumers are unaffected
+        let fresh_consumer = ring_buffer.consumer();
+        ring_buffer.enqueue(99);
+        assert_eq!(fresh_consumer.dequeue().unwrap(), Some(&99), "A new consumer should not be poisoned");
+    }
+
     /// uses varying number of threads for both enqueue / dequeue operations and performs all-in / all-out as well as single-in / single-out tests,
     /// asserting the dequeued element sums are always correct
     #[test]
@@ -414,11 +1037,11 @@ mod tests {
         assert_eq!(observed_sum.load(Ordering::Relaxed), expected_sum, "Error in single-in / single-out multi-threaded test (with {} threads)", threads);
 
         /// iterate from 'start' to 'finish', dividing the work among the given number of 'threads', calling 'callback' on each iteration
-        fn multi_threaded_iterate(start: u32, finish: u32, threads: u32, callback: impl Fn(u32) -> () + std::marker::Sync) {
+        fn multi_threaded_iterate(start: u32, finish: u32, threads: u32, callback: impl Fn(u32) + std::marker::Sync) {
             crossbeam::scope(|scope| {
                 let cb = &callback;
-                let join_handlers: Vec<crossbeam::thread::ScopedJoinHandle<()>> = (start..start+threads).into_iter()
-                    .map(|thread_number| scope.spawn(move |_| iterate(thread_number, finish, threads, &cb)))
+                let join_handlers: Vec<crossbeam::thread::ScopedJoinHandle<()>> = (start..start+threads)
+                    .map(|thread_number| scope.spawn(move |_| iterate(thread_number, finish, threads, cb)))
                     .collect();
                 for join_handler in join_handlers {
                     join_handler.join().unwrap();
@@ -427,7 +1050,7 @@ mod tests {
         }
 
         /// iterate from 'start' to 'finish' with the given 'step' size and calls 'callback' on each iteration
-        fn iterate(start: u32, finish: u32, step: u32, callback: impl Fn(u32) -> () + std::marker::Sync) {
+        fn iterate(start: u32, finish: u32, step: u32, callback: impl Fn(u32) + std::marker::Sync) {
             for i in (start..finish).step_by(step as usize) {
                 callback(i);
             }
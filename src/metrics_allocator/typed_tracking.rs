@@ -0,0 +1,91 @@
+//! Per-type allocation tracking for [MetricsAllocator](super::MetricsAllocator), enabled by the `typed_tracking` feature:
+//! distinguishes, for instance, the contributions of a container's node objects from the ones of its backing array.\
+//! [GlobalAlloc](std::alloc::GlobalAlloc) doesn't receive type information, so allocation sites must be annotated:
+//! ```nocompile
+//!     ALLOC.tag_next_allocation::<Node>();
+//!     let node = Box::new(Node::default());
+//!     let (bytes, count) = ALLOC.typed_statistics()[&TypeId::of::<Node>()];
+//! ```
+
+use std::any::TypeId;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The maximum number of distinct types that may be tagged in a [TypedAllocations] -- as the allocator can't allocate
+/// while allocating, the counters live in a fixed-size table
+pub const TYPED_TRACKING_CAPACITY: usize = 64;
+
+thread_local! {
+    /// the tag set by [TypedAllocations::tag_next_allocation()] for the next allocation made by this thread:
+    /// (the address of the tagging [TypedAllocations], its slot for the type)
+    static NEXT_ALLOCATION_TAG: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+}
+
+/// Counters for the allocations of a single type
+struct TypedAllocationCounters {
+    bytes: AtomicU64,
+    count: AtomicU64,
+}
+
+/// Tracks the allocations tagged with a Rust type -- see [super::MetricsAllocator::tag_next_allocation()]
+pub(crate) struct TypedAllocations {
+    /// the tagged types, in the order they were first tagged -- indexing `counters`.
+    /// Only touched when tagging or querying -- never inside the allocator
+    types:    Mutex<Vec<TypeId>>,
+    counters: [TypedAllocationCounters; TYPED_TRACKING_CAPACITY],
+}
+impl TypedAllocations {
+
+    pub(crate) const fn new() -> Self {
+        Self {
+            types:    Mutex::new(Vec::new()),
+            counters: [const { TypedAllocationCounters { bytes: AtomicU64::new(0), count: AtomicU64::new(0) } }; TYPED_TRACKING_CAPACITY],
+        }
+    }
+
+    /// Attributes the next allocation made by the calling thread to type `T`.\
+    /// Panics if more than [TYPED_TRACKING_CAPACITY] distinct types are tagged.
+    pub(crate) fn tag_next_allocation<T: ?Sized + 'static>(&self) {
+        let type_id = TypeId::of::<T>();
+        // registering a new type may allocate: this happens before the tag is set, so it isn't attributed to `T`
+        let slot = {
+            let mut types = self.types.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            match types.iter().position(|tagged_type| tagged_type == &type_id) {
+                Some(slot) => slot,
+                None => {
+                    assert!(types.len() < TYPED_TRACKING_CAPACITY, "MetricsAllocator: no more than {} distinct types may be tagged -- can't track `{}`",
+                            TYPED_TRACKING_CAPACITY, std::any::type_name::<T>());
+                    types.push(type_id);
+                    types.len() - 1
+                },
+            }
+        };
+        NEXT_ALLOCATION_TAG.set(Some((self as *const Self as usize, slot)));
+    }
+
+    /// Accounts an allocation of `bytes` to the type tagged by the calling thread, if any -- consuming the tag.\
+    /// Called from within the allocator: neither allocates nor locks.
+    #[inline(always)]
+    pub(crate) fn track_allocation(&self, bytes: usize) {
+        let Ok(Some((tagger, slot))) = NEXT_ALLOCATION_TAG.try_with(|tag| tag.get()) else { return };
+        // tags set through other instances belong to them
+        if tagger != self as *const Self as usize {
+            return
+        }
+        NEXT_ALLOCATION_TAG.set(None);
+        let counters = &self.counters[slot];
+        counters.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+        counters.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the (bytes, count) allocated for each tagged type, since the program started
+    pub(crate) fn statistics(&self) -> HashMap<TypeId, (u64, u64)> {
+        let types = self.types.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        types.into_iter()
+            .zip(self.counters.iter())
+            .map(|(type_id, counters)| (type_id, (counters.bytes.load(Ordering::Relaxed), counters.count.load(Ordering::Relaxed))))
+            .collect()
+    }
+}
@@ -0,0 +1,93 @@
+//! How the times measured by each thread of an iterator pass are combined into the pass' time -- see [Aggregation].\
+//! A single stop-the-world event (a page fault storm, a descheduled thread, a blocked lock) inflates one of the times:
+//! the robust aggregations keep it from dragging the pass' time -- and, with it, the complexity analysis.
+//! Used by [crate::CrudOptions::with_aggregation()].
+
+use std::time::Duration;
+
+
+/// The statistic taken over the times measured by each thread of a pass
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Aggregation {
+    /// the arithmetic mean -- every thread counts, including the ones hit by interference
+    #[default]
+    Mean,
+    /// the middle time -- or the mean of the two middle ones, for an even number of threads -- ignoring any minority of outliers
+    Median,
+    /// the mean after discarding the given fraction of the fastest & of the slowest times -- `TrimmedMean(0.25)` keeps the middle half.
+    /// Fractions are clamped to `0.0..=0.5`, but at least one time is always kept
+    TrimmedMean(f64),
+}
+impl Aggregation {
+
+    /// Combines the given `durations` -- sorting them, in the process. `Duration::ZERO` is returned if none are given
+    pub fn aggregate(&self, durations: &mut [Duration]) -> Duration {
+        if durations.is_empty() {
+            return Duration::ZERO
+        }
+        durations.sort_unstable();
+        let len = durations.len();
+        let kept = match *self {
+            Aggregation::Mean => &durations[..],
+            Aggregation::Median => &durations[(len - 1) / 2 ..= len / 2],
+            Aggregation::TrimmedMean(fraction) => {
+                let trimmed = ((len as f64 * fraction.clamp(0.0, 0.5)).floor() as usize).min((len - 1) / 2);
+                &durations[trimmed .. len - trimmed]
+            },
+        };
+        Duration::from_secs_f64(kept.iter().map(Duration::as_secs_f64).sum::<f64>() / kept.len() as f64)
+    }
+}
+
+/// An [Aggregation] free of floats -- the [Aggregation::TrimmedMean] fraction is clamped & kept in parts per million --
+/// so the options holding it may be `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum FixedPointAggregation {
+    #[default]
+    Mean,
+    Median,
+    TrimmedMean { trimmed_ppm: u32 },
+}
+impl From<Aggregation> for FixedPointAggregation {
+    fn from(aggregation: Aggregation) -> Self {
+        match aggregation {
+            Aggregation::Mean => Self::Mean,
+            Aggregation::Median => Self::Median,
+            Aggregation::TrimmedMean(fraction) => Self::TrimmedMean { trimmed_ppm: (fraction.clamp(0.0, 0.5) * 1e6).round() as u32 },
+        }
+    }
+}
+impl From<FixedPointAggregation> for Aggregation {
+    fn from(aggregation: FixedPointAggregation) -> Self {
+        match aggregation {
+            FixedPointAggregation::Mean => Self::Mean,
+            FixedPointAggregation::Median => Self::Median,
+            FixedPointAggregation::TrimmedMean { trimmed_ppm } => Self::TrimmedMean(trimmed_ppm as f64 / 1e6),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [aggregation](super) module
+
+    use super::*;
+
+
+    /// checks each aggregation -- with an outlier among the times
+    #[test]
+    fn aggregations() {
+        let millis = |millis: &[u64]| millis.iter().map(|&millis| Duration::from_millis(millis)).collect::<Vec<_>>();
+        let times = millis(&[10, 12, 11, 100]);
+        assert_eq!(Aggregation::default().aggregate(&mut times.clone()),            Duration::from_micros(33_250), "Wrong mean");
+        assert_eq!(Aggregation::Median.aggregate(&mut times.clone()),               Duration::from_micros(11_500), "Wrong median for an even number of times");
+        assert_eq!(Aggregation::Median.aggregate(&mut millis(&[100, 10, 12])),      Duration::from_millis(12),     "Wrong median for an odd number of times");
+        assert_eq!(Aggregation::TrimmedMean(0.25).aggregate(&mut times.clone()),    Duration::from_micros(11_500), "The fastest & the slowest times should have been discarded");
+        assert_eq!(Aggregation::TrimmedMean(0.0).aggregate(&mut times.clone()),     Duration::from_micros(33_250), "Trimming nothing should be the mean");
+        assert_eq!(Aggregation::TrimmedMean(0.9).aggregate(&mut millis(&[10, 12])), Duration::from_millis(11),     "At least one time should be kept");
+        assert_eq!(Aggregation::TrimmedMean(0.5).aggregate(&mut millis(&[7])),      Duration::from_millis(7),      "A single time should be kept");
+        assert_eq!(Aggregation::Median.aggregate(&mut []),                          Duration::ZERO,                "No times should aggregate to zero");
+    }
+}
@@ -0,0 +1,264 @@
+//! Loads the expected complexities of the analysed algorithms from a baseline file -- so projects analysing dozens of algorithms
+//! may keep their complexity policy in a single place, rather than scattered through the call sites.\
+//! See [ComplexityBaseline].
+
+use crate::low_level_analysis::types::{BigOAlgorithmComplexity, ComplexityBoundPair};
+use std::{collections::HashMap, path::Path};
+use serde::Deserialize;
+
+
+/// The expected complexities of an algorithm, as stated in a [ComplexityBaseline] -- `None` for the ones it doesn't state
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BaselineExpectation {
+    pub time_complexity:  Option<ComplexityBoundPair>,
+    pub space_complexity: Option<BigOAlgorithmComplexity>,
+}
+
+/// Expected time & space complexities, by algorithm name: the `test_name` given to the runners & builders or, for the CRUD
+/// operations, the `crud_name` followed by the operation -- `"MyContainer: Read"` -- as in [crate::ComplexityAssertion::algorithm].\
+/// Loaded from TOML files:
+/// ```toml
+///   ["MyContainer: Read"]
+///   time  = "O(1)"
+///   space = "O(1)"
+///
+///   [bsearch]
+///   time = ["O(1)", "O(log(n))"]    # (lower, upper) bounds -- see `ComplexityBoundPair`
+/// ```
+/// or JSON ones:
+/// ```json
+///   { "MyContainer: Read": { "time": "O(1)", "space": "O(1)" },
+///     "bsearch":           { "time": ["O(1)", "O(log(n))"] } }
+/// ```
+/// Complexities are written as in [BigOAlgorithmComplexity::as_pretty_str()] or as their variant names -- `"OLogN"`. Each algorithm
+/// may state its `time` -- a maximum or a `[lower, upper]` pair -- and its `space` -- a maximum -- while any other key is refused.\
+/// The async builders take their expectations through `with_baseline()`, as do the sync runners, through [crate::AlgorithmOptions::with_baseline()]
+/// & [crate::CrudOptions::with_baseline()] -- which hold the baseline for the whole test run:
+/// ```nocompile
+///   static BASELINE: LazyLock<ComplexityBaseline> = LazyLock::new(|| ComplexityBaseline::load("big-o-baseline.toml").unwrap());
+///   test_algorithm_with_options("bsearch", 15, || {}, 1000, || bsearch(1000), 2000, || bsearch(2000), OLogN, O1,
+///                               AlgorithmOptions::default().with_baseline(&BASELINE));
+/// ```
+/// Requires the `baseline` feature.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ComplexityBaseline {
+    expectations: HashMap<String, BaselineExpectation>,
+}
+impl ComplexityBaseline {
+
+    /// Loads the baseline at `path` -- parsed as JSON if its extension is `.json`; as TOML otherwise
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BaselineError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| BaselineError::Io { path: path.display().to_string(), message: err.to_string() })?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json_str(&contents),
+            _ => Self::from_toml_str(&contents),
+        }
+    }
+
+    /// Parses a TOML baseline -- see [Self] for the expected contents
+    pub fn from_toml_str(toml: &str) -> Result<Self, BaselineError> {
+        let raw_expectations = toml::from_str(toml)
+            .map_err(|err: toml::de::Error| BaselineError::Syntax { message: err.to_string() })?;
+        Self::from_raw_expectations(raw_expectations)
+    }
+
+    /// Parses a JSON baseline -- see [Self] for the expected contents
+    pub fn from_json_str(json: &str) -> Result<Self, BaselineError> {
+        let raw_expectations = serde_json::from_str(json)
+            .map_err(|err| BaselineError::Syntax { message: err.to_string() })?;
+        Self::from_raw_expectations(raw_expectations)
+    }
+
+    /// The expectations stated for `algorithm` -- `None` if the baseline has no entry for it
+    pub fn expectation(&self, algorithm: &str) -> Option<BaselineExpectation> {
+        self.expectations.get(algorithm).copied()
+    }
+
+    /// The expected time complexity of `algorithm` -- or `fallback`, if the baseline doesn't state it
+    pub fn time_complexity_or(&self, algorithm: &str, fallback: impl Into<ComplexityBoundPair>) -> ComplexityBoundPair {
+        self.expectation(algorithm)
+            .and_then(|expectation| expectation.time_complexity)
+            .unwrap_or_else(|| fallback.into())
+    }
+
+    /// The expected space complexity of `algorithm` -- or `fallback`, if the baseline doesn't state it
+    pub fn space_complexity_or(&self, algorithm: &str, fallback: BigOAlgorithmComplexity) -> BigOAlgorithmComplexity {
+        self.expectation(algorithm)
+            .and_then(|expectation| expectation.space_complexity)
+            .unwrap_or(fallback)
+    }
+
+    /// reads the complexities of the deserialized `raw_expectations`
+    fn from_raw_expectations(raw_expectations: HashMap<String, RawExpectation>) -> Result<Self, BaselineError> {
+        let expectations = raw_expectations.into_iter()
+            .map(|(algorithm, raw_expectation)| {
+                let expectation = raw_expectation.parse(&algorithm)
+                    .map_err(|message| BaselineError::Expectation { algorithm: algorithm.clone(), message })?;
+                Ok((algorithm, expectation))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { expectations })
+    }
+}
+
+
+/// Tells why a [ComplexityBaseline] couldn't be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaselineError {
+    /// the baseline file couldn't be read
+    Io { path: String, message: String },
+    /// the baseline isn't valid TOML or JSON -- or doesn't have the expected contents: the `message` tells where
+    Syntax { message: String },
+    /// the expectations of `algorithm` name unknown complexities -- or bounds in the wrong order
+    Expectation { algorithm: String, message: String },
+}
+impl std::fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, message } => write!(f, "couldn't read the complexity baseline '{}': {}", path, message),
+            Self::Syntax { message } => write!(f, "malformed complexity baseline: {}", message),
+            Self::Expectation { algorithm, message } => write!(f, "wrong complexity baseline for '{}': {}", algorithm, message),
+        }
+    }
+}
+impl std::error::Error for BaselineError {}
+
+
+/// The expectations of an algorithm, as written in the baseline file -- see [ComplexityBaseline]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawExpectation {
+    time:  Option<RawTimeComplexity>,
+    space: Option<String>,
+}
+impl RawExpectation {
+    /// reads the complexities of `algorithm`'s expectations
+    fn parse(self, algorithm: &str) -> Result<BaselineExpectation, String> {
+        let time_complexity = match self.time {
+            None => None,
+            Some(RawTimeComplexity::Maximum(upper)) => Some(ComplexityBoundPair::at_most(parse_complexity(&upper)?)),
+            Some(RawTimeComplexity::Bounds(lower, upper)) => {
+                let (lower, upper) = (parse_complexity(&lower)?, parse_complexity(&upper)?);
+                if lower > upper {
+                    return Err(format!("the lower time bound of '{}', {:?}, is worse than its upper bound, {:?}", algorithm, lower, upper))
+                }
+                Some(ComplexityBoundPair::new(lower, upper))
+            },
+        };
+        let space_complexity = self.space.as_deref().map(parse_complexity).transpose()?;
+        Ok(BaselineExpectation { time_complexity, space_complexity })
+    }
+}
+
+/// The time expectation, as written in the baseline file: either a maximum or a `[lower, upper]` pair
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawTimeComplexity {
+    Maximum(String),
+    Bounds(String, String),
+}
+
+/// reads back a complexity written as in [BigOAlgorithmComplexity::as_pretty_str()] or as its variant name
+fn parse_complexity(complexity: &str) -> Result<BigOAlgorithmComplexity, String> {
+    BigOAlgorithmComplexity::from_pretty_str(complexity)
+        .or_else(|| BigOAlgorithmComplexity::ALL.into_iter().find(|variant| format!("{:?}", variant) == complexity.trim()))
+        .ok_or_else(|| format!("unknown complexity '{}'", complexity))
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [baseline](super) module
+
+    use super::*;
+    use crate::BigOAlgorithmComplexity::*;
+
+
+    const TOML_BASELINE: &str = r#"
+        # expectations of the containers' operations
+        ["MyContainer: Read"]
+        time  = "O(1)"
+        space = "O(1)"    # no allocations on reads
+
+        ["bsearch"]
+        time = ["O(1)", "OLogN"]
+    "#;
+
+    const JSON_BASELINE: &str = r#"
+        { "MyContainer: Read": { "time": "O(1)", "space": "O(1)" },
+          "bsearch":           { "time": ["O(1)", "OLogN"] } }
+    "#;
+
+
+    /// assures both formats load into the same expectations -- and the files are parsed according to their extensions
+    #[test]
+    fn load() {
+        let toml_baseline = ComplexityBaseline::from_toml_str(TOML_BASELINE).expect("The TOML baseline should have been parsed");
+        let json_baseline = ComplexityBaseline::from_json_str(JSON_BASELINE).expect("The JSON baseline should have been parsed");
+        assert_eq!(toml_baseline, json_baseline, "Both formats should have yielded the same baseline");
+
+        // named after the process, so concurrent test runs don't share the files
+        let directory = std::env::temp_dir();
+        for (extension, contents) in [("toml", TOML_BASELINE), ("json", JSON_BASELINE)] {
+            let path = directory.join(format!("big_o_baseline_test_{}.{}", std::process::id(), extension));
+            std::fs::write(&path, contents).expect("The baseline file should have been written");
+            let loaded = ComplexityBaseline::load(&path);
+            std::fs::remove_file(&path).expect("The baseline file should have been removed");
+            assert_eq!(loaded.as_ref(), Ok(&toml_baseline), "'{}' was loaded wrongly", path.display());
+        }
+        assert!(matches!(ComplexityBaseline::load(directory.join(format!("big_o_missing_baseline_{}.toml", std::process::id()))), Err(BaselineError::Io { .. })),
+                "Missing files should be reported");
+    }
+
+    /// assures the formats aren't restricted to a subset of TOML & JSON
+    #[test]
+    fn format_features() {
+        let toml_baseline = ComplexityBaseline::from_toml_str(r#"
+            bsearch = { time = 'O(log(n))', space = "O1" }    # inline table & literal string
+            ["sort"]
+            time = [
+                "O(n)",
+                "O(n.log(n))",    # multi-line array
+            ]
+        "#).expect("The TOML baseline should have been parsed");
+        assert_eq!(toml_baseline.expectation("bsearch"), Some(BaselineExpectation { time_complexity: Some(OLogN.into()), space_complexity: Some(O1) }));
+        assert_eq!(toml_baseline.time_complexity_or("sort", O1), ComplexityBoundPair::new(ON, ONLogN));
+
+        let json_baseline = ComplexityBaseline::from_json_str(r#"{ "My\u0020Container:\tRead\n": { "time": "O\u0028n\u0029" } }"#)
+            .expect("The JSON baseline should have been parsed");
+        assert_eq!(json_baseline.time_complexity_or("My Container:\tRead\n", O1), ComplexityBoundPair::at_most(ON), "JSON escapes should have been honored");
+    }
+
+    /// checks malformed baselines are refused -- the syntax errors pointing to the offending line
+    #[test]
+    fn errors() {
+        let expectation_error = |message: &str| Err(BaselineError::Expectation { algorithm: String::from("a"), message: String::from(message) });
+        assert_eq!(ComplexityBaseline::from_toml_str("[a]\ntime = \"O(m)\""), expectation_error("unknown complexity 'O(m)'"));
+        assert_eq!(ComplexityBaseline::from_toml_str("[a]\ntime = [\"ON\", \"O1\"]"), expectation_error("the lower time bound of 'a', ON, is worse than its upper bound, O1"));
+        for (toml, line) in [("[a]\nspeed = \"O(1)\"", 2), ("time = \"O(1)\"", 1), ("[a b]", 1), ("[a]\nspace = [\"O1\", \"ON\"]", 2)] {
+            match ComplexityBaseline::from_toml_str(toml) {
+                Err(BaselineError::Syntax { message }) => assert!(message.contains(&format!("line {}", line)), "Wrong line for {:?}: {}", toml, message),
+                other => panic!("{:?} should have been refused as malformed, not as {:?}", toml, other),
+            }
+        }
+        match ComplexityBaseline::from_json_str("{ \"a\": {\n \"time\": \"O(1)\" \"space\": \"O(1)\" } }") {
+            Err(BaselineError::Syntax { message }) => assert!(message.contains("line 2"), "Wrong line for the missing separator: {}", message),
+            other => panic!("Members lacking separators should have been refused, not {:?}", other),
+        }
+    }
+
+    /// assures lookups find the stated expectations -- and fall back to the given ones for unstated or missing entries
+    #[test]
+    fn lookup_and_fallback() {
+        let baseline = ComplexityBaseline::from_toml_str(TOML_BASELINE).expect("The baseline should have been parsed");
+        assert_eq!(baseline.expectation("MyContainer: Read"), Some(BaselineExpectation { time_complexity: Some(O1.into()), space_complexity: Some(O1) }));
+        assert_eq!(baseline.time_complexity_or("bsearch", ON), ComplexityBoundPair::new(O1, OLogN), "Stated bounds should have been found");
+        assert_eq!(baseline.space_complexity_or("bsearch", ON), ON, "Unstated complexities should fall back");
+        assert_eq!(baseline.expectation("MyContainer: Update"), None, "There is no entry for the operation");
+        assert_eq!(baseline.time_complexity_or("MyContainer: Update", (O1, ON)), ComplexityBoundPair::new(O1, ON), "Missing entries should fall back");
+        assert_eq!(baseline.space_complexity_or("MyContainer: Update", WorseThanExponential), WorseThanExponential, "Missing entries should fall back");
+    }
+}
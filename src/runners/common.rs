@@ -3,6 +3,11 @@
 use crate::{
     features,
     low_level_analysis::types::*,
+    runners::{
+        aggregation::Aggregation,
+        timing::{self, TimingBackend, DefaultTimingBackend},
+        workload::Workload,
+    },
 };
 use std::{
     ops::Range,
@@ -11,32 +16,112 @@ use std::{
 use std::fmt::Debug;
 use std::future::Future;
 use std::hint::black_box;
+#[cfg(feature = "tokio")]
+use crate::utils::measurements::measurer::{measure_all_after_event, measure_all_before_event, CustomMeasurement, CustomMeasurerExecutor};
 
 /// wrap around the original [run_iterator_pass()] to output progress & intermediate results
-pub fn run_iterator_pass_verbosely<'a, _IteratorAlgorithmClosure: Fn(u32) -> u32 + Sync,
+#[allow(clippy::too_many_arguments)]
+pub fn run_iterator_pass_verbosely<_IteratorAlgorithmClosure: Fn(u32) -> u32 + Sync,
                                        _OutputClosure:            FnMut(&str)>
                                   (result_prefix:      &str,
                                    result_suffix:      &str,
                                    iterator_algorithm: &_IteratorAlgorithmClosure,
                                    algorithm_type:     &BigOIteratorAlgorithmType,
                                    range:              Range<u32>,
+                                   order:              IterationOrder,
                                    threads:            u32,
-                                   mut output:         _OutputClosure)
+                                   output:             _OutputClosure)
                                   -> (PassResult, u32) {
-    let (pass_result, r) = run_iterator_pass(iterator_algorithm, algorithm_type, range, threads);
-    output(&format!("{}{:?}/{}{}", result_prefix, pass_result.time_measurements, pass_result.space_measurements, result_suffix));
+    run_pinned_iterator_pass_verbosely(result_prefix, result_suffix, iterator_algorithm, algorithm_type, range, order, threads, None, output)
+}
+
+/// Same as [run_iterator_pass_verbosely()], but pinning the measuring threads to the `cpu_affinity` core, if given -- see [run_iterator_pass_pinned()]
+#[allow(clippy::too_many_arguments)]
+pub fn run_pinned_iterator_pass_verbosely<_IteratorAlgorithmClosure: Fn(u32) -> u32 + Sync,
+                                          _OutputClosure:            FnMut(&str)>
+                                         (result_prefix:      &str,
+                                          result_suffix:      &str,
+                                          iterator_algorithm: &_IteratorAlgorithmClosure,
+                                          algorithm_type:     &BigOIteratorAlgorithmType,
+                                          range:              Range<u32>,
+                                          order:              IterationOrder,
+                                          threads:            u32,
+                                          cpu_affinity:       Option<usize>,
+                                          output:             _OutputClosure)
+                                         -> (PassResult, u32) {
+    let (pass_result, r) = run_iterator_pass_pinned(iterator_algorithm, algorithm_type, range, order, threads, cpu_affinity);
+    output_iterator_pass(result_prefix, result_suffix, &pass_result, threads, output);
     (pass_result, r)
 }
 
+/// Same as [run_pinned_iterator_pass_verbosely()], but returning -- rather than re-panicking -- any panic of `iterator_algorithm`,
+/// with the element it happened on -- combining the times of the threads with the given `aggregation` and giving up on passes
+/// exceeding `pass_timeout`, if given: see [try_run_iterator_pass_with_backend()].
+/// Nothing is output for aborted passes
+#[allow(clippy::too_many_arguments)]
+pub fn try_run_pinned_iterator_pass_verbosely<_IteratorAlgorithmClosure: Fn(u32) -> u32 + Sync,
+                                              _OutputClosure:            FnMut(&str)>
+                                             (result_prefix:      &str,
+                                              result_suffix:      &str,
+                                              iterator_algorithm: &_IteratorAlgorithmClosure,
+                                              algorithm_type:     &BigOIteratorAlgorithmType,
+                                              range:              Range<u32>,
+                                              order:              IterationOrder,
+                                              threads:            u32,
+                                              cpu_affinity:       Option<usize>,
+                                              aggregation:        Aggregation,
+                                              pass_timeout:       Option<Duration>,
+                                              output:             _OutputClosure)
+                                             -> Result<(PassResult, u32), PassAbort> {
+    let (pass_result, r) = try_run_iterator_pass_pinned(iterator_algorithm, algorithm_type, range, order, threads, cpu_affinity, aggregation, pass_timeout)?;
+    output_iterator_pass(result_prefix, result_suffix, &pass_result, threads, output);
+    Ok((pass_result, r))
+}
+
+/// Same as [try_run_pinned_iterator_pass_verbosely()], but running the pass `sample_count` times and outputting & returning the
+/// median sample -- see [try_run_iterator_pass_pinned_median()]
+#[allow(clippy::too_many_arguments)]
+pub fn try_run_pinned_iterator_pass_median_verbosely<_IteratorAlgorithmClosure: Fn(u32) -> u32 + Sync,
+                                                     _OutputClosure:            FnMut(&str)>
+                                                    (result_prefix:      &str,
+                                                     result_suffix:      &str,
+                                                     iterator_algorithm: &_IteratorAlgorithmClosure,
+                                                     algorithm_type:     &BigOIteratorAlgorithmType,
+                                                     range:              Range<u32>,
+                                                     order:              IterationOrder,
+                                                     threads:            u32,
+                                                     cpu_affinity:       Option<usize>,
+                                                     aggregation:        Aggregation,
+                                                     pass_timeout:       Option<Duration>,
+                                                     sample_count:       u32,
+                                                     output:             _OutputClosure)
+                                                    -> Result<(PassResult, u32), PassAbort> {
+    let (pass_result, r) = try_run_iterator_pass_pinned_median(iterator_algorithm, algorithm_type, range, order, threads, cpu_affinity, aggregation, pass_timeout, sample_count)?;
+    output_iterator_pass(result_prefix, &format!("{}{}", samples_note(sample_count), result_suffix), &pass_result, threads, output);
+    Ok((pass_result, r))
+}
+
+/// outputs the measurements of an iterator pass -- for [run_pinned_iterator_pass_verbosely()] & [try_run_pinned_iterator_pass_verbosely()]
+fn output_iterator_pass(result_prefix: &str, result_suffix: &str, pass_result: &PassResult, threads: u32, mut output: impl FnMut(&str)) {
+    // threads overlapping for less than half of the time signal they didn't really run in parallel
+    let contention = if threads > 1 && pass_result.parallelism() < threads as f64 / 2.0 {
+        format!(" (contention? {} threads ran {:.2}x in parallel: wall-clock {:?})", threads, pass_result.parallelism(), pass_result.wall_clock_time)
+    } else {
+        String::new()
+    };
+    output(&format!("{}{:?}{}/{}{}{}", result_prefix, pass_result.time_measurements, timer_resolution_note(pass_result.time_measurements),
+                    pass_result.space_measurements, contention, result_suffix));
+}
+
 /// wrap around the original [run_sync_pass()] to output progress & intermediate results
-pub fn run_sync_pass_verbosely<'a, _OutputClosure:    FnMut(&str)>
+pub fn run_sync_pass_verbosely<_OutputClosure:    FnMut(&str)>
                               (result_prefix:  &str,
                                result_suffix:  &str,
                                algorithm:      impl FnMut() -> u32,
                                mut output:     _OutputClosure)
                               -> (PassResult, u32) {
     let (pass_result, r) = run_sync_pass(algorithm);
-    output(&format!("{}{:?}/{}{}", result_prefix, pass_result.time_measurements, pass_result.space_measurements, result_suffix));
+    output(&format!("{}{:?}{}/{}{}", result_prefix, pass_result.time_measurements, timer_resolution_note(pass_result.time_measurements), pass_result.space_measurements, result_suffix));
     (pass_result, r)
 }
 
@@ -51,14 +136,132 @@ pub async fn run_async_pass_verbosely<AlgorithmPassFn:   FnMut(Option<AlgoDataTy
                                       mut output:         impl FnMut(&str))
                                      -> (PassResult, AlgoDataType) {
     let (pass_result, algo_data) = run_async_pass(algo_data, algorithm_pass_fn).await;
-    output(&format!("{}{:?}/{}{}", result_prefix, pass_result.time_measurements, pass_result.space_measurements, result_suffix));
+    output(&format!("{}{:?}{}/{}{}", result_prefix, pass_result.time_measurements, timer_resolution_note(pass_result.time_measurements), pass_result.space_measurements, result_suffix));
     (pass_result, algo_data)
 }
 
+/// Same as [run_sync_pass_verbosely()], but running the pass `repetitions` times and keeping the fastest one -- the most stable
+/// estimator for CPU-bound micro-benchmarks, as it filters out scheduler interference.\
+/// `reset_fn` is called, untimed, in-between the repetitions. Each repetition gets its own allocator save point, so the space
+/// measurements reported are the ones of the fastest repetition -- not an accumulation of all of them.
+pub fn run_repeated_sync_pass_verbosely<_OutputClosure:    FnMut(&str)>
+                                       (result_prefix:  &str,
+                                        result_suffix:  &str,
+                                        mut algorithm:  impl FnMut() -> u32,
+                                        mut reset_fn:   impl FnMut(),
+                                        repetitions:    u32,
+                                        mut output:     _OutputClosure)
+                                       -> (PassResult, u32) {
+    let (mut fastest_pass_result, mut r) = run_sync_pass(&mut algorithm);
+    for _ in 1..repetitions {
+        reset_fn();
+        let (pass_result, pass_r) = run_sync_pass(&mut algorithm);
+        r ^= pass_r;
+        if pass_result.time_measurements < fastest_pass_result.time_measurements {
+            fastest_pass_result = pass_result;
+        }
+    }
+    output(&format!("{}{:?}{}/{}{}{}", result_prefix, fastest_pass_result.time_measurements, timer_resolution_note(fastest_pass_result.time_measurements),
+                    fastest_pass_result.space_measurements, repetitions_note(repetitions), result_suffix));
+    (fastest_pass_result, r)
+}
+
+/// Same as [run_async_pass_verbosely()], but running the pass `repetitions` times and keeping the fastest one
+/// -- see [run_repeated_sync_pass_verbosely()].\
+/// Each repetition receives the data returned by the previous one -- passed through `reset_fn`, untimed, if given.
+/// The `custom_measurers` are taken around each repetition -- so their measurements don't add up over all of them -- and the
+/// measurements of the fastest repetition are returned, along with its pass result.\
+/// If the pass panics, the panic is returned -- as the data it was working on is lost.
+#[cfg(feature = "tokio")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn run_repeated_async_pass_verbosely<AlgorithmPassFn:   FnMut(Option<AlgoDataType>) -> AlgorithmPassFut + Send + Sync,
+                                                      AlgorithmPassFut:  Future<Output=AlgoDataType> + Send,
+                                                      ResetFn:           FnMut(Option<AlgoDataType>) -> ResetFut + Send + Sync,
+                                                      ResetFut:          Future<Output=AlgoDataType> + Send,
+                                                      AlgoDataType:      Send + Sync + Debug>
+                                                     (result_prefix:          &str,
+                                                      result_suffix:          &str,
+                                                      algo_data:              Option<AlgoDataType>,
+                                                      mut algorithm_pass_fn:  AlgorithmPassFn,
+                                                      mut reset_fn:           Option<ResetFn>,
+                                                      repetitions:            u32,
+                                                      custom_measurers:       &mut Vec<Box<dyn CustomMeasurerExecutor<AlgoDataType>>>,
+                                                      mut output:             impl FnMut(&str))
+                                                     -> Result<(PassResult, AlgoDataType, Vec<CustomMeasurement>), AlgorithmPanic> {
+    let mut fastest: Option<(PassResult, Vec<CustomMeasurement>)> = None;
+    let mut algo_data = algo_data;
+    for repetition in 0..repetitions.max(1) {
+        if repetition > 0 {
+            if let Some(reset_fn) = &mut reset_fn {
+                algo_data = Some(reset_fn(algo_data).await);
+            }
+        }
+        measure_all_before_event(algo_data.as_ref(), custom_measurers).await;
+        let (pass_result, pass_algo_data) = try_run_async_pass(algo_data, &mut algorithm_pass_fn).await?;
+        let custom_measurements = measure_all_after_event(Some(&pass_algo_data), custom_measurers).await;
+        algo_data = Some(pass_algo_data);
+        let is_fastest = match &fastest {
+            Some((fastest_pass_result, _)) => pass_result.time_measurements < fastest_pass_result.time_measurements,
+            None => true,
+        };
+        if is_fastest {
+            fastest = Some((pass_result, custom_measurements));
+        }
+    }
+    let ((fastest_pass_result, custom_measurements), algo_data) = fastest.zip(algo_data).expect("BUG! At least one repetition runs");
+    output(&format!("{}{:?}{}/{}{}{}", result_prefix, fastest_pass_result.time_measurements, timer_resolution_note(fastest_pass_result.time_measurements),
+                    fastest_pass_result.space_measurements, repetitions_note(repetitions), result_suffix));
+    Ok((fastest_pass_result, algo_data, custom_measurements))
+}
 
+/// Passes measuring less than this many times the [timing::timer_resolution()] are quantised to a few of its steps -- and warned
+/// about, see [timer_resolution_note()]
+pub const TIMER_RESOLUTION_MULTIPLE: u32 = 10;
+
+/// the warning added, right after the measured time, to the verbose output of passes whose `elapsed` time is within
+/// [TIMER_RESOLUTION_MULTIPLE] times the [timing::timer_resolution()] -- as they can't be measured reliably -- recommending more
+/// iterations. Empty for the others
+pub(crate) fn timer_resolution_note(elapsed: Duration) -> String {
+    let timer_resolution = timing::timer_resolution();
+    if elapsed < timer_resolution * TIMER_RESOLUTION_MULTIPLE {
+        format!(" (WARN: within {}x the timer resolution of {:?} -- use more iterations)", TIMER_RESOLUTION_MULTIPLE, timer_resolution)
+    } else {
+        String::new()
+    }
+}
+
+/// the note added to the verbose output of passes that were sampled several times -- see [try_run_pinned_iterator_pass_median_verbosely()]
+pub(crate) fn samples_note(sample_count: u32) -> String {
+    if sample_count > 1 {
+        format!(" (median of {})", sample_count)
+    } else {
+        String::new()
+    }
+}
+
+/// the note added to the verbose output of passes that were repeated -- see [run_repeated_sync_pass_verbosely()]
+fn repetitions_note(repetitions: u32) -> String {
+    if repetitions > 1 {
+        format!(" (fastest of {})", repetitions)
+    } else {
+        String::new()
+    }
+}
+
+
+
+/// The order in which an iterator algorithm is fed with the elements of a range -- see [run_iterator_pass()]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterationOrder {
+    /// from `range.start` up to `range.end-1` -- for the [crate::runners::standard] iterator runners & the CRUD deletes: running their
+    /// passes in the reverse order, the latter undo the creates from the last created element to the first one
+    Ascending,
+    /// from `range.end-1` down to `range.start` -- for the CRUD creates, reads & updates
+    Descending,
+}
 
 /// Runs a pass on the given `iterator_algorithm` callback function or closure,
-/// measuring (and returning) the time it took to run all iterations specified in `range`
+/// measuring (and returning) the time it took to run all iterations specified in `range`, in the given `order`
 /// -- with the option to run the iteration of the given number of `threads`.\
 /// An `iterator_algorithm` is one that provides 1 element on each call or processes 1 element on each call.\
 /// See [run_sync_pass()] for algorithms which generates or operates on several elements per call.
@@ -69,97 +272,780 @@ pub async fn run_async_pass_verbosely<AlgorithmPassFn:   FnMut(Option<AlgoDataTy
 ///     fn iterator_algorithm(i: u32) -> u32 {0}
 /// ```
 /// returns: tuple with ([PassResult], computed_number: u32)
-pub(crate) fn run_iterator_pass<'a, _AlgorithmClosure: Fn(u32) -> u32 + Sync>
+pub(crate) fn run_iterator_pass<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
                                (iterator_algorithm: &_AlgorithmClosure,
                                 algorithm_type:     &BigOIteratorAlgorithmType,
                                 range:              Range<u32>,
+                                order:              IterationOrder,
                                 threads:            u32)
                                -> (PassResult, u32) {
+    run_iterator_pass_pinned(iterator_algorithm, algorithm_type, range, order, threads, None)
+}
 
-    type ThreadLoopResult = (Duration, u32);
+/// Same as [run_iterator_pass()], but pinning each of the measuring threads to the `cpu_affinity` core, if given -- so the OS
+/// won't migrate them between cores with different frequencies or cache states. See [pin_current_thread()]
+pub(crate) fn run_iterator_pass_pinned<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                      (iterator_algorithm: &_AlgorithmClosure,
+                                       algorithm_type:     &BigOIteratorAlgorithmType,
+                                       range:              Range<u32>,
+                                       order:              IterationOrder,
+                                       threads:            u32,
+                                       cpu_affinity:       Option<usize>)
+                                      -> (PassResult, u32) {
+    run_iterator_pass_with_backend(iterator_algorithm, algorithm_type, range, order, threads, cpu_affinity.as_slice(), &DefaultTimingBackend::default())
+}
+
+/// Same as [run_iterator_pass_pinned()], but spreading the measuring threads over several cores -- one thread per element of `core_ids`:
+/// the thread running the `n`th chunk of the `range` is pinned to `core_ids[n]`, so each chunk stays on its core for the whole pass,
+/// sparing the measurements of the spikes caused by the OS migrating threads between cores. See [pin_current_thread()].\
+/// An empty `core_ids` runs the pass on a single, unpinned, thread.
+#[cfg(feature = "core_pinning")]
+pub fn run_iterator_pass_pinned_to_cores<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                        (iterator_algorithm: &_AlgorithmClosure,
+                                         algorithm_type:     &BigOIteratorAlgorithmType,
+                                         range:              Range<u32>,
+                                         order:              IterationOrder,
+                                         core_ids:           &[usize])
+                                        -> (PassResult, u32) {
+    let threads = std::cmp::max(1, core_ids.len() as u32);
+    run_iterator_pass_with_backend(iterator_algorithm, algorithm_type, range, order, threads, core_ids, &DefaultTimingBackend::default())
+}
+
+/// Same as [run_iterator_pass_pinned()], but returning -- rather than re-panicking -- any panic of `iterator_algorithm`,
+/// with the element it happened on -- combining the times of the threads with the given `aggregation` and giving up on passes
+/// exceeding `pass_timeout`, if given
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn try_run_iterator_pass_pinned<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                          (iterator_algorithm: &_AlgorithmClosure,
+                                           algorithm_type:     &BigOIteratorAlgorithmType,
+                                           range:              Range<u32>,
+                                           order:              IterationOrder,
+                                           threads:            u32,
+                                           cpu_affinity:       Option<usize>,
+                                           aggregation:        Aggregation,
+                                           pass_timeout:       Option<Duration>)
+                                          -> Result<(PassResult, u32), PassAbort> {
+    try_run_iterator_pass_with_backend(iterator_algorithm, algorithm_type, range, order, threads, cpu_affinity.as_slice(), aggregation, pass_timeout, &DefaultTimingBackend::default())
+}
+
+/// Same as [try_run_iterator_pass_pinned()], but running the pass `sample_count` times and returning its median sample
+/// ([PassStatistics::median]) -- see [run_iterator_pass_multi_sample()]. Gives up on the first aborted sample
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn try_run_iterator_pass_pinned_median<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                                 (iterator_algorithm: &_AlgorithmClosure,
+                                                  algorithm_type:     &BigOIteratorAlgorithmType,
+                                                  range:              Range<u32>,
+                                                  order:              IterationOrder,
+                                                  threads:            u32,
+                                                  cpu_affinity:       Option<usize>,
+                                                  aggregation:        Aggregation,
+                                                  pass_timeout:       Option<Duration>,
+                                                  sample_count:       u32)
+                                                 -> Result<(PassResult, u32), PassAbort> {
+    let mut r = 0;
+    let mut samples = Vec::with_capacity(std::cmp::max(1, sample_count) as usize);
+    for _ in 0..std::cmp::max(1, sample_count) {
+        let (pass_result, pass_r) = try_run_iterator_pass_pinned(iterator_algorithm, algorithm_type, range.clone(), order, threads, cpu_affinity, aggregation, pass_timeout)?;
+        r ^= pass_r;
+        samples.push(pass_result);
+    }
+    Ok((PassStatistics::from_samples(samples).median, r))
+}
+
+/// Same as [run_iterator_pass()], but feeding `iterator_algorithm` with the values of the given `workload`, rather than with
+/// the bare iteration indexes -- see [crate::runners::workload]. [Workload::Identity] behaves exactly as [run_iterator_pass()]
+pub fn run_iterator_pass_with_workload<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                      (iterator_algorithm: &_AlgorithmClosure,
+                                       algorithm_type:     &BigOIteratorAlgorithmType,
+                                       range:              Range<u32>,
+                                       order:              IterationOrder,
+                                       threads:            u32,
+                                       workload:           Workload)
+                                      -> (PassResult, u32) {
+    let workload_range = range.clone();
+    run_iterator_pass(&|e| iterator_algorithm(workload.value(e, &workload_range)), algorithm_type, range, order, threads)
+}
+
+/// Pins the calling thread to the `cpu_affinity` core, if given -- until the returned [ThreadPinning] is dropped, when the thread's
+/// original affinity is restored.\
+/// Requires the platform to support thread affinity -- silently doing nothing where it is unavailable (or if the core doesn't exist).
+#[cfg(feature = "core_pinning")]
+pub(crate) fn pin_current_thread(cpu_affinity: Option<usize>) -> ThreadPinning {
+    let Some(core_id) = cpu_affinity else {
+        return ThreadPinning { original_affinity: None }
+    };
+    let original_affinity = ThreadPinning::current_affinity();
+    // pinning is a best-effort improvement to the measurements: failing to do it is not an error
+    let _pinned = core_affinity::set_for_current(core_affinity::CoreId { id: core_id });
+    ThreadPinning { original_affinity }
+}
+
+/// Without the `core_pinning` feature, threads are never pinned -- see the feature's version
+#[cfg(not(feature = "core_pinning"))]
+pub(crate) fn pin_current_thread(_cpu_affinity: Option<usize>) -> ThreadPinning {
+    ThreadPinning {}
+}
+
+/// Returned by [pin_current_thread()]: restores the affinity the thread had before being pinned when dropped.\
+/// Restoring is only supported on Linux -- elsewhere, the thread remains pinned
+pub(crate) struct ThreadPinning {
+    /// the affinity mask to restore, if the thread was pinned
+    #[cfg(feature = "core_pinning")]
+    original_affinity: Option<ThreadAffinity>,
+}
+#[cfg(all(feature = "core_pinning", target_os = "linux"))]
+type ThreadAffinity = libc::cpu_set_t;
+#[cfg(all(feature = "core_pinning", not(target_os = "linux")))]
+type ThreadAffinity = ();
+#[cfg(feature = "core_pinning")]
+impl ThreadPinning {
+    #[cfg(target_os = "linux")]
+    fn current_affinity() -> Option<ThreadAffinity> {
+        // SAFETY: `cpu_set_t` is plain data, for which all zeroes is a valid (empty) set -- filled in by `sched_getaffinity()`
+        let mut affinity: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        let outcome = unsafe { libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut affinity) };
+        (outcome == 0).then_some(affinity)
+    }
+    #[cfg(not(target_os = "linux"))]
+    fn current_affinity() -> Option<ThreadAffinity> {
+        None
+    }
+}
+#[cfg(feature = "core_pinning")]
+impl Drop for ThreadPinning {
+    fn drop(&mut self) {
+        #[cfg(target_os = "linux")]
+        if let Some(original_affinity) = &self.original_affinity {
+            // SAFETY: the mask was filled in by `sched_getaffinity()` for this same thread -- failing to restore it is not an error
+            let _restored = unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), original_affinity) };
+        }
+    }
+}
+
+/// Same as [run_iterator_pass_pinned()], but measuring each thread's elapsed time with the given timing `backend` -- see [crate::runners::timing].\
+/// The `n`th thread is pinned to `core_ids[n]` -- cycling through them if there are more threads than cores; not pinned if empty
+pub fn run_iterator_pass_with_backend<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                            (iterator_algorithm: &_AlgorithmClosure,
+                                             algorithm_type:     &BigOIteratorAlgorithmType,
+                                             range:              Range<u32>,
+                                             order:              IterationOrder,
+                                             threads:            u32,
+                                             core_ids:           &[usize],
+                                             backend:            &impl TimingBackend)
+                                            -> (PassResult, u32) {
+    try_run_iterator_pass_with_backend(iterator_algorithm, algorithm_type, range.clone(), order, threads, core_ids, Aggregation::Mean, None, backend)
+        .unwrap_or_else(|pass_abort| panic!("Panic! while running provided 'algorithm' closure: algo type: {:?}, range: {:?}: {}", algorithm_type, range, pass_abort))
+}
+
+/// Same as [run_iterator_pass_with_backend()], but returning the panic of `iterator_algorithm` -- with the element it happened on --
+/// instead of re-panicking -- and combining the times of the threads with the given `aggregation`. The measurements of the other threads are lost.\
+/// If `pass_timeout` is given, each thread checks it every [DEADLINE_CHECK_INTERVAL] elements, giving up on the pass once it is
+/// exceeded -- reporting how many iterations were completed, see [PassTimeout]. The timeout is best-effort, at the iteration
+/// granularity: it may be overrun by the time of a few iterations and an iteration that hangs can't be interrupted.
+#[allow(clippy::too_many_arguments)]
+pub fn try_run_iterator_pass_with_backend<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                                (iterator_algorithm: &_AlgorithmClosure,
+                                                 algorithm_type:     &BigOIteratorAlgorithmType,
+                                                 range:              Range<u32>,
+                                                 order:              IterationOrder,
+                                                 threads:            u32,
+                                                 core_ids:           &[usize],
+                                                 aggregation:        Aggregation,
+                                                 pass_timeout:       Option<Duration>,
+                                                 backend:            &impl TimingBackend)
+                                                -> Result<(PassResult, u32), PassAbort> {
+    try_run_iterator_pass_timing_iterations(iterator_algorithm, algorithm_type, range, order, threads, core_ids, aggregation, pass_timeout, false, backend)
+        .map(|(pass_result, _iteration_timings, r)| (pass_result, r))
+}
+
+/// Same as [run_iterator_pass()], but also returning how long each call of `iterator_algorithm` took -- in the order the elements
+/// were processed, with the chunks of the threads one after the other -- for distribution analysis (percentiles, outliers, ...).\
+/// Timing every call perturbs the time measurements -- more so for the fastest algorithms -- so this is not meant to be used for
+/// complexity classification: use [run_iterator_pass()] when only the complexities matter.
+pub fn run_iterator_pass_with_iteration_timings<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                               (iterator_algorithm: &_AlgorithmClosure,
+                                                algorithm_type:     &BigOIteratorAlgorithmType,
+                                                range:              Range<u32>,
+                                                order:              IterationOrder,
+                                                threads:            u32)
+                                               -> (PassResult, Vec<Duration>, u32) {
+    try_run_iterator_pass_timing_iterations(iterator_algorithm, algorithm_type, range.clone(), order, threads, &[], Aggregation::Mean, None, true, &DefaultTimingBackend::default())
+        .unwrap_or_else(|pass_abort| panic!("Panic! while running provided 'algorithm' closure: algo type: {:?}, range: {:?}: {}", algorithm_type, range, pass_abort))
+}
+
+/// [try_run_iterator_pass_with_backend()]'s implementation -- also timing each call of `iterator_algorithm` if `per_iteration_timings`,
+/// otherwise returning no timings: the storage for them is allocated before the allocator's save point, so it isn't measured
+#[allow(clippy::too_many_arguments)]
+fn try_run_iterator_pass_timing_iterations<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                          (iterator_algorithm:    &_AlgorithmClosure,
+                                           algorithm_type:        &BigOIteratorAlgorithmType,
+                                           range:                 Range<u32>,
+                                           order:                 IterationOrder,
+                                           threads:               u32,
+                                           core_ids:              &[usize],
+                                           aggregation:           Aggregation,
+                                           pass_timeout:          Option<Duration>,
+                                           per_iteration_timings: bool,
+                                           backend:               &impl TimingBackend)
+                                          -> Result<(PassResult, Vec<Duration>, u32), PassAbort> {
+
+    /// the outcome of a thread: its (elapsed time, result, completed iterations, iteration timings) -- the iterations being short
+    /// of its range if the deadline was exceeded
+    type ThreadLoopResult = Result<(Duration, u32, u32, Vec<Duration>), AlgorithmPanic>;
 
     fn thread_loop<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
-                  (iterator_algorithm: &_AlgorithmClosure, algorithm_type: &BigOIteratorAlgorithmType, range: Range<u32>)
+                  (iterator_algorithm: &_AlgorithmClosure, order: IterationOrder, range: Range<u32>, deadline: Option<Instant>,
+                   mut iteration_timings: Option<Vec<Duration>>, backend: &impl TimingBackend)
                    -> ThreadLoopResult {
         let mut thread_r: u32 = range.end;
+        // the element being processed -- to tell which one made the algorithm panic
+        let mut current_element = range.start;
+        let mut completed_iterations = 0;
+        // reading the clock on every iteration would slow down (and be measured along with) fast algorithms
+        let deadline_exceeded = |completed_iterations: u32| completed_iterations.is_multiple_of(DEADLINE_CHECK_INTERVAL) &&
+                                                            deadline.is_some_and(|deadline| Instant::now() >= deadline);
+        let mut call = |e: u32| match &mut iteration_timings {
+            None => iterator_algorithm(e),
+            Some(iteration_timings) => {
+                let iteration_start = backend.start();
+                let r = iterator_algorithm(e);
+                iteration_timings.push(backend.elapsed(iteration_start));
+                r
+            },
+        };
 
-        let thread_start = Instant::now();
+        let thread_start = backend.start();
 
-        // run 'algorithm()' allowing normal or reversed order
-        match algorithm_type {
-            BigOIteratorAlgorithmType::ConstantSet => {
-                if range.end < range.start {
-                    for e in (range.end..range.start).rev() {
-                        thread_r ^= iterator_algorithm(e);
-                    }
-                } else {
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            match order {
+                IterationOrder::Ascending => {
                     for e in range {
-                        thread_r ^= iterator_algorithm(e);
-                    }
-                }
-            },
-            BigOIteratorAlgorithmType::SetResizing => {
-                if range.end < range.start {
-                    for e in (range.end..range.start).rev() {
-                        thread_r ^= iterator_algorithm(e);
+                        if deadline_exceeded(completed_iterations) {
+                            break
+                        }
+                        current_element = e;
+                        thread_r ^= call(e);
+                        completed_iterations += 1;
                     }
-                } else {
-                    for e in range {
-                        thread_r ^= iterator_algorithm(e);
+                },
+                IterationOrder::Descending => {
+                    for e in range.rev() {
+                        if deadline_exceeded(completed_iterations) {
+                            break
+                        }
+                        current_element = e;
+                        thread_r ^= call(e);
+                        completed_iterations += 1;
                     }
-                }
-            },
-        }
+                },
+            }
+        }));
 
-        let thread_end = Instant::now();
-        let thread_duration = thread_end.duration_since(thread_start);
+        let thread_duration = backend.elapsed(thread_start);
 
-        (thread_duration, thread_r)
+        match outcome {
+            Ok(()) => Ok((thread_duration, thread_r, completed_iterations, iteration_timings.unwrap_or_default())),
+            Err(payload) => Err(AlgorithmPanic { element: Some(current_element), message: panic_message(payload.as_ref()) }),
+        }
     }
 
+    assert!(threads > 0, "Iterator pass for {:?} algorithm over {:?}: at least 1 thread is needed", algorithm_type, range);
+
+    // any timing calibration must happen before the measurements
+    backend.calibrate();
+
+    // the span must be started before the allocator's save point, so its allocations aren't measured
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_iterator_pass_span(algorithm_type, &range, threads);
+
     // use crossbeam's scoped threads to avoid requiring a 'static lifetime for our algorithm's closure
     crossbeam::scope(|scope| {
 
-        // start all threads
-        let i32_range = range.end as i32 .. range.start as i32;
-        let chunk_size = (i32_range.end-i32_range.start)/threads as i32;
+        // start all threads -- each one with a continuous chunk of the range: see [thread_chunk()]
         let mut thread_handlers: Vec<crossbeam::thread::ScopedJoinHandle<ThreadLoopResult>> = Vec::with_capacity(threads as usize);
+        // allocated before the allocator's save point, so it isn't measured
+        let mut thread_durations = Vec::with_capacity(threads as usize);
+        let mut threads_iteration_timings = Vec::with_capacity(if per_iteration_timings {threads as usize} else {0});
+        let mut threads_iteration_timings_storage = (0..threads)
+            .map(|n| per_iteration_timings.then(|| Vec::with_capacity(thread_chunk(&range, order, threads, n).len())))
+            .collect::<Vec<_>>()
+            .into_iter();
         let allocator_savepoint = features::ALLOC.save_point();
-        for n in 0..threads as i32 {
-            let chunked_range = i32_range.start+chunk_size*n..i32_range.start+chunk_size*(n+1);
-            thread_handlers.push( scope.spawn(move |_| thread_loop(iterator_algorithm, algorithm_type, chunked_range.start as u32 .. chunked_range.end as u32)) );
+        let wall_clock_start = Instant::now();
+        let deadline = pass_timeout.map(|pass_timeout| wall_clock_start + pass_timeout);
+        for n in 0..threads {
+            let chunked_range = thread_chunk(&range, order, threads, n);
+            // each chunk's thread is pinned to the core at the chunk's position
+            let core_id = (!core_ids.is_empty()).then(|| core_ids[n as usize % core_ids.len()]);
+            let thread_iteration_timings = threads_iteration_timings_storage.next().flatten();
+            thread_handlers.push( scope.spawn(move |_| {
+                let _pinning = pin_current_thread(core_id);
+                thread_loop(iterator_algorithm, order, chunked_range, deadline, thread_iteration_timings, backend)
+            }) );
         }
 
-        // wait for them all to finish
+        // wait for them all to finish -- keeping the first panic, if any
         let mut r = range.start+1;
-        let mut elapsed_seconds_average = 0.0f64;
+        let mut summed_threads_time = Duration::ZERO;
+        let mut completed_iterations = 0;
+        let mut algorithm_panic = None;
         for handler in thread_handlers {
-            let joining_result = handler.join();
-            if joining_result.is_err() {
-                panic!("Panic! while running provided 'algorithm' closure: algo type: {:?}, range: {:?}: Error: {:?}", algorithm_type, range, joining_result.unwrap_err())
+            let thread_loop_result = handler.join()
+                .unwrap_or_else(|payload| Err(AlgorithmPanic { element: None, message: panic_message(payload.as_ref()) }));
+            match thread_loop_result {
+                Ok((thread_duration, thread_r, thread_completed_iterations, thread_iteration_timings)) => {
+                    thread_durations.push(thread_duration);
+                    if per_iteration_timings {
+                        threads_iteration_timings.push(thread_iteration_timings);
+                    }
+                    summed_threads_time += thread_duration;
+                    r ^= thread_r;
+                    completed_iterations += thread_completed_iterations;
+                },
+                Err(thread_panic) => { algorithm_panic.get_or_insert(thread_panic); },
             }
-            let (thread_duration, thread_r) = joining_result.unwrap();
-            let thread_elapsed_seconds = thread_duration.as_secs_f64();
-            elapsed_seconds_average += thread_elapsed_seconds as f64 / threads as f64;
-            r ^= thread_r;
+        }
+        let wall_clock_time = wall_clock_start.elapsed();
+        if let Some(algorithm_panic) = algorithm_panic {
+            return Err(PassAbort::Panicked(algorithm_panic))
+        }
+        let iterations = range.len() as u32;
+        if let (Some(timeout), true) = (pass_timeout, completed_iterations < iterations) {
+            return Err(PassAbort::TimedOut(PassTimeout { timeout, completed_iterations, iterations }))
         }
 
         let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
+        let time_measurements = aggregation.aggregate(&mut thread_durations);
+        let iteration_timings = threads_iteration_timings.concat();
 
-        (PassResult {
-            time_measurements:  Duration::from_secs_f64(elapsed_seconds_average),
+        Ok((PassResult {
+            time_measurements,
+            wall_clock_time,
+            summed_threads_time,
             space_measurements: BigOSpacePassMeasurements {
                 used_memory_before: allocator_savepoint.metrics.current_used_memory,
                 used_memory_after:  allocator_statistics.current_used_memory,
                 min_used_memory:    allocator_statistics.min_used_memory,
                 max_used_memory:    allocator_statistics.max_used_memory,
             },
-        }, r)
+            #[cfg(feature = "opentelemetry")]
+            span_ids: pass_span.end(time_measurements),
+        }, iteration_timings, r))
 
     }).unwrap()
 
 }
 
+/// How many elements the threads of [try_run_iterator_pass_with_backend()] process in-between checking if their pass' deadline was exceeded
+pub const DEADLINE_CHECK_INTERVAL: u32 = 64;
+
+/// Why a pass run by [try_run_iterator_pass_pinned()] & friends was given up on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassAbort {
+    /// the algorithm panicked
+    Panicked(AlgorithmPanic),
+    /// the pass exceeded its timeout
+    TimedOut(PassTimeout),
+}
+impl std::fmt::Display for PassAbort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PassAbort::Panicked(algorithm_panic) => write!(f, "{}", algorithm_panic),
+            PassAbort::TimedOut(pass_timeout)    => write!(f, "{}", pass_timeout),
+        }
+    }
+}
+
+/// Tells how far a pass got before exceeding its timeout -- see [try_run_iterator_pass_with_backend()]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassTimeout {
+    /// the exceeded timeout
+    pub timeout:              Duration,
+    /// how many iterations were completed, among all threads
+    pub completed_iterations: u32,
+    /// how many iterations the pass had
+    pub iterations:           u32,
+}
+impl std::fmt::Display for PassTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pass timed out after {:?}, having completed {} of its {} iterations", self.timeout, self.completed_iterations, self.iterations)
+    }
+}
+
+/// The panic of an algorithm closure, caught while running a pass -- see [try_run_iterator_pass_pinned()]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgorithmPanic {
+    /// the element the algorithm was processing when it panicked -- if known
+    pub element: Option<u32>,
+    /// the panic message
+    pub message: String,
+}
+impl std::fmt::Display for AlgorithmPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.element {
+            Some(element) => write!(f, "algorithm panicked at element {}: '{}'", element, self.message),
+            None => write!(f, "algorithm panicked: '{}'", self.message),
+        }
+    }
+}
+
+/// extracts the message of a panic `payload` -- as given to `panic!()`, which are either `&str`s or `String`s
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("<non-string panic payload>"))
+}
+
+/// The async counterpart of [run_iterator_pass()]: awaits `iterator_algorithm(e)` for each element of `range`, one after the other, in the
+/// given `order` -- for algorithms like a database lookup loop.\
+/// Runs on the caller's runtime: the time of any other task that gets polled in-between is accounted to the pass, as is the time the
+/// futures spend waiting -- prefer single-threaded runtimes, like `#[tokio::test]`'s default one, with no other tasks.
+pub async fn run_async_iterator_pass<_AsyncAlgorithmClosure: Fn(u32) -> _AlgorithmFuture + Sync,
+                                     _AlgorithmFuture:       Future<Output=u32> + Send>
+                                    (iterator_algorithm: &_AsyncAlgorithmClosure,
+                                     algorithm_type:     &BigOIteratorAlgorithmType,
+                                     range:              Range<u32>,
+                                     order:              IterationOrder)
+                                    -> (PassResult, u32) {
+    run_async_iterator_pass_timing_iterations(iterator_algorithm, algorithm_type, range, order, None).await
+}
+
+/// Same as [run_async_iterator_pass()], but also pushing how long each call of `iterator_algorithm` took into `iteration_timings`,
+/// if given -- which should have the capacity for the whole `range` pre-allocated, so its growth won't be measured
+pub(crate) async fn run_async_iterator_pass_timing_iterations<_AsyncAlgorithmClosure: Fn(u32) -> _AlgorithmFuture + Sync,
+                                                              _AlgorithmFuture:       Future<Output=u32> + Send>
+                                                             (iterator_algorithm:    &_AsyncAlgorithmClosure,
+                                                              algorithm_type:        &BigOIteratorAlgorithmType,
+                                                              range:                 Range<u32>,
+                                                              order:                 IterationOrder,
+                                                              mut iteration_timings: Option<&mut Vec<Duration>>)
+                                                             -> (PassResult, u32) {
+    // the span must be started before the allocator's save point, so its allocations aren't measured
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_iterator_pass_span(algorithm_type, &range, 1);
+    #[cfg(not(feature = "opentelemetry"))]
+    let _ = algorithm_type;
+
+    let mut r = range.end;
+    let allocator_savepoint = features::ALLOC.save_point();
+    let start = Instant::now();
+    match order {
+        IterationOrder::Ascending => {
+            for e in range {
+                r ^= match iteration_timings.as_deref_mut() {
+                    None => iterator_algorithm(e).await,
+                    Some(iteration_timings) => {
+                        let iteration_start = Instant::now();
+                        let r = iterator_algorithm(e).await;
+                        iteration_timings.push(iteration_start.elapsed());
+                        r
+                    },
+                };
+            }
+        },
+        IterationOrder::Descending => {
+            for e in range.rev() {
+                r ^= match iteration_timings.as_deref_mut() {
+                    None => iterator_algorithm(e).await,
+                    Some(iteration_timings) => {
+                        let iteration_start = Instant::now();
+                        let r = iterator_algorithm(e).await;
+                        iteration_timings.push(iteration_start.elapsed());
+                        r
+                    },
+                };
+            }
+        },
+    }
+    let time_measurements = start.elapsed();
+    let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
+
+    (PassResult {
+        time_measurements,
+        wall_clock_time:     time_measurements,
+        summed_threads_time: time_measurements,
+        space_measurements: BigOSpacePassMeasurements {
+            used_memory_before: allocator_savepoint.metrics.current_used_memory,
+            used_memory_after:  allocator_statistics.current_used_memory,
+            min_used_memory:    allocator_statistics.min_used_memory,
+            max_used_memory:    allocator_statistics.max_used_memory,
+        },
+        #[cfg(feature = "opentelemetry")]
+        span_ids: pass_span.end(time_measurements),
+    }, r)
+}
+
+/// Same as [run_iterator_pass()], single-threaded, but also timing each iteration -- for the statistics needed by
+/// [crate::low_level_analysis::time_analysis::analyse_amortized_time_complexity()].\
+/// Timing each call adds its overhead to the reported time, which is the sum of the iterations' elapsed times.
+pub fn run_iterator_pass_with_iteration_statistics<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                                  (iterator_algorithm: &_AlgorithmClosure,
+                                                   range:              Range<u32>,
+                                                   order:              IterationOrder)
+                                                  -> (PassResult, BigOIterationTimeStatistics, u32) {
+    // allocated before the save point, so it isn't taken into account in the space measurements
+    let mut elapsed_times = Vec::<Duration>::with_capacity(range.len());
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_iterator_pass_span(&BigOIteratorAlgorithmType::ConstantSet, &range, 1);
+    let mut r = range.end;
+    let allocator_savepoint = features::ALLOC.save_point();
+    let mut run = |e| {
+        let start = Instant::now();
+        r ^= iterator_algorithm(e);
+        elapsed_times.push(start.elapsed());
+    };
+    match order {
+        IterationOrder::Ascending  => range.for_each(&mut run),
+        IterationOrder::Descending => range.rev().for_each(&mut run),
+    }
+    let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
+    let time_measurements = elapsed_times.iter().sum();
+    let pass_result = PassResult {
+        time_measurements,
+        wall_clock_time:     time_measurements,
+        summed_threads_time: time_measurements,
+        space_measurements: BigOSpacePassMeasurements {
+            used_memory_before: allocator_savepoint.metrics.current_used_memory,
+            used_memory_after:  allocator_statistics.current_used_memory,
+            min_used_memory:    allocator_statistics.min_used_memory,
+            max_used_memory:    allocator_statistics.max_used_memory,
+        },
+        #[cfg(feature = "opentelemetry")]
+        span_ids: pass_span.end(time_measurements),
+    };
+    (pass_result, BigOIterationTimeStatistics::from_elapsed_times(&mut elapsed_times), r)
+}
+
+/// Same as [run_iterator_pass()], single-threaded, but also returning the pass' [MeasurementTimeline]: every `sample_every` iterations,
+/// the elapsed time since the start of the pass is appended -- revealing algorithms degrading along the pass. Iterations after
+/// the last sample are left out of the timeline, so all its intervals are comparable.
+/// See [degraded_within_pass()].\
+/// Reading the clock adds its overhead to the reported time -- keep `sample_every` large enough for it not to matter.
+pub fn run_iterator_pass_with_timeline<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                      (sample_every:       u32,
+                                       iterator_algorithm: &_AlgorithmClosure,
+                                       range:              Range<u32>,
+                                       order:              IterationOrder)
+                                      -> (PassResult, MeasurementTimeline, u32) {
+    assert!(sample_every > 0, "Timeline pass over {:?}: `sample_every` must be at least 1", range);
+    // allocated before the save point, so it isn't taken into account in the space measurements
+    let mut timeline = MeasurementTimeline::with_capacity(range.len() / sample_every as usize);
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_iterator_pass_span(&BigOIteratorAlgorithmType::ConstantSet, &range, 1);
+    let mut r = range.end;
+    let allocator_savepoint = features::ALLOC.save_point();
+    let start = Instant::now();
+    let mut run = |(i, e): (u32, u32)| {
+        r ^= iterator_algorithm(e);
+        if (i + 1) % sample_every == 0 {
+            timeline.push(start.elapsed().as_nanos() as u64);
+        }
+    };
+    match order {
+        IterationOrder::Ascending  => (0..).zip(range).for_each(&mut run),
+        IterationOrder::Descending => (0..).zip(range.rev()).for_each(&mut run),
+    }
+    let time_measurements = start.elapsed();
+    let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
+    (PassResult {
+        time_measurements,
+        wall_clock_time:     time_measurements,
+        summed_threads_time: time_measurements,
+        space_measurements: BigOSpacePassMeasurements {
+            used_memory_before: allocator_savepoint.metrics.current_used_memory,
+            used_memory_after:  allocator_statistics.current_used_memory,
+            min_used_memory:    allocator_statistics.min_used_memory,
+            max_used_memory:    allocator_statistics.max_used_memory,
+        },
+        #[cfg(feature = "opentelemetry")]
+        span_ids: pass_span.end(time_measurements),
+    }, timeline, r)
+}
+
+/// Same as [run_iterator_pass()], single-threaded, but without consulting the [features::ALLOC]ator at all -- for algorithms known
+/// to be allocation-free (bit manipulation, in-place sorting), sparing them of the save point overhead.\
+/// The returned [PassResult::space_measurements] are zeroed: space is not measured.
+pub fn run_iterator_pass_no_alloc<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                 (iterator_algorithm: &_AlgorithmClosure,
+                                  range:              Range<u32>,
+                                  order:              IterationOrder)
+                                 -> (PassResult, u32) {
+    let mut r = range.end;
+    let start = Instant::now();
+    match order {
+        IterationOrder::Ascending  => range.for_each(|e| r ^= iterator_algorithm(e)),
+        IterationOrder::Descending => range.rev().for_each(|e| r ^= iterator_algorithm(e)),
+    }
+    let time_measurements = start.elapsed();
+    (PassResult {
+        time_measurements,
+        wall_clock_time:     time_measurements,
+        summed_threads_time: time_measurements,
+        ..PassResult::default()
+    }, r)
+}
+
+/// Measures the per-iteration overhead [run_iterator_pass()] adds to the measured times -- the loop, the result accumulation
+/// and the thread synchronization -- by running a no-op algorithm for the given number of `iterations` & `threads`.\
+/// See [run_iterator_pass_calibrated()].
+pub fn measure_runner_overhead(iterations: u32, threads: u32) -> Duration {
+    Duration::from_secs_f64(runner_overhead_secs_per_iteration(iterations, threads, &DefaultTimingBackend::default()))
+}
+
+/// [measure_runner_overhead()]'s implementation, measuring with the given timing `backend` -- in seconds, as a float: optimized, the
+/// overhead of an iteration is often a fraction of the nanosecond a `Duration` would round it down to
+fn runner_overhead_secs_per_iteration(iterations: u32, threads: u32, backend: &impl TimingBackend) -> f64 {
+    let (pass_result, _r) = run_iterator_pass_with_backend(&|e| black_box(e), &BigOIteratorAlgorithmType::ConstantSet, 0..iterations, IterationOrder::Ascending, threads, &[], backend);
+    pass_result.time_measurements.as_secs_f64() / iterations_per_thread(iterations, threads) as f64
+}
+
+/// Measures the memory [run_iterator_pass()] itself allocates while measuring a pass of `threads` -- the threads' bookkeeping --
+/// which shows up in the pass' space measurements regardless of the algorithm: the biggest peak over a few no-op passes is taken,
+/// as it varies slightly from pass to pass.\
+/// See [crate::runners::standard::test_set_resizing_iterator_algorithm()], which discounts it from each segment.
+pub fn measure_runner_space_overhead(threads: u32) -> usize {
+    const SAMPLES: u32 = 10;
+    (0..SAMPLES)
+        .map(|_| run_iterator_pass(&|e| black_box(e), &BigOIteratorAlgorithmType::ConstantSet, 0..threads, IterationOrder::Ascending, threads).0.space_measurements)
+        .map(|space_measurements| space_measurements.max_used_memory - space_measurements.used_memory_before)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Estimates how long the runner alone will take to go through a warmup (of `warmup_percentage`) and two passes of
+/// `iterations_per_pass` split among `threads` -- by timing a no-op algorithm on 1% of the iterations and extrapolating linearly.\
+/// The algorithm's own time is not included: the estimate is a lower bound, useful for spotting an analysis that would take
+/// too long before it even starts.
+pub fn dry_run_estimate(iterations_per_pass: u32, threads: u32, warmup_percentage: u32) -> Duration {
+    if iterations_per_pass == 0 || threads == 0 {
+        return Duration::ZERO
+    }
+    let sample_iterations = std::cmp::max(iterations_per_pass / 100, threads);
+    let (sample_pass_result, _r) = run_iterator_pass(&|e| black_box(e), &BigOIteratorAlgorithmType::ConstantSet, 0..sample_iterations, IterationOrder::Ascending, threads);
+    // scaled as a float: optimized, an iteration may take less than the nanosecond a `Duration` would round it down to
+    let time_per_pass = sample_pass_result.time_measurements.mul_f64(iterations_per_thread(iterations_per_pass, threads) as f64 / iterations_per_thread(sample_iterations, threads) as f64);
+    time_per_pass * (200 + warmup_percentage) / 100
+}
+
+/// Suggests how many iterations a pass should have for it to last about `target_pass_duration` -- for use as the
+/// `*_iterations_per_pass` of [crate::runners::crud::test_crud_algorithms()] and friends: too few iterations and IO / OS times dominate the
+/// measurements, too many and the analysis takes too long.\
+/// `sample_fn` is run, single-threaded, with exponentially increasing iteration counts until a pass takes a noticeable fraction
+/// of `target_pass_duration`, from which the count is extrapolated linearly -- then checked by a pass of that many iterations,
+/// which corrects it should the extrapolation be off (caches warming up, for instance). Since it is run several times, it should
+/// have no lasting side effects -- sample a read-like operation or reset the data between the calls.\
+/// The suggested count is reported through the output, together with the duration measured for it.
+pub fn suggest_iterations(sample_fn: &(impl Fn(u32) -> u32 + Sync), target_pass_duration: Duration) -> u32 {
+    let measure = |iterations: u32| run_iterator_pass(sample_fn, &BigOIteratorAlgorithmType::ConstantSet, 0..iterations, IterationOrder::Ascending, 1).0.time_measurements;
+    let extrapolate = |iterations: u32, elapsed: Duration| {
+        let suggestion = iterations as f64 * target_pass_duration.as_secs_f64() / elapsed.as_secs_f64().max(f64::MIN_POSITIVE);
+        suggestion.clamp(1.0, u32::MAX as f64) as u32
+    };
+    let mut iterations: u32 = 1;
+    let mut elapsed = measure(iterations);
+    while elapsed < target_pass_duration / 8 && iterations <= u32::MAX / 2 {
+        iterations *= 2;
+        elapsed = measure(iterations);
+    }
+    let suggestion = extrapolate(iterations, elapsed);
+    let checked_elapsed = measure(suggestion);
+    let checked_suggestion = extrapolate(suggestion, checked_elapsed);
+    features::OUTPUT(&format!("suggest_iterations(): {} iterations per pass are suggested for a {:?} target -- {} iterations took {:?}\n",
+                            checked_suggestion, target_pass_duration, suggestion, checked_elapsed));
+    checked_suggestion
+}
+
+/// Same as [run_iterator_pass()], but subtracting the runner's overhead from the reported time -- giving a more accurate picture
+/// for O(1) algorithms that are only a few nanoseconds slower than the runner itself.\
+/// The overhead is measured, before the pass, with [measure_runner_overhead()] -- so this takes about the double of the time.
+pub fn run_iterator_pass_calibrated<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                   (iterator_algorithm: &_AlgorithmClosure,
+                                    algorithm_type:     &BigOIteratorAlgorithmType,
+                                    range:              Range<u32>,
+                                    order:              IterationOrder,
+                                    threads:            u32)
+                                   -> (PassResult, u32) {
+    run_iterator_pass_calibrated_with_backend(iterator_algorithm, algorithm_type, range, order, threads, &DefaultTimingBackend::default())
+}
+
+/// Same as [run_iterator_pass_calibrated()], but measuring both the overhead & the pass with the given timing `backend`
+pub(crate) fn run_iterator_pass_calibrated_with_backend<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                                      (iterator_algorithm: &_AlgorithmClosure,
+                                                       algorithm_type:     &BigOIteratorAlgorithmType,
+                                                       range:              Range<u32>,
+                                                       order:              IterationOrder,
+                                                       threads:            u32,
+                                                       backend:            &impl TimingBackend)
+                                                      -> (PassResult, u32) {
+    let iterations = range.len() as u32;
+    let overhead_secs_per_iteration = runner_overhead_secs_per_iteration(iterations, threads, backend);
+    let (mut pass_result, r) = run_iterator_pass_with_backend(iterator_algorithm, algorithm_type, range, order, threads, &[], backend);
+    let overhead = Duration::from_secs_f64(overhead_secs_per_iteration * iterations_per_thread(iterations, threads) as f64);
+    pass_result.time_measurements = pass_result.time_measurements.saturating_sub(overhead);
+    (pass_result, r)
+}
+
+/// Runs the same [run_iterator_pass()] `sample_count` times, returning the statistics of the measured times -- so the complexity
+/// analysis may use the median ([PassStatistics::median]) rather than a single sample, reducing the retry rate on noisy machines.\
+/// Only suitable for algorithms whose passes may be repeated -- [BigOIteratorAlgorithmType::ConstantSet] ones, for instance.\
+/// The CRUD runners sample their read & update passes this way when given [crate::runners::crud::CrudOptions::with_sample_count()].
+pub fn run_iterator_pass_multi_sample<_AlgorithmClosure: Fn(u32) -> u32 + Sync>
+                                     (iterator_algorithm: &_AlgorithmClosure,
+                                      algorithm_type:     &BigOIteratorAlgorithmType,
+                                      range:              Range<u32>,
+                                      order:              IterationOrder,
+                                      threads:            u32,
+                                      sample_count:       u32)
+                                     -> (PassStatistics, u32) {
+    let mut r = 0;
+    let samples = (0..std::cmp::max(1, sample_count))
+        .map(|_| {
+            let (pass_result, pass_r) = run_iterator_pass(iterator_algorithm, algorithm_type, range.clone(), order, threads);
+            r ^= pass_r;
+            pass_result
+        })
+        .collect();
+    (PassStatistics::from_samples(samples), r)
+}
+
+/// the continuous chunk of `range` the `n`th of `threads` threads runs in [run_iterator_pass()] -- the first thread gets the first elements,
+/// according to the `order`, and the remainder of the division is spread over the first threads, one element each, so no iterations
+/// are dropped and no thread runs more than one iteration above the others
+fn thread_chunk(range: &Range<u32>, order: IterationOrder, threads: u32, n: u32) -> Range<u32> {
+    let chunk_size = range.len() as u32 / threads;
+    let remainder = range.len() as u32 % threads;
+    let offset = chunk_size*n + n.min(remainder);
+    let len = chunk_size + if n < remainder {1} else {0};
+    match order {
+        IterationOrder::Ascending  => range.start + offset .. range.start + offset + len,
+        IterationOrder::Descending => range.end - offset - len .. range.end - offset,
+    }
+}
+
+/// how many iterations each thread runs in [run_iterator_pass()] -- never 0, so it may be used as a divisor
+fn iterations_per_thread(iterations: u32, threads: u32) -> u32 {
+    std::cmp::max(1, iterations / threads)
+}
+
+/// Runs `pass` as the `pass_number`th pass of an analysis (starting at 1), over a set of `set_size` elements -- so its `big_o.pass`
+/// span tells so, when the `opentelemetry` feature is enabled: see [crate::runners::telemetry]
+pub(crate) fn in_pass<R>(pass_number: u32, set_size: u64, pass: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "opentelemetry")]
+    return crate::runners::telemetry::in_pass(pass_number, set_size, pass);
+    #[cfg(not(feature = "opentelemetry"))]
+    {
+        let _ = (pass_number, set_size);
+        pass()
+    }
+}
+
+/// The async counterpart of [in_pass()]: each poll of the `pass` future happens within it
+#[cfg(feature = "tokio")]
+pub(crate) async fn in_async_pass<PassFuture: Future>(pass_number: u32, set_size: u64, pass: PassFuture) -> PassFuture::Output {
+    let mut pass = std::pin::pin!(pass);
+    std::future::poll_fn(|context| in_pass(pass_number, set_size, || pass.as_mut().poll(context))).await
+}
+
 /// Runs a pass on the given synchronous `algorithm` callback function or closure,
 /// measuring (and returning) the time it took to run it.\
 /// See [run_iterator_pass()] for algorithms which generates or operates on a single element per call.
@@ -171,23 +1057,38 @@ pub(crate) fn run_iterator_pass<'a, _AlgorithmClosure: Fn(u32) -> u32 + Sync>
 /// returns: tuple with ([PassResult]], computed_number: u32)
 ///
 /// See also [run_async_pass()]
-pub(crate) fn run_sync_pass(mut algorithm:  impl FnMut() -> u32)
+pub(crate) fn run_sync_pass(algorithm:  impl FnMut() -> u32)
                            -> (PassResult, u32) {
+    run_sync_pass_with_backend(algorithm, &DefaultTimingBackend::default())
+}
+
+/// Same as [run_sync_pass()], but measuring the time with the given `timing_backend` -- allowing the runners to be tested with mocked times
+pub(crate) fn run_sync_pass_with_backend(mut algorithm:  impl FnMut() -> u32,
+                                         timing_backend: &impl TimingBackend)
+                                        -> (PassResult, u32) {
 
+    timing_backend.calibrate();
+    // the span must be started before the allocator's save point, so its allocations aren't measured
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_pass_span();
     let allocator_savepoint = features::ALLOC.save_point();
-    let start = Instant::now();
+    let start = timing_backend.start();
     let r = algorithm();
-    let duration = start.elapsed();
+    let duration = timing_backend.elapsed(start);
     let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
 
     (PassResult {
-        time_measurements:  duration,
+        time_measurements:   duration,
+        wall_clock_time:     duration,
+        summed_threads_time: duration,
         space_measurements: BigOSpacePassMeasurements {
             used_memory_before: allocator_savepoint.metrics.current_used_memory,
             used_memory_after:  allocator_statistics.current_used_memory,
             min_used_memory:    allocator_statistics.min_used_memory,
             max_used_memory:    allocator_statistics.max_used_memory,
         },
+        #[cfg(feature = "opentelemetry")]
+        span_ids: pass_span.end(duration),
     }, r)
 }
 
@@ -206,43 +1107,527 @@ pub(crate) async fn run_async_pass<AlgorithmPassFn:   FnMut(Option<AlgoDataType>
                                    AlgorithmPassFut:  Future<Output=AlgoDataType> + Send,
                                    AlgoDataType:      Send + Sync + Debug>
                                   (algo_data:              Option<AlgoDataType>,
-                                   mut algorithm_pass_fn:  AlgorithmPassFn)
+                                   algorithm_pass_fn:      AlgorithmPassFn)
                                   -> (PassResult, AlgoDataType) {
+    try_run_async_pass(algo_data, algorithm_pass_fn).await
+        .unwrap_or_else(|algorithm_panic| panic!("Panic! while running provided async 'algorithm' closure: {}", algorithm_panic))
+}
+
+/// Same as [run_async_pass()], but returning -- rather than re-panicking -- any panic of `algorithm_pass_fn`'s future
+pub(crate) async fn try_run_async_pass<AlgorithmPassFn:   FnMut(Option<AlgoDataType>) -> AlgorithmPassFut + Send + Sync,
+                                       AlgorithmPassFut:  Future<Output=AlgoDataType> + Send,
+                                       AlgoDataType:      Send + Sync + Debug>
+                                      (algo_data:              Option<AlgoDataType>,
+                                       mut algorithm_pass_fn:  AlgorithmPassFn)
+                                      -> Result<(PassResult, AlgoDataType), AlgorithmPanic> {
 
+    // the span must be started before the allocator's save point, so its allocations aren't measured
+    #[cfg(feature = "opentelemetry")]
+    let pass_span = crate::runners::telemetry::start_pass_span();
     let allocator_savepoint = features::ALLOC.save_point();
     let start = Instant::now();
-    let algo_data = black_box(algorithm_pass_fn(algo_data).await);
+    let mut pass_future = std::pin::pin!(algorithm_pass_fn(algo_data));
+    // catches panics on each poll -- without boxing the future, which would be accounted for in the space measurements
+    let outcome = std::future::poll_fn(|context| {
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| pass_future.as_mut().poll(context))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => std::task::Poll::Ready(Err(AlgorithmPanic { element: None, message: panic_message(payload.as_ref()) })),
+        }
+    }).await;
     let duration = start.elapsed();
     let allocator_statistics = features::ALLOC.delta_statistics(&allocator_savepoint);
+    let algo_data = black_box(outcome?);
 
-    (PassResult {
-        time_measurements:  duration,
+    Ok((PassResult {
+        time_measurements:   duration,
+        wall_clock_time:     duration,
+        summed_threads_time: duration,
         space_measurements: BigOSpacePassMeasurements {
             used_memory_before: allocator_savepoint.metrics.current_used_memory,
             used_memory_after:  allocator_statistics.current_used_memory,
             min_used_memory:    allocator_statistics.min_used_memory,
             max_used_memory:    allocator_statistics.max_used_memory,
         },
-    }, algo_data)
+        #[cfg(feature = "opentelemetry")]
+        span_ids: pass_span.end(duration),
+    }, algo_data))
+}
+
+/// Statistics over the times measured for several samples of the same pass -- see [run_iterator_pass_multi_sample()]
+#[derive(Clone,Copy,Debug)]
+pub struct PassStatistics {
+    pub mean:    Duration,
+    pub std_dev: Duration,
+    pub p50:     Duration,
+    pub p95:     Duration,
+    pub p99:     Duration,
+    /// the median sample -- suitable for feeding the complexity analysis
+    pub median:  PassResult,
+}
+impl PassStatistics {
+    /// computes the statistics for the given (non-empty) `samples`
+    pub fn from_samples(mut samples: Vec<PassResult>) -> Self {
+        assert!(!samples.is_empty(), "PassStatistics: at least one sample is needed");
+        samples.sort_by_key(|sample| sample.time_measurements);
+        let count = samples.len() as f64;
+        let mean = samples.iter().map(|sample| sample.time_measurements.as_secs_f64()).sum::<f64>() / count;
+        let variance = samples.iter().map(|sample| (sample.time_measurements.as_secs_f64() - mean).powi(2)).sum::<f64>() / count;
+        // nearest-rank percentiles
+        let percentile = |p: f64| samples[((p * count).ceil() as usize).clamp(1, samples.len()) - 1];
+        Self {
+            mean:    Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            p50:     percentile(0.50).time_measurements,
+            p95:     percentile(0.95).time_measurements,
+            p99:     percentile(0.99).time_measurements,
+            median:  percentile(0.50),
+        }
+    }
+}
+
+/// The elapsed nanoseconds since the start of a pass, sampled every `sample_every` iterations -- see [run_iterator_pass_with_timeline()]
+pub type MeasurementTimeline = Vec<u64>;
+
+/// Tells if the `timeline` shows the algorithm getting slower as the pass went on -- like a hash table fill causing increasing
+/// collisions -- which the pass' aggregated time hides: every sampled interval took, at least, about as long as the previous one
+/// (within [features::PERCENT_TOLERANCE]) and the last took noticeably longer than the first.\
+/// `false` if the timeline has less than 3 intervals.
+pub fn degraded_within_pass(timeline: &[u64]) -> bool {
+    let intervals = std::iter::once(0).chain(timeline.iter().copied())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|elapsed| elapsed[1].saturating_sub(elapsed[0]) as f64)
+        .collect::<Vec<_>>();
+    let (Some(&first), Some(&last)) = (intervals.first(), intervals.last()) else {
+        return false
+    };
+    intervals.len() >= 3 &&
+    intervals.windows(2).all(|interval| interval[1] >= interval[0] * (1.0 - features::PERCENT_TOLERANCE)) &&
+    last > first * (1.0 + features::PERCENT_TOLERANCE)
 }
 
 /// contains the measurements for a pass done in [run_sync_pass()]
-#[derive(Clone,Copy)]
+#[derive(Clone,Copy,Debug)]
 pub struct PassResult {
-    pub time_measurements:  Duration,
-    pub space_measurements: BigOSpacePassMeasurements,
+    /// the times measured by each thread, combined by an [Aggregation] -- their mean, by default -- the one used in the complexity analysis
+    pub time_measurements:   Duration,
+    /// the overall elapsed time of the pass, from the start of the first thread to the end of the last one
+    pub wall_clock_time:     Duration,
+    /// the sum of the times measured by each thread -- see [Self::parallelism()]
+    pub summed_threads_time: Duration,
+    pub space_measurements:  BigOSpacePassMeasurements,
+    /// identifies the `big_o.pass` span this pass was traced in -- only set when a tracer provider is recording.
+    /// See [crate::runners::telemetry]
+    #[cfg(feature = "opentelemetry")]
+    pub span_ids: Option<crate::runners::telemetry::PassSpanIds>,
 }
 impl Default for PassResult {
     fn default() -> Self {
         Self {
             time_measurements: Duration::default(),
+            wall_clock_time: Duration::default(),
+            summed_threads_time: Duration::default(),
             space_measurements: BigOSpacePassMeasurements {
                 used_memory_before: 0,
                 used_memory_after:  0,
                 min_used_memory:    0,
                 max_used_memory:    0,
-            }
+            },
+            #[cfg(feature = "opentelemetry")]
+            span_ids: None,
         }
     }
 }
+impl PassResult {
+    /// how many threads ran at the same time, on average: the summed threads' time over the wall-clock time.\
+    /// Close to the number of threads if they really overlapped; close to 1 if they ran one after the other -- like when spawning them
+    /// takes longer than running them. Notice time spent waiting on locks is accounted to each thread, showing up as longer thread times instead
+    pub fn parallelism(&self) -> f64 {
+        if self.wall_clock_time.is_zero() {
+            1.0
+        } else {
+            self.summed_threads_time.as_secs_f64() / self.wall_clock_time.as_secs_f64()
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [common](super) module
+
+    use super::*;
+    use crate::runners::timing::{MockTimingBackend, ScriptedTimingBackend};
+    use serial_test::serial;
+
+
+    /// pins the exact sequence in which the algorithm is called, for each [IterationOrder] -- with 1 and several threads
+    #[test]
+    #[serial]
+    fn iteration_order() {
+        let called_sequence = |order, threads| {
+            let calls = parking_lot::Mutex::new(Vec::<(std::thread::ThreadId, u32)>::new());
+            run_iterator_pass(&|e| { calls.lock().push((std::thread::current().id(), e)); e }, &BigOIteratorAlgorithmType::SetResizing, 10..16, order, threads);
+            let calls = calls.into_inner();
+            // group the calls per thread, keeping the order each one did them
+            let mut thread_ids: Vec<std::thread::ThreadId> = calls.iter().map(|(thread_id, _)| *thread_id).collect();
+            thread_ids.dedup();
+            thread_ids.into_iter()
+                .map(|thread_id| calls.iter().filter(|(id, _)| *id == thread_id).map(|(_, e)| *e).collect::<Vec<u32>>())
+                .collect::<Vec<Vec<u32>>>()
+        };
+        assert_eq!(called_sequence(IterationOrder::Ascending,  1), vec![vec![10, 11, 12, 13, 14, 15]], "Wrong single-threaded ascending order");
+        assert_eq!(called_sequence(IterationOrder::Descending, 1), vec![vec![15, 14, 13, 12, 11, 10]], "Wrong single-threaded descending order");
+        let mut chunks = called_sequence(IterationOrder::Descending, 2);
+        chunks.sort_by_key(|chunk| std::cmp::Reverse(chunk[0]));
+        assert_eq!(chunks, vec![vec![15, 14, 13], vec![12, 11, 10]], "Each thread should get a continuous, descending chunk");
+        let mut chunks = called_sequence(IterationOrder::Ascending, 4);
+        chunks.sort_by_key(|chunk| chunk[0]);
+        assert_eq!(chunks, vec![vec![10, 11], vec![12, 13], vec![14], vec![15]], "The remainder of the range should be spread over the first threads");
+        let mut chunks = called_sequence(IterationOrder::Descending, 4);
+        chunks.sort_by_key(|chunk| std::cmp::Reverse(chunk[0]));
+        assert_eq!(chunks, vec![vec![15, 14], vec![13, 12], vec![11], vec![10]], "The remainder of the range should be spread over the first threads");
+    }
+
+    /// checks the statistics computed by [PassStatistics::from_samples()]
+    #[test]
+    fn pass_statistics() {
+        let samples = [5, 1, 3, 2, 4].into_iter()
+            .map(|millis| PassResult { time_measurements: Duration::from_millis(millis), ..PassResult::default() })
+            .collect();
+        let statistics = PassStatistics::from_samples(samples);
+        assert_eq!(statistics.mean, Duration::from_millis(3), "Wrong mean");
+        assert_eq!(statistics.std_dev.as_micros(), 1414, "Wrong standard deviation");
+        assert_eq!(statistics.p50, Duration::from_millis(3), "Wrong median");
+        assert_eq!(statistics.p95, Duration::from_millis(5), "Wrong 95th percentile");
+        assert_eq!(statistics.p99, Duration::from_millis(5), "Wrong 99th percentile");
+        assert_eq!(statistics.median.time_measurements, statistics.p50, "The median sample doesn't match the median time");
+
+        let (statistics, _r) = run_iterator_pass_multi_sample(&|e| e, &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, 1, 7);
+        assert!(statistics.p50 <= statistics.p95 && statistics.p95 <= statistics.p99, "Percentiles are not monotonic: {:?}", statistics);
+    }
+
+    /// assures [measure_runner_space_overhead()] covers the space measured for passes of algorithms that don't allocate
+    #[test]
+    #[serial]
+    fn runner_space_overhead() {
+        let overhead = measure_runner_space_overhead(4);
+        assert!(overhead < 64 * 1024, "The runner space overhead of {} bytes is unreasonably high", overhead);
+        let (pass_result, _r) = run_iterator_pass(&|e| black_box(e), &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, 4);
+        let space = pass_result.space_measurements.max_used_memory - pass_result.space_measurements.used_memory_before;
+        assert!(space <= overhead, "A non-allocating pass used {} bytes, more than the {} bytes of the runner overhead", space, overhead);
+    }
+
+    /// assures [run_iterator_pass_calibrated()] reports only the time spent in the algorithm -- deterministically, with scripted times:
+    /// the no-op overhead pass is timed first, then the algorithm's pass
+    #[test]
+    fn calibrated_pass() {
+        const ITERATIONS: u32 = 1000;
+        // 0.5ns of overhead per iteration -- less than a `Duration` may represent per iteration -- and 5µs per algorithm iteration
+        let backend = ScriptedTimingBackend::new(&[Duration::from_nanos(500), Duration::from_micros(5) * ITERATIONS + Duration::from_nanos(500)]);
+        let (pass_result, _r) = run_iterator_pass_calibrated_with_backend(&|e| e, &BigOIteratorAlgorithmType::ConstantSet, 0..ITERATIONS, IterationOrder::Ascending, 1, &backend);
+        assert_eq!(pass_result.time_measurements, Duration::from_micros(5) * ITERATIONS, "The runner overhead should have been discounted");
+        let overhead = runner_overhead_secs_per_iteration(ITERATIONS, 1, &MockTimingBackend { elapsed: Duration::from_nanos(500) });
+        assert!((overhead - 0.5e-9).abs() < 1e-15, "Sub-nanosecond overheads should be kept, but {}s was measured", overhead);
+    }
+
+    /// assures [dry_run_estimate()] extrapolates the runner's time linearly -- and accounts for the warmup
+    #[test]
+    #[serial]
+    fn dry_run() {
+        assert_eq!(dry_run_estimate(0, 1, 0), Duration::ZERO, "Nothing to run should take no time");
+        let estimate = dry_run_estimate(10_000_000, 1, 0);
+        let estimate_with_warmup = dry_run_estimate(10_000_000, 1, 100);
+        assert!(estimate > Duration::ZERO, "Running 10M iterations should take some time");
+        assert!(estimate_with_warmup > estimate, "A full warmup ({:?}) should take longer than no warmup ({:?})", estimate_with_warmup, estimate);
+        assert!(estimate < Duration::from_secs(10), "Estimated {:?} for 10M no-op iterations -- way too much", estimate);
+    }
+
+    /// assures [suggest_iterations()] finds, approximately, how many iterations fit in the target duration
+    #[test]
+    #[serial]
+    fn suggested_iterations() {
+        const TIME_PER_ITERATION: Duration = Duration::from_micros(10);
+        const TARGET_PASS_DURATION: Duration = Duration::from_millis(50);
+        let busy_algorithm = |e| {
+            let start = Instant::now();
+            while start.elapsed() < TIME_PER_ITERATION {
+                std::hint::spin_loop();
+            }
+            e
+        };
+        let expected = (TARGET_PASS_DURATION.as_nanos() / TIME_PER_ITERATION.as_nanos()) as u32;
+        let suggested = suggest_iterations(&busy_algorithm, TARGET_PASS_DURATION);
+        assert!(suggested > expected / 2 && suggested <= expected, "Suggested {} iterations, when about {} were expected", suggested, expected);
+    }
+
+    /// assures [run_iterator_pass_with_iteration_statistics()] catches an occasional spike
+    #[test]
+    #[serial]
+    fn iteration_statistics() {
+        let spiky_algorithm = |e| {
+            if e == 50 {
+                spin_sleep::sleep(Duration::from_millis(1));
+            }
+            e
+        };
+        let (pass_result, statistics, _r) = run_iterator_pass_with_iteration_statistics(&spiky_algorithm, 0..100, IterationOrder::Descending);
+        assert_eq!(statistics.iterations, 100, "Wrong number of iterations");
+        assert!(statistics.min_elapsed_per_iteration <= statistics.median_elapsed_per_iteration, "min > median: {:?}", statistics);
+        assert!(statistics.max_elapsed_per_iteration >= Duration::from_millis(1), "The spike wasn't caught: {:?}", statistics);
+        assert!(statistics.median_elapsed_per_iteration < Duration::from_micros(100), "The median should disregard the spike: {:?}", statistics);
+        assert!(pass_result.time_measurements >= statistics.max_elapsed_per_iteration, "The pass time should include all iterations");
+    }
+
+    /// assures [run_iterator_pass_with_iteration_timings()] times each call, in the order the elements were processed -- even across threads
+    #[test]
+    #[serial]
+    fn iteration_timings() {
+        let spiky_algorithm = |e| {
+            if e == 50 {
+                spin_sleep::sleep(Duration::from_millis(1));
+            }
+            e
+        };
+        let (pass_result, iteration_timings, _r) = run_iterator_pass_with_iteration_timings(&spiky_algorithm, &BigOIteratorAlgorithmType::ConstantSet, 0..100, IterationOrder::Descending, 3);
+        assert_eq!(iteration_timings.len(), 100, "Wrong number of timings");
+        let slowest = (0..iteration_timings.len()).max_by_key(|&i| iteration_timings[i]).unwrap();
+        assert_eq!(slowest, 49, "The spike on element 50 should be the 50th timing of a descending pass");
+        assert!(iteration_timings[slowest] >= Duration::from_millis(1), "The spike wasn't caught: {:?}", iteration_timings[slowest]);
+        assert!(pass_result.summed_threads_time >= iteration_timings.iter().sum(), "The threads' times should include all iterations");
+    }
+
+    /// assures a pass can't be run without threads
+    #[test]
+    #[should_panic(expected = "at least 1 thread is needed")]
+    fn zero_threads() {
+        run_iterator_pass(&|n| n, &BigOIteratorAlgorithmType::ConstantSet, 0..100, IterationOrder::Ascending, 0);
+    }
+
+    /// assures the wall-clock time of multi-threaded passes is captured along with the per-thread average -- threads that
+    /// only sleep should run in parallel even on a single CPU
+    #[test]
+    #[serial]
+    fn wall_clock_and_parallelism() {
+        let (pass_result, _r) = run_iterator_pass(&|e| { spin_sleep::sleep(Duration::from_millis(20)); e },
+                                                  &BigOIteratorAlgorithmType::ConstantSet, 0..4, IterationOrder::Ascending, 4);
+        assert!(pass_result.wall_clock_time >= pass_result.time_measurements, "The wall-clock ({:?}) can't be shorter than the threads' average ({:?})", pass_result.wall_clock_time, pass_result.time_measurements);
+        assert!(pass_result.summed_threads_time >= Duration::from_millis(80), "Wrong summed threads time: {:?}", pass_result.summed_threads_time);
+        assert!(pass_result.parallelism() > 2.0, "Sleeping threads should overlap -- parallelism was {:.2}", pass_result.parallelism());
+
+        let (pass_result, _r) = run_sync_pass(|| 0);
+        assert_eq!((pass_result.wall_clock_time, pass_result.summed_threads_time), (pass_result.time_measurements, pass_result.time_measurements), "Single threaded passes should have all times equal");
+    }
 
+    /// assures [run_repeated_sync_pass_verbosely()] keeps the fastest repetition, resetting in-between them
+    #[test]
+    #[serial]
+    fn fastest_of_repetitions() {
+        let sleeps_ms = [30, 5, 20];
+        let mut calls = 0;
+        let mut resets = 0;
+        let mut output = String::new();
+        let (pass_result, _r) = run_repeated_sync_pass_verbosely("Pass: ", "", || { spin_sleep::sleep(Duration::from_millis(sleeps_ms[calls])); calls += 1; 0 },
+                                                                 || resets += 1, 3, |text| output.push_str(text));
+        assert_eq!((calls, resets), (3, 2), "Wrong number of repetitions / resets");
+        assert!(pass_result.time_measurements >= Duration::from_millis(5) && pass_result.time_measurements < Duration::from_millis(20),
+                "The fastest repetition (of ~5ms) should have been kept -- got {:?}", pass_result.time_measurements);
+        assert!(output.ends_with(" (fastest of 3)"), "Repeated passes should be noted in the output: '{}'", output);
+    }
+
+    /// assures only passes measuring within a few timer resolution steps are warned about
+    #[test]
+    fn timer_resolution_warning() {
+        assert!(timer_resolution_note(Duration::ZERO).contains("timer resolution"), "Instantaneous passes should be warned about");
+        assert_eq!(timer_resolution_note(Duration::from_secs(1)), "", "A 1s pass is way above any timer resolution");
+    }
+
+    /// assures [run_iterator_pass_with_workload()] feeds the algorithm with the workload's values
+    #[test]
+    #[serial]
+    fn workload_pass() {
+        let fed_values = parking_lot::Mutex::new(Vec::new());
+        let workload = Workload::Shuffled { seed: 7 };
+        run_iterator_pass_with_workload(&|e| { fed_values.lock().push(e); e }, &BigOIteratorAlgorithmType::ConstantSet, 10..20, IterationOrder::Ascending, 1, workload);
+        assert_eq!(fed_values.into_inner(), workload.sequence(10..20).collect::<Vec<_>>(), "The algorithm should receive the workload's values");
+    }
+
+    /// assures the panic of an algorithm is caught with its message & the element it happened on -- and that the panicking
+    /// runners include them in their own panic messages
+    #[test]
+    #[serial]
+    fn panicking_algorithm() {
+        let algorithm = |e| if e == 42 { panic!("can't handle {}", e) } else { e };
+        let outcome = try_run_iterator_pass_pinned(&algorithm, &BigOIteratorAlgorithmType::ConstantSet, 0..100, IterationOrder::Descending, 4, None, Aggregation::Mean, None);
+        assert_eq!(outcome.err(), Some(PassAbort::Panicked(AlgorithmPanic { element: Some(42), message: String::from("can't handle 42") })), "Wrong panic report");
+        let payload = std::panic::catch_unwind(|| run_iterator_pass(&algorithm, &BigOIteratorAlgorithmType::ConstantSet, 0..100, IterationOrder::Ascending, 1))
+            .expect_err("The non-`try_` runner should panic");
+        assert!(panic_message(payload.as_ref()).ends_with("algorithm panicked at element 42: 'can't handle 42'"), "Wrong panic message: {}", panic_message(payload.as_ref()));
+    }
+
+    /// assures [run_async_iterator_pass()] awaits the algorithm for every element, in order, accounting for the awaited time
+    #[tokio::test]
+    #[serial]
+    async fn async_iterator_pass() {
+        let fed_elements = parking_lot::Mutex::new(Vec::new());
+        let (pass_result, _r) = run_async_iterator_pass(&|e| {
+                                                             fed_elements.lock().push(e);
+                                                             async move { tokio::time::sleep(Duration::from_millis(2)).await; e }
+                                                         },
+                                                         &BigOIteratorAlgorithmType::ConstantSet, 10..15, IterationOrder::Descending).await;
+        assert_eq!(fed_elements.into_inner(), vec![14, 13, 12, 11, 10], "Wrong elements / order");
+        assert!(pass_result.time_measurements >= Duration::from_millis(10), "The awaited time should be measured -- got {:?}", pass_result.time_measurements);
+    }
+
+    /// Attests a pass exceeding its timeout is aborted -- on all threads, at the next deadline check -- reporting how many iterations were completed
+    #[test]
+    #[serial]
+    fn pass_timeout() {
+        let algorithm = |n: u32| {
+            std::thread::sleep(Duration::from_millis(10));
+            n
+        };
+        let outcome = try_run_iterator_pass_pinned(&algorithm, &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, 4, None, Aggregation::Mean, Some(Duration::from_millis(50)));
+        let Err(PassAbort::TimedOut(pass_timeout)) = outcome else { panic!("The pass should have timed out, but got {:?}", outcome.map(|(pass_result, _r)| pass_result.time_measurements)) };
+        assert_eq!((pass_timeout.timeout, pass_timeout.iterations), (Duration::from_millis(50), 1000), "Wrong timeout report");
+        assert!(pass_timeout.completed_iterations > 0 && pass_timeout.completed_iterations < 1000, "Some, but not all, iterations should have been completed -- {} were", pass_timeout.completed_iterations);
+        assert!(pass_timeout.completed_iterations.is_multiple_of(DEADLINE_CHECK_INTERVAL), "The deadline should only be checked every {} elements", DEADLINE_CHECK_INTERVAL);
+        let outcome = try_run_iterator_pass_pinned(&|n| n, &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, 4, None, Aggregation::Mean, Some(Duration::from_secs(60)));
+        assert!(outcome.is_ok(), "A pass within its timeout shouldn't be aborted");
+    }
+
+    /// assures [run_iterator_pass_pinned()] runs all the measuring threads on the requested core -- where affinity is supported
+    #[test]
+    #[serial]
+    #[cfg(all(target_os = "linux", feature = "core_pinning"))]
+    fn pinned_threads() {
+        let observed_cores = parking_lot::Mutex::new(Vec::new());
+        run_iterator_pass_pinned(&|e| { observed_cores.lock().push(core_affinity::get_core_ids()); e },
+                                 &BigOIteratorAlgorithmType::ConstantSet, 0..16, IterationOrder::Ascending, 4, Some(0));
+        let observed_cores = observed_cores.into_inner();
+        assert_eq!(observed_cores.len(), 16, "Wrong number of iterations");
+        assert!(observed_cores.iter().all(|cores| cores == &Some(vec![core_affinity::CoreId { id: 0 }])), "Threads were not pinned to core 0: {:?}", observed_cores);
+    }
+
+    /// assures [pin_current_thread()] restores the thread's original affinity once its [ThreadPinning] is dropped
+    #[test]
+    #[serial]
+    #[cfg(all(target_os = "linux", feature = "core_pinning"))]
+    fn pinning_is_undone() {
+        let original_cores = core_affinity::get_core_ids();
+        let pinning = pin_current_thread(Some(0));
+        assert_eq!(core_affinity::get_core_ids(), Some(vec![core_affinity::CoreId { id: 0 }]), "The thread should have been pinned to core 0");
+        drop(pinning);
+        assert_eq!(core_affinity::get_core_ids(), original_cores, "The thread's original affinity should have been restored");
+    }
+
+    /// assures [run_iterator_pass_pinned_to_cores()] pins each chunk's thread to the core at the chunk's position -- where affinity is supported
+    #[test]
+    #[serial]
+    #[cfg(all(target_os = "linux", feature = "core_pinning"))]
+    fn threads_pinned_to_cores() {
+        let available_cores = core_affinity::get_core_ids().expect("Couldn't list the available cores");
+        let core_ids = (0..4).map(|n| available_cores[n % available_cores.len()].id).collect::<Vec<_>>();
+        let observed_cores = parking_lot::Mutex::new(Vec::new());
+        run_iterator_pass_pinned_to_cores(&|e| { observed_cores.lock().push((e, core_affinity::get_core_ids())); e },
+                                          &BigOIteratorAlgorithmType::ConstantSet, 0..16, IterationOrder::Ascending, &core_ids);
+        let observed_cores = observed_cores.into_inner();
+        assert_eq!(observed_cores.len(), 16, "Wrong number of iterations");
+        for (e, cores) in observed_cores {
+            assert_eq!(cores, Some(vec![core_affinity::CoreId { id: core_ids[e as usize / 4] }]), "Element {}'s chunk ran on the wrong core", e);
+        }
+        let processed = parking_lot::Mutex::new(Vec::new());
+        run_iterator_pass_pinned_to_cores(&|e| { processed.lock().push(e); e }, &BigOIteratorAlgorithmType::ConstantSet, 0..16, IterationOrder::Ascending, &[]);
+        assert_eq!(processed.into_inner(), (0..16).collect::<Vec<_>>(), "Without cores, all elements should still be processed -- by a single thread");
+    }
+
+    /// assures the whole pass -> analysis -> report pipeline is deterministic when the passes' times are scripted -- see [run_sync_pass_with_backend()]
+    #[test]
+    fn scripted_pass_analysis() {
+        let backend = ScriptedTimingBackend::new(&[Duration::from_millis(10), Duration::from_millis(20)]);
+        let (pass1_result, _r) = run_sync_pass_with_backend(|| 1, &backend);
+        let (pass2_result, _r) = run_sync_pass_with_backend(|| 2, &backend);
+        assert_eq!((pass1_result.time_measurements, pass2_result.time_measurements), (Duration::from_millis(10), Duration::from_millis(20)),
+                   "The scripted times should have been reported");
+        let measurements = AlgorithmMeasurements {
+            measurement_name: "scripted",
+            passes_info: AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 },
+            time_measurements: BigOTimeMeasurements { pass_1_measurements: pass1_result.time_measurements, pass_2_measurements: pass2_result.time_measurements },
+            space_measurements: BigOSpaceMeasurements { pass_1_measurements: pass1_result.space_measurements, pass_2_measurements: pass2_result.space_measurements },
+            pass1_measurements: BigOPassMeasurements { time_measurements: pass1_result.time_measurements, space_measurements: pass1_result.space_measurements, custom_measurements: vec![] },
+            pass2_measurements: BigOPassMeasurements { time_measurements: pass2_result.time_measurements, space_measurements: pass2_result.space_measurements, custom_measurements: vec![] },
+        };
+        let time_complexity = crate::low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
+        assert_eq!(time_complexity, BigOAlgorithmComplexity::ON, "Doubling `n` & the time should be linear");
+        let report = measurements.to_string();
+        assert!(report.contains("10ms") && report.contains("20ms") && report.contains("10.000µs"), "The report should show the scripted times: {}", report);
+    }
+
+    /// assures [run_iterator_pass_with_backend()] reports the average of the times measured, by the given backend, on each thread
+    #[test]
+    #[serial]
+    fn mocked_timing_backend() {
+        let backend = MockTimingBackend { elapsed: Duration::from_millis(42) };
+        for threads in [1, 2, 4] {
+            let (pass_result, _r) = run_iterator_pass_with_backend(&|n| n, &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, threads, &[], &backend);
+            let drift = pass_result.time_measurements.abs_diff(backend.elapsed);
+            assert!(drift < Duration::from_micros(1), "With {} threads, {:?} was measured instead of {:?}", threads, pass_result.time_measurements, backend.elapsed);
+        }
+    }
+
+    /// assures [run_iterator_pass_no_alloc()] runs every element, without measuring any space -- even if the algorithm allocates
+    #[test]
+    #[serial]
+    fn no_alloc_pass() {
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let allocating = |e: u32| {
+            calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            vec![e; 16].len() as u32
+        };
+        let (pass_result, _r) = run_iterator_pass_no_alloc(&allocating, 0..1000, IterationOrder::Descending);
+        assert_eq!(calls.into_inner(), 1000, "Every element in the range should have been processed");
+        assert!(pass_result.time_measurements > Duration::ZERO, "The pass should have been timed");
+        assert_eq!(pass_result.space_measurements.max_used_memory, 0, "The allocator should not have been consulted");
+    }
+
+    /// assures [run_iterator_pass_with_timeline()] samples the elapsed times along the pass -- spotting algorithms getting slower
+    #[test]
+    #[serial]
+    fn timeline() {
+        // each element takes longer than the previous one
+        let spin = |e: u32| {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_nanos(e as u64 * 100) {}
+            e
+        };
+        let (pass_result, timeline, _r) = run_iterator_pass_with_timeline(100, &spin, 0..1050, IterationOrder::Ascending);
+        assert_eq!(timeline.len(), 10, "A sample should be taken every 100 iterations -- and none for the last, incomplete, interval");
+        assert!(timeline.windows(2).all(|elapsed| elapsed[0] <= elapsed[1]), "Elapsed times should accumulate: {:?}", timeline);
+        assert!(*timeline.last().unwrap() <= pass_result.time_measurements.as_nanos() as u64, "The last sample can't be after the end of the pass");
+
+        // the trend detection is checked on synthetic timelines, as preemptions would make the measured intervals bounce
+        assert!(degraded_within_pass(&[100, 300, 600, 1000]), "Growing intervals should have been spotted as a degradation");
+        assert!(!degraded_within_pass(&[100, 200, 300, 400]), "Constant intervals are not a degradation");
+        assert!(!degraded_within_pass(&[100, 300, 350, 600]), "A faster interval breaks the monotonic increase");
+        assert!(!degraded_within_pass(&[100, 300]), "Too few intervals to tell a trend");
+        assert!(!degraded_within_pass(&[]), "No samples, no degradation");
+    }
+
+    /// assures the times of the threads are combined with the requested [Aggregation] -- so a single slow thread may be ignored
+    #[test]
+    #[serial]
+    fn thread_times_aggregation() {
+        let thread_times = [10, 12, 11, 100].map(Duration::from_millis);
+        for (aggregation, expected_time) in [(Aggregation::Mean,              Duration::from_micros(33_250)),
+                                             (Aggregation::Median,            Duration::from_micros(11_500)),
+                                             (Aggregation::TrimmedMean(0.25), Duration::from_micros(11_500))] {
+            let backend = ScriptedTimingBackend::new(&thread_times);
+            let (pass_result, _r) = try_run_iterator_pass_with_backend(&|n| n, &BigOIteratorAlgorithmType::ConstantSet, 0..1000, IterationOrder::Ascending, 4, &[], aggregation, None, &backend)
+                .expect("The pass shouldn't have been aborted");
+            let drift = pass_result.time_measurements.abs_diff(expected_time);
+            assert!(drift < Duration::from_micros(1), "{:?} measured {:?} instead of {:?}", aggregation, pass_result.time_measurements, expected_time);
+            assert_eq!(pass_result.summed_threads_time, Duration::from_millis(133), "The summed threads' time should not be aggregated");
+        }
+    }
+}
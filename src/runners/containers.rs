@@ -0,0 +1,445 @@
+//! Ready-made CRUD harnesses for containers, sparing the boilerplate of wrapping them in a lock and writing
+//! the Create, Read, Update & Delete closures for [super::crud::test_crud_algorithms()].\
+//! Any container may be analysed by implementing either [VecLike] or [MapLike] for it -- implementations are
+//! provided for `Vec`, `VecDeque`, `HashMap` & `BTreeMap`.\
+//! Alternatively, containers may implement [Analyzable], telling how each CRUD operation is done on them -- implementations are
+//! provided for `Vec` & `HashMap`, as examples.
+//!
+//! Example:
+//! ```no_run
+//! use big_o_test::{BigOAlgorithmComplexity, runners::containers::analyse_vec_like};
+//! analyse_vec_like("Vec Push & Pop", 15, Vec::<u32>::new(), |n| n,
+//!                  BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+//!                  BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+//!                  BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+//!                  BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+//!                  25, 250_000, 1);
+//! ```
+
+use crate::{
+    low_level_analysis::types::{BigOAlgorithmComplexity, ComplexityBoundPair},
+    runners::crud::test_crud_algorithms,
+};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+    hint::black_box,
+    sync::RwLock,
+};
+
+
+/// Sequential containers that may be analysed with [analyse_vec_like()]: elements are created & deleted at the end,
+/// read & updated by their index
+pub trait VecLike<T> {
+    fn push(&mut self, element: T);
+    fn get(&self, index: usize) -> Option<&T>;
+    fn set(&mut self, index: usize, element: T);
+    fn pop(&mut self) -> Option<T>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// empties the container, also releasing its memory -- otherwise, retries would see a better than O(1) space complexity
+    fn reset(&mut self);
+}
+
+/// Associative containers that may be analysed with [analyse_map_like()]
+pub trait MapLike<K, V> {
+    fn insert(&mut self, key: K, value: V);
+    fn get(&self, key: &K) -> Option<&V>;
+    fn remove(&mut self, key: &K) -> Option<V>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// empties the container, also releasing its memory -- otherwise, retries would see a better than O(1) space complexity
+    fn reset(&mut self);
+}
+
+/// Containers that may be analysed with [test_crud_algorithms_for()], telling how each CRUD operation is done over the element `n`
+pub trait Analyzable {
+    /// what elements are addressed by -- indexes for sequential containers, keys for associative ones
+    type Key;
+    fn create(&mut self, n: u32);
+    /// reads the element `n`, returning a value dependent on the read -- to avoid any 'call removal optimizations'
+    fn read(&self, n: u32) -> u32;
+    fn update(&mut self, n: u32);
+    fn delete(&mut self, n: u32);
+    /// empties the container, also releasing its memory -- otherwise, retries would see a better than O(1) space complexity
+    fn reset(&mut self);
+}
+
+impl<T> VecLike<T> for Vec<T> {
+    fn push(&mut self, element: T) {
+        Vec::push(self, element)
+    }
+    fn get(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+    fn set(&mut self, index: usize, element: T) {
+        self[index] = element;
+    }
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+    fn reset(&mut self) {
+        self.clear();
+        self.shrink_to_fit();
+    }
+}
+
+impl<T> VecLike<T> for VecDeque<T> {
+    fn push(&mut self, element: T) {
+        self.push_back(element)
+    }
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+    fn set(&mut self, index: usize, element: T) {
+        self[index] = element;
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+    fn reset(&mut self) {
+        self.clear();
+        self.shrink_to_fit();
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapLike<K, V> for HashMap<K, V, S> {
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+    fn reset(&mut self) {
+        self.clear();
+        self.shrink_to_fit();
+    }
+}
+
+impl<K: Ord, V> MapLike<K, V> for BTreeMap<K, V> {
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+    fn reset(&mut self) {
+        // nodes are freed as they are removed
+        self.clear();
+    }
+}
+
+/// Elements are pushed & popped at the end, being read & updated by their index -- modulo the length
+impl<T: From<u32>> Analyzable for Vec<T> {
+    type Key = usize;
+    fn create(&mut self, n: u32) {
+        self.push(T::from(n));
+    }
+    fn read(&self, n: u32) -> u32 {
+        <[T]>::get(self, n as usize % self.len().max(1)).is_some() as u32
+    }
+    fn update(&mut self, n: u32) {
+        if !self.is_empty() {
+            let index = n as usize % self.len();
+            self[index] = T::from(n);
+        }
+    }
+    fn delete(&mut self, _n: u32) {
+        self.pop();
+    }
+    fn reset(&mut self) {
+        VecLike::reset(self)
+    }
+}
+
+/// Elements are keyed by `n`: updates replace the value of an existing key
+impl<K: From<u32> + Eq + Hash, V: From<u32>, S: BuildHasher> Analyzable for HashMap<K, V, S> {
+    type Key = K;
+    fn create(&mut self, n: u32) {
+        self.insert(K::from(n), V::from(n));
+    }
+    fn read(&self, n: u32) -> u32 {
+        HashMap::get(self, &K::from(n)).is_some() as u32
+    }
+    fn update(&mut self, n: u32) {
+        self.insert(K::from(n), V::from(n.wrapping_add(1)));
+    }
+    fn delete(&mut self, n: u32) {
+        HashMap::remove(self, &K::from(n));
+    }
+    fn reset(&mut self) {
+        MapLike::reset(self)
+    }
+}
+
+
+/// Runs [test_crud_algorithms()] on the given [Analyzable] `container` -- shared among threads through a `RwLock`, locked for
+/// reading on reads and for writing on the other operations.\
+/// The container is emptied with [Analyzable::reset()] whenever the CRUD runner resets.
+/// The same `iterations_per_pass` & `threads` are used for all operations.
+#[allow(clippy::too_many_arguments)]
+pub fn test_crud_algorithms_for<Container: Analyzable + Send + Sync>
+                               (crud_name: &str, max_retry_attempts: u32,
+                                container: Container,
+                                expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                                expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                                expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                                expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                                warmup_percentage: u32, iterations_per_pass: u32, threads: u32) {
+
+    let container = RwLock::new(container);
+    test_crud_algorithms(crud_name, max_retry_attempts,
+        |n| {
+            container.write().unwrap().reset();
+            n
+        },
+        |n| {
+            container.write().unwrap().create(n);
+            n
+        }, expected_create_time_complexity, expected_create_space_complexity,
+        |n| container.read().unwrap().read(n),
+        expected_read_time_complexity, expected_read_space_complexity,
+        |n| {
+            container.write().unwrap().update(n);
+            n
+        }, expected_update_time_complexity, expected_update_space_complexity,
+        |n| {
+            container.write().unwrap().delete(n);
+            n
+        }, expected_delete_time_complexity, expected_delete_space_complexity,
+        warmup_percentage, iterations_per_pass, iterations_per_pass, iterations_per_pass, iterations_per_pass,
+        threads, threads, threads, threads);
+}
+
+/// Runs [test_crud_algorithms()] on the given `container` -- shared among threads through a `RwLock` -- where:
+///   - Create pushes `element_fn(n)`;
+///   - Read gets the element at `n` (modulo the container's length);
+///   - Update sets the element at `n` (modulo the container's length) to `element_fn(n)`;
+///   - Delete pops an element.
+///
+/// The container is emptied with [VecLike::reset()] whenever the CRUD runner resets.
+/// The same `iterations_per_pass` & `threads` are used for all operations.
+#[allow(clippy::too_many_arguments)]
+pub fn analyse_vec_like<T:         Send + Sync,
+                        Container: VecLike<T> + Send + Sync>
+                       (crud_name: &str, max_retry_attempts: u32,
+                        container: Container,
+                        element_fn: impl Fn(u32) -> T + Sync,
+                        expected_create_time_complexity: BigOAlgorithmComplexity, expected_create_space_complexity: BigOAlgorithmComplexity,
+                        expected_read_time_complexity:   BigOAlgorithmComplexity, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                        expected_update_time_complexity: BigOAlgorithmComplexity, expected_update_space_complexity: BigOAlgorithmComplexity,
+                        expected_delete_time_complexity: BigOAlgorithmComplexity, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                        warmup_percentage: u32, iterations_per_pass: u32, threads: u32) {
+
+    let container = RwLock::new(container);
+    test_crud_algorithms(crud_name, max_retry_attempts,
+        |_n| {
+            let mut container = container.write().unwrap();
+            container.reset();
+            container.len() as u32
+        },
+        |n| {
+            let element = element_fn(n);
+            let mut container = container.write().unwrap();
+            container.push(element);
+            container.len() as u32
+        }, expected_create_time_complexity, expected_create_space_complexity,
+        |n| {
+            let container = container.read().unwrap();
+            let len = container.len();
+            black_box(container.get(n as usize % len.max(1)));
+            len as u32
+        }, expected_read_time_complexity, expected_read_space_complexity,
+        |n| {
+            let element = element_fn(n);
+            let mut container = container.write().unwrap();
+            let len = container.len();
+            if len > 0 {
+                container.set(n as usize % len, element);
+            }
+            len as u32
+        }, expected_update_time_complexity, expected_update_space_complexity,
+        |_n| {
+            let mut container = container.write().unwrap();
+            black_box(container.pop());
+            container.len() as u32
+        }, expected_delete_time_complexity, expected_delete_space_complexity,
+        warmup_percentage, iterations_per_pass, iterations_per_pass, iterations_per_pass, iterations_per_pass,
+        threads, threads, threads, threads);
+}
+
+/// Runs [test_crud_algorithms()] on the given `container` -- shared among threads through a `RwLock` -- where:
+///   - Create inserts `value_fn(n)` at `key_fn(n)`;
+///   - Read gets the value at `key_fn(n)`;
+///   - Update inserts `value_fn(n+1)` at the existing `key_fn(n)`;
+///   - Delete removes `key_fn(n)`.
+///
+/// Keys & values are built outside the lock. The container is emptied with [MapLike::reset()] whenever the CRUD runner resets.
+/// The same `iterations_per_pass` & `threads` are used for all operations.
+#[allow(clippy::too_many_arguments)]
+pub fn analyse_map_like<K:         Send + Sync,
+                        V:         Send + Sync,
+                        Container: MapLike<K, V> + Send + Sync>
+                       (crud_name: &str, max_retry_attempts: u32,
+                        container: Container,
+                        key_fn:   impl Fn(u32) -> K + Sync,
+                        value_fn: impl Fn(u32) -> V + Sync,
+                        expected_create_time_complexity: BigOAlgorithmComplexity, expected_create_space_complexity: BigOAlgorithmComplexity,
+                        expected_read_time_complexity:   BigOAlgorithmComplexity, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                        expected_update_time_complexity: BigOAlgorithmComplexity, expected_update_space_complexity: BigOAlgorithmComplexity,
+                        expected_delete_time_complexity: BigOAlgorithmComplexity, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                        warmup_percentage: u32, iterations_per_pass: u32, threads: u32) {
+
+    let container = RwLock::new(container);
+    test_crud_algorithms(crud_name, max_retry_attempts,
+        |_n| {
+            let mut container = container.write().unwrap();
+            container.reset();
+            container.len() as u32
+        },
+        |n| {
+            let (key, value) = (key_fn(n), value_fn(n));
+            let mut container = container.write().unwrap();
+            container.insert(key, value);
+            container.len() as u32
+        }, expected_create_time_complexity, expected_create_space_complexity,
+        |n| {
+            let key = key_fn(n);
+            let container = container.read().unwrap();
+            black_box(container.get(&key));
+            container.len() as u32
+        }, expected_read_time_complexity, expected_read_space_complexity,
+        |n| {
+            let (key, value) = (key_fn(n), value_fn(n+1));
+            let mut container = container.write().unwrap();
+            container.insert(key, value);
+            container.len() as u32
+        }, expected_update_time_complexity, expected_update_space_complexity,
+        |n| {
+            let key = key_fn(n);
+            let mut container = container.write().unwrap();
+            black_box(container.remove(&key));
+            container.len() as u32
+        }, expected_delete_time_complexity, expected_delete_space_complexity,
+        warmup_percentage, iterations_per_pass, iterations_per_pass, iterations_per_pass, iterations_per_pass,
+        threads, threads, threads, threads);
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [containers](super) module
+
+    use super::*;
+    use serial_test::serial;
+
+
+    /// assures the [VecLike] & [MapLike] implementations behave as their containers
+    #[test]
+    #[serial]
+    fn container_implementations() {
+        fn exercise_vec_like(mut container: impl VecLike<u32>) {
+            container.push(1);
+            container.push(2);
+            container.set(0, 10);
+            assert_eq!(container.get(0), Some(&10), "set() didn't update the element");
+            assert_eq!(container.pop(), Some(2), "pop() should take the last element");
+            assert_eq!(container.len(), 1, "Wrong length");
+            container.reset();
+            assert!(container.is_empty(), "reset() should empty the container");
+        }
+        fn exercise_map_like(mut container: impl MapLike<u32, String>) {
+            container.insert(1, "one".to_string());
+            container.insert(1, "uno".to_string());
+            assert_eq!(container.get(&1).map(String::as_str), Some("uno"), "insert() didn't update the value");
+            assert_eq!(container.remove(&1).as_deref(), Some("uno"), "remove() should return the value");
+            assert_eq!(container.remove(&1), None, "The key should be gone");
+            container.insert(2, "two".to_string());
+            container.reset();
+            assert!(container.is_empty(), "reset() should empty the container");
+        }
+        exercise_vec_like(Vec::new());
+        exercise_vec_like(VecDeque::new());
+        exercise_map_like(HashMap::new());
+        exercise_map_like(BTreeMap::new());
+    }
+
+    /// assures the [Analyzable] implementations perform each CRUD operation on the element `n`
+    #[test]
+    #[serial]
+    fn analyzable_implementations() {
+        let mut vec = Vec::<u32>::new();
+        (0..3).for_each(|n| vec.create(n));
+        vec.update(4);
+        assert_eq!(vec, vec![0, 4, 2], "Updates should be done on the element `n` -- modulo the length");
+        assert_eq!(vec.read(7), 1, "Reads should find the element `n` -- modulo the length");
+        vec.delete(2);
+        assert_eq!(vec, vec![0, 4], "Deletes should pop the last element");
+        Analyzable::reset(&mut vec);
+        assert!(vec.is_empty() && vec.capacity() == 0, "reset() should empty the container, releasing its memory");
+
+        let mut map = HashMap::<u32, u64>::new();
+        (0..3).for_each(|n| map.create(n));
+        map.update(1);
+        assert_eq!(map.get(&1), Some(&2), "Updates should replace the value of the key `n`");
+        assert_eq!((map.read(2), map.read(3)), (1, 0), "Reads should only find the created keys");
+        map.delete(0);
+        assert_eq!(map.len(), 2, "Deletes should remove the key `n`");
+        Analyzable::reset(&mut map);
+        assert!(map.is_empty(), "reset() should empty the container");
+    }
+
+    /// attests the trait methods are correctly wired by [test_crud_algorithms_for()] -- bounds are loose, as only the wiring is checked here
+    #[test]
+    #[serial]
+    fn analyzable_crud() {
+        test_crud_algorithms_for("HashMap<u32, u32> through Analyzable", 15, HashMap::<u32, u32>::new(),
+                                 BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON,
+                                 BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON,
+                                 BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON,
+                                 BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON,
+                                 10, 20_000, 1);
+    }
+
+    /// attests the CRUD closures are correctly wired for [analyse_vec_like()] & [analyse_map_like()]
+    /// -- the `BTreeMap` time bounds are loose, as `O(log(n))` is hard to tell apart from its neighbours on small sets
+    #[test]
+    #[serial]
+    fn best_case_crud() {
+        analyse_vec_like("Vec Push & Pop (best case)", 15, Vec::<u32>::new(), |n| n,
+                         BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+                         25, 100_000, 1);
+        analyse_map_like("BTreeMap<u32, u32>", 15, BTreeMap::<u32, u32>::new(), |n| n, |n| n,
+                         BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+                         BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+                         0, 20_000, 1);
+    }
+}
@@ -0,0 +1,216 @@
+//! Bridges this crate with `criterion`, enabled by the `criterion` feature: criterion handles the per-benchmark timing &
+//! reporting, while we handle the asymptotic analysis.\
+//! [CriterionBridgeRunner] drives criterion's measurement loop -- through `Bencher::iter_custom()` -- keeping the times it
+//! reports, so the same runs feed both the criterion reports and the complexity analysis. Existing benchmarks may, instead,
+//! be analysed from the estimates criterion saved for them -- see [from_criterion_measurement()].
+
+use crate::{
+    features::OUTPUT,
+    low_level_analysis::{
+        self,
+        types::{BigOAlgorithmComplexity, BigOAlgorithmAnalysis, BigOAlgorithmMeasurements, AlgorithmMeasurements, ConstantSetIteratorAlgorithmMeasurements,
+                SetResizingIteratorAlgorithmMeasurements, AlgorithmPassesInfo, BigOTimeMeasurements, BigOSpaceMeasurements, BigOPassMeasurements},
+    },
+    runners::{
+        common::run_sync_pass,
+        record::{ComplexityAssertion, ComplexityAssertionRecord},
+    },
+};
+use std::{
+    cell::Cell,
+    fs,
+    hint::black_box,
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+use criterion::{measurement::WallTime, BenchmarkGroup, BenchmarkId, Throughput};
+
+
+/// Tells how many elements a measurement's (second) pass processed -- see [into_criterion_throughput()]
+pub trait PassElements {
+    fn pass_2_elements(&self) -> u64;
+}
+impl PassElements for AlgorithmMeasurements<'_> {
+    fn pass_2_elements(&self) -> u64 {
+        self.passes_info.pass2_n
+    }
+}
+impl PassElements for ConstantSetIteratorAlgorithmMeasurements<'_> {
+    fn pass_2_elements(&self) -> u64 {
+        self.passes_info.repetitions
+    }
+}
+impl PassElements for SetResizingIteratorAlgorithmMeasurements<'_> {
+    fn pass_2_elements(&self) -> u64 {
+        self.passes_info.delta_set_size
+    }
+}
+
+/// The criterion [Throughput] of the `analysis`' second pass -- the number of elements it processed
+pub fn into_criterion_throughput<T: BigOAlgorithmMeasurements + PassElements>(analysis: &BigOAlgorithmAnalysis<T>) -> Throughput {
+    Throughput::Elements(analysis.algorithm_measurements.pass_2_elements())
+}
+
+
+/// Builds the analysis of the criterion benchmark `function_name`, from `group_name`, run for 2 values of `n` -- as
+/// `BenchmarkId::new(function_name, pass1_n)` & `BenchmarkId::new(function_name, pass2_n)` -- out of the mean times criterion
+/// saved into `criterion_dir` (`target/criterion`, unless configured otherwise): so existing benchmarks may have their time
+/// complexity analysed without being run again.\
+/// Criterion doesn't expose the names held by its [BenchmarkId]s & [BenchmarkGroup]s, so they are given as strings -- and neither
+/// does it measure allocations: the space is not analysed, being reported as `O(1)`. See [CriterionBridgeRunner] to measure both.\
+/// The observed time complexity is reported to the [ComplexityAssertionRecord::global()] -- without expectations, it always passes.
+pub fn from_criterion_measurement<'a>(criterion_dir: &Path, group_name: &str, function_name: &'a str, pass1_n: u32, pass2_n: u32)
+                                     -> io::Result<BigOAlgorithmAnalysis<AlgorithmMeasurements<'a>>> {
+    let pass_measurements = |n: u32| -> io::Result<BigOPassMeasurements> {
+        let estimates_file = criterion_dir.join(filename_safe(group_name)).join(filename_safe(function_name)).join(n.to_string()).join("new").join("estimates.json");
+        let estimates: serde_json::Value = serde_json::from_str(&fs::read_to_string(&estimates_file)?)?;
+        let mean_nanos = estimates["mean"]["point_estimate"].as_f64()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("No mean estimate found in '{}'", estimates_file.display())))?;
+        Ok(BigOPassMeasurements {
+            time_measurements:   Duration::from_secs_f64(mean_nanos / 1e9),
+            space_measurements:  Default::default(),
+            custom_measurements: vec![],
+        })
+    };
+    let analysis = analyse(function_name, pass1_n, pass_measurements(pass1_n)?, pass2_n, pass_measurements(pass2_n)?);
+    ComplexityAssertionRecord::global().record(ComplexityAssertion::new(function_name, BigOAlgorithmComplexity::WorseThanExponential, analysis.time_complexity,
+                                                                        BigOAlgorithmComplexity::WorseThanExponential, None::<BigOAlgorithmComplexity>));
+    Ok(analysis)
+}
+
+/// the name criterion gives to the directory of a group, function or parameter named `name` -- for the names within its length limit
+fn filename_safe(name: &str) -> String {
+    name.replace(['?', '"', '/', '\\', '*', '<', '>', ':', '|', '^'], "_")
+}
+
+/// analyses the time & space complexities of `test_name` -- given the mean measurements of a single call for `pass1_n` & `pass2_n`
+fn analyse(test_name: &str, pass1_n: u32, pass1: BigOPassMeasurements, pass2_n: u32, pass2: BigOPassMeasurements) -> BigOAlgorithmAnalysis<AlgorithmMeasurements<'_>> {
+    let measurements = AlgorithmMeasurements {
+        measurement_name: test_name,
+        passes_info: AlgorithmPassesInfo {
+            pass1_n: u64::from(pass1_n),
+            pass2_n: u64::from(pass2_n),
+        },
+        time_measurements: BigOTimeMeasurements {
+            pass_1_measurements: pass1.time_measurements,
+            pass_2_measurements: pass2.time_measurements,
+        },
+        space_measurements: BigOSpaceMeasurements {
+            pass_1_measurements: pass1.space_measurements,
+            pass_2_measurements: pass2.space_measurements,
+        },
+        pass1_measurements: pass1,
+        pass2_measurements: pass2,
+    };
+    let time_complexity  = low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
+    let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity(&measurements.passes_info, &measurements.space_measurements);
+    BigOAlgorithmAnalysis::new(time_complexity, space_complexity, measurements)
+}
+
+
+/// Benchmarks a regular (non-iterator) algorithm with criterion, for 2 values of `n`, and analyses its time & space complexities
+/// -- see [Self::run()]:
+/// ```nocompile
+///     let mut criterion = Criterion::default();
+///     let mut group = criterion.benchmark_group("sorting");
+///     let analysis = CriterionBridgeRunner::new("sort", 1<<16, 1<<17)
+///         .run(&mut group, |n| { let mut v = (0..n).rev().collect::<Vec<_>>(); v.sort(); v[0] });
+///     group.finish();
+///     assert!(analysis.time_complexity.is_at_most(BigOAlgorithmComplexity::ONLogN));
+/// ```
+pub struct CriterionBridgeRunner<'a> {
+    /// the criterion benchmark function name -- also used as the analysis' measurement name
+    test_name: &'a str,
+    /// `n` for the first pass
+    pass1_n:   u32,
+    /// `n` for the second pass (usually the double of the first)
+    pass2_n:   u32,
+}
+impl<'a> CriterionBridgeRunner<'a> {
+
+    pub fn new(test_name: &'a str, pass1_n: u32, pass2_n: u32) -> Self {
+        Self { test_name, pass1_n, pass2_n }
+    }
+
+    /// Benchmarks `algorithm(n)` in the given criterion `group` -- as `test_name/pass1_n` & `test_name/pass2_n` -- analysing
+    /// the complexity from the mean time criterion measured for each call.\
+    /// Space is measured in an additional, untimed, call for each `n` -- done before criterion's warmup.\
+    /// The observed complexities are reported to the [ComplexityAssertionRecord::global()] -- without expectations, they always pass.
+    pub fn run(&self, group: &mut BenchmarkGroup<'_, WallTime>, algorithm: impl Fn(u32) -> u32) -> BigOAlgorithmAnalysis<AlgorithmMeasurements<'a>> {
+        let pass1 = self.run_pass(group, &algorithm, self.pass1_n);
+        let pass2 = self.run_pass(group, &algorithm, self.pass2_n);
+        let analysis = analyse(self.test_name, self.pass1_n, pass1, self.pass2_n, pass2);
+        OUTPUT(&format!("'{}' (through criterion): {}\n", self.test_name, analysis));
+        ComplexityAssertionRecord::global().record(ComplexityAssertion::new(self.test_name, BigOAlgorithmComplexity::WorseThanExponential, analysis.time_complexity,
+                                                                            BigOAlgorithmComplexity::WorseThanExponential, analysis.space_complexity));
+        analysis
+    }
+
+    /// measures the space for `algorithm(n)`, then has criterion time it -- returning the mean time of a single call
+    fn run_pass(&self, group: &mut BenchmarkGroup<'_, WallTime>, algorithm: &impl Fn(u32) -> u32, n: u32) -> BigOPassMeasurements {
+        let (space_pass_result, _r) = run_sync_pass(|| algorithm(n));
+        let elapsed    = Cell::new(Duration::ZERO);
+        let iterations = Cell::new(0u64);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new(self.test_name, n), &n, |bencher, &n| bencher.iter_custom(|iters| {
+            let start = Instant::now();
+            for _ in 0..iters {
+                black_box(algorithm(black_box(n)));
+            }
+            let duration = start.elapsed();
+            elapsed.set(elapsed.get() + duration);
+            iterations.set(iterations.get() + iters);
+            duration
+        }));
+        BigOPassMeasurements {
+            time_measurements:   Duration::from_secs_f64(elapsed.get().as_secs_f64() / iterations.get().max(1) as f64),
+            space_measurements:  space_pass_result.space_measurements,
+            custom_measurements: vec![],
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [criterion_bridge](super) module
+
+    use super::*;
+    use crate::BigOAlgorithmComplexity;
+    use criterion::Criterion;
+    use serial_test::serial;
+
+
+    /// attests the bridge yields both the criterion benchmarks and a sound analysis -- for an `O(n)` time & space algorithm
+    #[test]
+    #[serial]
+    fn bridged_analysis() {
+        let criterion_dir = std::env::temp_dir().join(format!("big-o-test-criterion-bridge-{}", std::process::id()));
+        let mut criterion = Criterion::default()
+            .output_directory(&criterion_dir)
+            .sample_size(10)
+            .warm_up_time(Duration::from_millis(100))
+            .measurement_time(Duration::from_millis(500))
+            .without_plots();
+        let mut group = criterion.benchmark_group("criterion_bridge");
+        let analysis = CriterionBridgeRunner::new("sum", 1<<16, 1<<17)
+            .run(&mut group, |n| (0..n).collect::<Vec<u32>>().into_iter().fold(0u32, |sum, e| sum.wrapping_add(black_box(e))));
+        group.finish();
+        assert!(analysis.time_complexity.is_at_most(BigOAlgorithmComplexity::ON), "Collecting & summing `n` elements should be O(n) in time -- it was {:?}", analysis.time_complexity);
+        assert_eq!(analysis.space_complexity, BigOAlgorithmComplexity::ON, "Collecting `n` elements should be O(n) in space");
+        match into_criterion_throughput(&analysis) {
+            Throughput::Elements(elements) => assert_eq!(elements, 1<<17, "Wrong throughput"),
+            other => panic!("Unexpected throughput {:?}", other),
+        }
+
+        // the same benchmarks, analysed from the estimates criterion saved
+        let saved_analysis = from_criterion_measurement(&criterion_dir, "criterion_bridge", "sum", 1<<16, 1<<17)
+            .expect("The estimates saved by criterion should have been read");
+        let _ = fs::remove_dir_all(&criterion_dir);
+        assert!(saved_analysis.time_complexity.is_at_most(BigOAlgorithmComplexity::ON), "The saved estimates should also be O(n) in time -- they were {:?}", saved_analysis.time_complexity);
+        assert_eq!(saved_analysis.space_complexity, BigOAlgorithmComplexity::O1, "Criterion doesn't measure space -- so it should be reported as O(1)");
+        assert!(from_criterion_measurement(&criterion_dir, "criterion_bridge", "sum", 1<<16, 1<<17).is_err(), "Missing estimates should be reported as an error");
+    }
+}
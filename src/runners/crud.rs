@@ -3,28 +3,191 @@
 //! See [tests] and `tests/big-o-tests.rs` for examples.
 
 use crate::{
-    features::{OUTPUT},
+    features::{ALLOC, OUTPUT, PERCENT_TOLERANCE},
     low_level_analysis::{
         self,
-        types::{BigOIteratorAlgorithmType, ConstantSetIteratorAlgorithmMeasurements, SetResizingIteratorAlgorithmMeasurements,
-                BigOAlgorithmAnalysis, BigOTimeMeasurements, BigOSpaceMeasurements,
-                SetResizingIteratorAlgorithmPassesInfo, ConstantSetIteratorAlgorithmPassesInfo, BigOAlgorithmComplexity},
+        types::{BigOIteratorAlgorithmType, BigOAlgorithmMeasurements, ConstantSetIteratorAlgorithmMeasurements, SetResizingIteratorAlgorithmMeasurements,
+                BigOAlgorithmAnalysis, BigOTimeMeasurements, BigOSpaceMeasurements, MeasurementAnomaly,
+                SetResizingIteratorAlgorithmPassesInfo, ConstantSetIteratorAlgorithmPassesInfo, BigOAlgorithmComplexity, ComplexityBoundPair},
+    },
+    runners::{
+        aggregation::{Aggregation, FixedPointAggregation},
+        common::*,
+        output::{OutputConfig, SummaryScope},
+        record::{ComplexityAssertion, ComplexityAssertionRecord},
+        workload::Workload,
     },
-    runners::common::*,
 };
+#[cfg(feature = "baseline")]
+use crate::runners::baseline::ComplexityBaseline;
 use std::{
     ops::Range,
-    time::{Instant},
-    io::{self, Write},
+    time::{Duration, Instant},
     {error::Error, fmt},
     fmt::{Display, Formatter},
     collections::BTreeMap,
 };
 
 
+/// How many bytes `reset_fn` may leave allocated, regardless of the amount of memory used by the CRUD operations,
+/// before a retry is refused -- see [test_crud_algorithms()]
+const RESET_LEAK_SLACK_BYTES: usize = 4096;
+
+/// Identifies which CRUD operation is about to run when `reset_fn` is called -- see [test_crud_algorithms_v2()]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CrudOp {
+    Create,
+    Read,
+    Update,
+    Delete,
+    Warmup,
+}
+impl CrudOp {
+    /// the operation's name, as used in reports & [ComplexityAssertion::algorithm] -- kept apart from the `Debug` output, so
+    /// baselines keyed by it don't depend on the derived formatting
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Create => "Create",
+            Self::Read   => "Read",
+            Self::Update => "Update",
+            Self::Delete => "Delete",
+            Self::Warmup => "Warmup",
+        }
+    }
+    /// the name of this operation of the `crud_name` algorithms -- `"MyContainer: Read"` -- see [ComplexityAssertion::algorithm]
+    pub fn algorithm_name(self, crud_name: &str) -> String {
+        format!("{}: {}", crud_name, self.name())
+    }
+}
+
+/// The analysed operations, in the order their passes run -- indexes the per-operation arrays (see [crud_op_index()])
+pub(crate) const CRUD_OPS: [CrudOp; 4] = [CrudOp::Create, CrudOp::Read, CrudOp::Update, CrudOp::Delete];
+
+/// the position of `crud_op` in [CRUD_OPS]
+pub(crate) fn crud_op_index(crud_op: CrudOp) -> usize {
+    CRUD_OPS.iter().position(|&analysed_op| analysed_op == crud_op)
+        .unwrap_or_else(|| panic!("{:?} is not a CRUD operation that may be analysed", crud_op))
+}
+
+/// Opt-in behaviours for the CRUD analysis -- see [test_crud_algorithms_with_options()].\
+/// The defaults keep the analysis sequential & deterministic.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CrudOptions {
+    /// see [Self::with_parallel_operations()]
+    pub parallel_operations: bool,
+    /// see [Self::with_cpu_affinity()]
+    pub cpu_affinity:        Option<usize>,
+    /// see [Self::with_workload()]
+    pub workload:            Workload,
+    /// see [Self::with_aggregation()] -- kept without floats, so the options are `Eq`: read it through [Self::aggregation()]
+    pub(crate) aggregation:  FixedPointAggregation,
+    /// see [Self::with_max_time_coefficient()] -- indexed by [crud_op_index()]
+    pub max_time_coefficients: [Option<Duration>; 4],
+    /// see [Self::with_timeout()]
+    pub timeout:             Option<Duration>,
+    /// see [Self::with_sample_count()] -- 0 behaves as 1
+    pub sample_count:        u32,
+    /// see [Self::with_baseline()]
+    #[cfg(feature = "baseline")]
+    pub baseline:            Option<&'static ComplexityBaseline>,
+}
+impl CrudOptions {
+    /// If `parallel`, the read & update passes run concurrently -- on separate threads -- cutting the wall-clock time of the analysis.\
+    /// Only enable it if you certify `read_fn` & `update_fn` are independent of each other: neither changes the set size and they
+    /// may run at the same time, on the same elements. The create passes still run before them -- as they build the set the others
+    /// operate on -- and the delete passes still run after.\
+    /// Notice the allocator is process-wide, so the space measured for reads also accounts for the allocations of the concurrent
+    /// updates (and vice-versa): their expected space complexities should take that into account.
+    pub fn with_parallel_operations(mut self, parallel: bool) -> Self {
+        self.parallel_operations = parallel;
+        self
+    }
+    /// Pins every measuring thread to the CPU core `core_id` -- so the OS won't migrate them between cores with different
+    /// frequencies or cache states, making the time measurements bounce.\
+    /// Requires the platform to support thread affinity: silently does nothing where it is unavailable. Notice all the threads of
+    /// an operation share the same core, so pinning operations with several threads serializes them. Requires the `core_pinning` feature.
+    #[cfg(feature = "core_pinning")]
+    pub fn with_cpu_affinity(mut self, core_id: usize) -> Self {
+        self.cpu_affinity = Some(core_id);
+        self
+    }
+    /// Feeds `read_fn` & `update_fn` with the values of `workload` -- a sorted, reversed, shuffled or random sequence of the elements
+    /// of each pass -- rather than with the bare iteration indexes: the default, [Workload::Identity], keeps them sequential.\
+    /// Creates & deletes are unaffected, as the set they build or tear down must be known. Use a seeded workload for reproducibility.
+    pub fn with_workload(mut self, workload: Workload) -> Self {
+        self.workload = workload;
+        self
+    }
+    /// Combines the times measured by each thread of a pass with `aggregation` -- rather than with their mean, the default.\
+    /// [Aggregation::Median] & [Aggregation::TrimmedMean] keep a single thread hit by interference (or blocked on a lock) from
+    /// dragging the time of the whole pass. Only meaningful for operations running with several threads.
+    pub fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation.into();
+        self
+    }
+    /// The aggregation set by [Self::with_aggregation()] -- with the [Aggregation::TrimmedMean] fraction clamped to `0.0..=0.5`
+    /// and rounded to parts per million
+    pub fn aggregation(&self) -> Aggregation {
+        self.aggregation.into()
+    }
+    /// Fails the analysis of `crud_op` if its estimated time coefficient -- the time taken per unit of its complexity's model term:
+    /// per element, for `O(n)` -- exceeds `max_time_coefficient`, even if the complexity class matches: catching "still `O(n)`, but
+    /// 5x slower" regressions. Like time complexity mismatches, exceeding it is retried.\
+    /// Not checked if no coefficient could be estimated, as for the "between" complexities -- see [BigOAlgorithmComplexity::model_term()]
+    pub fn with_max_time_coefficient(mut self, crud_op: CrudOp, max_time_coefficient: Duration) -> Self {
+        self.max_time_coefficients[crud_op_index(crud_op)] = Some(max_time_coefficient);
+        self
+    }
+    /// Aborts the analysis if any of its passes -- warmups included -- runs for longer than `timeout`, reporting the operation
+    /// & how many of the pass' iterations were completed: so a pathologically slow (or degenerate) algorithm fails fast rather than
+    /// hanging the test suite. Like panics, timeouts are not retried.\
+    /// The timeout is best-effort, at the iteration granularity: the deadline is checked every [DEADLINE_CHECK_INTERVAL] iterations,
+    /// so a single iteration that never returns can't be interrupted.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Runs each read & update pass `sample_count` times, feeding the complexity analysis with the median sample -- rather than
+    /// with a single one -- so a pass hit by interference doesn't fail (and retry) the analysis: see [run_iterator_pass_multi_sample()].\
+    /// Creates & deletes are unaffected, as they can't be repeated without rebuilding the set -- and the read & update passes take
+    /// `sample_count` times longer to run.
+    pub fn with_sample_count(mut self, sample_count: u32) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+    /// Takes the expected complexities of each operation from `baseline` -- a `static`, usually lazily loaded, shared by the whole
+    /// test run: see [ComplexityBaseline] -- looked up as `"<crud_name>: <operation>"`, like `"MyContainer: Read"`. Complexities
+    /// missing from the baseline keep the ones given to the runner. Requires the `baseline` feature.
+    #[cfg(feature = "baseline")]
+    pub fn with_baseline(mut self, baseline: &'static ComplexityBaseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+    /// the expected complexities of the `crud_op` of `crud_name` -- taken from the [Self::with_baseline()], if it states them
+    fn expectations(&self, crud_name: &str, crud_op: CrudOp, expected_time_complexity: ComplexityBoundPair, expected_space_complexity: BigOAlgorithmComplexity)
+                   -> (ComplexityBoundPair, BigOAlgorithmComplexity) {
+        #[cfg(feature = "baseline")]
+        if let Some(baseline) = self.baseline {
+            let algorithm = crud_op.algorithm_name(crud_name);
+            return (baseline.time_complexity_or(&algorithm, expected_time_complexity), baseline.space_complexity_or(&algorithm, expected_space_complexity))
+        }
+        #[cfg(not(feature = "baseline"))]
+        let _ = (crud_name, crud_op);
+        (expected_time_complexity, expected_space_complexity)
+    }
+}
+
 /// Runs [analyze_crud_algorithms()], trying to match the given maximum time & space complexities to the ones observed in runtime when running the algorithm
 /// -- retrying as much as `max_retry_attempts` to avoid flaky test results.\
-/// In case of rejection, a detailed run log with measurements & analysis results is issued.
+/// In case of rejection, a detailed run log with measurements & analysis results is issued.\
+/// Time complexities accept either a maximum -- a [BigOAlgorithmComplexity] -- or a `(lower, upper)` tuple / [ComplexityBoundPair],
+/// also rejecting algorithms that perform better than expected.\
+/// Before each retry, `reset_fn` must free the memory allocated by the failed attempt -- or the retry is refused, as its SPACE
+/// analysis would be compromised. This requirement only applies if the space is analysed: time-only analyses -- having all the
+/// `expected_*_space_complexity` set to [BigOAlgorithmComplexity::WorseThanExponential] -- may warm up & retry with a `reset_fn`
+/// that keeps caches & allocations around.\
+/// See [test_crud_algorithms_v2()] if `reset_fn` should know which operation is about to run.
+#[allow(clippy::too_many_arguments)]
 pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                             CreateClosure: Fn(u32) -> u32 + Sync,
                             ReadClosure:   Fn(u32) -> u32 + Sync,
@@ -32,13 +195,124 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                             DeleteClosure: Fn(u32) -> u32 + Sync>
                            (crud_name: &str, max_retry_attempts: u32,
                             reset_fn:  ResetClosure,
-                            create_fn: CreateClosure, expected_create_time_complexity: BigOAlgorithmComplexity, expected_create_space_complexity: BigOAlgorithmComplexity,
-                            read_fn:   ReadClosure,   expected_read_time_complexity:   BigOAlgorithmComplexity, expected_read_space_complexity:   BigOAlgorithmComplexity,
-                            update_fn: UpdateClosure, expected_update_time_complexity: BigOAlgorithmComplexity, expected_update_space_complexity: BigOAlgorithmComplexity,
-                            delete_fn: DeleteClosure, expected_delete_time_complexity: BigOAlgorithmComplexity, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                            create_fn: CreateClosure, expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                            read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                            update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                            delete_fn: DeleteClosure, expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
                             warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
                             create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
                            where PassResult: Copy {
+    test_crud_algorithms_v2(crud_name, max_retry_attempts,
+                            |_crud_op, n| reset_fn(n),
+                            create_fn, expected_create_time_complexity, expected_create_space_complexity,
+                            read_fn,   expected_read_time_complexity,   expected_read_space_complexity,
+                            update_fn, expected_update_time_complexity, expected_update_space_complexity,
+                            delete_fn, expected_delete_time_complexity, expected_delete_space_complexity,
+                            warmup_percentage, create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass, delete_iterations_per_pass,
+                            create_threads, read_threads, update_threads, delete_threads)
+}
+
+/// Same as [test_crud_algorithms()], but `reset_fn(crud_op, n)` also receives the [CrudOp] about to run after the reset:
+/// the first analysed operation -- when resetting after the warmup pass -- or [CrudOp::Warmup] -- when resetting before a
+/// retry that will warm up again
+#[allow(clippy::too_many_arguments)]
+pub fn test_crud_algorithms_v2<ResetClosure:  Fn(CrudOp, u32) -> u32 + Sync,
+                               CreateClosure: Fn(u32) -> u32 + Sync,
+                               ReadClosure:   Fn(u32) -> u32 + Sync,
+                               UpdateClosure: Fn(u32) -> u32 + Sync,
+                               DeleteClosure: Fn(u32) -> u32 + Sync>
+                              (crud_name: &str, max_retry_attempts: u32,
+                               reset_fn:  ResetClosure,
+                               create_fn: CreateClosure, expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                               read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                               update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                               delete_fn: DeleteClosure, expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                               warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
+                               create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
+                              where PassResult: Copy {
+    test_crud_algorithms_with_options(crud_name, max_retry_attempts, CrudOptions::default(),
+                                      reset_fn,
+                                      create_fn, expected_create_time_complexity, expected_create_space_complexity,
+                                      read_fn,   expected_read_time_complexity,   expected_read_space_complexity,
+                                      update_fn, expected_update_time_complexity, expected_update_space_complexity,
+                                      delete_fn, expected_delete_time_complexity, expected_delete_space_complexity,
+                                      warmup_percentage, create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass, delete_iterations_per_pass,
+                                      create_threads, read_threads, update_threads, delete_threads)
+}
+
+/// Same as [test_crud_algorithms_v2()], but accepting [CrudOptions] -- like running the read & update passes in parallel
+#[allow(clippy::too_many_arguments)]
+pub fn test_crud_algorithms_with_options<ResetClosure:  Fn(CrudOp, u32) -> u32 + Sync,
+                                         CreateClosure: Fn(u32) -> u32 + Sync,
+                                         ReadClosure:   Fn(u32) -> u32 + Sync,
+                                         UpdateClosure: Fn(u32) -> u32 + Sync,
+                                         DeleteClosure: Fn(u32) -> u32 + Sync>
+                                        (crud_name: &str, max_retry_attempts: u32, options: CrudOptions,
+                                         reset_fn:  ResetClosure,
+                                         create_fn: CreateClosure, expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                                         read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                                         update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                                         delete_fn: DeleteClosure, expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                                         warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
+                                         create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
+                                        where PassResult: Copy {
+    retry_crud_analysis(crud_name, max_retry_attempts, options, None, |_set_size| {},
+                        reset_fn,
+                        create_fn, expected_create_time_complexity, expected_create_space_complexity,
+                        read_fn,   expected_read_time_complexity,   expected_read_space_complexity,
+                        update_fn, expected_update_time_complexity, expected_update_space_complexity,
+                        delete_fn, expected_delete_time_complexity, expected_delete_space_complexity,
+                        warmup_percentage, create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass, delete_iterations_per_pass,
+                        create_threads, read_threads, update_threads, delete_threads)
+}
+
+/// Analyses the Read & Update operations of an already populated container or database -- skipping the Create & Delete passes
+/// [test_crud_algorithms()] uses to build & tear down the set, which may be wasteful or not even possible.\
+/// The set sizes are given rather than built: `select_set_fn(set_size)` is called, untimed, before each pass -- with `pass_1_set_size`,
+/// then `pass_2_set_size` -- so the operations may be pointed to a set of that size (growing it or switching databases, for instance).
+/// Each pass runs `read_iterations_per_pass` reads & `update_iterations_per_pass` updates -- like in [test_crud_algorithms()], with
+/// the second pass' `n`s following the first pass' ones -- and an operation is skipped if its number of iterations is `0`.\
+/// Time mismatches are retried up to `max_retry_attempts`, while space mismatches fail right away.
+#[allow(clippy::too_many_arguments)]
+pub fn test_read_update_algorithms<ReadClosure:   Fn(u32) -> u32 + Sync,
+                                   UpdateClosure: Fn(u32) -> u32 + Sync>
+                                  (crud_name: &str, max_retry_attempts: u32,
+                                   select_set_fn: impl FnMut(u64),
+                                   read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                                   update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                                   pass_1_set_size: u64, pass_2_set_size: u64,
+                                   read_iterations_per_pass: u32, update_iterations_per_pass: u32,
+                                   read_threads: u32, update_threads: u32)
+                                  where PassResult: Copy {
+    let no_time_bounds = ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential);
+    retry_crud_analysis(crud_name, max_retry_attempts, CrudOptions::default(), Some((pass_1_set_size, pass_2_set_size)), select_set_fn,
+                        |_crud_op, _n| 0,
+                        |n| n,     no_time_bounds,                  BigOAlgorithmComplexity::WorseThanExponential,
+                        read_fn,   expected_read_time_complexity,   expected_read_space_complexity,
+                        update_fn, expected_update_time_complexity, expected_update_space_complexity,
+                        |n| n,     no_time_bounds,                  BigOAlgorithmComplexity::WorseThanExponential,
+                        0, 0, read_iterations_per_pass, update_iterations_per_pass, 0,
+                        1, read_threads, update_threads, 1)
+}
+
+/// Runs [internal_analyse_crud_algorithms()], retrying as much as `max_retry_attempts` on time mismatches --
+/// see [test_crud_algorithms_with_options()] & [test_read_update_algorithms()]
+#[allow(clippy::too_many_arguments)]
+fn retry_crud_analysis<ResetClosure:  Fn(CrudOp, u32) -> u32 + Sync,
+                       CreateClosure: Fn(u32) -> u32 + Sync,
+                       ReadClosure:   Fn(u32) -> u32 + Sync,
+                       UpdateClosure: Fn(u32) -> u32 + Sync,
+                       DeleteClosure: Fn(u32) -> u32 + Sync>
+                      (crud_name: &str, max_retry_attempts: u32, options: CrudOptions,
+                       constant_set_sizes: Option<(u64, u64)>, mut select_set_fn: impl FnMut(u64),
+                       reset_fn:  ResetClosure,
+                       create_fn: CreateClosure, expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                       read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                       update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                       delete_fn: DeleteClosure, expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                       warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
+                       create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
+                      where PassResult: Copy {
 
     // adapts the 'iterations_per_pass' to the 'attempt' number, so each retry uses slightly different values
     fn adapt(attempt: u32, iterations_per_pass: u32) -> u32 {
@@ -55,12 +329,32 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
 
     }
 
+    let runner_estimate = dry_run_estimate(create_iterations_per_pass, create_threads, warmup_percentage) +
+                          dry_run_estimate(read_iterations_per_pass,   read_threads,   warmup_percentage) +
+                          dry_run_estimate(update_iterations_per_pass, update_threads, warmup_percentage) +
+                          dry_run_estimate(delete_iterations_per_pass, delete_threads, warmup_percentage);
+    // in CI, the reports are only written if the analysis fails -- see [OutputConfig::ci_verbosity()]
+    let _summary = SummaryScope::begin(OutputConfig::ci_verbosity());
+    OUTPUT(&format!("'{}': the runner alone is estimated to take {:?} per attempt -- plus the time spent in the CRUD algorithms\n", crud_name, runner_estimate));
+
+    // the baseline, if any, has the final word on the expectations -- see [CrudOptions::with_baseline()]
+    let (expected_create_time_complexity, expected_create_space_complexity) = options.expectations(crud_name, CrudOp::Create, expected_create_time_complexity.into(), expected_create_space_complexity);
+    let   (expected_read_time_complexity,   expected_read_space_complexity) = options.expectations(crud_name, CrudOp::Read,     expected_read_time_complexity.into(),   expected_read_space_complexity);
+    let (expected_update_time_complexity, expected_update_space_complexity) = options.expectations(crud_name, CrudOp::Update, expected_update_time_complexity.into(), expected_update_space_complexity);
+    let (expected_delete_time_complexity, expected_delete_space_complexity) = options.expectations(crud_name, CrudOp::Delete, expected_delete_time_complexity.into(), expected_delete_space_complexity);
+
     let mut collected_errors = Vec::<CRUDComplexityAnalysisError>::with_capacity(max_retry_attempts as usize);
 
+    // `reset_fn` is only required to deallocate if the space is analysed -- see [test_crud_algorithms()]
+    let space_analysed = [expected_create_space_complexity, expected_read_space_complexity, expected_update_space_complexity, expected_delete_space_complexity].into_iter()
+        .any(|expected_space_complexity| expected_space_complexity != BigOAlgorithmComplexity::WorseThanExponential);
+
     // in order to reduce false-negatives, retry up to 'max_retry_attempts' if time complexity don't match
     // the maximum acceptable create, read, update or delete 'expected_*_time_complexity'(ies)
     for attempt in 0..max_retry_attempts {
 
+        let attempt_start_used_memory = ALLOC.save_point().metrics.current_used_memory;
+
         let adapted_create_iterations_per_pass = adapt(attempt, create_iterations_per_pass);
         let   adapted_read_iterations_per_pass = adapt(attempt, read_iterations_per_pass);
         let adapted_update_iterations_per_pass = adapt(attempt, update_iterations_per_pass);
@@ -72,25 +366,43 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                                                              &update_fn, expected_update_time_complexity, expected_update_space_complexity,
                                                              &delete_fn, expected_delete_time_complexity, expected_delete_space_complexity,
                                                              warmup_percentage, adapted_create_iterations_per_pass, adapted_read_iterations_per_pass, adapted_update_iterations_per_pass, adapted_delete_iterations_per_pass,
-                                                             create_threads, read_threads, update_threads, delete_threads);
+                                                             create_threads, read_threads, update_threads, delete_threads, options,
+                                                             constant_set_sizes.map(|(pass_1_set_size, pass_2_set_size)| ConstantSet { pass_1_set_size, pass_2_set_size, select_set_fn: &mut select_set_fn }));
 
         // In case of error, retry only if the complexity analysis failed to match the maximum requirement for Time,
         // which can be affected by run-time environment conditions (specially if the involved machines aren't fully idle
-        // or on low RAM conditions, causing swap or page faults to kick in).
+        // or on low RAM conditions, causing swap or page faults to kick in) -- or if the time measurements were anomalous.
         // Space complexity analysis is always deterministic, regardless of the environment conditions.
         if crud_analysis.is_err() {
             let crud_analysis_error = crud_analysis.err().unwrap();
-            if crud_analysis_error.failed_analysis == "Time" {
+            if crud_analysis_error.failed_analysis == FailedAnalysis::Time {
                 if attempt < max_retry_attempts-1 {
+                    let error_retained_bytes = crud_analysis_error.retained_bytes();
                     collected_errors.push(crud_analysis_error);
                     OUTPUT(&format!("\nAttempt {} failed. Resetting before retrying", attempt+1));
-                    reset_fn(100);  // 100% of the created elements
+                    let before_reset_used_memory = ALLOC.save_point().metrics.current_used_memory;
+                    reset_fn(if warmup_percentage > 0 {CrudOp::Warmup} else {first_crud_op(create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass)},
+                             100);  // 100% of the created elements
+                    let after_reset_used_memory = ALLOC.save_point().metrics.current_used_memory;
+                    // memory allocated by the attempt should be back to what it was before it -- except for the collected error
+                    let attempt_baseline_used_memory = attempt_start_used_memory + error_retained_bytes;
+                    let attempt_allocated_bytes = before_reset_used_memory.saturating_sub(attempt_baseline_used_memory);
+                    let leaked_bytes = after_reset_used_memory.saturating_sub(attempt_baseline_used_memory);
+                    if space_analysed && leaked_bytes > std::cmp::max(RESET_LEAK_SLACK_BYTES, (attempt_allocated_bytes as f64 * PERCENT_TOLERANCE) as usize) {
+                        panic!("At attempt #{}, `reset_fn` didn't free the memory allocated by the CRUD operations: {} of the {} bytes allocated \
+                                during the attempt remain in use -- the SPACE analysis of the next attempt would be compromised. \
+                                Please make `reset_fn` deallocate everything (calling `shrink_to_fit()` on the cleared containers, for instance)",
+                               attempt+1, leaked_bytes, attempt_allocated_bytes);
+                    }
                     OUTPUT("...\n");
                     continue;
                 } else {
                     let unique_failed_operations_count = collected_errors.iter()
                         .rfold(BTreeMap::<String, u32>::new(), |mut acc, collected_error| {
-                            let key = format!("{} with {:?}", collected_error.failed_operation, collected_error.failed_complexity);
+                            let key = match collected_error.measurement_anomaly {
+                                Some(measurement_anomaly) => format!("{} with {}", collected_error.failed_operation, measurement_anomaly),
+                                None => format!("{} with {:?}", collected_error.failed_operation, collected_error.failed_complexity),
+                            };
                             let op_count = acc.get_mut(&key);
                             match op_count {
                                 Some(count) => *count += 1,
@@ -111,6 +423,9 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                             Previous attempts failed at:\n\
                             {}", max_retry_attempts, crud_analysis_error, previous_errors);
                 }
+            } else if let FailedAnalysis::Aborted(_) = crud_analysis_error.failed_analysis {
+                // a panicking algorithm would panic again -- and a pass that timed out would, most likely, time out again
+                panic!("At attempt #{}, {}\n", attempt+1, crud_analysis_error);
             } else {
                 // mismatched space complexity (if not on the first loop, reset_fn probably didn't deallocated)
                 panic!("At attempt #{}, SPACE complexity mismatch: {}\n", attempt+1, crud_analysis_error);
@@ -122,7 +437,7 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
 
 /// Runs time & space analysis for Create, Read, Update and Delete algorithms -- usually from a container or database.
 /// Returns the Optional analysis for each operation + the full report, in textual form.
-/// An analysis will be None if the provided '*_iterations_per_pass' or '*_threads' are 0.\
+/// An analysis will be None if the provided '*_iterations_per_pass' is 0 -- in which case '*_threads' is ignored; otherwise, it should be > 0.\
 /// --> This function is not meant to be run in tests -- see [test_crud_algorithms()] instead.
 ///   - `reset_fn` -- a closure or function that will be called after warming up, to restore the empty
 ///                   state of the container and to deallocate any memory allocated during the warmup pass
@@ -137,8 +452,8 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
 ///                            and second passes, to hot load caches, resolve page faults, establish
 ///                            network connections or do any other operations that might impact the
 ///                            time complexity analysis. Note, however, that the [reset_fn] must
-///                            also deallocate any allocated memory so the space complexity analysis
-///                            is not compromised.
+///                            also deallocate any allocated memory if the space complexity analysis
+///                            is to be relied on -- time-only analyses may keep it.
 ///   - `create_iterations_per_pass`, `read_iterations_per_pass`, `update_iterations_per_pass` &
 ///     `delete_iterations_per_pass` -- number of times each CRUD algorithm should run, per pass -- not
 ///                                     too small (any involved IO/OS times should be negligible) nor too
@@ -150,6 +465,7 @@ pub fn test_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
 ///                    numbers are integers, so the unit should be at least one or two orders of magnitude
 ///                    broader than the measured values. Space measurements are always in bytes and their
 ///                    presentation unit (b, KiB, MiB or GiB) are automatically selected.
+#[allow(clippy::too_many_arguments, clippy::doc_overindented_list_items)]
 pub fn analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                                CreateClosure: Fn(u32) -> u32 + Sync,
                                ReadClosure:   Fn(u32) -> u32 + Sync,
@@ -163,27 +479,161 @@ pub fn analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                                delete_fn: DeleteClosure,
                                warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
                                create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
-                              -> (Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements> >,    // create analysis
-                                  Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements> >,    // read analysis
-                                  Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements> >,    // update analysis
-                                  Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements> >,    // delete analysis
-                                  String)                                                                       // the full report
+                              -> CrudAnalyses<'_>
                               where PassResult: Copy {
 
-    internal_analyse_crud_algorithms(crud_name, reset_fn,
-                                     create_fn,  BigOAlgorithmComplexity::WorseThanExponential,  BigOAlgorithmComplexity::WorseThanExponential,
-                                     read_fn,     BigOAlgorithmComplexity::WorseThanExponential,   BigOAlgorithmComplexity::WorseThanExponential,
-                                     update_fn, BigOAlgorithmComplexity::WorseThanExponential,  BigOAlgorithmComplexity::WorseThanExponential,
-                                     delete_fn,  BigOAlgorithmComplexity::WorseThanExponential,  BigOAlgorithmComplexity::WorseThanExponential,
+    let no_time_bounds = ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential);
+    internal_analyse_crud_algorithms(crud_name, |_crud_op, n| reset_fn(n),
+                                     create_fn,  no_time_bounds,  BigOAlgorithmComplexity::WorseThanExponential,
+                                     read_fn,     no_time_bounds,   BigOAlgorithmComplexity::WorseThanExponential,
+                                     update_fn, no_time_bounds,  BigOAlgorithmComplexity::WorseThanExponential,
+                                     delete_fn,  no_time_bounds,  BigOAlgorithmComplexity::WorseThanExponential,
                                      warmup_percentage, create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass, delete_iterations_per_pass,
-                                     create_threads, read_threads, update_threads, delete_threads).unwrap()
+                                     create_threads, read_threads, update_threads, delete_threads, CrudOptions::default(), None).unwrap()
+}
+
+/// Same as [analyse_crud_algorithms()], but the report is GitHub-flavored Markdown -- to be dropped into PR descriptions or READMEs:
+/// a `##` section for each analysed operation, with a table of its passes' measurements followed by its `code` formatted complexities.\
+/// Unlike the text report, the progress of the passes is left out.
+#[allow(clippy::too_many_arguments)]
+pub fn analyse_crud_algorithms_markdown<ResetClosure:  Fn(u32) -> u32 + Sync,
+                                        CreateClosure: Fn(u32) -> u32 + Sync,
+                                        ReadClosure:   Fn(u32) -> u32 + Sync,
+                                        UpdateClosure: Fn(u32) -> u32 + Sync,
+                                        DeleteClosure: Fn(u32) -> u32 + Sync>
+                                       (crud_name: &str,
+                                        reset_fn: ResetClosure,
+                                        create_fn: CreateClosure,
+                                        read_fn: ReadClosure,
+                                        update_fn: UpdateClosure,
+                                        delete_fn: DeleteClosure,
+                                        warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
+                                        create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
+                                       -> CrudAnalyses<'_>
+                                       where PassResult: Copy {
+    let (create_analysis, read_analysis, update_analysis, delete_analysis, _text_report) =
+        analyse_crud_algorithms(crud_name, reset_fn, create_fn, read_fn, update_fn, delete_fn,
+                                warmup_percentage, create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass, delete_iterations_per_pass,
+                                create_threads, read_threads, update_threads, delete_threads);
+    let markdown_report = format!("# '{}' CRUD Algorithm Complexity Analysis\n{}{}{}{}",
+                                  crud_name,
+                                  markdown_section("Create", &create_analysis),
+                                  markdown_section("Read",   &read_analysis),
+                                  markdown_section("Update", &update_analysis),
+                                  markdown_section("Delete", &delete_analysis));
+    (create_analysis, read_analysis, update_analysis, delete_analysis, markdown_report)
+}
+
+/// the Markdown section of an operation, for [analyse_crud_algorithms_markdown()] -- empty if it wasn't analysed
+fn markdown_section<T: BigOAlgorithmMeasurements>(operation: &str, analysis: &Option<BigOAlgorithmAnalysis<T>>) -> String {
+    let Some(analysis) = analysis else {
+        return String::new()
+    };
+    let time_measurements = analysis.algorithm_measurements.time_measurements();
+    let space_measurements = analysis.algorithm_measurements.space_measurements();
+    format!("\n## {operation}\n\n\
+             | pass | Δt | Δs |\n\
+             |:----:|---:|---:|\n\
+             | 1 | {:?} | {} |\n\
+             | 2 | {:?} | {} |\n\n\
+             - time complexity: `{}`\n\
+             - space complexity: `{}`\n",
+            time_measurements.pass_1_measurements, space_measurements.pass_1_measurements,
+            time_measurements.pass_2_measurements, space_measurements.pass_2_measurements,
+            analysis.time_complexity.as_pretty_str(), analysis.space_complexity.as_pretty_str())
+}
+
+/// the first operation to run on a pass, given the number of iterations of each operation
+fn first_crud_op(create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32) -> CrudOp {
+    if create_iterations_per_pass > 0 {
+        CrudOp::Create
+    } else if read_iterations_per_pass > 0 {
+        CrudOp::Read
+    } else if update_iterations_per_pass > 0 {
+        CrudOp::Update
+    } else {
+        CrudOp::Delete
+    }
+}
+
+/// The optional analysis for each CRUD operation + the full report, as returned by [analyse_crud_algorithms()]
+pub type CrudAnalyses<'a> = (Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements<'a>> >,    // create analysis
+                             Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements<'a>> >,    // read analysis
+                             Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements<'a>> >,    // update analysis
+                             Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements<'a>> >,    // delete analysis
+                             String);                                                                      // the full report
+
+/// The observed time & space complexities of a single CRUD operation -- see [CrudVerdict]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CrudOperationVerdict {
+    pub time_complexity:  BigOAlgorithmComplexity,
+    pub space_complexity: BigOAlgorithmComplexity,
+}
+
+/// Summary of the complexities observed by [analyse_crud_algorithms()] -- an operation is `None` if it wasn't analysed.\
+/// Use [CrudVerdict::worst_time()] & [CrudVerdict::worst_space()] for an overall rating, like in
+/// `verdict.worst_time().map_or(true, |worst| worst.is_at_most(BigOAlgorithmComplexity::OLogN))`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CrudVerdict {
+    pub create: Option<CrudOperationVerdict>,
+    pub read:   Option<CrudOperationVerdict>,
+    pub update: Option<CrudOperationVerdict>,
+    pub delete: Option<CrudOperationVerdict>,
+}
+impl CrudVerdict {
+    /// builds the verdict out of the analyses returned by [analyse_crud_algorithms()]
+    pub fn from_tuple(crud_analyses: &CrudAnalyses) -> Self {
+        fn verdict<T: BigOAlgorithmMeasurements>(analysis: &Option<BigOAlgorithmAnalysis<T>>) -> Option<CrudOperationVerdict> {
+            analysis.as_ref().map(|analysis| CrudOperationVerdict {
+                time_complexity:  analysis.time_complexity,
+                space_complexity: analysis.space_complexity,
+            })
+        }
+        let (create_analysis, read_analysis, update_analysis, delete_analysis, _report) = crud_analyses;
+        Self {
+            create: verdict(create_analysis),
+            read:   verdict(read_analysis),
+            update: verdict(update_analysis),
+            delete: verdict(delete_analysis),
+        }
+    }
+    /// the worst time complexity among the analysed operations -- `None` if no operation was analysed
+    pub fn worst_time(&self) -> Option<BigOAlgorithmComplexity> {
+        self.operations()
+            .map(|operation| operation.time_complexity)
+            .reduce(BigOAlgorithmComplexity::worse_of)
+    }
+    /// the worst space complexity among the analysed operations -- `None` if no operation was analysed
+    pub fn worst_space(&self) -> Option<BigOAlgorithmComplexity> {
+        self.operations()
+            .map(|operation| operation.space_complexity)
+            .reduce(BigOAlgorithmComplexity::worse_of)
+    }
+    /// iterates over the analysed operations
+    fn operations(&self) -> impl Iterator<Item=&CrudOperationVerdict> {
+        [&self.create, &self.read, &self.update, &self.delete].into_iter().flatten()
+    }
+}
+
+/// Which analysis of an operation failed -- telling [retry_crud_analysis()] whether it is worth retrying
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FailedAnalysis {
+    /// the time complexity or coefficient didn't match -- or the time measurements couldn't be trusted: retried,
+    /// as they are affected by the run-time environment conditions
+    Time,
+    /// the space complexity didn't match: deterministic, so not retried
+    Space,
+    /// the pass was given up on -- its algorithm panicked or it timed out: not retried, as it would, most likely, happen again
+    Aborted(PassAbort),
 }
 
 #[derive(Debug)]
 struct CRUDComplexityAnalysisError {
     pub failed_operation:     String,
-    pub failed_analysis:      String,
+    pub failed_analysis:      FailedAnalysis,
     pub failed_complexity:    BigOAlgorithmComplexity,
+    /// set if the time measurements couldn't be trusted -- in which case `failed_analysis` is [FailedAnalysis::Time], so they are retaken
+    pub measurement_anomaly:  Option<MeasurementAnomaly>,
     pub failed_assertion_msg: String,
     #[allow(dead_code)]
     pub partial_report:       String,
@@ -194,36 +644,70 @@ impl Display for CRUDComplexityAnalysisError {
     }
 }
 impl Error for CRUDComplexityAnalysisError {}
+impl CRUDComplexityAnalysisError {
+    /// the heap bytes kept by this error -- so they are not mistaken for memory `reset_fn` failed to free.\
+    /// `partial_report` is not accounted for, as it is built with the allocator metrics paused
+    fn retained_bytes(&self) -> usize {
+        self.failed_operation.capacity() + self.failed_assertion_msg.capacity()
+    }
+}
+
+/// The externally provided set the read & update operations run on -- see [test_read_update_algorithms()]
+struct ConstantSet<'a> {
+    pass_1_set_size: u64,
+    pass_2_set_size: u64,
+    select_set_fn:   &'a mut dyn FnMut(u64),
+}
 
 /// Returns the analysed complexities + the full report, as a string in the form (create, read, update, delete, report).
-/// If one of the measured complexities don't match the maximum expected, None is returned for that analysis, provided it's *_number_of_iterations_per_pass is > 0.
-fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
+/// If one of the measured complexities don't match the maximum expected, None is returned for that analysis, provided it's *_number_of_iterations_per_pass is > 0.\
+/// The read & update set sizes are the ones built by the create passes -- unless an external `constant_set` is given, in which case
+/// it is selected before each pass.
+#[allow(clippy::too_many_arguments)]
+fn internal_analyse_crud_algorithms<'a, ResetClosure:  Fn(CrudOp, u32) -> u32 + Sync,
                                     CreateClosure: Fn(u32) -> u32 + Sync,
                                     ReadClosure:   Fn(u32) -> u32 + Sync,
                                     UpdateClosure: Fn(u32) -> u32 + Sync,
                                     DeleteClosure: Fn(u32) -> u32 + Sync>
-                                   (crud_name: &str,
+                                   (crud_name: &'a str,
                                     reset_fn:  ResetClosure,
-                                    create_fn: CreateClosure, expected_create_time_complexity: BigOAlgorithmComplexity, expected_create_space_complexity: BigOAlgorithmComplexity,
-                                    read_fn:   ReadClosure,   expected_read_time_complexity:   BigOAlgorithmComplexity, expected_read_space_complexity:   BigOAlgorithmComplexity,
-                                    update_fn: UpdateClosure, expected_update_time_complexity: BigOAlgorithmComplexity, expected_update_space_complexity: BigOAlgorithmComplexity,
-                                    delete_fn: DeleteClosure, expected_delete_time_complexity: BigOAlgorithmComplexity, expected_delete_space_complexity: BigOAlgorithmComplexity,
+                                    create_fn: CreateClosure, expected_create_time_complexity: impl Into<ComplexityBoundPair>, expected_create_space_complexity: BigOAlgorithmComplexity,
+                                    read_fn:   ReadClosure,   expected_read_time_complexity:   impl Into<ComplexityBoundPair>, expected_read_space_complexity:   BigOAlgorithmComplexity,
+                                    update_fn: UpdateClosure, expected_update_time_complexity: impl Into<ComplexityBoundPair>, expected_update_space_complexity: BigOAlgorithmComplexity,
+                                    delete_fn: DeleteClosure, expected_delete_time_complexity: impl Into<ComplexityBoundPair>, expected_delete_space_complexity: BigOAlgorithmComplexity,
                                     warmup_percentage: u32, create_iterations_per_pass: u32, read_iterations_per_pass: u32, update_iterations_per_pass: u32, delete_iterations_per_pass: u32,
-                                    create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32)
-                                   -> Result<(Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements> >,       // create analysis
-                                              Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements> >,       // read analysis
-                                              Option< BigOAlgorithmAnalysis<ConstantSetIteratorAlgorithmMeasurements> >,       // update analysis
-                                              Option< BigOAlgorithmAnalysis<SetResizingIteratorAlgorithmMeasurements> >,       // delete analysis
-                                              String),                                                                         // full report
-                                             CRUDComplexityAnalysisError>
+                                    create_threads: u32, read_threads: u32, update_threads: u32, delete_threads: u32,
+                                    options: CrudOptions,
+                                    mut constant_set: Option<ConstantSet<'_>>)
+                                   -> Result<CrudAnalyses<'a>, CRUDComplexityAnalysisError>
                                    where PassResult: Copy {
 
+    for (operation, iterations_per_pass, threads) in [("Create", create_iterations_per_pass, create_threads),
+                                                      ("Read",   read_iterations_per_pass,   read_threads),
+                                                      ("Update", update_iterations_per_pass, update_threads),
+                                                      ("Delete", delete_iterations_per_pass, delete_threads)] {
+        assert!(iterations_per_pass == 0 || threads > 0,
+                "'{}': the '{}' operation has {} iterations per pass, but 0 threads to run them -- use 0 iterations to skip an operation, not 0 threads",
+                crud_name, operation, iterations_per_pass);
+    }
+
+    let expected_create_time_complexity: ComplexityBoundPair = expected_create_time_complexity.into();
+    let   expected_read_time_complexity: ComplexityBoundPair =   expected_read_time_complexity.into();
+    let expected_update_time_complexity: ComplexityBoundPair = expected_update_time_complexity.into();
+    let expected_delete_time_complexity: ComplexityBoundPair = expected_delete_time_complexity.into();
+
+    // the report is built with the allocator metrics paused -- so it won't pollute the space analysis
+    ALLOC.pause();
     let mut full_report = String::with_capacity(2048);
+    ALLOC.resume();
 
-    // wrap around the original 'OUTPUT' function to capture the [full_report]
-    let mut _output = |msg: &str| {
-        full_report.push_str(msg);
-        OUTPUT(msg);
+    // wrap around the original 'OUTPUT' function to capture the [full_report] -- formatting `msg` only after pausing the allocator
+    let mut _output = |msg: fmt::Arguments| {
+        ALLOC.pause();
+        let msg_start = full_report.len();
+        fmt::Write::write_fmt(&mut full_report, msg).expect("Writing to a String can't fail");
+        OUTPUT(&full_report[msg_start..]);
+        ALLOC.resume();
     };
 
     let mut create_passes_results = [PassResult::default(); NUMBER_OF_PASSES as usize];
@@ -237,9 +721,27 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
     // to avoid any call cancellation optimizations when running in release mode
     let mut r: u32 = 0;
 
+    // the set sizes for the read & update analysis
+    let (pass_1_set_size, pass_2_set_size) = match &constant_set {
+        Some(constant_set) => (constant_set.pass_1_set_size, constant_set.pass_2_set_size),
+        None => (u64::from(create_iterations_per_pass), u64::from(create_iterations_per_pass) * 2),
+    };
+    // both passes over the same (or no) set would make the analysis divide by zero -- yielding a bogus complexity
+    if read_iterations_per_pass > 0 || update_iterations_per_pass > 0 {
+        assert!(constant_set.is_some() || create_iterations_per_pass > 0,
+                "'{}': the 'Read' & 'Update' operations need a set to operate on, but there are no 'Create' passes to build it -- \
+                 use `test_read_update_algorithms()` for sets built elsewhere", crud_name);
+        assert!(pass_1_set_size > 0 && pass_1_set_size != pass_2_set_size,
+                "'{}': the 'Read' & 'Update' passes must operate on distinct, non-empty sets -- but sets of {} & {} elements were given",
+                crud_name, pass_1_set_size, pass_2_set_size);
+    }
+
+    // the size of the set the read & update operations run over on the given pass
+    let constant_set_size = |pass_number: u32| if pass_number == 0 { pass_1_set_size } else { pass_2_set_size };
+
     // range calculation
     fn calc_regular_cru_range(iterations_per_pass: u32, pass_number: u32) -> Range<u32> { iterations_per_pass * pass_number       .. iterations_per_pass * (pass_number + 1) }
-    fn calc_regular_d_range(iterations_per_pass: u32, pass_number: u32) -> Range<u32> { iterations_per_pass * (pass_number + 1) .. iterations_per_pass * pass_number }
+    fn calc_regular_d_range(iterations_per_pass: u32, pass_number: u32) -> Range<u32> { iterations_per_pass * pass_number       .. iterations_per_pass * (pass_number + 1) }
 
     /// Contains factored out code to measure & analyse READ or UPDATE operations, checking the expected maximum time & space complexities
     ///   - [pass_number] -- u32 in the range [0..NUMBER_OF_PASSES]: specifies the number of the pass being run
@@ -251,24 +753,43 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
     ///     to return in error if the expectations are not met)
     ///   - [number_of_iterations_per_pass] -- u32: either [read_iterations_per_pass] or [update_iterations_per_pass]
     ///   - [number_of_threads] -- u32: either [read_threads] or [update_threads]
+    ///   - [parallel_pass_result] -- Option<Result<(PassResult, u32), PassAbort>>: the outcome of the pass, if it was already run in parallel -- see [run_parallel_constant_set_passes()]
     macro_rules! run_constant_set_pass {
         ($pass_number: expr, $operation_name: literal, $suffix: expr, $passes_results: ident,
-         $algorithm_closure: ident, $expected_time_complexity: ident, $expected_space_complexity: ident,
-         $number_of_iterations_per_pass: expr, $number_of_threads: ident) => {
+         $algorithm_closure: ident, $expected_time_complexity: ident, $expected_space_complexity: ident, $max_time_coefficient: expr,
+         $number_of_iterations_per_pass: expr, $number_of_threads: ident, $parallel_pass_result: expr) => {
             if $number_of_iterations_per_pass > 0 {
-                let (pass_result, pass_r) = run_iterator_pass_verbosely(&format!("{}: ", $operation_name.to_ascii_lowercase()), $suffix,
-                                                                        &$algorithm_closure, &BigOIteratorAlgorithmType::SetResizing,
-                                                                        calc_regular_cru_range($number_of_iterations_per_pass, $pass_number),
-                                                                        $number_of_threads, &mut _output);
+                let pass_outcome = match $parallel_pass_result {
+                    Some(Ok((pass_result, pass_r))) => {
+                        _output(format_args!("{}: {:?}{}/{}{}{}", $operation_name.to_ascii_lowercase(), pass_result.time_measurements, timer_resolution_note(pass_result.time_measurements),
+                                        pass_result.space_measurements, samples_note(options.sample_count), $suffix));
+                        Ok((pass_result, pass_r))
+                    },
+                    Some(Err(pass_abort)) => Err(pass_abort),
+                    None => {
+                        let range = calc_regular_cru_range($number_of_iterations_per_pass, $pass_number);
+                        let workload_range = range.clone();
+                        in_pass($pass_number+1, constant_set_size($pass_number), || {
+                            try_run_pinned_iterator_pass_median_verbosely(&format!("{}: ", $operation_name.to_ascii_lowercase()), $suffix,
+                                                                          &|e| $algorithm_closure(options.workload.value(e, &workload_range)), &BigOIteratorAlgorithmType::ConstantSet,
+                                                                          range, IterationOrder::Descending,
+                                                                          $number_of_threads, options.cpu_affinity, options.aggregation(), options.timeout, options.sample_count, |msg| _output(format_args!("{}", msg)))
+                        })
+                    },
+                };
+                let (pass_result, pass_r) = match pass_outcome {
+                    Ok(pass_outcome) => pass_outcome,
+                    Err(pass_abort) => return_with_abort_error!($operation_name, $expected_time_complexity, $expected_space_complexity, pass_abort),
+                };
                 $passes_results[$pass_number as usize] = pass_result;
                 r ^= pass_r;
                 if $pass_number == NUMBER_OF_PASSES-1 {
                     let measurements = ConstantSetIteratorAlgorithmMeasurements {
                         measurement_name: $operation_name,
                         passes_info: ConstantSetIteratorAlgorithmPassesInfo {
-                            pass_1_set_size: create_iterations_per_pass,
-                            pass_2_set_size: create_iterations_per_pass * 2,
-                            repetitions: $number_of_iterations_per_pass,
+                            pass_1_set_size,
+                            pass_2_set_size,
+                            repetitions: u64::from($number_of_iterations_per_pass),
                         },
                         time_measurements: BigOTimeMeasurements {
                             pass_1_measurements: $passes_results[0].time_measurements,
@@ -292,7 +813,7 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                     };
                     let  time_complexity = low_level_analysis::time_analysis::  analyse_time_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
                     let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
-                    yield_analysis_or_return_with_error!($operation_name, measurements, $expected_time_complexity, $expected_space_complexity, time_complexity, space_complexity)
+                    yield_analysis_or_return_with_error!($operation_name, measurements, $expected_time_complexity, $expected_space_complexity, $max_time_coefficient, time_complexity, space_complexity)
                 } else {
                     None
                 }
@@ -316,21 +837,30 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
     ///   - [number_of_threads] -- u32: either [create_threads] or [delete_threads]
     macro_rules! run_set_resizing_pass {
         ($pass_number: expr, $operation_name: literal, $suffix: ident, $result_prefix_closure: expr,
-         $passes_results: ident, $range_fn: ident, $last_pass_number: expr,
-         $algorithm_closure: ident, $expected_time_complexity: ident, $expected_space_complexity: ident,
+         $passes_results: ident, $range_fn: ident, $iteration_order: expr, $last_pass_number: expr,
+         $algorithm_closure: ident, $expected_time_complexity: ident, $expected_space_complexity: ident, $max_time_coefficient: expr,
          $number_of_iterations_per_pass: expr, $number_of_threads: ident) => {
             if $number_of_iterations_per_pass > 0 {
-                let (pass_result, pass_r) = run_iterator_pass_verbosely(&$result_prefix_closure($pass_number, $operation_name), $suffix,
-                                                                        &$algorithm_closure, &BigOIteratorAlgorithmType::SetResizing,
-                                                                        $range_fn($number_of_iterations_per_pass, $pass_number),
-                                                                        $number_of_threads, &mut _output);
+                // the set grows up to -- or shrinks down from -- this size
+                let pass_number: u32 = $pass_number;
+                let set_size = u64::from($number_of_iterations_per_pass) * u64::from(pass_number+1);
+                let pass_outcome = in_pass(pass_number+1, set_size, || {
+                    try_run_pinned_iterator_pass_verbosely(&$result_prefix_closure($pass_number, $operation_name), $suffix,
+                                                           &$algorithm_closure, &BigOIteratorAlgorithmType::SetResizing,
+                                                           $range_fn($number_of_iterations_per_pass, $pass_number), $iteration_order,
+                                                           $number_of_threads, options.cpu_affinity, options.aggregation(), options.timeout, |msg| _output(format_args!("{}", msg)))
+                });
+                let (pass_result, pass_r) = match pass_outcome {
+                    Ok(pass_outcome) => pass_outcome,
+                    Err(pass_abort) => return_with_abort_error!($operation_name, $expected_time_complexity, $expected_space_complexity, pass_abort),
+                };
                 $passes_results[$pass_number as usize] = pass_result;
                 r ^= pass_r;
                 if $pass_number == $last_pass_number {
                     let measurements = SetResizingIteratorAlgorithmMeasurements {
                         measurement_name: $operation_name,
                         passes_info: SetResizingIteratorAlgorithmPassesInfo {
-                            delta_set_size: $number_of_iterations_per_pass,
+                            delta_set_size: u64::from($number_of_iterations_per_pass),
                         },
                         time_measurements: BigOTimeMeasurements {
                             pass_1_measurements: $passes_results[0].time_measurements,
@@ -343,7 +873,7 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                     };
                     let  time_complexity = low_level_analysis::time_analysis::  analyse_time_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
                     let space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_set_resizing_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
-                    yield_analysis_or_return_with_error!($operation_name, measurements, $expected_time_complexity, $expected_space_complexity, time_complexity, space_complexity)
+                    yield_analysis_or_return_with_error!($operation_name, measurements, $expected_time_complexity, $expected_space_complexity, $max_time_coefficient, time_complexity, space_complexity)
                 } else {
                     None
                 }
@@ -357,57 +887,132 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
     /// returns the [BigOAlgorithmAnalysis] or return the method with the error message
     macro_rules! yield_analysis_or_return_with_error {
         ($operation_name: literal, $measurements: ident,
-         $expected_time_complexity: ident, $expected_space_complexity: ident,
-         $observed_time_complexity: ident, $observed_space_complexity: ident) => {
-            if $observed_time_complexity as u32 > $expected_time_complexity as u32 {
-                _output(&format!(" ** Aborted due to TIME complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n", $operation_name, $expected_time_complexity, $observed_time_complexity));
+         $expected_time_complexity: ident, $expected_space_complexity: ident, $max_time_coefficient: expr,
+         $observed_time_complexity: ident, $observed_space_complexity: ident) => {{
+            // anomalies only matter if there are time expectations to be checked
+            let time_anomaly = ($expected_time_complexity != ComplexityBoundPair::at_most(BigOAlgorithmComplexity::WorseThanExponential))
+                .then(|| low_level_analysis::time_analysis::detect_time_anomaly(&$measurements.time_measurements))
+                .flatten();
+            // untrustworthy measurements fail the analysis -- even if their complexities happened to match
+            ComplexityAssertionRecord::global().record(ComplexityAssertion::with_failure(format!("{}: {}", crud_name, $operation_name), $expected_time_complexity, $observed_time_complexity,
+                                                                                         $expected_space_complexity, $observed_space_complexity,
+                                                                                         time_anomaly.map(|anomaly| format!("untrustworthy TIME measurements: {}", anomaly))));
+            if let Some(measurement_anomaly) = time_anomaly {
+                _output(format_args!(" ** Aborted due to a TIME measurement anomaly on '{}' operation: {} (measured: {:?})\n\n", $operation_name, measurement_anomaly, $observed_time_complexity));
+                return Err(CRUDComplexityAnalysisError {
+                    failed_operation:     $operation_name.to_string(),
+                    failed_analysis:      FailedAnalysis::Time,
+                    failed_complexity:    $observed_time_complexity,
+                    measurement_anomaly:  Some(measurement_anomaly),
+                    failed_assertion_msg: format!("'{}' algorithm TIME measurements can't be trusted: {}", $operation_name, measurement_anomaly),
+                    partial_report:       full_report,
+                });
+            } else if !$expected_time_complexity.contains($observed_time_complexity) {
+                _output(format_args!(" ** Aborted due to TIME complexity mismatch on '{}' operation: {}, measured: {:?}\n\n", $operation_name, $expected_time_complexity, $observed_time_complexity));
                 return Err(CRUDComplexityAnalysisError {
                     failed_operation:     $operation_name.to_string(),
-                    failed_analysis:      "Time".to_owned(),
+                    failed_analysis:      FailedAnalysis::Time,
                     failed_complexity:    $observed_time_complexity,
-                    failed_assertion_msg: format!("'{}' algorithm was expected to match a maximum TIME complexity of '{:?}', but '{:?}' was measured", $operation_name, $expected_time_complexity, $observed_time_complexity),
+                    measurement_anomaly:  None,
+                    failed_assertion_msg: format!("'{}' algorithm was expected to match a TIME complexity ({}), but '{:?}' was measured", $operation_name, $expected_time_complexity, $observed_time_complexity),
                     partial_report:       full_report,
                 });
-            } else if $observed_space_complexity as u32 > $expected_space_complexity as u32 {
-                _output(&format!(" ** Aborted due to SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n", $operation_name, $expected_space_complexity, $observed_space_complexity));
+            } else if !$observed_space_complexity.is_at_most($expected_space_complexity) {
+                _output(format_args!(" ** Aborted due to SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n", $operation_name, $expected_space_complexity, $observed_space_complexity));
                 return Err(CRUDComplexityAnalysisError {
                     failed_operation:     $operation_name.to_string(),
-                    failed_analysis:      "Space".to_owned(),
+                    failed_analysis:      FailedAnalysis::Space,
                     failed_complexity:    $observed_space_complexity,
+                    measurement_anomaly:  None,
                     failed_assertion_msg: format!("'{}' algorithm was expected to match a maximum SPACE complexity of '{:?}', but '{:?}' was measured", $operation_name, $expected_space_complexity, $observed_space_complexity),
                     partial_report:       full_report,
                 });
             } else {
-                Some(BigOAlgorithmAnalysis {
+                let analysis = BigOAlgorithmAnalysis {
+                    time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient($observed_time_complexity, &$measurements),
+                    is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&$measurements),
                     algorithm_measurements: $measurements,
                     $observed_time_complexity,
                     $observed_space_complexity,
-                })
+                };
+                let max_time_coefficient: Option<Duration> = $max_time_coefficient;
+                if let Some(max_time_coefficient) = max_time_coefficient.filter(|&max_time_coefficient| analysis.exceeds_time_coefficient(max_time_coefficient)) {
+                    let time_coefficient = analysis.fmt_time_coefficient().unwrap_or_default();
+                    _output(format_args!(" ** Aborted due to TIME coefficient excess on '{}' operation: maximum: {:?}, measured: {}\n\n", $operation_name, max_time_coefficient, time_coefficient));
+                    return Err(CRUDComplexityAnalysisError {
+                        failed_operation:     $operation_name.to_string(),
+                        failed_analysis:      FailedAnalysis::Time,
+                        failed_complexity:    $observed_time_complexity,
+                        measurement_anomaly:  None,
+                        failed_assertion_msg: format!("'{}' algorithm matched its TIME complexity ({:?}), but its coefficient was expected to be at most {:?} -- {} was measured",
+                                                      $operation_name, $observed_time_complexity, max_time_coefficient, time_coefficient),
+                        partial_report:       full_report,
+                    });
+                }
+                Some(analysis)
             }
-        }
+        }}
+    }
+
+    /// returns the method with the error telling `$operation_name`'s pass was aborted -- its algorithm panicked or it timed out, as given by `$pass_abort`
+    macro_rules! return_with_abort_error {
+        ($operation_name: expr, $expected_time_complexity: expr, $expected_space_complexity: expr, $pass_abort: expr) => {{
+            let pass_abort: PassAbort = $pass_abort;
+            _output(format_args!(" ** Aborted as the '{}' operation's {}\n\n", $operation_name, pass_abort));
+            ComplexityAssertionRecord::global().record(ComplexityAssertion::aborted(format!("{}: {}", crud_name, $operation_name), $expected_time_complexity,
+                                                                                    $expected_space_complexity, pass_abort.to_string()));
+            let failed_assertion_msg = format!("'{}' {}", $operation_name, pass_abort);
+            return Err(CRUDComplexityAnalysisError {
+                failed_operation:     $operation_name.to_string(),
+                failed_analysis:      FailedAnalysis::Aborted(pass_abort),
+                // no complexity was measured
+                failed_complexity:    BigOAlgorithmComplexity::WorseThanExponential,
+                measurement_anomaly:  None,
+                failed_assertion_msg,
+                partial_report:       full_report,
+            });
+        }}
     }
 
     macro_rules! run_create_pass {
         ($pass_number: expr) => {{
             let suffix = if read_iterations_per_pass > 0 || update_iterations_per_pass > 0 {", "} else {""};
             run_set_resizing_pass!($pass_number, "Create", suffix, |_pass_number: u32, pass_name: &str| format!("{}: ", pass_name.to_ascii_lowercase()),
-                                   create_passes_results, calc_regular_cru_range, NUMBER_OF_PASSES-1,
-                                   create_fn, expected_create_time_complexity, expected_create_space_complexity,
+                                   create_passes_results, calc_regular_cru_range, IterationOrder::Descending, NUMBER_OF_PASSES-1,
+                                   create_fn, expected_create_time_complexity, expected_create_space_complexity, options.max_time_coefficients[crud_op_index(CrudOp::Create)],
                                    create_iterations_per_pass, create_threads)
         }}
     }
     macro_rules! run_read_pass {
-        ($pass_number: expr) => {{
+        ($pass_number: expr, $parallel_pass_result: expr) => {{
             let suffix = if update_iterations_per_pass > 0 {", "} else {""};
-            run_constant_set_pass!($pass_number, "Read", suffix, read_passes_results, read_fn, expected_read_time_complexity, expected_read_space_complexity, read_iterations_per_pass, read_threads)
+            run_constant_set_pass!($pass_number, "Read", suffix, read_passes_results, read_fn, expected_read_time_complexity, expected_read_space_complexity, options.max_time_coefficients[crud_op_index(CrudOp::Read)], read_iterations_per_pass, read_threads, $parallel_pass_result)
         }}
     }
     macro_rules! run_update_pass {
-        ($pass_number: expr) => {{
+        ($pass_number: expr, $parallel_pass_result: expr) => {{
             let suffix = "";
-            run_constant_set_pass!($pass_number, "Update", suffix, update_passes_results, update_fn, expected_update_time_complexity, expected_update_space_complexity, update_iterations_per_pass, update_threads)
+            run_constant_set_pass!($pass_number, "Update", suffix, update_passes_results, update_fn, expected_update_time_complexity, expected_update_space_complexity, options.max_time_coefficients[crud_op_index(CrudOp::Update)], update_iterations_per_pass, update_threads, $parallel_pass_result)
         }}
     }
+
+    // runs the read & update passes concurrently, if requested by [CrudOptions::with_parallel_operations()] -- returning their
+    // outcomes for [run_read_pass!()] & [run_update_pass!()]. `(None, None)` is returned if they should run sequentially
+    let run_parallel_constant_set_passes = |pass_number: u32| {
+        if !options.parallel_operations || read_iterations_per_pass == 0 || update_iterations_per_pass == 0 {
+            return (None, None)
+        }
+        let (read_range, update_range) = (calc_regular_cru_range(read_iterations_per_pass, pass_number), calc_regular_cru_range(update_iterations_per_pass, pass_number));
+        let fed_read_fn   = |e| read_fn(options.workload.value(e, &read_range));
+        let fed_update_fn = |e| update_fn(options.workload.value(e, &update_range));
+        let set_size = constant_set_size(pass_number);
+        std::thread::scope(|scope| {
+            let read_pass   = scope.spawn(|| in_pass(pass_number+1, set_size, || try_run_iterator_pass_pinned_median(&fed_read_fn,   &BigOIteratorAlgorithmType::ConstantSet, read_range.clone(),   IterationOrder::Descending, read_threads, options.cpu_affinity, options.aggregation(), options.timeout, options.sample_count)));
+            let update_pass = scope.spawn(|| in_pass(pass_number+1, set_size, || try_run_iterator_pass_pinned_median(&fed_update_fn, &BigOIteratorAlgorithmType::ConstantSet, update_range.clone(), IterationOrder::Descending, update_threads, options.cpu_affinity, options.aggregation(), options.timeout, options.sample_count)));
+            (Some(read_pass.join().unwrap_or_else(|err| std::panic::resume_unwind(err))),
+             Some(update_pass.join().unwrap_or_else(|err| std::panic::resume_unwind(err))))
+        })
+    };
     macro_rules! run_delete_pass {
         ($pass_number: expr) => {{
             let suffix = "";
@@ -418,87 +1023,107 @@ fn internal_analyse_crud_algorithms<ResetClosure:  Fn(u32) -> u32 + Sync,
                                        } else {
                                          "; 1st: "
                                        },
-                                   delete_passes_results, calc_regular_d_range, 0,
-                                   delete_fn, expected_delete_time_complexity, expected_delete_space_complexity,
+                                   delete_passes_results, calc_regular_d_range, IterationOrder::Ascending, 0,
+                                   delete_fn, expected_delete_time_complexity, expected_delete_space_complexity, options.max_time_coefficients[crud_op_index(CrudOp::Delete)],
                                    delete_iterations_per_pass, delete_threads)
         }}
     }
 
 
-    _output(&format!("{} CRUD Algorithm Complexity Analysis:\n  ", crud_name));
+    _output(format_args!("{} CRUD Algorithm Complexity Analysis:\n  ", crud_name));
 
     // warmup
     if warmup_percentage > 0 {
 
         // warmup ranges
         let calc_warmup_cru_range = |iterations_per_pass|  0 .. iterations_per_pass * warmup_percentage / 100;
-        let calc_warmup_d_range = |iterations_per_pass| iterations_per_pass * warmup_percentage / 100 .. 0;
+        let calc_warmup_d_range = |iterations_per_pass| 0 .. iterations_per_pass * warmup_percentage / 100;
 
         let warmup_start = Instant::now();
-        _output("warming up [");
-        io::stdout().flush().unwrap();
+        _output(format_args!("warming up ["));
         if create_iterations_per_pass > 0 {
-            _output("C");
-            let (_elapse, warmup_r) = run_iterator_pass(&create_fn, &BigOIteratorAlgorithmType::SetResizing, calc_warmup_cru_range(create_iterations_per_pass), create_threads);
+            _output(format_args!("C"));
+            let (_elapse, warmup_r) = match try_run_iterator_pass_pinned(&create_fn, &BigOIteratorAlgorithmType::SetResizing, calc_warmup_cru_range(create_iterations_per_pass), IterationOrder::Descending, create_threads, options.cpu_affinity, options.aggregation(), options.timeout) {
+                Ok(warmup_outcome) => warmup_outcome,
+                Err(pass_abort) => return_with_abort_error!("Create", expected_create_time_complexity, expected_create_space_complexity, pass_abort),
+            };
             r ^= warmup_r;
         }
         if read_iterations_per_pass > 0 {
-            _output("R");
-            let (_elapse, warmup_r) = run_iterator_pass(&read_fn, &BigOIteratorAlgorithmType::ConstantSet, calc_warmup_cru_range(read_iterations_per_pass), read_threads);
+            _output(format_args!("R"));
+            let (_elapse, warmup_r) = match try_run_iterator_pass_pinned(&read_fn, &BigOIteratorAlgorithmType::ConstantSet, calc_warmup_cru_range(read_iterations_per_pass), IterationOrder::Descending, read_threads, options.cpu_affinity, options.aggregation(), options.timeout) {
+                Ok(warmup_outcome) => warmup_outcome,
+                Err(pass_abort) => return_with_abort_error!("Read", expected_read_time_complexity, expected_read_space_complexity, pass_abort),
+            };
             r ^= warmup_r;
         }
         if update_iterations_per_pass > 0 {
-            _output("U");
-            let (_elapse, warmup_r) = run_iterator_pass(&update_fn, &BigOIteratorAlgorithmType::ConstantSet, calc_warmup_cru_range(update_iterations_per_pass), update_threads);
+            _output(format_args!("U"));
+            let (_elapse, warmup_r) = match try_run_iterator_pass_pinned(&update_fn, &BigOIteratorAlgorithmType::ConstantSet, calc_warmup_cru_range(update_iterations_per_pass), IterationOrder::Descending, update_threads, options.cpu_affinity, options.aggregation(), options.timeout) {
+                Ok(warmup_outcome) => warmup_outcome,
+                Err(pass_abort) => return_with_abort_error!("Update", expected_update_time_complexity, expected_update_space_complexity, pass_abort),
+            };
             r ^= warmup_r;
         }
         if delete_iterations_per_pass > 0 {
-            _output("D");
-            let (_elapse, warmup_r) = run_iterator_pass(&delete_fn, &BigOIteratorAlgorithmType::SetResizing, calc_warmup_d_range(delete_iterations_per_pass), delete_threads);
+            _output(format_args!("D"));
+            let (_elapse, warmup_r) = match try_run_iterator_pass_pinned(&delete_fn, &BigOIteratorAlgorithmType::SetResizing, calc_warmup_d_range(delete_iterations_per_pass), IterationOrder::Ascending, delete_threads, options.cpu_affinity, options.aggregation(), options.timeout) {
+                Ok(warmup_outcome) => warmup_outcome,
+                Err(pass_abort) => return_with_abort_error!("Delete", expected_delete_time_complexity, expected_delete_space_complexity, pass_abort),
+            };
             r ^= warmup_r;
         }
-        _output("] ");
-        reset_fn(create_iterations_per_pass * warmup_percentage / 100);
+        _output(format_args!("] "));
+        reset_fn(first_crud_op(create_iterations_per_pass, read_iterations_per_pass, update_iterations_per_pass),
+                 create_iterations_per_pass * warmup_percentage / 100);
 
         let warmup_end = Instant::now();
         let warmup_elapsed = warmup_end.duration_since(warmup_start);
-        _output(&format!("{:?}, ", warmup_elapsed));
+        _output(format_args!("{:?}, ", warmup_elapsed));
     }
 
-    _output("First Pass (");
+    if let Some(constant_set) = constant_set.as_mut() {
+        (constant_set.select_set_fn)(constant_set.pass_1_set_size);
+    }
+    _output(format_args!("First Pass ("));
     run_create_pass!(0);
-    run_read_pass!(0);
-    run_update_pass!(0);
+    let (parallel_read_pass, parallel_update_pass) = run_parallel_constant_set_passes(0);
+    run_read_pass!(0, parallel_read_pass);
+    run_update_pass!(0, parallel_update_pass);
 
-    _output("); Second Pass (");
+    if let Some(constant_set) = constant_set.as_mut() {
+        (constant_set.select_set_fn)(constant_set.pass_2_set_size);
+    }
+    _output(format_args!("); Second Pass ("));
     let create_analysis = run_create_pass!(1);
-    let read_analysis = run_read_pass!(1);
-    let update_analysis = run_update_pass!(1);
+    let (parallel_read_pass, parallel_update_pass) = run_parallel_constant_set_passes(1);
+    let read_analysis = run_read_pass!(1, parallel_read_pass);
+    let update_analysis = run_update_pass!(1, parallel_update_pass);
 
-    _output("):\n\n");
+    _output(format_args!("):\n\n"));
 
     // output analysis reports
     if create_iterations_per_pass > 0 {
-        _output(&format!("{}\n\n", create_analysis.as_ref().unwrap()));
+        _output(format_args!("{}\n\n", create_analysis.as_ref().unwrap()));
     }
     if read_iterations_per_pass > 0 {
-        _output(&format!("{}\n\n", read_analysis.as_ref().unwrap()));
+        _output(format_args!("{}\n\n", read_analysis.as_ref().unwrap()));
     }
     if update_iterations_per_pass > 0 {
-        _output(&format!("{}\n\n", update_analysis.as_ref().unwrap()));
+        _output(format_args!("{}\n\n", update_analysis.as_ref().unwrap()));
     }
 
     // delete passes (passes are applied in reverse order)
     let delete_analysis;
     if delete_iterations_per_pass > 0 {
-        _output("Delete Passes (");
+        _output(format_args!("Delete Passes ("));
         run_delete_pass!(1);
         delete_analysis = run_delete_pass!(0);
 
-        _output(&format!(") r={}:\n", r));
+        _output(format_args!(") r={}:\n", r));
 
         // output the analysis report
-        _output(&format!("{}\n\n", delete_analysis.as_ref().unwrap()));
+        _output(format_args!("{}\n\n", delete_analysis.as_ref().unwrap()));
     } else {
         delete_analysis = None;
     }
@@ -513,12 +1138,10 @@ mod tests {
     //! Unit tests for [crud_analysis](super) module -- using 'serial_test' crate in order to make time measurements more reliable.
 
     use super::*;
-    use crate:: {
-        low_level_analysis::types::BigOAlgorithmMeasurements,
-    };
     use std::{
         collections::HashMap,
         sync::atomic::{Ordering, AtomicU32},
+        time::Duration,
     };
     use serial_test::serial;
 
@@ -630,7 +1253,7 @@ mod tests {
             report) = analyse_crud_algorithms("MyContainer",
                                               |_n| panic!("'reset_fn' should not be called if there is no warmup taking place"),
                                               |n| (n+1)/(n+1),
-                                              &|n| (n+1)/(n+1),
+                                              |n| (n+1)/(n+1),
                                               |n| (n+1)/(n+1),
                                               |_n| panic!("'delete_fn' should not be called if there is no warmup taking place"),
                                               0/*no warmup*/, iterations_per_pass, iterations_per_pass, iterations_per_pass, 0,
@@ -646,7 +1269,7 @@ mod tests {
             report) = analyse_crud_algorithms("MyContainer",
                                               |_n| panic!("'reset_fn' should not be called if there is no warmup taking place"),
                                               |n| (n+1)/(n+1),
-                                              &|_n| panic!("'read_fn' should not be called if there is no warmup taking place"),
+                                              |_n| panic!("'read_fn' should not be called if there is no warmup taking place"),
                                               |_n| panic!("'update_fn' should not be called if there is no warmup taking place"),
                                               |_n| panic!("'delete_fn' should not be called if there is no warmup taking place"),
                                               0/*no warmup*/, iterations_per_pass, 0, 0, 0,
@@ -654,6 +1277,455 @@ mod tests {
         assert_passes_progress(&report, false, true, false, false, false);
     }
 
+    /// checks the sections & tables of [analyse_crud_algorithms_markdown()]'s report -- only for the analysed operations
+    #[test]
+    #[serial]
+    fn markdown_report() {
+        let (create_analysis, read_analysis, update_analysis, delete_analysis, report) =
+            analyse_crud_algorithms_markdown("MyContainer",
+                                             |_n| panic!("'reset_fn' should not be called if there is no warmup taking place"),
+                                             |n| n, |n| n, |n| n,
+                                             |_n| panic!("'delete_fn' should not be called if it has no iterations"),
+                                             0, 1000, 1000, 1000, 0,
+                                             1, 1, 1, 1);
+        assert!(delete_analysis.is_none(), "Operations without iterations shouldn't be analysed");
+        let expected_report = format!("# 'MyContainer' CRUD Algorithm Complexity Analysis\n{}{}{}",
+                                      expected_markdown_section("Create", &create_analysis.expect("'Create' should have been analysed")),
+                                      expected_markdown_section("Read",   &read_analysis.expect("'Read' should have been analysed")),
+                                      expected_markdown_section("Update", &update_analysis.expect("'Update' should have been analysed")));
+        assert_eq!(report, expected_report, "Wrong Markdown report -- operations not analysed shouldn't have sections");
+    }
+
+    /// the section [analyse_crud_algorithms_markdown()] should report for the `operation`'s `analysis`
+    fn expected_markdown_section<T: BigOAlgorithmMeasurements>(operation: &str, analysis: &BigOAlgorithmAnalysis<T>) -> String {
+        let time_measurements = analysis.algorithm_measurements.time_measurements();
+        let space_measurements = analysis.algorithm_measurements.space_measurements();
+        [format!("\n## {}\n\n", operation),
+         "| pass | Δt | Δs |\n".to_owned(),
+         "|:----:|---:|---:|\n".to_owned(),
+         format!("| 1 | {:?} | {} |\n", time_measurements.pass_1_measurements, space_measurements.pass_1_measurements),
+         format!("| 2 | {:?} | {} |\n\n", time_measurements.pass_2_measurements, space_measurements.pass_2_measurements),
+         format!("- time complexity: `{}`\n", analysis.time_complexity.as_pretty_str()),
+         format!("- space complexity: `{}`\n", analysis.space_complexity.as_pretty_str())]
+            .concat()
+    }
+
+    /// Attests `reset_fn` is told which operation is about to run after the warmup
+    #[test]
+    #[serial]
+    fn reset_fn_receives_the_next_crud_op() {
+        let iterations_per_pass = 10000;
+        for (create_iterations_per_pass, read_iterations_per_pass, expected_crud_op) in [(iterations_per_pass, iterations_per_pass, CrudOp::Create),
+                                                                                         (0,                   iterations_per_pass, CrudOp::Read)] {
+            let reset_calls = parking_lot::Mutex::new(Vec::<(CrudOp, u32)>::new());
+            internal_analyse_crud_algorithms("MyContainer",
+                                             |crud_op, n| { reset_calls.lock().push((crud_op, n)); n },
+                                             |n| (n+1)/(n+1), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| (n+1)/(n+1), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| (n+1)/(n+1), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| (n+1)/(n+1), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             10, create_iterations_per_pass, read_iterations_per_pass, 0, 0,
+                                             1, 1, 1, 1, CrudOptions::default(),
+                                             // without creates, the set must be given
+                                             (create_iterations_per_pass == 0).then_some(ConstantSet { pass_1_set_size: 1 << 10, pass_2_set_size: 1 << 20, select_set_fn: &mut |_set_size| {} })).unwrap();
+            assert_eq!(reset_calls.into_inner(), vec![(expected_crud_op, create_iterations_per_pass / 10)], "Wrong 'reset_fn' calls");
+        }
+    }
+
+    /// Attests a retry is refused, with an explicit message, if `reset_fn` doesn't free the memory allocated by the failed attempt
+    /// -- here, `create_fn` is O(n) in time, failing the (impossible) time requirement, and `reset_fn` keeps the created elements.
+    /// Only applies if the space is analysed
+    #[test]
+    #[serial]
+    #[should_panic(expected = "`reset_fn` didn't free the memory")]
+    fn leaking_reset_fn() {
+        let vec_locker = parking_lot::RwLock::new(Vec::<u32>::new());
+        test_crud_algorithms("Leaking Vec", 2,
+                             |_n| vec_locker.read().len() as u32,
+                             |n| {
+                                 let mut vec = vec_locker.write();
+                                 let found = vec.contains(&n);
+                                 vec.push(n);
+                                 found as u32
+                             }, BigOAlgorithmComplexity::BetterThanO1, BigOAlgorithmComplexity::ON2,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             0, 4096, 0, 0, 0,
+                             1, 1, 1, 1);
+    }
+
+    /// Attests time-only analyses -- with no space expectations -- are retried even if `reset_fn` doesn't free the memory:
+    /// the same scenario as [leaking_reset_fn()], but giving up on the time requirement rather than refusing the retry
+    #[test]
+    #[serial]
+    #[should_panic(expected = "gave up retrying")]
+    fn leaking_reset_fn_on_time_only_analysis() {
+        let vec_locker = parking_lot::RwLock::new(Vec::<u32>::new());
+        test_crud_algorithms("Leaking Vec", 2,
+                             |_n| vec_locker.read().len() as u32,
+                             |n| {
+                                 let mut vec = vec_locker.write();
+                                 let found = vec.contains(&n);
+                                 vec.push(n);
+                                 found as u32
+                             }, BigOAlgorithmComplexity::BetterThanO1, BigOAlgorithmComplexity::WorseThanExponential,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                             0, 4096, 0, 0, 0,
+                             1, 1, 1, 1);
+    }
+
+    /// Attests a second pass running faster than the first is reported as a (retryable) measurement anomaly,
+    /// rather than being trusted as `BetterThanO1` -- here, only the first pass' creations are slow
+    #[test]
+    #[serial]
+    fn decreasing_time_anomaly() {
+        let crud_analysis = internal_analyse_crud_algorithms("MyContainer",
+                                                             |_crud_op, n| n,
+                                                             |n| { if n < 4 { std::thread::sleep(std::time::Duration::from_millis(1)) }; n },
+                                                                    BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             0, 4, 0, 0, 0,
+                                                             1, 1, 1, 1, CrudOptions::default(), None);
+        match crud_analysis {
+            Err(crud_analysis_error) => {
+                assert_eq!(crud_analysis_error.measurement_anomaly, Some(MeasurementAnomaly::DecreasingTime), "Wrong anomaly: {}", crud_analysis_error);
+                assert_eq!(crud_analysis_error.failed_analysis, FailedAnalysis::Time, "Anomalies should be retried, like time mismatches");
+            },
+            Ok(_) => panic!("The decreasing time should have been reported as an anomaly"),
+        }
+        let assertion = recorded_assertion("MyContainer: Create");
+        assert!(!assertion.passed && assertion.failure.is_some(), "The anomaly should have failed the recorded assertion: {:?}", assertion);
+    }
+
+    /// Attests an operation with iterations, but no threads to run them, is rejected with a clear message
+    #[test]
+    #[serial]
+    #[should_panic(expected = "the 'Update' operation has 100 iterations per pass, but 0 threads")]
+    fn zero_threads() {
+        let _ = internal_analyse_crud_algorithms("MyContainer",
+                                                 |_crud_op, n| n,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 0, 100, 100, 100, 0,
+                                                 1, 1, 0, 0, CrudOptions::default(), None);
+    }
+
+    /// Attests reads & updates without a set to operate on -- no creates & no external set -- are rejected with a clear message
+    #[test]
+    #[serial]
+    #[should_panic(expected = "the 'Read' & 'Update' operations need a set to operate on, but there are no 'Create' passes to build it")]
+    fn reads_without_creates() {
+        let _ = internal_analyse_crud_algorithms("MyContainer",
+                                                 |_crud_op, n| n,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                 0, 0, 100, 0, 0,
+                                                 1, 1, 1, 1, CrudOptions::default(), None);
+    }
+
+    /// Attests external sets of the same size on both passes are rejected -- as their analysis would divide by zero
+    #[test]
+    #[serial]
+    #[should_panic(expected = "the 'Read' & 'Update' passes must operate on distinct, non-empty sets -- but sets of 1000 & 1000 elements were given")]
+    fn same_size_external_sets() {
+        test_read_update_algorithms("MyDatabase", 1,
+                                    |_set_size| {},
+                                    |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                    |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                    1000, 1000, 100, 100, 1, 1);
+    }
+
+    /// Attests time complexity ranges are enforced on their lower bound as well:
+    /// an O(1) read is rejected when at least O(n) was expected
+    #[test]
+    #[serial]
+    fn time_complexity_lower_bound() {
+        let result = internal_analyse_crud_algorithms("MyContainer",
+                                                      |_crud_op, n| n,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n, (BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::WorseThanExponential), BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      0, 1000, 1000, 0, 0,
+                                                      1, 1, 1, 1, CrudOptions::default(), None);
+        match result {
+            Ok(_) => panic!("An O(1) read should have been rejected by the (O(n), worse than exponential) range"),
+            Err(error) => {
+                assert_eq!(error.failed_operation, "Read", "Wrong failed operation");
+                assert_eq!(error.failed_analysis, FailedAnalysis::Time, "Wrong failed analysis");
+            },
+        }
+    }
+
+    /// Attests [CrudOptions::with_max_time_coefficient()] rejects an operation matching its time complexity, but with a greater
+    /// constant factor than allowed -- reporting the measured coefficient
+    #[test]
+    #[serial]
+    fn time_coefficient_ceiling() {
+        let spin = |n: u32| {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_micros(2) {}
+            n
+        };
+        // the complexity class isn't checked, but coefficients are only estimated for the ones having a model -- so we may retry
+        let error = (0..10)
+            .filter_map(|_| internal_analyse_crud_algorithms("MyContainer",
+                                                             |_crud_op, n| n,
+                                                             |n| n,  BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             spin,   BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             |n| n,  BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             |n| n,  BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                             0, 1000, 1000, 0, 0,
+                                                             1, 1, 1, 1, CrudOptions::default().with_max_time_coefficient(CrudOp::Read, Duration::from_nanos(1)), None)
+                .err())
+            .next()
+            .expect("A 2µs read should have exceeded the 1ns coefficient ceiling");
+        assert_eq!((error.failed_operation.as_str(), error.failed_analysis), ("Read", FailedAnalysis::Time), "Coefficient excesses should be retried, like time mismatches");
+        assert!(error.failed_assertion_msg.contains("coefficient was expected to be at most 1ns -- ≈"), "The measured coefficient should be reported: '{}'", error.failed_assertion_msg);
+    }
+
+    /// Pins the exact order in which elements are created & deleted: each pass creates its elements in descending order and the
+    /// deletes, running the passes in reverse, remove them in ascending order -- the last created elements are the first ones to go
+    #[test]
+    #[serial]
+    fn crud_iteration_order() {
+        let created = parking_lot::Mutex::new(Vec::<u32>::new());
+        let deleted = parking_lot::Mutex::new(Vec::<u32>::new());
+        internal_analyse_crud_algorithms("MyContainer",
+                                         |_crud_op, n| n,
+                                         |n| { created.lock().push(n); n }, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| n,                             BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| n,                             BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| { deleted.lock().push(n); n }, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         0, 4, 0, 0, 4,
+                                         1, 1, 1, 1, CrudOptions::default(), None).unwrap();
+        assert_eq!(created.into_inner(), vec![3, 2, 1, 0, 7, 6, 5, 4], "Wrong create order");
+        assert_eq!(deleted.into_inner(), vec![4, 5, 6, 7, 0, 1, 2, 3], "Wrong delete order");
+    }
+
+    /// Attests [CrudOptions::with_parallel_operations()] runs the read & update passes concurrently -- each read waits (for a while)
+    /// until the update of the same element happened, which only takes place if they run at the same time
+    #[test]
+    #[serial]
+    fn parallel_read_and_update() {
+        let updated = (0..8).map(|_| AtomicU32::new(0)).collect::<Vec<_>>();
+        let reads_after_updates = AtomicU32::new(0);
+        let (_create_analysis, read_analysis, update_analysis, _delete_analysis, report) =
+            internal_analyse_crud_algorithms("MyContainer",
+                                             |_crud_op, n| n,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| {
+                                                 let wait_start = Instant::now();
+                                                 while updated[n as usize].load(Ordering::Relaxed) == 0 && wait_start.elapsed().as_secs() < 1 {
+                                                     std::thread::yield_now();
+                                                 }
+                                                 reads_after_updates.fetch_add(updated[n as usize].load(Ordering::Relaxed), Ordering::Relaxed)
+                                             },
+                                             BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| updated[n as usize].fetch_add(1, Ordering::Relaxed), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             0, 4, 4, 4, 0,
+                                             1, 1, 1, 1, CrudOptions::default().with_parallel_operations(true), None).unwrap();
+        assert_eq!(reads_after_updates.into_inner(), 8, "Every read should have seen its element updated by the concurrent update pass");
+        for (operation, analysis) in [("read", read_analysis), ("update", update_analysis)] {
+            let measurements = analysis.unwrap_or_else(|| panic!("'{}' should have been analysed", operation)).algorithm_measurements;
+            assert!(measurements.pass1_measurements.time_measurements > Duration::ZERO && measurements.pass2_measurements.time_measurements > Duration::ZERO,
+                    "Both '{}' passes should have been measured: {:?} & {:?}", operation, measurements.pass1_measurements.time_measurements, measurements.pass2_measurements.time_measurements);
+        }
+        let passes_report = report.split("First Pass (").nth(1).and_then(|passes| passes.split("):\n\n").next()).expect("The passes section is missing from the report");
+        for pass_report in passes_report.split("; Second Pass (") {
+            assert!(pass_report.contains("read: ") && pass_report.contains("update: "), "Parallel passes should be reported just like the sequential ones: '{}'", pass_report);
+        }
+    }
+
+    /// Attests [CrudOptions::with_workload()] feeds the reads with the workload's values -- leaving the creates sequential
+    #[test]
+    #[serial]
+    fn read_workload() {
+        let created = parking_lot::Mutex::new(Vec::new());
+        let read = parking_lot::Mutex::new(Vec::new());
+        internal_analyse_crud_algorithms("MyContainer",
+                                         |_crud_op, n| n,
+                                         |n| { created.lock().push(n); n }, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| { read.lock().push(n); n },    BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                         0, 4, 4, 0, 0,
+                                         1, 1, 1, 1, CrudOptions::default().with_workload(Workload::Reverse), None).unwrap();
+        assert_eq!(created.into_inner(), vec![3, 2, 1, 0, 7, 6, 5, 4], "Creates should not be affected by the workload");
+        assert_eq!(read.into_inner(),    vec![0, 1, 2, 3, 4, 5, 6, 7], "Reads should follow the workload -- reversing the descending order -- on each pass");
+    }
+
+    /// Attests [CrudOptions::with_sample_count()] repeats the read & update passes -- leaving the creates & deletes run once
+    #[test]
+    #[serial]
+    fn sample_count() {
+        let (creates, reads, updates, deletes) = (AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0));
+        let (_create_analysis, read_analysis, update_analysis, _delete_analysis, report) =
+            internal_analyse_crud_algorithms("MyContainer",
+                                             |_crud_op, n| n,
+                                             |n| creates.fetch_add(1, Ordering::Relaxed) ^ n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n|   reads.fetch_add(1, Ordering::Relaxed) ^ n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| updates.fetch_add(1, Ordering::Relaxed) ^ n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| deletes.fetch_add(1, Ordering::Relaxed) ^ n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             0, 4, 4, 4, 4,
+                                             1, 1, 1, 1, CrudOptions::default().with_sample_count(3), None).unwrap();
+        assert_eq!((creates.into_inner(), deletes.into_inner()), (8, 8), "Creates & deletes should have run once per pass");
+        assert_eq!((reads.into_inner(), updates.into_inner()), (24, 24), "Reads & updates should have run 3 samples per pass");
+        assert!(read_analysis.is_some() && update_analysis.is_some(), "Both read & update should have been analysed on their median samples");
+        assert_eq!(report.matches(" (median of 3)").count(), 4, "Each read & update pass should tell it was sampled: '{}'", report);
+    }
+
+    /// Attests [CrudOptions::with_aggregation()] keeps the aggregation -- comparable, as the options are `Eq` -- and that the analysis
+    /// runs its multi-threaded passes with it
+    #[test]
+    #[serial]
+    fn aggregation() {
+        let options = CrudOptions::default().with_aggregation(Aggregation::TrimmedMean(0.25));
+        assert_eq!(options.aggregation(), Aggregation::TrimmedMean(0.25), "The trimmed fraction should have been kept");
+        assert_eq!(options, CrudOptions::default().with_aggregation(Aggregation::TrimmedMean(0.25)), "Equal aggregations should give equal options");
+        assert_ne!(options, CrudOptions::default().with_aggregation(Aggregation::TrimmedMean(0.1)), "Different fractions should give different options");
+        assert_eq!(CrudOptions::default().with_aggregation(Aggregation::TrimmedMean(0.9)).aggregation(), Aggregation::TrimmedMean(0.5), "Fractions should be clamped");
+        assert_eq!(CrudOptions::default().aggregation(), Aggregation::Mean, "The mean should be the default");
+
+        let (_create_analysis, read_analysis, update_analysis, _delete_analysis, _report) =
+            internal_analyse_crud_algorithms("MyContainer",
+                                             |_crud_op, n| n,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             0, 400, 400, 400, 400,
+                                             1, 4, 4, 1, CrudOptions::default().with_aggregation(Aggregation::Median), None).unwrap();
+        assert!(read_analysis.is_some() && update_analysis.is_some(), "The multi-threaded reads & updates should have been analysed on their median thread times");
+    }
+
+    /// Attests [test_read_update_algorithms()] skips creates & deletes, selecting the given sets before each pass
+    /// -- whose sizes are the ones used in the analysis
+    #[test]
+    #[serial]
+    fn read_update_on_external_sets() {
+        let (pass_1_set, pass_2_set) = ((0..1u32 << 10).collect::<Vec<_>>(), (0..1u32 << 20).collect::<Vec<_>>());
+        let selected_set = parking_lot::RwLock::new(&pass_1_set);
+        let mut selected_set_sizes = vec![];
+        let mut select_set_fn = |set_size: u64| {
+            selected_set_sizes.push(set_size);
+            *selected_set.write() = if set_size == pass_1_set.len() as u64 { &pass_1_set } else { &pass_2_set };
+        };
+        let read_fn = |n: u32| { let set = selected_set.read(); set.binary_search(&(n % set.len() as u32)).unwrap_or_default() as u32 };
+        let (create_analysis, read_analysis, update_analysis, delete_analysis, _report) =
+            internal_analyse_crud_algorithms("MyDatabase",
+                                             |_crud_op, _n| panic!("'reset_fn' should not be called on external sets"),
+                                             |_n| panic!("'create_fn' should not be called on external sets"), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             read_fn, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             |_n| panic!("'delete_fn' should not be called on external sets"), BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                             0, 0, 1000, 0, 0,
+                                             1, 1, 1, 1, CrudOptions::default(),
+                                             Some(ConstantSet { pass_1_set_size: pass_1_set.len() as u64, pass_2_set_size: pass_2_set.len() as u64, select_set_fn: &mut select_set_fn })).unwrap();
+        assert!(create_analysis.is_none() && update_analysis.is_none() && delete_analysis.is_none(), "Only reads should have been analysed");
+        let passes_info = read_analysis.expect("Reads should have been analysed").algorithm_measurements.passes_info;
+        assert_eq!((passes_info.pass_1_set_size, passes_info.pass_2_set_size, passes_info.repetitions), (1 << 10, 1 << 20, 1000), "The external set sizes should have been analysed");
+        assert_eq!(selected_set_sizes, vec![1 << 10, 1 << 20], "Each pass should have selected its set");
+
+        test_read_update_algorithms("MyDatabase", 1,
+                                    |set_size| *selected_set.write() = if set_size == pass_1_set.len() as u64 { &pass_1_set } else { &pass_2_set },
+                                    read_fn, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::O1,
+                                    |n| n,   BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::O1,
+                                    pass_1_set.len() as u64, pass_2_set.len() as u64, 1000, 1000, 1, 1);
+    }
+
+    /// Attests a panicking operation is reported as an error -- telling the operation, the element and the panic message
+    #[test]
+    #[serial]
+    fn panicking_operation() {
+        let result = internal_analyse_crud_algorithms("MyContainer",
+                                                      |_crud_op, n| n,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| if n == 13 { panic!("element {} can't be read", n) } else { n },
+                                                      BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      0, 10, 10, 10, 10,
+                                                      1, 2, 1, 1, CrudOptions::default(), None);
+        let Err(error) = result else { panic!("A panicking read should yield an error") };
+        assert_eq!((error.failed_operation.as_str(), error.failed_analysis),
+                   ("Read", FailedAnalysis::Aborted(PassAbort::Panicked(AlgorithmPanic { element: Some(13), message: String::from("element 13 can't be read") }))),
+                   "Wrong failure identification");
+        assert_eq!(error.failed_assertion_msg, "'Read' algorithm panicked at element 13: 'element 13 can't be read'", "Wrong failure message");
+    }
+
+    /// Attests a pass exceeding its timeout aborts the analysis -- telling the operation & how far the pass got
+    #[test]
+    #[serial]
+    fn pass_timeout() {
+        let slow_read = |n: u32| {
+            std::thread::sleep(Duration::from_millis(10));
+            n
+        };
+        let result = internal_analyse_crud_algorithms("MyContainer",
+                                                      |_crud_op, n| n,
+                                                      |n| n,     BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      slow_read, BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n,     BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      |n| n,     BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+                                                      0, 1000, 1000, 0, 0,
+                                                      1, 1, 1, 1, CrudOptions::default().with_timeout(Duration::from_millis(50)), None);
+        let Err(error) = result else { panic!("A 10s read pass should have exceeded its 50ms timeout") };
+        assert_eq!(error.failed_operation, "Read", "Wrong failed operation");
+        assert!(matches!(error.failed_analysis, FailedAnalysis::Aborted(PassAbort::TimedOut(_))), "Wrong failure identification: {:?}", error.failed_analysis);
+        assert!(error.failed_assertion_msg.starts_with("'Read' pass timed out after 50ms, having completed "), "Wrong failure message: '{}'", error.failed_assertion_msg);
+        assert!(error.failed_assertion_msg.ends_with(" of its 1000 iterations"), "Wrong failure message: '{}'", error.failed_assertion_msg);
+        let assertion = recorded_assertion("MyContainer: Read");
+        assert!(!assertion.passed && assertion.observed_time_complexity.is_none(), "The aborted pass should have been recorded as a failure: {:?}", assertion);
+        assert!(assertion.failure.as_deref().is_some_and(|failure| failure.starts_with("pass timed out after 50ms")), "Wrong recorded failure: {:?}", assertion.failure);
+    }
+
+    /// the outcome `algorithm` last reported to the [ComplexityAssertionRecord::global()]
+    fn recorded_assertion(algorithm: &str) -> ComplexityAssertion {
+        ComplexityAssertionRecord::global().assertions().into_iter()
+            .find(|assertion| assertion.algorithm == algorithm)
+            .unwrap_or_else(|| panic!("'{}' should have been recorded", algorithm))
+    }
+
+    /// Attests [CrudVerdict] summarizes the analysed operations -- and only them
+    #[test]
+    #[serial]
+    fn crud_verdict() {
+        let iterations_per_pass = 10000;
+        let crud_analyses = analyse_crud_algorithms("MyContainer",
+                                                    |_n| panic!("'reset_fn' should not be called if there is no warmup taking place"),
+                                                    |n| (n+1)/(n+1),
+                                                    |n| (n+1)/(n+1),
+                                                    |_n| panic!("'update_fn' should not be called if its 'iterations_per_pass' is 0"),
+                                                    |n| (n+1)/(n+1),
+                                                    0, iterations_per_pass, iterations_per_pass, 0, iterations_per_pass,
+                                                    1, 1, 1, 1);
+        let verdict = CrudVerdict::from_tuple(&crud_analyses);
+        let (create_analysis, read_analysis, _update_analysis, _delete_analysis, _report) = &crud_analyses;
+        assert_eq!(verdict.create.map(|create| create.time_complexity), create_analysis.as_ref().map(|analysis| analysis.time_complexity), "Create verdict mismatch");
+        assert_eq!(verdict.read.map(|read| read.space_complexity), read_analysis.as_ref().map(|analysis| analysis.space_complexity), "Read verdict mismatch");
+        assert!(verdict.update.is_none(), "Update wasn't analysed, so it shouldn't have a verdict");
+        assert!(verdict.delete.is_some(), "Delete verdict is missing");
+        let expected_worst_time = [&verdict.create, &verdict.read, &verdict.delete].into_iter()
+            .map(|operation| operation.unwrap().time_complexity)
+            .max();
+        assert_eq!(verdict.worst_time(), expected_worst_time, "Wrong worst time complexity");
+        assert!(verdict.worst_space().is_some(), "Worst space complexity is missing");
+
+        let nothing_analysed = CrudVerdict { create: None, read: None, update: None, delete: None };
+        assert_eq!(nothing_analysed.worst_time(), None, "No operations means no worst time complexity");
+    }
+
     /// Attests the same number of iterations are produced regardless of the number of threads:
     ///   - 'iterations_per_pass must' be a multiple of 'n_threads'
     #[test]
@@ -687,4 +1759,23 @@ mod tests {
             assert_eq!(0, map.len(), "failed to delete records when testing for n_threads {}", n_threads);
         }
     }
+
+    /// assures [CrudOptions::with_baseline()] looks the operations up as `"<crud_name>: <operation>"` -- the missing ones falling
+    /// back to the given expectations
+    #[cfg(feature = "baseline")]
+    #[test]
+    fn baseline_expectations() {
+        let baseline = ComplexityBaseline::from_toml_str(r#"
+            ["container: Read"]
+            time  = ["O(1)", "O(log(n))"]
+            space = "O(1)"
+        "#).expect("The baseline should have been parsed");
+        let options = CrudOptions::default().with_baseline(Box::leak(Box::new(baseline)));
+        let given = (ComplexityBoundPair::at_most(BigOAlgorithmComplexity::ON), BigOAlgorithmComplexity::ON);
+        assert_eq!(options.expectations("container", CrudOp::Read, given.0, given.1),
+                   (ComplexityBoundPair::new(BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::OLogN), BigOAlgorithmComplexity::O1),
+                   "The baseline should have overridden the given expectations");
+        assert_eq!(options.expectations("container", CrudOp::Update, given.0, given.1), given, "Operations missing from the baseline should fall back");
+        assert_eq!(CrudOptions::default().expectations("container", CrudOp::Read, given.0, given.1), given, "Without a baseline, the given expectations should be kept");
+    }
 }
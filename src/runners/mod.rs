@@ -1,6 +1,19 @@
 //! Contains executors of the algorithms, gathering metrics to pass to
 //! [crate::low_level_analysis] in order to have their complexity measured
 
-pub(crate) mod common;
+pub mod aggregation;
+pub mod common;
+pub mod timing;
+pub mod output;
+pub mod record;
+#[cfg(feature = "baseline")]
+pub mod baseline;
+pub mod workload;
+#[cfg(feature = "opentelemetry")]
+pub mod telemetry;
 pub mod standard;
 pub mod crud;
+pub mod specialized;
+pub mod containers;
+#[cfg(feature = "criterion")]
+pub mod criterion_bridge;
@@ -0,0 +1,242 @@
+//! Runtime control over the analysis reports sunk through [crate::OUTPUT]: how much of them gets written -- see [Verbosity] --
+//! and where to -- see [OutputConfig].\
+//! The configuration is process-wide, as the runners report from the measuring threads as well as from the analysis one:
+//! install it with [set_output_config()]. Without one, the reports are written, verbosely, to the sink selected by the crate's
+//! features -- except for the CRUD runners, which only summarize when running in CI: see [OutputConfig::ci_verbosity()].
+
+use crate::features::{self, ALLOC};
+use std::cell::RefCell;
+use std::io::{stdout, Write};
+use std::sync::{Mutex, PoisonError};
+
+
+/// Environment variable telling the analysis is running in CI -- set by most CI providers
+pub const CI_ENV_VAR: &str = "CI";
+
+/// Environment variable that, set to `1`, keeps the CRUD runners verbose in CI -- see [OutputConfig::ci_verbosity()]
+pub const VERBOSE_ENV_VAR: &str = "BIG_O_VERBOSE";
+
+/// The configuration installed by [set_output_config()] -- `None` for the crate's features defaults
+static OUTPUT_CONFIG: Mutex<Option<OutputConfig>> = Mutex::new(None);
+
+thread_local! {
+    /// The reports held back while a [SummaryScope] is active on this thread -- `None` when not summarizing.\
+    /// Per thread, so concurrent test runs (on other test threads) don't hold back -- nor have written -- each other's reports
+    static SUMMARY: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+
+/// How much of the analysis reports is written -- each level includes the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// nothing is written -- failures are still told by the panics of the `test_*` runners
+    Silent,
+    /// only the complexity mismatches & aborted analyses are written
+    ErrorsOnly,
+    /// the reports of each `test_*` run are held back & only written if the run fails -- errors are written as they happen
+    /// by the runners not summarizing them. Summaries are per thread: runs happening concurrently (in other test threads) keep their own
+    Summary,
+    /// every intermediate measurement & analysis is written, as they happen -- the default
+    #[default]
+    Verbose,
+}
+
+/// Where, and how much of, the analysis reports are written -- see [set_output_config()]
+pub struct OutputConfig {
+    pub verbosity: Verbosity,
+    pub writer:    Box<dyn Write + Send>,
+}
+impl OutputConfig {
+
+    pub fn new(verbosity: Verbosity, writer: impl Write + Send + 'static) -> Self {
+        Self { verbosity, writer: Box::new(writer) }
+    }
+
+    /// The verbosity the CRUD runners use, in the absence of an installed configuration: [Verbosity::Summary] if running in CI
+    /// -- the [CI_ENV_VAR] environment variable being set to anything but `false` or `0` -- unless [VERBOSE_ENV_VAR] is `1`.
+    /// [Verbosity::Verbose] otherwise
+    pub fn ci_verbosity() -> Verbosity {
+        let in_ci = std::env::var(CI_ENV_VAR).is_ok_and(|ci| !ci.is_empty() && ci != "false" && ci != "0");
+        let verbose = std::env::var(VERBOSE_ENV_VAR).is_ok_and(|verbose| verbose == "1");
+        if in_ci && !verbose { Verbosity::Summary } else { Verbosity::Verbose }
+    }
+}
+impl Default for OutputConfig {
+    /// Verbose reports to stdout -- the behavior of the `report_stdout` feature
+    fn default() -> Self {
+        Self::new(Verbosity::Verbose, stdout())
+    }
+}
+
+/// Installs `config` for all runners -- replacing the sink selected by the crate's features, as well as the CI defaults
+/// of the CRUD runners.\
+/// Has no effect if the reports are disabled at compile time -- by not enabling any of the `report_*` features.
+pub fn set_output_config(config: OutputConfig) {
+    *OUTPUT_CONFIG.lock().unwrap_or_else(PoisonError::into_inner) = Some(config);
+}
+
+/// Uninstalls the configuration set by [set_output_config()] -- returning it -- restoring the crate's features defaults
+pub fn reset_output_config() -> Option<OutputConfig> {
+    OUTPUT_CONFIG.lock().unwrap_or_else(PoisonError::into_inner).take()
+}
+
+/// Writes `buf`, a report of the given `level` -- [Verbosity::Verbose] or [Verbosity::ErrorsOnly] -- either to the installed
+/// [OutputConfig]'s writer or through `default_print`, provided the verbosity allows it. While a [SummaryScope] is active, it is held back instead.
+pub(crate) fn write(level: Verbosity, default_print: fn(&str), buf: &str) {
+    let held_back = SUMMARY.with_borrow_mut(|summary| {
+        let Some(summary) = summary.as_mut() else { return false };
+        // holding back the report must not pollute the space analysis of the running algorithm
+        ALLOC.pause();
+        summary.push_str(buf);
+        ALLOC.resume();
+        true
+    });
+    if held_back {
+        return
+    }
+    let mut config = OUTPUT_CONFIG.lock().unwrap_or_else(PoisonError::into_inner);
+    let verbosity = config.as_ref().map_or(Verbosity::Verbose, |config| config.verbosity);
+    if level > verbosity {
+        return
+    }
+    match config.as_mut() {
+        Some(config) => features::locked_write(&mut config.writer, buf),
+        None => features::locked_print(default_print, buf),
+    }
+}
+
+/// Holds back the reports written while alive -- if the verbosity is [Verbosity::Summary] -- writing them when dropped
+/// only if the thread is panicking: the `test_*` runners fail by panicking.\
+/// Only the reports written by the thread that began the scope are held back. Scopes don't nest: the inner ones do nothing, leaving
+/// the decision to the outermost.
+pub(crate) struct SummaryScope {
+    summarizing: bool,
+}
+impl SummaryScope {
+
+    /// Starts summarizing if the installed [OutputConfig] asks for it -- or, without one, if `default_verbosity` does
+    pub(crate) fn begin(default_verbosity: Verbosity) -> Self {
+        let verbosity = OUTPUT_CONFIG.lock().unwrap_or_else(PoisonError::into_inner).as_ref().map_or(default_verbosity, |config| config.verbosity);
+        let summarizing = verbosity == Verbosity::Summary && SUMMARY.with_borrow_mut(|summary| {
+            let outermost = summary.is_none();
+            if outermost {
+                *summary = Some(String::new());
+            }
+            outermost
+        });
+        Self { summarizing }
+    }
+}
+impl Drop for SummaryScope {
+    fn drop(&mut self) {
+        if !self.summarizing {
+            return
+        }
+        let summary = SUMMARY.with_borrow_mut(Option::take).unwrap_or_default();
+        if std::thread::panicking() {
+            features::OUTPUT_ERROR(&summary);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [output](super) module
+
+    use super::*;
+    use serial_test::serial;
+    use std::sync::Arc;
+
+
+    /// a writer whose contents may be inspected after being given away to an [OutputConfig]
+    #[derive(Clone, Default)]
+    struct SharedSink(Arc<Mutex<Vec<u8>>>);
+    impl SharedSink {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).expect("Garbled UTF-8 output")
+        }
+    }
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// writes a verbose report & an error report, returning what got written with `verbosity`
+    fn written_with(verbosity: Verbosity) -> String {
+        let sink = SharedSink::default();
+        set_output_config(OutputConfig::new(verbosity, sink.clone()));
+        write(Verbosity::Verbose,    |_| (), "measurement; ");
+        write(Verbosity::ErrorsOnly, |_| (), "mismatch; ");
+        reset_output_config();
+        sink.contents()
+    }
+
+    /// checks each verbosity writes what it should
+    #[test]
+    #[serial]
+    fn verbosity_levels() {
+        assert_eq!(written_with(Verbosity::Silent),     "",                         "Silent should write nothing");
+        assert_eq!(written_with(Verbosity::ErrorsOnly), "mismatch; ",               "ErrorsOnly should only write the errors");
+        assert_eq!(written_with(Verbosity::Summary),    "mismatch; ",               "Outside of a summary scope, Summary should only write the errors");
+        assert_eq!(written_with(Verbosity::Verbose),    "measurement; mismatch; ",  "Verbose should write everything");
+        assert_eq!(OutputConfig::default().verbosity, Verbosity::Verbose, "The default config should be verbose");
+    }
+
+    /// assures summaries are only written if the scope ends by panicking -- and that, then, nothing is lost
+    #[test]
+    #[serial]
+    fn summary_scope() {
+        let sink = SharedSink::default();
+        set_output_config(OutputConfig::new(Verbosity::Summary, sink.clone()));
+
+        {
+            let _summary = SummaryScope::begin(Verbosity::Verbose);
+            write(Verbosity::Verbose, |_| (), "passing run; ");
+        }
+        assert_eq!(sink.contents(), "", "The reports of a passing run should be held back");
+
+        let failed_run = std::panic::catch_unwind(|| {
+            let _summary = SummaryScope::begin(Verbosity::Verbose);
+            let _inner_summary = SummaryScope::begin(Verbosity::Verbose);
+            write(Verbosity::Verbose,    |_| (), "failing run; ");
+            write(Verbosity::ErrorsOnly, |_| (), "mismatch; ");
+            panic!("complexity mismatch");
+        });
+        assert!(failed_run.is_err(), "The run should have failed");
+        reset_output_config();
+        // reports are only written if enabled by the crate's features
+        if cfg!(any(feature = "report_stdout", feature = "report_stderr")) {
+            assert_eq!(sink.contents(), "failing run; mismatch; ", "The reports of a failing run should be written, in order");
+        }
+
+        let _summary = SummaryScope::begin(Verbosity::Summary);
+        assert!(SUMMARY.with_borrow(Option::is_some), "Without an installed config, the default verbosity should be used");
+    }
+
+    /// assures the reports of other threads are neither held back by -- nor mixed into -- the summary of a thread
+    #[test]
+    #[serial]
+    fn summaries_are_per_thread() {
+        let sink = SharedSink::default();
+        set_output_config(OutputConfig::new(Verbosity::Summary, sink.clone()));
+        let failed_run = std::panic::catch_unwind(|| {
+            let _summary = SummaryScope::begin(Verbosity::Verbose);
+            write(Verbosity::Verbose, |_| (), "failing run; ");
+            std::thread::scope(|scope| { scope.spawn(|| write(Verbosity::ErrorsOnly, |_| (), "other thread's mismatch; ")); });
+            assert_eq!(sink.contents(), "other thread's mismatch; ", "Reports of other threads shouldn't be held back");
+            panic!("complexity mismatch");
+        });
+        assert!(failed_run.is_err(), "The run should have failed");
+        reset_output_config();
+        if cfg!(any(feature = "report_stdout", feature = "report_stderr")) {
+            assert_eq!(sink.contents(), "other thread's mismatch; failing run; ", "Only the thread's own reports should be in its summary");
+        }
+    }
+}
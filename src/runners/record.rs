@@ -0,0 +1,246 @@
+//! Accumulates the outcomes of the complexity assertions made by the `test_*` runners -- so a test binary running dozens of them
+//! may print an aggregated summary at the end, rather than having their results scattered through the individual reports.\
+//! See [ComplexityAssertionRecord::global()] & [ComplexityAssertionRecord::print_summary()].
+
+use crate::{
+    features::{ALLOC, OUTPUT_ERROR},
+    low_level_analysis::types::{BigOAlgorithmComplexity, ComplexityBoundPair},
+};
+use std::sync::{Mutex, PoisonError};
+
+
+/// The record every `test_*` runner reports to -- see [ComplexityAssertionRecord::global()]
+static GLOBAL_RECORD: ComplexityAssertionRecord = ComplexityAssertionRecord::new();
+
+
+/// The outcome of checking the observed complexities of an algorithm against the expected ones
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexityAssertion {
+    /// the test name -- followed by the operation, for the CRUD runners: `"MyContainer: Read"`
+    pub algorithm:                 String,
+    pub expected_time_complexity:  ComplexityBoundPair,
+    /// `None` if the analysis didn't get to observe it -- as when one of its passes was aborted
+    pub observed_time_complexity:  Option<BigOAlgorithmComplexity>,
+    pub expected_space_complexity: BigOAlgorithmComplexity,
+    /// `None` if the analysis didn't get to observe it -- or if it doesn't measure the space
+    pub observed_space_complexity: Option<BigOAlgorithmComplexity>,
+    /// why the analysis failed regardless of the observed complexities -- a time measurement anomaly or an aborted pass
+    pub failure:                   Option<String>,
+    /// `true` if there was no [Self::failure] and the observed complexities met their expectations
+    pub passed:                    bool,
+}
+impl ComplexityAssertion {
+    /// Builds the outcome of asserting the given complexities -- [Self::passed] being derived from them
+    pub fn new(algorithm:                 impl Into<String>,
+               expected_time_complexity:  impl Into<ComplexityBoundPair>,
+               observed_time_complexity:  impl Into<Option<BigOAlgorithmComplexity>>,
+               expected_space_complexity: BigOAlgorithmComplexity,
+               observed_space_complexity: impl Into<Option<BigOAlgorithmComplexity>>)
+              -> Self {
+        Self::with_failure(algorithm, expected_time_complexity, observed_time_complexity, expected_space_complexity, observed_space_complexity, None)
+    }
+
+    /// Same as [Self::new()], but failing the assertion -- whatever the observed complexities are -- if there is a `failure`
+    pub fn with_failure(algorithm:                 impl Into<String>,
+                        expected_time_complexity:  impl Into<ComplexityBoundPair>,
+                        observed_time_complexity:  impl Into<Option<BigOAlgorithmComplexity>>,
+                        expected_space_complexity: BigOAlgorithmComplexity,
+                        observed_space_complexity: impl Into<Option<BigOAlgorithmComplexity>>,
+                        failure:                   Option<String>)
+                       -> Self {
+        let expected_time_complexity = expected_time_complexity.into();
+        let observed_time_complexity = observed_time_complexity.into();
+        let observed_space_complexity = observed_space_complexity.into();
+        let passed = failure.is_none()
+            && observed_time_complexity.is_none_or(|observed| expected_time_complexity.contains(observed))
+            && observed_space_complexity.is_none_or(|observed| observed.is_at_most(expected_space_complexity));
+        Self {
+            algorithm: algorithm.into(),
+            expected_time_complexity,
+            observed_time_complexity,
+            expected_space_complexity,
+            observed_space_complexity,
+            failure,
+            passed,
+        }
+    }
+
+    /// The failed outcome of an analysis aborted before observing any complexities -- as one of its passes timed out or panicked,
+    /// as told by `reason`
+    pub fn aborted(algorithm:                 impl Into<String>,
+                   expected_time_complexity:  impl Into<ComplexityBoundPair>,
+                   expected_space_complexity: BigOAlgorithmComplexity,
+                   reason:                    impl Into<String>)
+                  -> Self {
+        Self::with_failure(algorithm, expected_time_complexity, None::<BigOAlgorithmComplexity>, expected_space_complexity, None::<BigOAlgorithmComplexity>, Some(reason.into()))
+    }
+}
+
+/// Thread-safe accumulator of [ComplexityAssertion]s -- the runners report to the [Self::global()] one.\
+/// Retried analyses report once per attempt: as an algorithm already in the record has its outcome replaced, only the last
+/// attempt is kept -- which is the one deciding if the test passes.
+pub struct ComplexityAssertionRecord {
+    assertions: Mutex<Vec<ComplexityAssertion>>,
+}
+impl ComplexityAssertionRecord {
+
+    pub const fn new() -> Self {
+        Self { assertions: Mutex::new(Vec::new()) }
+    }
+
+    /// The process-wide record all `test_*` runners report to -- shared by all the tests of a test binary.\
+    /// To print its summary after all tests ran, call [Self::print_summary()] from a test marked as `#[serial(last)]` or from a
+    /// custom test harness.
+    pub fn global() -> &'static Self {
+        &GLOBAL_RECORD
+    }
+
+    /// Adds `assertion` to the record -- replacing the previous outcome of the same algorithm, if any
+    pub fn record(&self, assertion: ComplexityAssertion) {
+        // recording may happen between the passes of other operations -- so it must not pollute their space analysis
+        ALLOC.pause();
+        let mut assertions = self.assertions.lock().unwrap_or_else(PoisonError::into_inner);
+        match assertions.iter_mut().find(|recorded| recorded.algorithm == assertion.algorithm) {
+            Some(recorded) => *recorded = assertion,
+            None => assertions.push(assertion),
+        }
+        drop(assertions);
+        ALLOC.resume();
+    }
+
+    /// The recorded assertions, in the order their algorithms were first reported
+    pub fn assertions(&self) -> Vec<ComplexityAssertion> {
+        self.assertions.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Forgets all recorded assertions
+    pub fn clear(&self) {
+        self.assertions.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Renders the recorded assertions as an aligned table -- one line per algorithm, with its expected & observed complexities and
+    /// verdict -- preceded by the passed & failed counts
+    pub fn summary(&self) -> String {
+        let assertions = self.assertions();
+        let header = ["algorithm", "expected time", "observed time", "expected space", "observed space", "verdict"];
+        let rows = assertions.iter()
+            .map(|assertion| [
+                assertion.algorithm.clone(),
+                assertion.expected_time_complexity.to_string(),
+                assertion.observed_time_complexity.map_or("-", |observed| observed.as_pretty_str()).to_string(),
+                format!("maximum: {:?}", assertion.expected_space_complexity),
+                assertion.observed_space_complexity.map_or("-", |observed| observed.as_pretty_str()).to_string(),
+                match (assertion.passed, &assertion.failure) {
+                    (true, _) => String::from("PASS"),
+                    (false, None) => String::from("FAIL"),
+                    (false, Some(failure)) => format!("FAIL: {}", failure),
+                },
+            ])
+            .collect::<Vec<_>>();
+        let mut widths = header.map(str::chars).map(Iterator::count);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let render_line = |cells: [&str; 6]| {
+            let line = cells.iter().zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            format!("{}\n", line.trim_end())
+        };
+        let passed = assertions.iter().filter(|assertion| assertion.passed).count();
+        let mut summary = format!("Complexity assertions: {} passed, {} failed\n", passed, assertions.len() - passed);
+        summary.push_str(&render_line(header));
+        summary.push_str(&render_line(widths.map(|width| "-".repeat(width)).each_ref().map(String::as_str)));
+        for row in &rows {
+            summary.push_str(&render_line(row.each_ref().map(String::as_str)));
+        }
+        summary
+    }
+
+    /// Writes the [Self::summary()] -- even if the [crate::runners::output::Verbosity] only allows errors to be written
+    pub fn print_summary(&self) {
+        OUTPUT_ERROR(&self.summary());
+    }
+}
+impl Default for ComplexityAssertionRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [record](super) module
+
+    use super::*;
+    use crate::BigOAlgorithmComplexity::*;
+    use serial_test::serial;
+
+
+    /// assures outcomes are judged against their expectations -- and that retried algorithms keep only their last outcome
+    #[test]
+    fn recording() {
+        let record = ComplexityAssertionRecord::new();
+        record.record(ComplexityAssertion::new("vec push", O1, ON, O1, O1));
+        record.record(ComplexityAssertion::new("hash map get", (O1, OLogN), O1, O1, O1));
+        record.record(ComplexityAssertion::new("vec push", O1, O1, O1, O1));
+        let outcomes = record.assertions().into_iter()
+            .map(|assertion| (assertion.algorithm, assertion.passed))
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![(String::from("vec push"), true), (String::from("hash map get"), true)], "The retry should have replaced the failed outcome");
+        record.record(ComplexityAssertion::new("hash map get", O1, O1, BetterThanO1, O1));
+        assert!(!record.assertions()[1].passed, "Exceeding the expected space should fail");
+        record.clear();
+        assert!(record.assertions().is_empty(), "Nothing should be left after clearing");
+    }
+
+    /// assures failures -- time anomalies & aborted passes -- fail the assertion, whatever the observed complexities are
+    #[test]
+    fn failures() {
+        let anomalous = ComplexityAssertion::with_failure("vec push", O1, O1, O1, O1, Some(String::from("untrustworthy TIME measurements")));
+        assert!(!anomalous.passed, "A failure should fail the assertion, even if the complexities matched");
+        let aborted = ComplexityAssertion::aborted("vec sort", ONLogN, ON, "pass timed out after 50ms");
+        assert!(!aborted.passed, "Aborted analyses should fail");
+        assert_eq!((aborted.observed_time_complexity, aborted.observed_space_complexity), (None, None), "Nothing should have been observed");
+        let unmeasured_space = ComplexityAssertion::new("bsearch", OLogN, OLogN, O1, None::<BigOAlgorithmComplexity>);
+        assert!(unmeasured_space.passed, "Complexities that weren't observed shouldn't fail the assertion");
+
+        let record = ComplexityAssertionRecord::new();
+        record.record(aborted);
+        let summary = record.summary();
+        let cells = summary.lines().last().unwrap().split(" | ").map(str::trim).collect::<Vec<_>>();
+        assert_eq!(cells, vec!["vec sort", "maximum: ONLogN", "-", "maximum: ON", "-", "FAIL: pass timed out after 50ms"],
+                   "Unobserved complexities should be rendered as '-' -- and the failure told along with the verdict:\n{}", summary);
+    }
+
+    /// checks the summary counts the verdicts & lines up the table
+    #[test]
+    fn summary() {
+        let record = ComplexityAssertionRecord::new();
+        record.record(ComplexityAssertion::new("bsearch", OLogN, OLogN, O1, O1));
+        record.record(ComplexityAssertion::new("MyContainer: Read", O1, ON, O1, O1));
+        let summary = record.summary();
+        let lines = summary.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "Complexity assertions: 1 passed, 1 failed", "Wrong counts");
+        assert_eq!(lines.len(), 5, "The counts, the header, the separator & one line per algorithm were expected:\n{}", summary);
+        assert!(lines[2].starts_with("----------------- | "), "Columns should be as wide as their widest cell:\n{}", summary);
+        assert!(lines[3].starts_with("bsearch           | maximum: OLogN") && lines[3].ends_with("| PASS"), "Wrong line for 'bsearch':\n{}", summary);
+        assert!(lines[4].starts_with("MyContainer: Read | maximum: O1") && lines[4].ends_with("| FAIL"), "Wrong line for the CRUD operation:\n{}", summary);
+    }
+
+    /// assures the runners report to the global record
+    #[test]
+    #[serial]
+    fn runners_report() {
+        crate::test_algorithm_no_space("record's constant algorithm", 3, |n| n, 1000, 2000, WorseThanExponential);
+        let assertion = ComplexityAssertionRecord::global().assertions().into_iter()
+            .find(|assertion| assertion.algorithm == "record's constant algorithm")
+            .expect("The runner should have reported its outcome");
+        assert!(assertion.passed, "The assertion should have passed: {:?}", assertion);
+    }
+}
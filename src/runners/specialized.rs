@@ -0,0 +1,88 @@
+//! Convenience runners for common families of algorithms -- sparing users from writing the boilerplate to feed
+//! (and to check) them.\
+//! See `tests/big-o-tests.rs` for examples.
+
+use crate::{
+    low_level_analysis::types::BigOAlgorithmComplexity,
+    runners::standard::{test_algorithm, test_constant_set_iterator_algorithm_with_context},
+};
+use std::{
+    cell::RefCell,
+    hint::black_box,
+};
+
+
+/// Analyses the `sort_fn` sorting algorithm with [test_algorithm()], sorting `pass1_n` elements on the first pass
+/// and the double of that on the second.\
+/// Input data is built by `data_generator(n)` when resetting -- so it isn't accounted for in the measurements, as
+/// isn't the verification that `sort_fn` really sorted it: done after each attempt, panicking if any pass' output is unsorted.
+pub fn test_sort_algorithm<T: Ord>(test_name:                 &str,
+                                   max_retry_attempts:        u32,
+                                   sort_fn:                   impl Fn(&mut [T]),
+                                   data_generator:            impl Fn(usize) -> Vec<T>,
+                                   pass1_n:                   u64,
+                                   expected_time_complexity:  BigOAlgorithmComplexity,
+                                   expected_space_complexity: BigOAlgorithmComplexity) {
+    let pass2_n = pass1_n * 2;
+    let pass1_data = RefCell::new(Vec::<T>::new());
+    let pass2_data = RefCell::new(Vec::<T>::new());
+    // tells if the data was sorted since it was last verified
+    let sorted = RefCell::new(false);
+
+    let verify = || {
+        if sorted.replace(false) {
+            for (pass, data) in [(1, &pass1_data), (2, &pass2_data)] {
+                assert!(data.borrow().windows(2).all(|w| w[0] <= w[1]),
+                        "'{}': `sort_fn` didn't sort the {} elements of pass {}", test_name, data.borrow().len(), pass);
+            }
+        }
+    };
+    let sort = |data: &RefCell<Vec<T>>| {
+        let mut data = data.borrow_mut();
+        sort_fn(black_box(&mut data));
+        *sorted.borrow_mut() = true;
+        data.len() as u32
+    };
+
+    test_algorithm(test_name, max_retry_attempts,
+                   || {
+                       verify();
+                       // frees the previous attempt's data before generating the new one, so the memory may be reused
+                       *pass1_data.borrow_mut() = Vec::new();
+                       *pass2_data.borrow_mut() = Vec::new();
+                       *pass1_data.borrow_mut() = data_generator(pass1_n as usize);
+                       *pass2_data.borrow_mut() = data_generator(pass2_n as usize);
+                   },
+                   pass1_n, || sort(&pass1_data),
+                   pass2_n, || sort(&pass2_data),
+                   expected_time_complexity, expected_space_complexity);
+    verify();
+}
+
+/// Analyses the `search_fn(key, container)` search algorithm -- a binary search, a tree or hash table lookup... -- with
+/// [test_constant_set_iterator_algorithm_with_context()]: `pass_1_n` & `pass_2_n` are the sizes of the sets the searches run on
+/// -- not the number of searches.\
+/// `container` should be pre-populated by the caller, holding the sets of both passes -- like a `(pass_1_set, pass_2_set)` tuple --
+/// which `search_fn` tells apart by the searched `key`s: the last elements of each set, `pass_1_n-repetitions..pass_1_n` on the first
+/// pass, then `pass_2_n-repetitions..pass_2_n` -- where `repetitions` is the smallest of `pass_1_n` & `pass_2_n - pass_1_n`, so keys
+/// below `pass_1_n` always belong to the first pass.
+#[allow(clippy::too_many_arguments)]
+pub fn test_search_algorithm<T, C: Sync>(test_name:                 &str,
+                                         max_retry_attempts:        u32,
+                                         container:                 &C,
+                                         search_fn:                 impl Fn(u32, &C) -> T + Sync,
+                                         pass_1_n:                  u32,
+                                         pass_2_n:                  u32,
+                                         expected_time_complexity:  BigOAlgorithmComplexity,
+                                         expected_space_complexity: BigOAlgorithmComplexity) {
+    assert!(pass_2_n > pass_1_n, "'{}': `pass_2_n` ({}) should be greater than `pass_1_n` ({})", test_name, pass_2_n, pass_1_n);
+    let repetitions = pass_1_n.min(pass_2_n - pass_1_n);
+    test_constant_set_iterator_algorithm_with_context(test_name, max_retry_attempts,
+                                                      container,
+                                                      |key, container| {
+                                                          black_box(search_fn(key, container));
+                                                          key
+                                                      },
+                                                      pass_1_n, pass_2_n, repetitions, 1,
+                                                      expected_time_complexity, expected_space_complexity);
+}
@@ -1,46 +1,665 @@
 //! Knows how to run & measure regular, non-iterator algorithms for the purpose of having their complexities analysed.\
 // //! See [tests] and `tests/big-o-tests.rs` for examples.
 
-use std::time::Duration;
+use std::{
+    cell::Cell,
+    ops::Range,
+    time::Duration,
+};
 use keen_retry::{loggable_retry_errors, ResolvedResult, RetryProducerResult, RetryResult};
 use crate::{
-    features::{OUTPUT},
+    api::{GithubActionsReporter, Reporter},
+    features::{OUTPUT, OUTPUT_ERROR},
     low_level_analysis::{
         self,
         types::{
             BigOAlgorithmAnalysis,
             BigOAlgorithmComplexity,
+            ComplexityBoundPair,
             AlgorithmPassesInfo,
             AlgorithmMeasurements,
             BigOTimeMeasurements,
             BigOSpaceMeasurements,
+            BigOIteratorAlgorithmType,
+            BigOAlgorithmMeasurements,
+            ConstantSetIteratorAlgorithmPassesInfo,
+            ConstantSetIteratorAlgorithmMeasurements,
+            SetResizingIteratorAlgorithmPassesInfo,
+            SetResizingIteratorAlgorithmMeasurements,
         },
     },
-    runners::common::*
+    runners::{
+        common::*,
+        record::{ComplexityAssertion, ComplexityAssertionRecord},
+    },
 };
 use crate::low_level_analysis::types::BigOPassMeasurements;
+use crate::runners::aggregation::Aggregation;
+#[cfg(feature = "baseline")]
+use crate::runners::baseline::ComplexityBaseline;
+
+/// Opt-in behaviours for the [test_algorithm()] family of runners -- see [test_algorithm_with_options()] & friends.\
+/// The defaults behave just like the runners not taking options.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct AlgorithmOptions {
+    /// see [Self::with_timeout()]
+    pub timeout: Option<Duration>,
+    /// see [Self::with_repetitions_per_measurement()] -- 0 behaves as 1
+    pub repetitions_per_measurement: u32,
+    /// see [Self::with_baseline()]
+    #[cfg(feature = "baseline")]
+    pub baseline: Option<&'static ComplexityBaseline>,
+}
+impl AlgorithmOptions {
+    /// Aborts the analysis if any of its passes runs for longer than `timeout`, reporting how far it got: so a misconfigured analysis
+    /// -- like an `O(n³)` algorithm given a large `n` -- fails fast rather than hanging the test suite. Timeouts are not retried.\
+    /// The timeout is best-effort: passes iterating over elements check it every [DEADLINE_CHECK_INTERVAL] iterations -- so a single
+    /// iteration that never returns can't be interrupted -- while passes of a single call (and the allocation-free ones, see
+    /// [test_algorithm_no_space()]) can't be interrupted at all: they are only noticed exceeding it once they return.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Runs each pass `repetitions` times, keeping the fastest -- for CPU-bound micro-benchmarks, the minimum time is the most stable
+    /// estimator, filtering out scheduler interference: see [run_repeated_sync_pass_verbosely()]. Defaults to 1.\
+    /// Only the passes made of a single call are repeated -- the ones of [test_algorithm()], [test_algorithm_with_setup_teardown()]
+    /// & [test_algorithm_growing_n()] -- calling, untimed, `reset_fn` or `teardown_fn` & `setup_fn` in-between the repetitions.
+    /// The timeout, if any, applies to the fastest repetition.
+    pub fn with_repetitions_per_measurement(mut self, repetitions: u32) -> Self {
+        self.repetitions_per_measurement = repetitions;
+        self
+    }
+    /// Takes the expected time & space complexities from the `test_name` entry of `baseline` -- a `static`, usually lazily loaded,
+    /// shared by the whole test run: see [ComplexityBaseline]. Complexities missing from the baseline keep the ones given to the runner.\
+    /// Time bounds stated by the baseline are honored, also rejecting algorithms that perform better than expected.
+    /// Requires the `baseline` feature.
+    #[cfg(feature = "baseline")]
+    pub fn with_baseline(mut self, baseline: &'static ComplexityBaseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+    /// the expected complexities of `test_name` -- taken from the [Self::with_baseline()], if it states them
+    fn expectations(&self, test_name: &str, expected_time_complexity: BigOAlgorithmComplexity, expected_space_complexity: BigOAlgorithmComplexity)
+                   -> (ComplexityBoundPair, BigOAlgorithmComplexity) {
+        #[cfg(feature = "baseline")]
+        if let Some(baseline) = self.baseline {
+            return (baseline.time_complexity_or(test_name, expected_time_complexity), baseline.space_complexity_or(test_name, expected_space_complexity))
+        }
+        #[cfg(not(feature = "baseline"))]
+        let _ = test_name;
+        (expected_time_complexity.into(), expected_space_complexity)
+    }
+}
 
 /// TODO
+#[track_caller]
 pub fn test_constant_set_iterator_algorithm() {}
 
-/// TODO
-pub fn test_set_resizing_iterator_algorithm() {}
+/// Analyses a set resizing iterator algorithm -- like inserts, pushes or enqueues -- calling `algorithm(n)` over three consecutive
+/// segments of `delta_set_size` elements: `0..delta_set_size`, then up to `2*delta_set_size` and, finally, up to `3*delta_set_size`.\
+/// Each pair of consecutive segments is analysed and the worst complexity among them is matched against the expected ones -- catching
+/// data structures whose complexity spikes only at a specific occupancy range (like hash tables near their load-factor threshold),
+/// which could appear `O(1)` when analysed over just 2 passes.\
+/// `reset_fn` is called before each attempt, to restore the empty set. Each segment is split among `threads`.\
+/// The memory the runner allocates for each segment is discounted from its space measurement -- see [measure_runner_space_overhead()].\
+/// Like [test_algorithm()], time mismatches are retried up to `max_retry_attempts`, while space mismatches fail right away.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_set_resizing_iterator_algorithm(test_name:                 &str,
+                                            max_retry_attempts:        u32,
+                                            mut reset_fn:              impl FnMut(),
+                                            algorithm:                 impl Fn(u32) -> u32 + Sync,
+                                            delta_set_size:            u32,
+                                            threads:                   u32,
+                                            expected_time_complexity:  BigOAlgorithmComplexity,
+                                            expected_space_complexity: BigOAlgorithmComplexity) {
+    let expected_time_complexity = ComplexityBoundPair::from(expected_time_complexity);
+    // the 3rd segment is accounted for as a full warmup
+    output_runner_estimate(test_name, dry_run_estimate(delta_set_size, threads, 100));
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_set_resizing_iterator_algorithm(test_name, &mut reset_fn, &algorithm, delta_set_size, threads, expected_time_complexity, expected_space_complexity, &attempts_reporter)
+        .retry_with(|_| analyse_set_resizing_iterator_algorithm(test_name, &mut reset_fn, &algorithm, delta_set_size, threads, expected_time_complexity, expected_space_complexity, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Same as [test_set_resizing_iterator_algorithm()], but also asserting the correctness of each operation -- so a degenerate
+/// implementation, doing no real work, isn't mistaken for an `O(1)` one: `verify_fn(n, r)` receives the element `n` and the value
+/// `r` returned by `algorithm(n)`, panicking with "Correctness check failed at iteration {n}: expected true" if it returns `false`.\
+/// As it runs along with the algorithm, `verify_fn` is also measured -- keep it cheap, like checking the container's new length.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_set_resizing_iterator_algorithm_with_verification(test_name:                 &str,
+                                                              max_retry_attempts:        u32,
+                                                              reset_fn:                  impl FnMut(),
+                                                              algorithm:                 impl Fn(u32) -> u32 + Sync,
+                                                              verify_fn:                 impl Fn(u32, u32) -> bool + Sync,
+                                                              delta_set_size:            u32,
+                                                              threads:                   u32,
+                                                              expected_time_complexity:  BigOAlgorithmComplexity,
+                                                              expected_space_complexity: BigOAlgorithmComplexity) {
+    let verified_algorithm = |n| {
+        let r = algorithm(n);
+        if !verify_fn(n, r) {
+            correctness_check_failed(n);
+        }
+        r
+    };
+    test_set_resizing_iterator_algorithm(test_name, max_retry_attempts, reset_fn, verified_algorithm, delta_set_size, threads, expected_time_complexity, expected_space_complexity)
+}
+
+/// The failure path of [test_set_resizing_iterator_algorithm_with_verification()] -- kept out of line, so the measured loop only
+/// pays for `verify_fn()`: the panic message is only formatted (and allocated) when a check fails
+#[cold]
+#[inline(never)]
+fn correctness_check_failed(n: u32) -> ! {
+    panic!("Correctness check failed at iteration {n}: expected true")
+}
+
+/// Analyses a constant set iterator algorithm -- like a search or a query -- that needs a shared, read-only `context`
+/// (a pre-built tree or a sorted vector, for instance), given by reference to every `algorithm(n, context)` call:
+/// no `Arc` wrapping nor `'static` lifetimes are required.\
+/// `algorithm` is called `repetitions` times on each pass, split among `threads`, with `n` ranging over the last `repetitions`
+/// elements of the set -- `pass1_set_size-repetitions..pass1_set_size` on the first pass, then the same for `pass2_set_size`
+/// -- so `n` may be used to tell which portion of the `context` belongs to each set. The set sizes may be in any ratio, as long as
+/// they differ: prefer `4x` or more if `2x` doesn't overcome the noise.\
+/// Like [test_algorithm()], time mismatches are retried up to `max_retry_attempts`, while space mismatches fail right away.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_constant_set_iterator_algorithm_with_context<C: Sync>
+                                                        (test_name:                 &str,
+                                                         max_retry_attempts:        u32,
+                                                         context:                   &C,
+                                                         algorithm:                 impl Fn(u32, &C) -> u32 + Sync,
+                                                         pass1_set_size:            u32,
+                                                         pass2_set_size:            u32,
+                                                         repetitions:               u32,
+                                                         threads:                   u32,
+                                                         expected_time_complexity:  BigOAlgorithmComplexity,
+                                                         expected_space_complexity: BigOAlgorithmComplexity) {
+    assert!(repetitions <= pass1_set_size && repetitions <= pass2_set_size,
+            "'{}': `repetitions` ({}) should not exceed any of the set sizes ({} & {})", test_name, repetitions, pass1_set_size, pass2_set_size);
+    assert_ne!(pass1_set_size, pass2_set_size, "'{}': the passes should run on sets of different sizes -- in any ratio", test_name);
+    let algorithm = |n| algorithm(n, context);
+    let expected_time_complexity = ComplexityBoundPair::from(expected_time_complexity);
+    output_runner_estimate(test_name, dry_run_estimate(repetitions, threads, 0));
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_constant_set_iterator_algorithm(test_name, pass1_set_size, pass2_set_size, repetitions, threads, &algorithm, expected_time_complexity, expected_space_complexity, &attempts_reporter)
+        .retry_with(|_| analyse_constant_set_iterator_algorithm(test_name, pass1_set_size, pass2_set_size, repetitions, threads, &algorithm, expected_time_complexity, expected_space_complexity, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
 
 /// Runs [analyse_algorithm()], trying to match the given maximum time & space complexities to the ones observed in runtime when running the algorithm
 /// -- retrying as much as `max_retry_attempts` to avoid flaky test results.\
-/// /// In case of rejection, a detailed run log with measurements & analysis results is issued.
+/// /// In case of rejection, a detailed run log with measurements & analysis results is issued.\
+/// The set sizes are `u64`s -- convert `usize` ones with `as u64` -- so sets beyond `u32::MAX` elements may be analysed.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
 pub fn test_algorithm(test_name:                 &str,
                       max_retry_attempts:        u32,
-                      mut reset_fn:              impl FnMut(),
-                      pass1_set_size:            u32,
-                      mut pass1_algorithm:       impl FnMut() -> u32,
-                      pass2_set_size:            u32,
-                      mut pass2_algorithm:       impl FnMut() -> u32,
+                      reset_fn:                  impl FnMut(),
+                      pass1_set_size:            u64,
+                      pass1_algorithm:           impl FnMut() -> u32,
+                      pass2_set_size:            u64,
+                      pass2_algorithm:           impl FnMut() -> u32,
                       expected_time_complexity:  BigOAlgorithmComplexity,
                       expected_space_complexity: BigOAlgorithmComplexity) {
-    let result = analyse_algorithm(test_name, &mut reset_fn, pass1_set_size, &mut pass1_algorithm, pass2_set_size, &mut pass2_algorithm, expected_time_complexity, expected_space_complexity)
-        .retry_with(|_| analyse_algorithm(test_name, &mut reset_fn, pass1_set_size, &mut pass1_algorithm, pass2_set_size, &mut pass2_algorithm, expected_time_complexity, expected_space_complexity))
+    test_algorithm_with_options(test_name, max_retry_attempts, reset_fn, pass1_set_size, pass1_algorithm, pass2_set_size, pass2_algorithm,
+                                expected_time_complexity, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm()], with the opt-in behaviours of `options`
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_with_options(test_name:                 &str,
+                                   max_retry_attempts:        u32,
+                                   mut reset_fn:              impl FnMut(),
+                                   pass1_set_size:            u64,
+                                   mut pass1_algorithm:       impl FnMut() -> u32,
+                                   pass2_set_size:            u64,
+                                   mut pass2_algorithm:       impl FnMut() -> u32,
+                                   expected_time_complexity:  BigOAlgorithmComplexity,
+                                   expected_space_complexity: BigOAlgorithmComplexity,
+                                   options:                   AlgorithmOptions) {
+    let (expected_time_complexity, expected_space_complexity) = options.expectations(test_name, expected_time_complexity, expected_space_complexity);
+    // each pass is a single call
+    output_runner_estimate(test_name, dry_run_estimate(1, 1, 0));
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_algorithm(test_name, &mut reset_fn, pass1_set_size, &mut pass1_algorithm, pass2_set_size, &mut pass2_algorithm, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_algorithm(test_name, &mut reset_fn, pass1_set_size, &mut pass1_algorithm, pass2_set_size, &mut pass2_algorithm, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Similar to [test_algorithm()], but for algorithms needing a fresh state on each pass -- like measuring inserts into a newly
+/// populated container: `setup_fn(n)` is called before, and `teardown_fn(n)` after, each `algorithm(n)` pass -- with `n` being
+/// `pass1_n`, then `pass2_n`.\
+/// Neither the setup nor the teardown are measured: only the time & allocations of `algorithm` are analysed.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_with_setup_teardown(test_name:                 &str,
+                                          max_retry_attempts:        u32,
+                                          setup_fn:                  impl FnMut(u64),
+                                          algorithm:                 impl FnMut(u64) -> u32,
+                                          teardown_fn:               impl FnMut(u64),
+                                          pass1_n:                   u64,
+                                          pass2_n:                   u64,
+                                          expected_time_complexity:  BigOAlgorithmComplexity,
+                                          expected_space_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_with_setup_teardown_and_options(test_name, max_retry_attempts, setup_fn, algorithm, teardown_fn, pass1_n, pass2_n,
+                                                   expected_time_complexity, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_with_setup_teardown()], with the opt-in behaviours of `options`
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_with_setup_teardown_and_options(test_name:                 &str,
+                                                      max_retry_attempts:        u32,
+                                                      mut setup_fn:              impl FnMut(u64),
+                                                      mut algorithm:             impl FnMut(u64) -> u32,
+                                                      mut teardown_fn:           impl FnMut(u64),
+                                                      pass1_n:                   u64,
+                                                      pass2_n:                   u64,
+                                                      expected_time_complexity:  BigOAlgorithmComplexity,
+                                                      expected_space_complexity: BigOAlgorithmComplexity,
+                                                      options:                   AlgorithmOptions) {
+    let (expected_time_complexity, expected_space_complexity) = options.expectations(test_name, expected_time_complexity, expected_space_complexity);
+    // each pass is a single call
+    output_runner_estimate(test_name, dry_run_estimate(1, 1, 0));
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_algorithm_with_setup_teardown(test_name, &mut setup_fn, &mut algorithm, &mut teardown_fn, pass1_n, pass2_n, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_algorithm_with_setup_teardown(test_name, &mut setup_fn, &mut algorithm, &mut teardown_fn, pass1_n, pass2_n, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter))
         .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Similar to [test_algorithm_with_setup_teardown()], without the setup & teardown: for recursive or otherwise non-iterator algorithms,
+/// like `fib(n)` or `sort(n)`, whose work grows with the `n` they are given -- rather than with how many times they are called.\
+/// `algorithm(n)` is called once per pass -- with `n` being `pass1_n`, then `pass2_n` -- and the single call's time & space
+/// are analysed as in the theoretical [low_level_analysis::time_analysis::analyse_time_complexity()] tests.
+#[track_caller]
+pub fn test_algorithm_growing_n(test_name:                 &str,
+                                max_retry_attempts:        u32,
+                                algorithm:                 impl Fn(u32) -> u32,
+                                pass1_n:                   u32,
+                                pass2_n:                   u32,
+                                expected_time_complexity:  BigOAlgorithmComplexity,
+                                expected_space_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_growing_n_with_options(test_name, max_retry_attempts, algorithm, pass1_n, pass2_n, expected_time_complexity, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_growing_n()], with the opt-in behaviours of `options`
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_growing_n_with_options(test_name:                 &str,
+                                             max_retry_attempts:        u32,
+                                             algorithm:                 impl Fn(u32) -> u32,
+                                             pass1_n:                   u32,
+                                             pass2_n:                   u32,
+                                             expected_time_complexity:  BigOAlgorithmComplexity,
+                                             expected_space_complexity: BigOAlgorithmComplexity,
+                                             options:                   AlgorithmOptions) {
+    let (expected_time_complexity, expected_space_complexity) = options.expectations(test_name, expected_time_complexity, expected_space_complexity);
+    // each pass is a single call
+    output_runner_estimate(test_name, dry_run_estimate(1, 1, 0));
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_algorithm_growing_n(test_name, &algorithm, pass1_n, pass2_n, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_algorithm_growing_n(test_name, &algorithm, pass1_n, pass2_n, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Similar to [test_algorithm()], but for algorithms whose efficiency is only visible under parallelism:
+/// `algorithm` is called once for each element in `0..pass1_n` (then `0..pass2_n`), with the work split
+/// among `pass1_threads` (then `pass2_threads`) threads -- so each pass may run with its own thread count.\
+/// The time measured for each pass is the average time spent by each of its threads.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_threaded(test_name:                 &str,
+                               max_retry_attempts:        u32,
+                               reset_fn:                  impl FnMut(),
+                               pass1_n:                   u32,
+                               pass1_threads:             u32,
+                               pass2_n:                   u32,
+                               pass2_threads:             u32,
+                               algorithm:                 impl Fn(u32) -> u32 + Sync,
+                               expected_time_complexity:  BigOAlgorithmComplexity,
+                               expected_space_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_threaded_with_options(test_name, max_retry_attempts, reset_fn, pass1_n, pass1_threads, pass2_n, pass2_threads, algorithm,
+                                         expected_time_complexity, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_threaded()], with the opt-in behaviours of `options`
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_threaded_with_options(test_name:                 &str,
+                                            max_retry_attempts:        u32,
+                                            mut reset_fn:              impl FnMut(),
+                                            pass1_n:                   u32,
+                                            pass1_threads:             u32,
+                                            pass2_n:                   u32,
+                                            pass2_threads:             u32,
+                                            algorithm:                 impl Fn(u32) -> u32 + Sync,
+                                            expected_time_complexity:  BigOAlgorithmComplexity,
+                                            expected_space_complexity: BigOAlgorithmComplexity,
+                                            options:                   AlgorithmOptions) {
+    let (expected_time_complexity, expected_space_complexity) = options.expectations(test_name, expected_time_complexity, expected_space_complexity);
+    output_runner_estimate(test_name, (dry_run_estimate(pass1_n, pass1_threads, 0) + dry_run_estimate(pass2_n, pass2_threads, 0)) / 2);
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_threaded_algorithm(test_name, &mut reset_fn, pass1_n, pass1_threads, pass2_n, pass2_threads, &algorithm, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_threaded_algorithm(test_name, &mut reset_fn, pass1_n, pass1_threads, pass2_n, pass2_threads, &algorithm, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Similar to [test_algorithm_threaded()], but for algorithms operating on a context -- a connection pool, a pre-populated container
+/// -- that `reset_fn()` builds from scratch: no `Mutex` is needed to replace the captured state, as the returned context is given by
+/// reference to every `algorithm(n, context)` call.\
+/// `reset_fn` is called before each attempt -- the new context replacing the one from the previous attempt -- then `algorithm` is
+/// called once for each element in `0..pass1_n` (then `0..pass2_n`), split among `threads`.\
+/// Building the context is not part of the analysis: only the time & allocations of `algorithm` are measured.
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_contextual<C: Send + Sync>(test_name:                 &str,
+                                                 max_retry_attempts:        u32,
+                                                 reset_fn:                  impl FnMut() -> C,
+                                                 algorithm:                 impl Fn(u32, &C) -> u32 + Sync,
+                                                 pass1_n:                   u32,
+                                                 pass2_n:                   u32,
+                                                 threads:                   u32,
+                                                 expected_time_complexity:  BigOAlgorithmComplexity,
+                                                 expected_space_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_contextual_with_options(test_name, max_retry_attempts, reset_fn, algorithm, pass1_n, pass2_n, threads,
+                                           expected_time_complexity, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_contextual()], with the opt-in behaviours of `options`
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+pub fn test_algorithm_contextual_with_options<C: Send + Sync>(test_name:                 &str,
+                                                              max_retry_attempts:        u32,
+                                                              mut reset_fn:              impl FnMut() -> C,
+                                                              algorithm:                 impl Fn(u32, &C) -> u32 + Sync,
+                                                              pass1_n:                   u32,
+                                                              pass2_n:                   u32,
+                                                              threads:                   u32,
+                                                              expected_time_complexity:  BigOAlgorithmComplexity,
+                                                              expected_space_complexity: BigOAlgorithmComplexity,
+                                                              options:                   AlgorithmOptions) {
+    let (expected_time_complexity, expected_space_complexity) = options.expectations(test_name, expected_time_complexity, expected_space_complexity);
+    output_runner_estimate(test_name, (dry_run_estimate(pass1_n, threads, 0) + dry_run_estimate(pass2_n, threads, 0)) / 2);
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_contextual_algorithm(test_name, &mut reset_fn, &algorithm, pass1_n, pass2_n, threads, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_contextual_algorithm(test_name, &mut reset_fn, &algorithm, pass1_n, pass2_n, threads, expected_time_complexity, expected_space_complexity, &options, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Similar to [test_algorithm_with_setup_teardown()], but for algorithms whose time is irrelevant -- like precomputation tables, built
+/// once at startup: `algorithm(n)` is run with `n` being `pass1_n`, then `pass2_n`, and only its space complexity is asserted.\
+/// Any time complexity is accepted -- even if the passes take close to no time, making its classification meaningless -- so there
+/// are no retries (space measurements are deterministic) and the time measurements are left out of the report.
+#[track_caller]
+pub fn test_algorithm_space_only(test_name:                 &str,
+                                 algorithm:                 impl FnMut(u64) -> u32,
+                                 pass1_n:                   u64,
+                                 pass2_n:                   u64,
+                                 expected_space_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_space_only_with_options(test_name, algorithm, pass1_n, pass2_n, expected_space_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_space_only()], with the opt-in behaviours of `options`
+#[track_caller]
+pub fn test_algorithm_space_only_with_options(test_name:                 &str,
+                                              mut algorithm:             impl FnMut(u64) -> u32,
+                                              pass1_n:                   u64,
+                                              pass2_n:                   u64,
+                                              expected_space_complexity: BigOAlgorithmComplexity,
+                                              options:                   AlgorithmOptions) {
+    // any time complexity is accepted -- see the assertion below
+    let (_, expected_space_complexity) = options.expectations(test_name, BigOAlgorithmComplexity::WorseThanExponential, expected_space_complexity);
+    // each pass is a single call
+    output_runner_estimate(test_name, dry_run_estimate(1, 1, 0));
+    // space mismatches are not retried
+    let attempts_reporter = AttemptsReporter::detect(0);
+    OUTPUT(&format!("Running '{}' algorithm, analysing its space only:\n", test_name));
+    let (pass1_result, r1) = in_pass(1, pass1_n, || run_sync_pass(|| algorithm(pass1_n)));
+    OUTPUT(&format!("  Pass 1: {}", pass1_result.space_measurements));
+    check_timeout(&pass1_result, &options).unwrap_or_else(|reason| panic!("{}", timed_out_msg(test_name, BigOAlgorithmComplexity::WorseThanExponential, expected_space_complexity, reason)));
+    let (pass2_result, r2) = in_pass(2, pass2_n, || run_sync_pass(|| algorithm(pass2_n)));
+    OUTPUT(&format!("; Pass 2: {}", pass2_result.space_measurements));
+    check_timeout(&pass2_result, &options).unwrap_or_else(|reason| panic!("{}", timed_out_msg(test_name, BigOAlgorithmComplexity::WorseThanExponential, expected_space_complexity, reason)));
+    let measurements = algorithm_measurements(test_name, pass1_n, pass1_result, pass2_n, pass2_result);
+    let observed_space_complexity = low_level_analysis::space_analysis::analyse_space_complexity(&measurements.passes_info, &measurements.space_measurements);
+    // any time complexity is accepted -- so it is only classified for the record
+    let observed_time_complexity = low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
+    ComplexityAssertionRecord::global().record(ComplexityAssertion::new(test_name, BigOAlgorithmComplexity::WorseThanExponential, observed_time_complexity, expected_space_complexity, observed_space_complexity));
+
+    OUTPUT("\n\n");
+    OUTPUT(&format!("'{}' regular-algorithm measurements (time not analysed):\n\
+                     --> Algorithm Space Analysis: {} ({})\n",
+                    test_name, observed_space_complexity.as_space_pretty_str(), measurements.space_measurements));
+    if !observed_space_complexity.is_at_most(expected_space_complexity) {
+        let msg = format!("\n ** Aborted due to SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n", test_name, expected_space_complexity, observed_space_complexity);
+        OUTPUT_ERROR(&msg);
+        attempts_reporter.report_failure(test_name, expected_space_complexity, observed_space_complexity);
+        panic!("{}", msg);
+    }
+    attempts_reporter.report_match(test_name, observed_space_complexity);
+    OUTPUT(&format!("r={}\n\n", r1 ^ r2));
+}
+
+/// Similar to [test_algorithm_threaded()], single-threaded, but for algorithms known to be allocation-free -- bit manipulation,
+/// in-place sorting: the allocator isn't consulted at all (see [run_iterator_pass_no_alloc()]), so neither its overhead nor
+/// the allocations of the runner itself pollute the time measurements.\
+/// `algorithm` is called once for each element in `0..pass1_n`, then `0..pass2_n`. Only the time complexity is asserted
+/// -- space is not measured and is reported as `O(1)`.
+#[track_caller]
+pub fn test_algorithm_no_space(test_name:                &str,
+                               max_retry_attempts:       u32,
+                               algorithm:                impl Fn(u32) -> u32 + Sync,
+                               pass1_n:                  u32,
+                               pass2_n:                  u32,
+                               expected_time_complexity: BigOAlgorithmComplexity) {
+    test_algorithm_no_space_with_options(test_name, max_retry_attempts, algorithm, pass1_n, pass2_n, expected_time_complexity, AlgorithmOptions::default())
+}
+
+/// Same as [test_algorithm_no_space()], with the opt-in behaviours of `options`
+#[track_caller]
+pub fn test_algorithm_no_space_with_options(test_name:                &str,
+                                            max_retry_attempts:       u32,
+                                            algorithm:                impl Fn(u32) -> u32 + Sync,
+                                            pass1_n:                  u32,
+                                            pass2_n:                  u32,
+                                            expected_time_complexity: BigOAlgorithmComplexity,
+                                            options:                  AlgorithmOptions) {
+    let (expected_time_complexity, _) = options.expectations(test_name, expected_time_complexity, BigOAlgorithmComplexity::O1);
+    output_runner_estimate(test_name, (dry_run_estimate(pass1_n, 1, 0) + dry_run_estimate(pass2_n, 1, 0)) / 2);
+    let attempts_reporter = AttemptsReporter::detect(max_retry_attempts);
+    let result = analyse_algorithm_no_space(test_name, pass1_n, pass2_n, &algorithm, expected_time_complexity, &options, &attempts_reporter)
+        .retry_with(|_| analyse_algorithm_no_space(test_name, pass1_n, pass2_n, &algorithm, expected_time_complexity, &options, &attempts_reporter))
+        .with_delays((0..max_retry_attempts).map(|_| Duration::from_secs(5)));
+    panic_on_failure(result, max_retry_attempts);
+}
+
+/// Outputs the [dry_run_estimate()] of a `test_*()` runner, before its analysis starts -- so an analysis that would take too long
+/// may be aborted early
+fn output_runner_estimate(test_name: &str, runner_estimate: Duration) {
+    OUTPUT(&format!("'{}': the runner alone is estimated to take {:?} per attempt -- plus the time spent in the algorithm\n", test_name, runner_estimate));
+}
+
+/// Runs `algorithm` for the same number of `iterations` -- `0..iterations` -- split among each of the given `thread_counts`,
+/// revealing if the algorithm scales with threads or if synchronization costs dominate.\
+/// Returns, for each thread count, the average time spent by each thread together with its complexity compared to the first
+/// thread count -- taking the number of threads as `n`: `BetterThanO1` means the algorithm scales (ideally, the time halves
+/// when threads double), `O1` means threads don't help and anything worse means contention grows with them.\
+/// A single thread reference -- whose `log(1) = 0` would classify any contention as `BetweenO1AndOLogN` -- is analysed with both
+/// thread counts doubled, which keeps their ratio for the linear & polynomial complexities while the logarithmic ones are measured
+/// as if starting from 2 threads.\
+/// See [SpeedupAnalysis] for the parallel efficiency. The complexity of the last thread count is reported to the
+/// [ComplexityAssertionRecord::global()] -- without expectations, it always passes.
+pub fn analyse_parallel_scaling(test_name:     &str,
+                                algorithm:     impl Fn(u32) -> u32 + Sync,
+                                iterations:    u32,
+                                thread_counts: &[u32])
+                               -> Vec<(u32, BigOAlgorithmComplexity, Duration)> {
+    // a single pass for each thread count
+    output_runner_estimate(test_name, thread_counts.iter().map(|&threads| dry_run_estimate(iterations, threads, 0) / 2).sum());
+    OUTPUT(&format!("Running '{}' parallel scaling analysis:\n", test_name));
+    let mut r = 0;
+    let mut separator = "  ";
+    let elapsed_times = thread_counts.iter()
+        .map(|&threads| {
+            let (pass_result, pass_r) = run_iterator_pass_verbosely(&format!("{}{} threads: ", separator, threads), "", &algorithm,
+                                                                    &BigOIteratorAlgorithmType::ConstantSet, 0..iterations, IterationOrder::Ascending, threads, OUTPUT);
+            separator = "; ";
+            r ^= pass_r;
+            (threads, pass_result.time_measurements)
+        })
+        .collect::<Vec<_>>();
+    let Some(&(first_threads, first_elapsed)) = elapsed_times.first() else {
+        return vec![]
+    };
+    let n_scale = if first_threads == 1 {2.0} else {1.0};
+    let scaling = elapsed_times.into_iter()
+        .map(|(threads, elapsed)| (threads,
+                                   low_level_analysis::analyse_complexity(first_elapsed.as_secs_f64(), elapsed.as_secs_f64(), first_threads as f64 * n_scale, threads as f64 * n_scale),
+                                   elapsed))
+        .collect::<Vec<_>>();
+    OUTPUT(&format!("\n\n'{}' parallel scaling: {}; r={}\n\n", test_name, SpeedupAnalysis::from_scaling(&scaling), r));
+    // the space is not analysed
+    ComplexityAssertionRecord::global().record(ComplexityAssertion::new(test_name, BigOAlgorithmComplexity::WorseThanExponential, scaling.last().map(|&(_, complexity, _)| complexity),
+                                                                        BigOAlgorithmComplexity::WorseThanExponential, None::<BigOAlgorithmComplexity>));
+    scaling
+}
+
+/// The parallel efficiency of an algorithm, comparing the first & last thread counts analysed by [analyse_parallel_scaling()]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedupAnalysis {
+    /// the speedup of a perfectly parallel algorithm: the ratio between the thread counts
+    pub ideal:    f64,
+    /// the measured speedup: the ratio between the elapsed times
+    pub observed: f64,
+}
+impl SpeedupAnalysis {
+
+    /// Computes the speedup between the first & last entries returned by [analyse_parallel_scaling()]
+    pub fn from_scaling(scaling: &[(u32, BigOAlgorithmComplexity, Duration)]) -> Self {
+        match (scaling.first(), scaling.last()) {
+            (Some(&(first_threads, _, first_elapsed)), Some(&(last_threads, _, last_elapsed))) => Self {
+                ideal:    last_threads as f64 / first_threads as f64,
+                observed: first_elapsed.as_secs_f64() / last_elapsed.as_secs_f64(),
+            },
+            _ => Self { ideal: 1.0, observed: 1.0 },
+        }
+    }
+
+    /// `observed / ideal`: `1.0` for perfect scaling; lower values denote synchronization (or scheduling) costs
+    pub fn efficiency(&self) -> f64 {
+        self.observed / self.ideal
+    }
+}
+impl std::fmt::Display for SpeedupAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "speedup: {:.2}x (ideal: {:.2}x) -- efficiency: {:.1}%", self.observed, self.ideal, self.efficiency() * 100.0)
+    }
+}
+
+/// evaluates to the outcome of a pass that didn't exceed the [AlgorithmOptions::with_timeout()] -- otherwise, returns the enclosing
+/// analysis of `test_name` with the fatal error, see [timed_out_msg()]: timeouts are not retried, as they would, most likely, happen again
+macro_rules! abort_on_timeout {
+    ($test_name: expr, $expected_time_complexity: expr, $expected_space_complexity: expr, $pass_outcome: expr) => {
+        match $pass_outcome {
+            Ok(pass) => pass,
+            Err(reason) => return RetryResult::Fatal { input: (), error: timed_out_msg($test_name, $expected_time_complexity, $expected_space_complexity, reason) },
+        }
+    }
+}
+
+/// errors with the reason to abort the analysis if the pass of `pass_result` ran for longer than the `options`' timeout -- for the
+/// passes that can't be interrupted, as they are only checked after they return
+fn check_timeout(pass_result: &PassResult, options: &AlgorithmOptions) -> Result<(), String> {
+    match options.timeout.filter(|&timeout| pass_result.wall_clock_time > timeout) {
+        Some(timeout) => Err(format!("pass timed out after {:?}, having run for {:?}", timeout, pass_result.wall_clock_time)),
+        None => Ok(()),
+    }
+}
+
+/// Same as [run_repeated_sync_pass_verbosely()] -- repeating the pass as told by the `options`, with `reset_fn` in-between the
+/// repetitions -- but erroring, see [check_timeout()], if the kept pass exceeded the `options`' timeout
+fn run_sync_pass_within_timeout(result_prefix: &str, algorithm: impl FnMut() -> u32, reset_fn: impl FnMut(), options: &AlgorithmOptions) -> Result<(PassResult, u32), String> {
+    let (pass_result, r) = run_repeated_sync_pass_verbosely(result_prefix, "", algorithm, reset_fn, options.repetitions_per_measurement.max(1), OUTPUT);
+    check_timeout(&pass_result, options)?;
+    Ok((pass_result, r))
+}
+
+/// Same as [run_iterator_pass_verbosely()], for the [BigOIteratorAlgorithmType::ConstantSet] `algorithm` fed, in ascending order,
+/// with the elements in `range` -- but giving up on the pass once it exceeds the `options`' timeout, erroring with the reason to abort
+/// the analysis. Panics of `algorithm` are re-raised
+fn run_iterator_pass_within_timeout(result_prefix: &str, algorithm: &(impl Fn(u32) -> u32 + Sync), range: Range<u32>, threads: u32, options: &AlgorithmOptions)
+                                   -> Result<(PassResult, u32), String> {
+    try_run_pinned_iterator_pass_verbosely(result_prefix, "", algorithm, &BigOIteratorAlgorithmType::ConstantSet, range.clone(), IterationOrder::Ascending,
+                                           threads, None, Aggregation::Mean, options.timeout, OUTPUT)
+        .map_err(|pass_abort| match pass_abort {
+            PassAbort::TimedOut(pass_timeout) => pass_timeout.to_string(),
+            PassAbort::Panicked(algorithm_panic) => panic!("Panic! while running provided 'algorithm' closure: algo type: {:?}, range: {:?}: {}",
+                                                           BigOIteratorAlgorithmType::ConstantSet, range, algorithm_panic),
+        })
+}
+
+/// outputs & returns the message the analysis of `test_name` is aborted with, as one of its passes timed out -- as told by `reason` --
+/// recording it as failed, along with its expected complexities
+fn timed_out_msg(test_name: &str, expected_time_complexity: impl Into<ComplexityBoundPair>, expected_space_complexity: BigOAlgorithmComplexity, reason: String) -> String {
+    let msg = format!("\n ** Aborted as a '{}' {}\n\n", test_name, reason);
+    OUTPUT_ERROR(&msg);
+    ComplexityAssertionRecord::global().record(ComplexityAssertion::aborted(test_name, expected_time_complexity, expected_space_complexity, reason));
+    msg
+}
+
+/// Annotates the GitHub Actions workflow with the verdict of each attempt of an analysis -- when running in GitHub Actions, see
+/// [GithubActionsReporter::detect()] -- counting the retries left, to tell the mismatches still to be retried from the failures
+struct AttemptsReporter {
+    reporter:     Option<GithubActionsReporter>,
+    retries_left: Cell<u32>,
+}
+impl AttemptsReporter {
+
+    /// Annotates the caller's source file -- for an analysis retried up to `max_retry_attempts` times
+    #[track_caller]
+    fn detect(max_retry_attempts: u32) -> Self {
+        Self {
+            reporter:     GithubActionsReporter::detect(),
+            retries_left: Cell::new(max_retry_attempts),
+        }
+    }
+
+    /// `test_name` matched its expectations -- measured as `observed`
+    fn report_match(&self, test_name: &str, observed: BigOAlgorithmComplexity) {
+        if let Some(reporter) = &self.reporter {
+            reporter.report_match(test_name, observed);
+        }
+    }
+
+    /// `test_name` measured as `observed`, out of the `expected` complexity -- a mismatch that isn't retried
+    fn report_failure(&self, test_name: &str, expected: impl std::fmt::Debug, observed: BigOAlgorithmComplexity) {
+        if let Some(reporter) = &self.reporter {
+            reporter.report_mismatch(test_name, &expected, observed, false);
+        }
+    }
+
+    /// Same as [Self::report_failure()], for a mismatch retried while there are retries left -- using one of them
+    fn report_retryable_mismatch(&self, test_name: &str, expected: impl std::fmt::Debug, observed: BigOAlgorithmComplexity) {
+        let retries_left = self.retries_left.get();
+        self.retries_left.set(retries_left.saturating_sub(1));
+        if let Some(reporter) = &self.reporter {
+            reporter.report_mismatch(test_name, &expected, observed, retries_left > 0);
+        }
+    }
+}
+
+/// panics with a detailed message if the retrying operations of [test_algorithm()] & friends didn't succeed
+fn panic_on_failure(result: ResolvedResult<(), (), String, String>, max_retry_attempts: u32) {
     let failure_msg = match result {
         ResolvedResult::Ok { .. } => None,
         ResolvedResult::Fatal { error, .. } => Some(error),
@@ -54,26 +673,169 @@ pub fn test_algorithm(test_name:                 &str,
 }
 
 /// Internal version of [test_algorithm()], allowing retries
+#[allow(clippy::too_many_arguments)]
 fn analyse_algorithm(test_name:                 &str,
                      reset_fn:                  &mut impl FnMut(),
-                     pass1_set_size:            u32,
+                     pass1_set_size:            u64,
                      pass1_algorithm:           &mut impl FnMut() -> u32,
-                     pass2_set_size:            u32,
+                     pass2_set_size:            u64,
                      pass2_algorithm:           &mut impl FnMut() -> u32,
-                     expected_time_complexity:  BigOAlgorithmComplexity,
-                     expected_space_complexity: BigOAlgorithmComplexity)
+                     expected_time_complexity:  ComplexityBoundPair,
+                     expected_space_complexity: BigOAlgorithmComplexity,
+                     options:                   &AlgorithmOptions,
+                     attempts_reporter:         &AttemptsReporter)
                     -> RetryProducerResult<String, String> {
 
     OUTPUT(&format!("Running '{}' algorithm:\n", test_name));
     let (_reset_pass_result,                   r0) = run_sync_pass_verbosely("  Resetting: ", "", || {reset_fn(); 0}, OUTPUT);
-    let (pass1_result, r1) = run_sync_pass_verbosely("; Pass 1: ", "", pass1_algorithm, OUTPUT);
-    let (pass2_result, r2) = run_sync_pass_verbosely("; Pass 2: ", "", pass2_algorithm, OUTPUT);
-    let measurements = AlgorithmMeasurements {
+    let (pass1_result, r1) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(1, pass1_set_size, || run_sync_pass_within_timeout("; Pass 1: ", &mut *pass1_algorithm, &mut *reset_fn, options)));
+    let (pass2_result, r2) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(2, pass2_set_size, || run_sync_pass_within_timeout("; Pass 2: ", &mut *pass2_algorithm, &mut *reset_fn, options)));
+    let measurements = algorithm_measurements(test_name, pass1_set_size, pass1_result, pass2_set_size, pass2_result);
+    analyse_measurements(test_name, measurements, expected_time_complexity, expected_space_complexity, r0 ^ r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_algorithm_with_setup_teardown()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_algorithm_with_setup_teardown(test_name:                 &str,
+                                         setup_fn:                  &mut impl FnMut(u64),
+                                         algorithm:                 &mut impl FnMut(u64) -> u32,
+                                         teardown_fn:               &mut impl FnMut(u64),
+                                         pass1_n:                   u64,
+                                         pass2_n:                   u64,
+                                         expected_time_complexity:  ComplexityBoundPair,
+                                         expected_space_complexity: BigOAlgorithmComplexity,
+                                         options:                   &AlgorithmOptions,
+                                         attempts_reporter:         &AttemptsReporter)
+                                        -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' algorithm, with per-pass setup & teardown:\n", test_name));
+    setup_fn(pass1_n);
+    let pass1_outcome = in_pass(1, pass1_n, || run_sync_pass_within_timeout("  Pass 1: ", || algorithm(pass1_n), || {teardown_fn(pass1_n); setup_fn(pass1_n)}, options));
+    teardown_fn(pass1_n);
+    let (pass1_result, r1) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, pass1_outcome);
+    setup_fn(pass2_n);
+    let pass2_outcome = in_pass(2, pass2_n, || run_sync_pass_within_timeout("; Pass 2: ", || algorithm(pass2_n), || {teardown_fn(pass2_n); setup_fn(pass2_n)}, options));
+    teardown_fn(pass2_n);
+    let (pass2_result, r2) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, pass2_outcome);
+    let measurements = algorithm_measurements(test_name, pass1_n, pass1_result, pass2_n, pass2_result);
+    analyse_measurements(test_name, measurements, expected_time_complexity, expected_space_complexity, r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_algorithm_growing_n()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_algorithm_growing_n(test_name:                 &str,
+                               algorithm:                 &impl Fn(u32) -> u32,
+                               pass1_n:                   u32,
+                               pass2_n:                   u32,
+                               expected_time_complexity:  ComplexityBoundPair,
+                               expected_space_complexity: BigOAlgorithmComplexity,
+                               options:                   &AlgorithmOptions,
+                               attempts_reporter:         &AttemptsReporter)
+                              -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' algorithm, once per pass:\n", test_name));
+    let (pass1_result, r1) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(1, pass1_n.into(), || run_sync_pass_within_timeout(&format!("  Pass 1 (n={}): ", pass1_n), || algorithm(pass1_n), || {}, options)));
+    let (pass2_result, r2) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(2, pass2_n.into(), || run_sync_pass_within_timeout(&format!("; Pass 2 (n={}): ", pass2_n), || algorithm(pass2_n), || {}, options)));
+    let measurements = algorithm_measurements(test_name, pass1_n.into(), pass1_result, pass2_n.into(), pass2_result);
+    analyse_measurements(test_name, measurements, expected_time_complexity, expected_space_complexity, r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_algorithm_threaded()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_threaded_algorithm(test_name:                 &str,
+                              reset_fn:                  &mut impl FnMut(),
+                              pass1_n:                   u32,
+                              pass1_threads:             u32,
+                              pass2_n:                   u32,
+                              pass2_threads:             u32,
+                              algorithm:                 &(impl Fn(u32) -> u32 + Sync),
+                              expected_time_complexity:  ComplexityBoundPair,
+                              expected_space_complexity: BigOAlgorithmComplexity,
+                              options:                   &AlgorithmOptions,
+                              attempts_reporter:         &AttemptsReporter)
+                             -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' threaded algorithm:\n", test_name));
+    let (_reset_pass_result,                   r0) = run_sync_pass_verbosely("  Resetting: ", "", || {reset_fn(); 0}, OUTPUT);
+    let (pass1_result, r1) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(1, pass1_n.into(), || run_iterator_pass_within_timeout(&format!("; Pass 1 ({} threads): ", pass1_threads), algorithm, 0..pass1_n, pass1_threads, options)));
+    let (pass2_result, r2) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(2, pass2_n.into(), || run_iterator_pass_within_timeout(&format!("; Pass 2 ({} threads): ", pass2_threads), algorithm, 0..pass2_n, pass2_threads, options)));
+    let measurements = algorithm_measurements(test_name, pass1_n.into(), pass1_result, pass2_n.into(), pass2_result);
+    analyse_measurements(test_name, measurements, expected_time_complexity, expected_space_complexity, r0 ^ r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_algorithm_contextual()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_contextual_algorithm<C: Send + Sync>(test_name:                 &str,
+                                                reset_fn:                  &mut impl FnMut() -> C,
+                                                algorithm:                 &(impl Fn(u32, &C) -> u32 + Sync),
+                                                pass1_n:                   u32,
+                                                pass2_n:                   u32,
+                                                threads:                   u32,
+                                                expected_time_complexity:  ComplexityBoundPair,
+                                                expected_space_complexity: BigOAlgorithmComplexity,
+                                                options:                   &AlgorithmOptions,
+                                                attempts_reporter:         &AttemptsReporter)
+                                               -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' contextual algorithm:\n", test_name));
+    let mut context = None;
+    let (_reset_pass_result,                   r0) = run_sync_pass_verbosely("  Resetting: ", "", || {context = Some(reset_fn()); 0}, OUTPUT);
+    let context = context.expect("`reset_fn` runs as part of the resetting pass");
+    let algorithm = |n| algorithm(n, &context);
+    let (pass1_result, r1) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(1, pass1_n.into(), || run_iterator_pass_within_timeout("; Pass 1: ", &algorithm, 0..pass1_n, threads, options)));
+    let (pass2_result, r2) = abort_on_timeout!(test_name, expected_time_complexity, expected_space_complexity, in_pass(2, pass2_n.into(), || run_iterator_pass_within_timeout("; Pass 2: ", &algorithm, 0..pass2_n, threads, options)));
+    let measurements = algorithm_measurements(test_name, pass1_n.into(), pass1_result, pass2_n.into(), pass2_result);
+    analyse_measurements(test_name, measurements, expected_time_complexity, expected_space_complexity, r0 ^ r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_algorithm_no_space()], allowing retries
+fn analyse_algorithm_no_space(test_name:                &str,
+                              pass1_n:                  u32,
+                              pass2_n:                  u32,
+                              algorithm:                &(impl Fn(u32) -> u32 + Sync),
+                              expected_time_complexity: ComplexityBoundPair,
+                              options:                  &AlgorithmOptions,
+                              attempts_reporter:        &AttemptsReporter)
+                             -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' allocation-free algorithm:\n", test_name));
+    let (pass1_result, r1) = run_iterator_pass_no_alloc(algorithm, 0..pass1_n, IterationOrder::Ascending);
+    OUTPUT(&format!("  Pass 1: {:?}", pass1_result.time_measurements));
+    abort_on_timeout!(test_name, expected_time_complexity, BigOAlgorithmComplexity::O1, check_timeout(&pass1_result, options));
+    let (pass2_result, r2) = run_iterator_pass_no_alloc(algorithm, 0..pass2_n, IterationOrder::Ascending);
+    OUTPUT(&format!("; Pass 2: {:?}", pass2_result.time_measurements));
+    abort_on_timeout!(test_name, expected_time_complexity, BigOAlgorithmComplexity::O1, check_timeout(&pass2_result, options));
+    let measurements = algorithm_measurements(test_name, pass1_n.into(), pass1_result, pass2_n.into(), pass2_result);
+    let observed_time_complexity = low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
+    check_analysis(test_name, BigOAlgorithmAnalysis {
+        time_complexity: observed_time_complexity,
+        // not measured: assumed, as the algorithm is declared allocation-free
+        space_complexity: BigOAlgorithmComplexity::O1,
+        time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient(observed_time_complexity, &measurements),
+        is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&measurements),
+        algorithm_measurements: measurements,
+    }, expected_time_complexity, BigOAlgorithmComplexity::O1, r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_constant_set_iterator_algorithm_with_context()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_constant_set_iterator_algorithm(test_name:                 &str,
+                                           pass1_set_size:            u32,
+                                           pass2_set_size:            u32,
+                                           repetitions:               u32,
+                                           threads:                   u32,
+                                           algorithm:                 &(impl Fn(u32) -> u32 + Sync),
+                                           expected_time_complexity:  ComplexityBoundPair,
+                                           expected_space_complexity: BigOAlgorithmComplexity,
+                                           attempts_reporter:         &AttemptsReporter)
+                                          -> RetryProducerResult<String, String> {
+
+    OUTPUT(&format!("Running '{}' constant set iterator algorithm:\n", test_name));
+    let (pass1_result, r1) = in_pass(1, pass1_set_size.into(), || run_iterator_pass_verbosely("  Pass 1: ", "", algorithm, &BigOIteratorAlgorithmType::ConstantSet, pass1_set_size-repetitions..pass1_set_size, IterationOrder::Ascending, threads, OUTPUT));
+    let (pass2_result, r2) = in_pass(2, pass2_set_size.into(), || run_iterator_pass_verbosely("; Pass 2: ", "", algorithm, &BigOIteratorAlgorithmType::ConstantSet, pass2_set_size-repetitions..pass2_set_size, IterationOrder::Ascending, threads, OUTPUT));
+    let measurements = ConstantSetIteratorAlgorithmMeasurements {
         measurement_name: test_name,
-        passes_info: AlgorithmPassesInfo {
-            pass1_n: pass1_set_size,
-            pass2_n: pass2_set_size,
-        },
+        passes_info: ConstantSetIteratorAlgorithmPassesInfo::new(u64::from(pass1_set_size), u64::from(pass2_set_size), u64::from(repetitions)),
         time_measurements: BigOTimeMeasurements {
             pass_1_measurements: pass1_result.time_measurements,
             pass_2_measurements: pass2_result.time_measurements,
@@ -82,39 +844,175 @@ fn analyse_algorithm(test_name:                 &str,
             pass_1_measurements: pass1_result.space_measurements,
             pass_2_measurements: pass2_result.space_measurements,
         },
-        pass1_measurements: BigOPassMeasurements {
-            time_measurements: pass1_result.time_measurements,
-            space_measurements: Default::default(),
-            custom_measurements: vec![],
+        pass1_measurements: pass_measurements(pass1_result),
+        pass2_measurements: pass_measurements(pass2_result),
+    };
+    let observed_time_complexity  = low_level_analysis::time_analysis::analyse_time_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.time_measurements);
+    let observed_space_complexity = low_level_analysis::space_analysis::analyse_space_complexity_for_constant_set_iterator_algorithm(&measurements.passes_info, &measurements.space_measurements);
+    check_analysis(test_name, BigOAlgorithmAnalysis {
+        time_complexity: observed_time_complexity,
+        space_complexity: observed_space_complexity,
+        time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient(observed_time_complexity, &measurements),
+        is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&measurements),
+        algorithm_measurements: measurements,
+    }, expected_time_complexity, expected_space_complexity, r1 ^ r2, attempts_reporter)
+}
+
+/// Internal version of [test_set_resizing_iterator_algorithm()], allowing retries
+#[allow(clippy::too_many_arguments)]
+fn analyse_set_resizing_iterator_algorithm(test_name:                 &str,
+                                           reset_fn:                  &mut impl FnMut(),
+                                           algorithm:                 &(impl Fn(u32) -> u32 + Sync),
+                                           delta_set_size:            u32,
+                                           threads:                   u32,
+                                           expected_time_complexity:  ComplexityBoundPair,
+                                           expected_space_complexity: BigOAlgorithmComplexity,
+                                           attempts_reporter:         &AttemptsReporter)
+                                          -> RetryProducerResult<String, String> {
+
+    const SEGMENTS: u32 = 3;
+
+    OUTPUT(&format!("Running '{}' set resizing iterator algorithm:\n", test_name));
+    let (_reset_pass_result, mut r) = run_sync_pass_verbosely("  Resetting: ", "", || {reset_fn(); 0}, OUTPUT);
+    // the runner's own allocations are the same for every segment -- left in, they would be taken as the algorithm's growth
+    let runner_space_overhead = measure_runner_space_overhead(threads);
+    let segments_results = (0..SEGMENTS)
+        .map(|segment| {
+            // the set grows up to the end of the segment
+            let (segment_result, segment_r) = in_pass(segment+1, u64::from(delta_set_size*(segment+1)), || {
+                run_iterator_pass_verbosely(&format!("; Segment {}: ", segment+1), "", algorithm, &BigOIteratorAlgorithmType::SetResizing,
+                                            delta_set_size*segment..delta_set_size*(segment+1), IterationOrder::Ascending, threads, OUTPUT)
+            });
+            r ^= segment_r;
+            segment_result
+        })
+        .collect::<Vec<_>>();
+
+    // analyses each pair of consecutive segments, taking `n` as the set size halfway through each one -- so the pairs are comparable
+    let pair_names = (1..SEGMENTS).map(|segment| format!("{} (segments {} & {})", test_name, segment, segment+1)).collect::<Vec<_>>();
+    let mut pairs_analyses = segments_results.windows(2).zip(pair_names.iter()).enumerate()
+        .map(|(first_segment, (segments, pair_name))| {
+            let measurements = SetResizingIteratorAlgorithmMeasurements {
+                measurement_name: pair_name,
+                passes_info: SetResizingIteratorAlgorithmPassesInfo { delta_set_size: u64::from(delta_set_size) },
+                time_measurements: BigOTimeMeasurements {
+                    pass_1_measurements: segments[0].time_measurements,
+                    pass_2_measurements: segments[1].time_measurements,
+                },
+                space_measurements: BigOSpaceMeasurements {
+                    pass_1_measurements: segments[0].space_measurements,
+                    pass_2_measurements: segments[1].space_measurements,
+                },
+            };
+            let n1 = delta_set_size as f64 * (first_segment as f64 + 0.5);
+            let n2 = delta_set_size as f64 * (first_segment as f64 + 1.5);
+            let space = |segment: &PassResult| (segment.space_measurements.max_used_memory - segment.space_measurements.used_memory_before).saturating_sub(runner_space_overhead) as f64;
+            let time_complexity = low_level_analysis::analyse_complexity(segments[0].time_measurements.as_secs_f64(), segments[1].time_measurements.as_secs_f64(), n1, n2);
+            BigOAlgorithmAnalysis {
+                time_complexity,
+                space_complexity: low_level_analysis::space_analysis::analyse_memory_complexity(space(&segments[0]), space(&segments[1]), n1, n2),
+                time_coefficient: low_level_analysis::complexity_coefficient(time_complexity, segments[1].time_measurements.as_secs_f64() / delta_set_size as f64, n2),
+                is_near_boundary: low_level_analysis::is_near_boundary(segments[0].time_measurements.as_secs_f64(), segments[1].time_measurements.as_secs_f64(), n1, n2),
+                algorithm_measurements: measurements,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the pair with the worst time is checked -- with the worst space among all pairs -- while the others are just reported
+    let worst_space_complexity = pairs_analyses.iter()
+        .map(|pair_analysis| pair_analysis.space_complexity)
+        .reduce(BigOAlgorithmComplexity::worse_of)
+        .expect("BUG! No segments were analysed");
+    let worst_time_pair = (0..pairs_analyses.len())
+        .max_by_key(|&pair| pairs_analyses[pair].time_complexity)
+        .expect("BUG! No segments were analysed");
+    let mut worst_pair_analysis = pairs_analyses.remove(worst_time_pair);
+    worst_pair_analysis.space_complexity = worst_space_complexity;
+    OUTPUT("\n\n");
+    for pair_analysis in pairs_analyses {
+        OUTPUT(&format!("{}\n", pair_analysis));
+    }
+    check_analysis(test_name, worst_pair_analysis, expected_time_complexity, expected_space_complexity, r, attempts_reporter)
+}
+
+/// builds the [BigOPassMeasurements] for the given pass result
+fn pass_measurements(pass_result: PassResult) -> BigOPassMeasurements {
+    BigOPassMeasurements {
+        time_measurements: pass_result.time_measurements,
+        space_measurements: pass_result.space_measurements,
+        custom_measurements: vec![],
+    }
+}
+
+/// builds the [AlgorithmMeasurements] for the given pass results
+fn algorithm_measurements(test_name: &str, pass1_n: u64, pass1_result: PassResult, pass2_n: u64, pass2_result: PassResult) -> AlgorithmMeasurements<'_> {
+    AlgorithmMeasurements {
+        measurement_name: test_name,
+        passes_info: AlgorithmPassesInfo {
+            pass1_n,
+            pass2_n,
+        },
+        time_measurements: BigOTimeMeasurements {
+            pass_1_measurements: pass1_result.time_measurements,
+            pass_2_measurements: pass2_result.time_measurements,
         },
-        pass2_measurements: BigOPassMeasurements {
-            time_measurements: Default::default(),
-            space_measurements: Default::default(),
-            custom_measurements: vec![],
+        space_measurements: BigOSpaceMeasurements {
+            pass_1_measurements: pass1_result.space_measurements,
+            pass_2_measurements: pass2_result.space_measurements,
         },
-    };
+        pass1_measurements: pass_measurements(pass1_result),
+        pass2_measurements: pass_measurements(pass2_result),
+    }
+}
+
+/// analyses the given `measurements` -- see [check_analysis()]
+fn analyse_measurements(test_name:                 &str,
+                        measurements:              AlgorithmMeasurements,
+                        expected_time_complexity:  ComplexityBoundPair,
+                        expected_space_complexity: BigOAlgorithmComplexity,
+                        r:                         u32,
+                        attempts_reporter:         &AttemptsReporter)
+                       -> RetryProducerResult<String, String> {
     let observed_time_complexity  = low_level_analysis::time_analysis::analyse_time_complexity(&measurements.passes_info, &measurements.time_measurements);
     let observed_space_complexity = low_level_analysis::space_analysis::analyse_space_complexity(&measurements.passes_info, &measurements.space_measurements);
-    let algorithm_analysis = BigOAlgorithmAnalysis {
+    check_analysis(test_name, BigOAlgorithmAnalysis {
         time_complexity: observed_time_complexity,
         space_complexity: observed_space_complexity,
+        time_coefficient: low_level_analysis::time_analysis::analyse_time_coefficient(observed_time_complexity, &measurements),
+        is_near_boundary: low_level_analysis::time_analysis::is_time_near_boundary(&measurements),
         algorithm_measurements: measurements,
-    };
+    }, expected_time_complexity, expected_space_complexity, r, attempts_reporter)
+}
+
+/// outputs the given `algorithm_analysis`, matching the observed complexities against the expected ones:
+/// space mismatches are fatal, while time mismatches may be retried
+fn check_analysis<T: BigOAlgorithmMeasurements>(test_name:                 &str,
+                                                algorithm_analysis:        BigOAlgorithmAnalysis<T>,
+                                                expected_time_complexity:  ComplexityBoundPair,
+                                                expected_space_complexity: BigOAlgorithmComplexity,
+                                                r:                         u32,
+                                                attempts_reporter:         &AttemptsReporter)
+                                               -> RetryProducerResult<String, String> {
+    let observed_time_complexity  = algorithm_analysis.time_complexity;
+    let observed_space_complexity = algorithm_analysis.space_complexity;
 
     OUTPUT("\n\n");
     OUTPUT(&format!("{}\n", algorithm_analysis));
+    ComplexityAssertionRecord::global().record(ComplexityAssertion::new(test_name, expected_time_complexity, observed_time_complexity, expected_space_complexity, observed_space_complexity));
 
-
-    if observed_space_complexity as u32 > expected_space_complexity as u32 {
+    if !observed_space_complexity.is_at_most(expected_space_complexity) {
         let msg = format!("\n ** Aborted due to SPACE complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?}\n\n", test_name, expected_space_complexity, observed_space_complexity);
-        OUTPUT(&msg);
+        OUTPUT_ERROR(&msg);
+        attempts_reporter.report_failure(test_name, expected_space_complexity, observed_space_complexity);
         RetryResult::Fatal { input: (), error: msg }
-    } else if observed_time_complexity as u32 > expected_time_complexity as u32 {
-        let msg = format!("\n ** TIME complexity mismatch on '{}' operation: maximum: {:?}, measured: {:?} -- a reattempt may be performed...\n\n", test_name, expected_time_complexity, observed_time_complexity);
-        OUTPUT(&msg);
+    } else if !expected_time_complexity.contains(observed_time_complexity) {
+        let msg = format!("\n ** TIME complexity mismatch on '{}' operation: {}, measured: {:?} -- a reattempt may be performed...\n\n", test_name, expected_time_complexity, observed_time_complexity);
+        OUTPUT_ERROR(&msg);
+        attempts_reporter.report_retryable_mismatch(test_name, expected_time_complexity, observed_time_complexity);
         RetryResult::Transient { input: (), error: msg }
     } else {
-        let msg = format!("r={}\n\n", r0 ^ r1 ^ r2);
+        attempts_reporter.report_match(test_name, observed_time_complexity);
+        let msg = format!("r={}\n\n", r);
         OUTPUT(&msg);
         RetryResult::Ok { reported_input: (), output: msg }
     }
@@ -0,0 +1,149 @@
+//! OpenTelemetry integration, enabled by the `opentelemetry` feature: wraps each pass done by [super::common::run_iterator_pass()],
+//! `run_sync_pass()` & `run_async_pass()` in a `big_o.pass` span -- allowing the measured times to be correlated with the traces of remote services the algorithm
+//! depends on (databases, network algorithms...), whose round-trips are also accounted for in the pass' elapsed time.\
+//! Spans are created through the global tracer provider -- see [opentelemetry::global::set_tracer_provider()].
+
+use crate::low_level_analysis::types::BigOIteratorAlgorithmType;
+use std::{
+    cell::Cell,
+    ops::Range,
+    time::Duration,
+};
+use opentelemetry::{
+    global::{self, BoxedSpan},
+    trace::{Span, SpanContext, SpanId, TraceFlags, TraceId, TraceState, Tracer},
+    KeyValue,
+};
+
+
+/// the name of the tracer -- as registered in the global tracer provider
+const TRACER_NAME: &str = "big-o-test";
+
+/// What the runner running a pass knows about it -- see [in_pass()]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PassContext {
+    /// the pass of the analysis -- starting at 1
+    pass_number: u32,
+    /// the number of elements in the set the pass operates on -- the biggest it gets, for passes resizing the set
+    set_size:    u64,
+}
+
+thread_local! {
+    /// the context of the pass being run by the current thread -- see [in_pass()]
+    static PASS_CONTEXT: Cell<Option<PassContext>> = const { Cell::new(None) };
+}
+
+/// Runs `pass` as the `pass_number`th pass of an analysis, over a set of `set_size` elements -- so the `big_o.pass` span started
+/// within it, in the same thread, tells so. Spans of passes run elsewhere -- like warmups -- go without these attributes
+pub(crate) fn in_pass<R>(pass_number: u32, set_size: u64, pass: impl FnOnce() -> R) -> R {
+    /// restores the context of the enclosing pass, if any -- even if `pass` panics
+    struct RestoreContext(Option<PassContext>);
+    impl Drop for RestoreContext {
+        fn drop(&mut self) {
+            PASS_CONTEXT.set(self.0);
+        }
+    }
+    let _restore_context = RestoreContext(PASS_CONTEXT.replace(Some(PassContext { pass_number, set_size })));
+    pass()
+}
+
+
+/// Identifies the `big_o.pass` span of a measured pass -- kept in [super::common::PassResult::span_ids].\
+/// Only the ids are kept (rather than the whole [SpanContext]) so the pass results remain `Copy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PassSpanIds {
+    pub trace_id:    TraceId,
+    pub span_id:     SpanId,
+    pub trace_flags: TraceFlags,
+}
+impl PassSpanIds {
+    /// the [SpanContext] of the pass' span -- to be correlated with the backend traces
+    pub fn span_context(&self) -> SpanContext {
+        SpanContext::new(self.trace_id, self.span_id, self.trace_flags, false, TraceState::default())
+    }
+}
+
+
+/// The `big_o.pass` span of a pass being measured -- see [start_pass_span()]
+pub(crate) struct PassSpan {
+    span: BoxedSpan,
+}
+
+/// Starts the span for the iterator pass about to be run over `range` with the given number of `threads` -- see [start_pass_span()]
+pub(crate) fn start_iterator_pass_span(algorithm_type: &BigOIteratorAlgorithmType, range: &Range<u32>, threads: u32) -> PassSpan {
+    let mut pass_span = start_pass_span();
+    pass_span.span.set_attribute(KeyValue::new("algorithm_type", format!("{:?}", algorithm_type)));
+    pass_span.span.set_attribute(KeyValue::new("iterations",     range.len() as i64));
+    pass_span.span.set_attribute(KeyValue::new("threads",        threads as i64));
+    pass_span
+}
+
+/// Starts the span for the pass about to be run -- with the `pass_number` & `set_size` given by the enclosing [in_pass()], if any.\
+/// Call it before taking the allocator's save point, so the span's allocations don't count as the algorithm's.
+pub(crate) fn start_pass_span() -> PassSpan {
+    let mut span = global::tracer(TRACER_NAME).start("big_o.pass");
+    if let Some(PassContext { pass_number, set_size }) = PASS_CONTEXT.get() {
+        span.set_attribute(KeyValue::new("pass_number", pass_number as i64));
+        span.set_attribute(KeyValue::new("set_size",    set_size as i64));
+    }
+    PassSpan { span }
+}
+
+impl PassSpan {
+    /// Records the measured `elapsed_time` and ends the span, returning its ids -- or `None` if no tracer provider is recording spans
+    pub(crate) fn end(mut self, elapsed_time: Duration) -> Option<PassSpanIds> {
+        self.span.set_attribute(KeyValue::new("elapsed_time", format!("{:?}", elapsed_time)));
+        self.span.set_attribute(KeyValue::new("elapsed_time_ns", elapsed_time.as_nanos() as i64));
+        let span_context = self.span.span_context().clone();
+        self.span.end();
+        span_context.is_valid().then(|| PassSpanIds {
+            trace_id:    span_context.trace_id(),
+            span_id:     span_context.span_id(),
+            trace_flags: span_context.trace_flags(),
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [telemetry](super) module
+
+    use super::*;
+
+
+    /// assures passes may be traced even if no tracer provider was set -- in which case no span ids are reported
+    #[test]
+    fn no_tracer_provider() {
+        let span = start_iterator_pass_span(&BigOIteratorAlgorithmType::ConstantSet, &(0..1000), 2);
+        assert_eq!(span.end(Duration::from_millis(1)), None, "The default no-op tracer provider should not yield valid span ids");
+    }
+
+    /// assures the pass context is only seen within [in_pass()] -- the enclosing one being restored after nested passes
+    #[test]
+    fn pass_context() {
+        assert_eq!(PASS_CONTEXT.get(), None, "No pass context should be set outside of a pass");
+        in_pass(1, 1000, || {
+            assert_eq!(PASS_CONTEXT.get(), Some(PassContext { pass_number: 1, set_size: 1000 }), "Wrong context for the outer pass");
+            in_pass(2, 2000, || assert_eq!(PASS_CONTEXT.get(), Some(PassContext { pass_number: 2, set_size: 2000 }), "Wrong context for the nested pass"));
+            assert_eq!(PASS_CONTEXT.get(), Some(PassContext { pass_number: 1, set_size: 1000 }), "The outer pass context should have been restored");
+        });
+        assert_eq!(PASS_CONTEXT.get(), None, "The pass context should have been cleared after the pass");
+    }
+
+    /// assures the span ids round-trip into a [SpanContext]
+    #[test]
+    fn span_context() {
+        let span_ids = PassSpanIds {
+            trace_id:    TraceId::from_bytes([1; 16]),
+            span_id:     SpanId::from_bytes([2; 8]),
+            trace_flags: TraceFlags::SAMPLED,
+        };
+        let span_context = span_ids.span_context();
+        assert!(span_context.is_valid(), "The rebuilt span context should be valid");
+        assert_eq!(span_context.trace_id(), span_ids.trace_id, "Trace id mismatch");
+        assert_eq!(span_context.span_id(),  span_ids.span_id,  "Span id mismatch");
+        assert!(span_context.is_sampled(), "Trace flags were lost");
+    }
+}
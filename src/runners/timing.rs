@@ -0,0 +1,215 @@
+//! Timing backends used by [super::common::run_iterator_pass()] & [super::common::run_sync_pass()] to measure the elapsed times.\
+//! The default backend -- [DefaultTimingBackend] -- is controlled by the crate's features:
+//!   - by default, [InstantTimingBackend] is used, relying on `std::time::Instant`;
+//!   - with `rdtsc_timing` (on x86_64), [RdtscTimingBackend] reads the CPU's time stamp counter instead, avoiding the clock call
+//!     overhead that pollutes the measurements of sub-microsecond algorithms. Ticks are converted to [Duration]s through a
+//!     calibration, done only once -- before the first measured pass.
+
+use std::time::Duration;
+use once_cell::sync::Lazy;
+
+
+/// How many back-to-back readings [estimate_resolution()] takes -- the smallest step observed among them is the resolution
+const RESOLUTION_SAMPLES: u32 = 64;
+
+/// cached result of [estimate_resolution()] for the [DefaultTimingBackend] -- see [timer_resolution()]
+static TIMER_RESOLUTION: Lazy<Duration> = Lazy::new(|| {
+    let backend = DefaultTimingBackend::default();
+    backend.calibrate();
+    estimate_resolution(&backend)
+});
+
+
+/// A source of time for measuring passes -- allows replacing the timing strategy (and mocking it)
+pub trait TimingBackend: Clone + Send + Sync {
+    /// the moment a measurement started
+    type Instant: Copy + Send;
+    /// performs any needed calibration -- called before each pass, outside the measured code: implementors should only do it once
+    fn calibrate(&self) {}
+    /// the moment a measurement starts
+    fn start(&self) -> Self::Instant;
+    /// the time elapsed since `start`
+    fn elapsed(&self, start: Self::Instant) -> Duration;
+}
+
+#[cfg(not(all(feature = "rdtsc_timing", target_arch = "x86_64")))]
+/// The timing backend used by the runners, as selected by the crate's features
+pub type DefaultTimingBackend = InstantTimingBackend;
+#[cfg(all(feature = "rdtsc_timing", target_arch = "x86_64"))]
+/// The timing backend used by the runners, as selected by the crate's features
+pub type DefaultTimingBackend = RdtscTimingBackend;
+
+
+/// Measures time through `std::time::Instant`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InstantTimingBackend;
+impl TimingBackend for InstantTimingBackend {
+    type Instant = std::time::Instant;
+
+    fn start(&self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+
+    fn elapsed(&self, start: Self::Instant) -> Duration {
+        start.elapsed()
+    }
+}
+
+/// The resolution of the [DefaultTimingBackend] -- the smallest non-zero time it is able to tell -- estimated once, on the first call.\
+/// On most platforms it is in the nanoseconds, but it may be as coarse as ~15ms on some Windows setups: measurements of a few
+/// multiples of it are quantised & can't be trusted -- see [estimate_resolution()]
+pub fn timer_resolution() -> Duration {
+    *TIMER_RESOLUTION
+}
+
+/// Estimates the resolution of `backend` by timing back-to-back readings: the smallest non-zero elapsed time among
+/// [RESOLUTION_SAMPLES] of them -- which also accounts for the cost of reading the clock.\
+/// Backends that never advance yield [Duration::ZERO].
+pub fn estimate_resolution(backend: &impl TimingBackend) -> Duration {
+    /// after how many readings without advancing the backend is considered stuck
+    const MAX_READINGS: u32 = 1 << 20;
+    (0..RESOLUTION_SAMPLES)
+        .filter_map(|_| {
+            let start = backend.start();
+            (0..MAX_READINGS)
+                .map(|_| backend.elapsed(start))
+                .find(|elapsed| !elapsed.is_zero())
+        })
+        .min()
+        .unwrap_or(Duration::ZERO)
+}
+
+
+#[cfg(all(feature = "rdtsc_timing", target_arch = "x86_64"))]
+pub use rdtsc::RdtscTimingBackend;
+
+#[cfg(all(feature = "rdtsc_timing", target_arch = "x86_64"))]
+mod rdtsc {
+    use super::*;
+    use std::arch::x86_64::_rdtsc;
+
+    /// how long the calibration busy-waits for, comparing the time stamp counter against `std::time::Instant`
+    const CALIBRATION_DURATION: Duration = Duration::from_millis(20);
+
+    /// cached result of the one-time calibration
+    static TICKS_PER_NANOSECOND: Lazy<f64> = Lazy::new(|| {
+        let start_instant = std::time::Instant::now();
+        let start_ticks = ticks();
+        while start_instant.elapsed() < CALIBRATION_DURATION {
+            std::hint::spin_loop();
+        }
+        let elapsed_ticks = ticks() - start_ticks;
+        let elapsed_nanos = start_instant.elapsed().as_nanos();
+        elapsed_ticks as f64 / elapsed_nanos as f64
+    });
+
+    fn ticks() -> u64 {
+        // SAFETY: `rdtsc` is available on all x86_64 CPUs
+        unsafe { _rdtsc() }
+    }
+
+    /// Measures time through the CPU's time stamp counter
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct RdtscTimingBackend;
+    impl TimingBackend for RdtscTimingBackend {
+        type Instant = u64;
+
+        fn calibrate(&self) {
+            Lazy::force(&TICKS_PER_NANOSECOND);
+        }
+
+        fn start(&self) -> Self::Instant {
+            ticks()
+        }
+
+        fn elapsed(&self, start: Self::Instant) -> Duration {
+            let elapsed_ticks = ticks().saturating_sub(start);
+            Duration::from_nanos((elapsed_ticks as f64 / *TICKS_PER_NANOSECOND) as u64)
+        }
+    }
+}
+
+
+/// Reports a predetermined duration for every measurement -- allowing deterministic tests of the code that depends on the measured times.\
+/// Requires the `testing` feature
+#[cfg(any(test, feature = "testing"))]
+#[derive(Clone, Copy, Debug)]
+pub struct MockTimingBackend {
+    pub elapsed: Duration,
+}
+#[cfg(any(test, feature = "testing"))]
+impl TimingBackend for MockTimingBackend {
+    type Instant = ();
+
+    fn start(&self) -> Self::Instant {}
+
+    fn elapsed(&self, _start: Self::Instant) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Reports the scripted durations, in sequence, for the successive measurements -- repeating the last one when they run out --
+/// allowing deterministic tests of whole passes, from measurement to report. Requires the `testing` feature
+#[cfg(any(test, feature = "testing"))]
+#[derive(Clone, Debug)]
+pub struct ScriptedTimingBackend {
+    durations: std::sync::Arc<[Duration]>,
+    next:      std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+#[cfg(any(test, feature = "testing"))]
+impl ScriptedTimingBackend {
+    /// `durations` are reported in sequence, the last one being repeated when they run out -- at least one must be given
+    pub fn new(durations: &[Duration]) -> Self {
+        assert!(!durations.is_empty(), "At least one duration must be scripted");
+        Self {
+            durations: durations.into(),
+            next:      Default::default(),
+        }
+    }
+}
+#[cfg(any(test, feature = "testing"))]
+impl TimingBackend for ScriptedTimingBackend {
+    type Instant = ();
+
+    fn start(&self) -> Self::Instant {}
+
+    fn elapsed(&self, _start: Self::Instant) -> Duration {
+        let next = self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.durations[next.min(self.durations.len()-1)]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [timing](super) module
+
+    use super::*;
+    use serial_test::serial;
+
+
+    /// assures the default timing backend measures elapsed times within a reasonable precision
+    #[test]
+    #[serial]
+    fn precision() {
+        const SLEEP: Duration = Duration::from_millis(50);
+        let backend = DefaultTimingBackend::default();
+        backend.calibrate();
+        let start = backend.start();
+        spin_sleep::sleep(SLEEP);
+        let measured = backend.elapsed(start);
+        assert!(measured >= SLEEP.mul_f64(0.95), "Measured {:?} for a {:?} sleep", measured, SLEEP);
+        assert!(measured <= SLEEP.mul_f64(1.5), "Measured {:?} for a {:?} sleep", measured, SLEEP);
+    }
+
+    /// checks the resolution is estimated from the smallest step of the backend -- and that stuck backends are detected
+    #[test]
+    fn resolution() {
+        let backend = ScriptedTimingBackend::new(&[Duration::ZERO, Duration::from_millis(15), Duration::ZERO, Duration::from_millis(16)]);
+        assert_eq!(estimate_resolution(&backend), Duration::from_millis(15), "The smallest non-zero step should be the resolution");
+        assert_eq!(estimate_resolution(&MockTimingBackend { elapsed: Duration::ZERO }), Duration::ZERO, "A stuck backend has no resolution");
+        let resolution = timer_resolution();
+        assert!(resolution > Duration::ZERO && resolution < Duration::from_millis(20), "Unreasonable timer resolution: {:?}", resolution);
+    }
+}
@@ -0,0 +1,110 @@
+//! Deterministic workloads for iterator passes: instead of the bare iteration index, the algorithm may be fed a value derived
+//! from it -- so the complexity can be analysed for different input distributions (sorted, reversed, shuffled or random accesses).\
+//! Values are computed on the fly, from the index alone, so workloads neither allocate nor synchronize the measuring threads.
+//! See [super::common::run_iterator_pass_with_workload()] and [crate::CrudOptions::with_workload()].
+
+use std::ops::Range;
+
+
+/// The sequence of values an iterator algorithm is fed with, for the indexes of a pass' range.\
+/// All values lie within the range; seeded workloads always yield the same sequence for the same seed & range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Workload {
+    /// the index itself -- the sorted sequence `range.start, range.start+1, ...`
+    #[default]
+    Identity,
+    /// the range, in reverse -- `range.end-1, range.end-2, ...`
+    Reverse,
+    /// a permutation of the range, determined by `seed`: every element is visited exactly once, in a scrambled order
+    Shuffled { seed: u64 },
+    /// uniformly distributed elements of the range, determined by `seed` -- repetitions may happen
+    Random { seed: u64 },
+}
+impl Workload {
+
+    /// The value to feed the algorithm with for the iteration `index`, of a pass over `range`
+    #[inline(always)]
+    pub fn value(&self, index: u32, range: &Range<u32>) -> u32 {
+        let len = range.end.saturating_sub(range.start) as u64;
+        if len <= 1 {
+            return index
+        }
+        let offset = (index - range.start) as u64;
+        let value_offset = match *self {
+            Workload::Identity         => offset,
+            Workload::Reverse          => len - 1 - offset,
+            Workload::Shuffled { seed } => permute(offset, len, seed),
+            Workload::Random { seed }   => mix(offset ^ mix(seed)) % len,
+        };
+        range.start + value_offset as u32
+    }
+
+    /// The whole sequence of values for a pass over `range`, in ascending index order
+    pub fn sequence(&self, range: Range<u32>) -> impl Iterator<Item=u32> + '_ {
+        let values_range = range.clone();
+        range.map(move |index| self.value(index, &values_range))
+    }
+}
+
+/// the `splitmix64` finalizer -- a cheap, well distributed, 64 bits hash
+#[inline(always)]
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// maps `offset` to its position in a `seed`ed permutation of `0..len` (`len` > 1), without allocating: rounds of bijective operations
+/// scramble the offset within the smallest power of 2 domain containing `len` -- repeated ("cycle walking") until the result falls in range
+#[inline(always)]
+fn permute(mut offset: u64, len: u64, seed: u64) -> u64 {
+    let bits = 64 - (len - 1).leading_zeros();
+    let mask = (1u64 << bits) - 1;
+    let shift = bits / 2 + 1;
+    let (xor_key, multiplier) = (mix(seed) & mask, mix(seed ^ 0xA5A5_A5A5_A5A5_A5A5) | 1);
+    loop {
+        for _round in 0..3 {
+            offset = (offset ^ xor_key) & mask;
+            offset = offset.wrapping_mul(multiplier) & mask;
+            offset ^= offset >> shift;
+        }
+        if offset < len {
+            return offset
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    //! Unit tests for the [workload](super) module
+
+    use super::*;
+
+
+    /// checks the sequences generated by each workload
+    #[test]
+    fn sequences() {
+        assert_eq!(Workload::default().sequence(10..15).collect::<Vec<_>>(), vec![10, 11, 12, 13, 14], "Identity should be the default workload");
+        assert_eq!(Workload::Reverse.sequence(10..15).collect::<Vec<_>>(),   vec![14, 13, 12, 11, 10], "Wrong reversed sequence");
+
+        for len in [2, 3, 1000, 1025] {
+            let range = 100..100+len;
+            let mut shuffled = Workload::Shuffled { seed: 42 }.sequence(range.clone()).collect::<Vec<_>>();
+            if len > 3 {
+                assert!(shuffled.windows(2).any(|w| w[0] > w[1]), "A shuffled sequence of {} elements should not be sorted", len);
+            }
+            shuffled.sort_unstable();
+            assert_eq!(shuffled, range.collect::<Vec<_>>(), "A shuffled sequence should be a permutation of its range");
+        }
+
+        let random = Workload::Random { seed: 42 }.sequence(0..1000).collect::<Vec<_>>();
+        assert!(random.iter().all(|&value| value < 1000), "Random values should lie within the range");
+        assert_eq!(random, Workload::Random { seed: 42 }.sequence(0..1000).collect::<Vec<_>>(), "The same seed should yield the same sequence");
+        assert_ne!(random, Workload::Random { seed: 43 }.sequence(0..1000).collect::<Vec<_>>(), "Different seeds should yield different sequences");
+        assert_ne!(Workload::Shuffled { seed: 1 }.sequence(0..1000).collect::<Vec<_>>(),
+                   Workload::Shuffled { seed: 2 }.sequence(0..1000).collect::<Vec<_>>(), "Different seeds should yield different permutations");
+    }
+}
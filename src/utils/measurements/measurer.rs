@@ -1,15 +1,17 @@
 //! Contains functionalities related to performing measurements
 
-use std::fmt::Debug;
-use std::future::Future;
-use std::marker::PhantomData;
-use std::pin::Pin;
+use std::fmt::{Debug, Display};
+#[cfg(feature = "tokio")]
+use std::{future::Future, marker::PhantomData, pin::Pin};
 use crate::BigOAlgorithmComplexity;
+#[cfg(feature = "tokio")]
+use crate::low_level_analysis::{self, types::AlgorithmPassesInfo};
 use crate::utils::measurements::presentable_measurements::PresentableMeasurement;
 
 
 /// Executes the "pre-event" steps of the measurements described by `measurements_details`.\
 /// See [CustomMeasurer] for more info.
+#[cfg(feature = "tokio")]
 pub async fn measure_all_before_event<AlgoDataType: Send + Sync + Debug>
                                      (algo_data:                 Option<&AlgoDataType>,
                                       measurements_executors:    &mut Vec<Box<dyn CustomMeasurerExecutor<AlgoDataType>>>) {
@@ -21,6 +23,7 @@ pub async fn measure_all_before_event<AlgoDataType: Send + Sync + Debug>
 
 /// Executes the "post-event" steps of the measurements described by `measurements_details`.\
 /// See [CustomMeasurer] for more info.
+#[cfg(feature = "tokio")]
 pub async fn measure_all_after_event<AlgoDataType: Send + Sync + Debug>
                                     (algo_data:                 Option<&AlgoDataType>,
                                      measurements_executors:    &mut Vec<Box<dyn CustomMeasurerExecutor<AlgoDataType>>>)
@@ -33,8 +36,36 @@ pub async fn measure_all_after_event<AlgoDataType: Send + Sync + Debug>
     measurements
 }
 
+/// Analyses the complexity of a custom measurement, from the values taken for it on each pass -- respecting its [MeasurementOrientation]
+#[cfg(feature = "tokio")]
+pub fn analyse_custom_measurement_complexity(passes_info: &AlgorithmPassesInfo,
+                                             pass_1_measurement: &CustomMeasurement,
+                                             pass_2_measurement: &CustomMeasurement)
+                                            -> BigOAlgorithmComplexity {
+    let (v1, v2) = (pass_1_measurement.measured_data.value, pass_2_measurement.measured_data.value);
+    let (u1, u2) = match pass_2_measurement.orientation {
+        MeasurementOrientation::LowerIsBetter  => (v1, v2),
+        // the cost is the reciprocal of the measured value: `(1/v2) / (1/v1) = v1 / v2`
+        MeasurementOrientation::HigherIsBetter => (v2, v1),
+    };
+    low_level_analysis::analyse_complexity(u1, u2, passes_info.pass1_n as f64, passes_info.pass2_n as f64)
+}
+
+/// Tells how a custom measurement relates to the algorithm's cost -- determining how its growth between
+/// passes is compared to the expected complexity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeasurementOrientation {
+    /// The measurement is a cost -- like time & memory: the expected complexity bounds its growth
+    #[default]
+    LowerIsBetter,
+    /// The measurement is a yield -- like throughput (ops/sec) or cache hit rates: the expected complexity
+    /// bounds the growth of its reciprocal. For instance, a throughput that halves when `n` doubles is `O(n)`
+    HigherIsBetter,
+}
+
 /// Trait describing how to execute custom measurements.
 /// This exists to allow storing different instantiations of [CustomMeasurer] in a single vector
+#[cfg(feature = "tokio")]
 pub trait CustomMeasurerExecutor<AlgoDataType: Send + Debug> {
     fn measure_before_event<'a>(&'a mut self,
                                 algo_data: Option<&'a AlgoDataType>)
@@ -48,11 +79,52 @@ pub trait CustomMeasurerExecutor<AlgoDataType: Send + Debug> {
 }
 
 /// Our domain-specific measured data -- to be used for asserting the algorithm complexity and reporting details
+#[derive(Clone, Debug)]
 pub struct CustomMeasurement {
     pub name: String,
     pub expected_complexity: BigOAlgorithmComplexity,
+    /// how [Self::measured_data] should be compared to [Self::expected_complexity]
+    pub orientation: MeasurementOrientation,
     pub description: String,
+    /// the reported measurement -- for [Self::averaged] measurements, the mean of [Self::samples]
     pub measured_data: PresentableMeasurement,
+    /// if set, [Self::merge_previous_attempt()] accumulates the measurements of all attempts, reporting their mean
+    pub averaged: bool,
+    /// the values measured on each attempt, oldest first -- only the current one if not [Self::averaged]
+    pub samples: Vec<f64>,
+}
+impl CustomMeasurement {
+
+    /// For [Self::averaged] measurements, accumulates the samples taken for the same measurement (and pass) on the previous
+    /// attempt, so the reported value becomes the mean of all attempts -- stabilising flaky measurements across reattempts.
+    /// Does nothing for regular measurements.
+    pub fn merge_previous_attempt(&mut self, previous_attempt: &CustomMeasurement) {
+        if !self.averaged {
+            return;
+        }
+        self.samples.splice(0..0, previous_attempt.samples.iter().copied());
+        self.measured_data = self.measured_data.with_value(self.mean());
+    }
+
+    /// the mean of all [Self::samples]
+    pub fn mean(&self) -> f64 {
+        self.samples.iter().sum::<f64>() / self.samples.len().max(1) as f64
+    }
+
+    /// the (population) standard deviation of all [Self::samples]
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        (self.samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / self.samples.len().max(1) as f64).sqrt()
+    }
+}
+impl Display for CustomMeasurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.averaged {
+            write!(f, "{} = {} (mean of {} attempts; σ={})", self.name, self.measured_data, self.samples.len(), self.measured_data.with_value(self.std_dev()))
+        } else {
+            write!(f, "{} = {}", self.name, self.measured_data)
+        }
+    }
 }
 
 /// Contains the definitions for a measurer that is performed
@@ -60,6 +132,7 @@ pub struct CustomMeasurement {
 /// Measurements are done in 2 steps:
 /// 1) A "pre-event" closure is executed to collect information. It may return any type;
 /// 2) The second, "post-event" closure receives the returned value from the above and, finally, yields a [PresentableMeasurement]
+#[cfg(feature = "tokio")]
 pub struct CustomMeasurer<BeforeMeasurerOutput:                                                             Send,
                           BeforeFut:       Future<Output=BeforeMeasurerOutput>                            + Send,
                           MeasureBeforeFn: FnMut(Option<&AlgoDataType>) -> BeforeFut                      + Send + Sync,
@@ -68,13 +141,18 @@ pub struct CustomMeasurer<BeforeMeasurerOutput:
                           AlgoDataType:                                                                     Send + Debug> {
     name: String,
     expected_complexity: BigOAlgorithmComplexity,
+    /// see [CustomMeasurement::orientation]
+    orientation: MeasurementOrientation,
     description: String,
+    /// see [CustomMeasurement::averaged]
+    averaged: bool,
     before_event_measurer_fn: MeasureBeforeFn,
     before_event_measurement: Option<BeforeMeasurerOutput>,
     after_event_measurer_fn:  MeasureAfterFn,
     _phantom: PhantomData<(BeforeFut, AfterFut, AlgoDataType)>,
 }
 
+#[cfg(feature = "tokio")]
 impl<BeforeMeasurerOutput:                                                             Send,
      BeforeFut:       Future<Output=BeforeMeasurerOutput>                            + Send,
      MeasureBeforeFn: FnMut(Option<&AlgoDataType>) -> BeforeFut                      + Send + Sync,
@@ -89,6 +167,7 @@ CustomMeasurer<BeforeMeasurerOutput,
                AlgoDataType> {
     pub fn new(name: impl Into<String>,
                expected_complexity: BigOAlgorithmComplexity,
+               orientation: MeasurementOrientation,
                description: impl Into<String>,
                before_event_measurer_fn: MeasureBeforeFn,
                after_event_measurer_fn: MeasureAfterFn)
@@ -96,15 +175,33 @@ CustomMeasurer<BeforeMeasurerOutput,
         Self {
             name: name.into(),
             expected_complexity,
+            orientation,
             description: description.into(),
+            averaged: false,
             before_event_measurer_fn,
             before_event_measurement: None,
             after_event_measurer_fn,
             _phantom: Default::default(),
         }
     }
+
+    /// Same as [Self::new()], but producing [CustomMeasurement::averaged] measurements: reporting the mean of the values
+    /// measured on all attempts, rather than only the value of the current one
+    pub fn new_with_averages(name: impl Into<String>,
+                             expected_complexity: BigOAlgorithmComplexity,
+                             orientation: MeasurementOrientation,
+                             description: impl Into<String>,
+                             before_event_measurer_fn: MeasureBeforeFn,
+                             after_event_measurer_fn: MeasureAfterFn)
+                            -> Self {
+        Self {
+            averaged: true,
+            ..Self::new(name, expected_complexity, orientation, description, before_event_measurer_fn, after_event_measurer_fn)
+        }
+    }
 }
 
+#[cfg(feature = "tokio")]
 impl<BeforeMeasurerOutput:                                                            Send,
     BeforeFut:       Future<Output=BeforeMeasurerOutput>                            + Send,
     MeasureBeforeFn: FnMut(Option<&AlgoDataType>) -> BeforeFut                      + Send + Sync,
@@ -145,14 +242,17 @@ CustomMeasurer<BeforeMeasurerOutput,
         CustomMeasurement {
             name: self.name.clone(),
             expected_complexity: self.expected_complexity,
+            orientation: self.orientation,
             description: self.description.clone(),
+            averaged: self.averaged,
+            samples: vec![after_event_measurement.value],
             measured_data: after_event_measurement,
         }
     }
 }
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "tokio"))]
 mod tests {
     use super::*;
     use crate::utils::measurements;
@@ -166,10 +266,61 @@ mod tests {
         let tolerance = 1e-2;
         let before_event_measurer = |_: Option<&()>| future::ready(Instant::now());
         let after_event_measurer = |_: Option<&()>, instant: Instant| future::ready(measurements::presentable_measurements::duration_measurement(instant.elapsed().add(Duration::from_secs_f64(expected_elapsed_seconds))));
-        let mut custom_measurer = CustomMeasurer::new("t", BigOAlgorithmComplexity::BetterThanO1, "t descr", before_event_measurer, after_event_measurer);
+        let mut custom_measurer = CustomMeasurer::new("t", BigOAlgorithmComplexity::BetterThanO1, MeasurementOrientation::LowerIsBetter, "t descr", before_event_measurer, after_event_measurer);
         custom_measurer.measure_before_event(None.as_ref()).await;
         let measurement_data = custom_measurer.measure_after_event(None).await;
         assert!(measurement_data.to_string().ends_with("s"), "This doesn't look like a duration measurement");
         assert!((measurement_data.value - expected_elapsed_seconds).abs() <= tolerance, "We expect a measurement of ~{expected_elapsed_seconds:.2} seconds; got {:.2} seconds", measurement_data.value);
     }
-}
\ No newline at end of file
+
+    /// assures averaged measurements accumulate the samples of all attempts, while regular ones only report the last attempt
+    #[test]
+    fn averaged_measurements() {
+        let measurement = |averaged, value| {
+            let before_event_measurer = |_: Option<&()>| future::ready(());
+            let after_event_measurer = |_: Option<&()>, _| future::ready(PresentableMeasurement::default());
+            let measurer = if averaged {
+                CustomMeasurer::new_with_averages("t", BigOAlgorithmComplexity::O1, MeasurementOrientation::LowerIsBetter, "t descr", before_event_measurer, after_event_measurer)
+            } else {
+                CustomMeasurer::new("t", BigOAlgorithmComplexity::O1, MeasurementOrientation::LowerIsBetter, "t descr", before_event_measurer, after_event_measurer)
+            };
+            measurer.as_custom_measurement(measurements::presentable_measurements::bytes_measurement(value))
+        };
+
+        let mut averaged = measurement(true, 10.0);
+        for value in [20.0, 30.0] {
+            let mut next_attempt = measurement(true, value);
+            next_attempt.merge_previous_attempt(&averaged);
+            averaged = next_attempt;
+        }
+        assert_eq!(averaged.samples, vec![10.0, 20.0, 30.0], "Samples of all attempts should be kept, oldest first");
+        assert_eq!(averaged.measured_data.value, 20.0, "The mean should be reported");
+        assert!((averaged.std_dev() - 8.165).abs() < 1e-3, "Wrong standard deviation: {}", averaged.std_dev());
+        assert_eq!(averaged.to_string(), "t = 20b (mean of 3 attempts; σ=8b)", "Wrong presentation");
+
+        let mut regular = measurement(false, 30.0);
+        regular.merge_previous_attempt(&measurement(false, 10.0));
+        assert_eq!(regular.samples, vec![30.0], "Regular measurements shouldn't keep previous attempts");
+        assert_eq!(regular.measured_data.value, 30.0, "Regular measurements should report the last attempt");
+    }
+
+    /// assures "higher is better" measurements have their growth inverted when analysed -- while "lower is better" ones don't
+    #[test]
+    fn inverted_measurements() {
+        let passes_info = AlgorithmPassesInfo { pass1_n: 1000, pass2_n: 2000 };
+        let measurement = |orientation, bytes_per_second| {
+            let measurer = CustomMeasurer::new("throughput", BigOAlgorithmComplexity::ON, orientation, "bytes/sec",
+                                               |_: Option<&()>| future::ready(()),
+                                               |_: Option<&()>, _| future::ready(PresentableMeasurement::default()));
+            measurer.as_custom_measurement(measurements::presentable_measurements::bytes_per_second_measurement(bytes_per_second))
+        };
+        let complexity = |orientation, pass_1_bytes_per_second, pass_2_bytes_per_second| analyse_custom_measurement_complexity(&passes_info,
+                                                                                                                        &measurement(orientation, pass_1_bytes_per_second),
+                                                                                                                        &measurement(orientation, pass_2_bytes_per_second));
+        assert_eq!(complexity(MeasurementOrientation::HigherIsBetter, 1000.0, 500.0),  BigOAlgorithmComplexity::ON,           "A throughput halving when `n` doubles means a linear cost");
+        assert_eq!(complexity(MeasurementOrientation::HigherIsBetter, 1000.0, 1000.0), BigOAlgorithmComplexity::O1,           "A steady throughput means a constant cost");
+        assert_eq!(complexity(MeasurementOrientation::HigherIsBetter, 1000.0, 2000.0), BigOAlgorithmComplexity::BetterThanO1, "A growing throughput means a decreasing cost");
+        assert_eq!(complexity(MeasurementOrientation::LowerIsBetter,  1000.0, 500.0),  BigOAlgorithmComplexity::BetterThanO1, "Costs should not be inverted");
+        assert_eq!(complexity(MeasurementOrientation::LowerIsBetter,  1000.0, 2000.0), BigOAlgorithmComplexity::ON,           "Costs should not be inverted");
+    }
+}
@@ -1,16 +1,22 @@
 //! Resting place for [PresentableMeasurement]
 
 use std::borrow::Cow;
+#[cfg(test)]
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::time::Duration;
+#[cfg(test)]
 use once_cell::sync::Lazy;
 
+/// := (threshold, scale, unit, format)
+type AutoScale = (f64, f64, Cow<'static, str>, &'static str);
+
 /// Holds and present custom unit measurements with auto-scaling
+#[derive(Clone, Debug)]
 pub struct PresentableMeasurement {
     pub(crate) value: f64,
     /// := (threshold, scale, unit, format)
-    auto_scale: &'static [(f64, f64, Cow<'static, str>, &'static str)],
+    auto_scale: &'static [AutoScale],
 }
 impl Default for PresentableMeasurement {
     fn default() -> Self {
@@ -21,6 +27,16 @@ impl Default for PresentableMeasurement {
     }
 }
 
+impl PresentableMeasurement {
+    /// another measurement of the same quantity -- presented with the same units
+    pub fn with_value(&self, value: f64) -> Self {
+        Self {
+            value,
+            auto_scale: self.auto_scale,
+        }
+    }
+}
+
 impl Display for PresentableMeasurement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let (scaled_value, suffix, format) = self.auto_scale.iter()
@@ -42,8 +58,9 @@ impl Display for PresentableMeasurement {
 
 /// Builds a [PresentableMeasurement] able to display & auto-scale
 /// quantities representing "a duration".
+#[cfg(test)]
 pub fn duration_measurement(duration: Duration) -> PresentableMeasurement {
-    const AUTO_SCALE_DATA: &[(f64, f64, Cow<'static, str>, &'static str)] = &[
+    const AUTO_SCALE_DATA: &[(f64, f64, Cow<'static, str>, &str)] = &[
         (0.0, 1.0, Cow::Borrowed(""), ":D"),
     ];
     
@@ -55,8 +72,9 @@ pub fn duration_measurement(duration: Duration) -> PresentableMeasurement {
 
 /// Builds a [PresentableMeasurement] able to display & auto-scale
 /// quantities representing "a number of bytes".
+#[cfg(test)]
 pub fn bytes_measurement(value: f64) -> PresentableMeasurement {
-    static AUTO_SCALE_DATA: Lazy<Vec<(f64, f64, Cow<'static, str>, &'static str)>> = Lazy::new(|| {
+    static AUTO_SCALE_DATA: Lazy<Vec<AutoScale>> = Lazy::new(|| {
         [
             ((1u64<<40) as f64, "TiB", ":.2"),
             ((1u64<<30) as f64, "GiB", ":.2"),
@@ -83,8 +101,9 @@ pub fn bytes_measurement(value: f64) -> PresentableMeasurement {
 
 /// Builds a [PresentableMeasurement] able to display & auto-scale
 /// quantities representing "a rate of bytes per second".
+#[cfg(test)]
 pub fn bytes_per_second_measurement(value: f64) -> PresentableMeasurement {
-    static AUTO_SCALE_DATA: Lazy<Vec<(f64, f64, Cow<'static, str>, &'static str)>> = Lazy::new(|| {
+    static AUTO_SCALE_DATA: Lazy<Vec<AutoScale>> = Lazy::new(|| {
         [
             ((1u64<<40) as f64, "TiB/s", ":.2"),
             ((1u64<<30) as f64, "GiB/s", ":.2"),
@@ -113,9 +132,10 @@ pub fn bytes_per_second_measurement(value: f64) -> PresentableMeasurement {
 
 /// Builds a [PresentableMeasurement] able to display & auto-scale
 /// quantities representing "a quantity of `custom_unit`".
+#[cfg(test)]
 pub fn custom_unit_measurement(value: f64, custom_unit: &'static str) -> PresentableMeasurement {
 
-    static mut AUTO_SCALE_DATA: Option<HashMap<&str, Vec<(f64, f64, Cow<'static, str>, &'static str)>>> = None;
+    static mut AUTO_SCALE_DATA: Option<HashMap<&str, Vec<AutoScale>>> = None;
 
     // non-synchronized one-time cache
     let auto_scale_data = unsafe {
@@ -146,9 +166,10 @@ pub fn custom_unit_measurement(value: f64, custom_unit: &'static str) -> Present
 
 /// Builds a [PresentableMeasurement] able to display & auto-scale
 /// quantities representing "a rate of `custom_unit` quantities per second".
+#[cfg(test)]
 fn custom_unit_per_second_measurement(value: f64, custom_unit: &'static str) -> PresentableMeasurement {
 
-    static mut AUTO_SCALE_DATA: Option<HashMap<&str, Vec<(f64, f64, Cow<'static, str>, &'static str)>>> = None;
+    static mut AUTO_SCALE_DATA: Option<HashMap<&str, Vec<AutoScale>>> = None;
 
     // non-synchronized cache per `custom_unit`
     let auto_scale_data = unsafe {
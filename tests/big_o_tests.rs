@@ -1,4 +1,6 @@
 //! Applies the Big-O crate to some Rust's std lib containers
+//! -- through the runners, which require the `std` feature
+#![cfg(feature = "std")]
 
 use big_o_test::*;
 use std::{
@@ -7,6 +9,7 @@ use std::{
 };
 use std::time::Duration;
 use ctor::ctor;
+#[cfg(feature = "tokio")]
 use big_o_test::RegularAsyncAnalyzerBuilder;
 
 #[cfg(debug_assertions)]
@@ -43,12 +46,12 @@ fn quick_sort_reversed_vec() {
                 vec2.push(i);
             }
         },
-        VEC1_LEN, || {
+        VEC1_LEN.into(), || {
             let mut vec1 = vec1.write();
             vec1.sort();
             vec1[12]
         },
-        VEC2_LEN, || {
+        VEC2_LEN.into(), || {
             let mut vec2 = vec2.write();
             vec2.sort();
             vec2[14]
@@ -57,6 +60,296 @@ fn quick_sort_reversed_vec() {
     )
 }
 
+/// Attests [test_algorithm_with_setup_teardown()] gives each pass its own container: pushes into a freshly pre-allocated `Vec`
+/// are O(n) in time and, as the allocation happens in the untimed setup, O(1) in space
+#[test]
+fn push_into_fresh_vec_per_pass() {
+    const PASS1_N: u64 = 1 << 22;
+    const PASS2_N: u64 = 1 << 23;
+    let vec = std::cell::RefCell::new(Vec::<u64>::new());
+    test_algorithm_with_setup_teardown(
+        "Push into a fresh pre-allocated Vec", 15,
+        |n| *vec.borrow_mut() = Vec::with_capacity(n as usize),
+        |n| {
+            let mut vec = vec.borrow_mut();
+            for i in 0..n {
+                vec.push(i);
+            }
+            vec.len() as u32
+        },
+        |_n| *vec.borrow_mut() = Vec::new(),
+        PASS1_N, PASS2_N,
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+    );
+    assert!(vec.borrow().is_empty(), "The teardown should have run after the last pass");
+}
+
+/// Attests [test_algorithm_threaded()] measures each pass with its own thread count:
+/// the per-element work is constant, so the second pass -- with twice the elements and twice the threads -- should be no worse than O(n).\
+/// Space is O(n) here as the bookkeeping allocations for spawning the threads grow with their count.
+#[test]
+fn threaded_atomic_sum() {
+    const PASS1_N: u32 = 1_000_000;
+    const PASS2_N: u32 = 2_000_000;
+    let sum = std::sync::atomic::AtomicU32::new(0);
+    test_algorithm_threaded(
+        "Threaded atomic sum", 15,
+        || sum.store(0, std::sync::atomic::Ordering::Relaxed),
+        PASS1_N, 1,
+        PASS2_N, 2,
+        |n| sum.fetch_add(n, std::sync::atomic::Ordering::Relaxed),
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::ON,
+    )
+}
+
+/// Attests [AlgorithmOptions::with_timeout()] aborts -- without retrying -- a threaded pass way slower than expected, telling how far it got
+#[test]
+#[should_panic(expected = "pass timed out after 50ms, having completed ")]
+fn threaded_pass_timeout() {
+    test_algorithm_threaded_with_options(
+        "Sleepy sum", 15,
+        || (),
+        1000, 2,
+        2000, 2,
+        |n| { std::thread::sleep(Duration::from_millis(10)); n },
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::WorseThanExponential,
+        AlgorithmOptions::default().with_timeout(Duration::from_millis(50)),
+    )
+}
+
+/// Attests [AlgorithmOptions::with_timeout()] also aborts single-call passes -- which can't be interrupted, so they are caught once they return
+#[test]
+#[should_panic(expected = "pass timed out after 50ms, having run for ")]
+fn single_call_pass_timeout() {
+    test_algorithm_growing_n_with_options(
+        "Sleepy growing n", 15,
+        |n| { std::thread::sleep(Duration::from_millis(n as u64)); n },
+        100, 200,
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::WorseThanExponential,
+        AlgorithmOptions::default().with_timeout(Duration::from_millis(50)),
+    )
+}
+
+/// Attests [test_algorithm_contextual()] hands the context built by the reset to every call -- without any `Mutex`:
+/// each lookup on a freshly populated `HashMap` is constant, so the pass with twice the lookups should be no worse than O(n) -- while,
+/// as nothing is allocated, space is O(1).
+#[test]
+fn hashmap_lookups_on_a_fresh_context() {
+    const PASS1_N: u32 = 1 << 16;
+    const PASS2_N: u32 = 1 << 17;
+    let mut resets = 0;
+    test_algorithm_contextual(
+        "HashMap lookups on a fresh context", 15,
+        || {
+            resets += 1;
+            (0..PASS2_N).map(|n| (n, n ^ resets)).collect::<HashMap<u32, u32>>()
+        },
+        |n, map| map.get(&n).copied().unwrap_or_default(),
+        PASS1_N, PASS2_N, 1,
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+    );
+    assert!(resets >= 1, "The context should have been built for every attempt");
+}
+
+/// Attests [test_algorithm_growing_n()] analyses recursive algorithms on a single call per pass: a divide & conquer sum
+/// visits each element once -- O(n) in time -- and, recursing only as deep as log(n), allocates nothing
+#[test]
+fn recursive_sum_on_growing_n() {
+    fn recursive_sum(from: u32, to: u32) -> u32 {
+        if to - from <= 1 {
+            from
+        } else {
+            let middle = from + (to - from) / 2;
+            recursive_sum(from, middle).wrapping_add(recursive_sum(middle, to))
+        }
+    }
+    test_algorithm_growing_n(
+        "Recursive sum", 15,
+        |n| recursive_sum(0, n),
+        1_000_000 * LOOP_MULTIPLIER, 2_000_000 * LOOP_MULTIPLIER,
+        BigOAlgorithmComplexity::ON, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_algorithm_no_space()] analyses allocation-free algorithms on their time alone: counting bits is constant
+/// per element, so the pass with twice the elements should be no worse than O(n)
+#[test]
+fn allocation_free_bit_counting() {
+    const PASS1_N: u32 = 1_000_000;
+    const PASS2_N: u32 = 2_000_000;
+    test_algorithm_no_space(
+        "Bit counting", 15,
+        |n| n.count_ones() ^ n.leading_zeros(),
+        PASS1_N, PASS2_N,
+        BigOAlgorithmComplexity::ON,
+    )
+}
+
+/// Attests [test_algorithm_space_only()] judges precomputation tables on their footprint alone: a table of squares takes O(n) space
+#[test]
+fn precomputed_squares_table() {
+    let table = std::cell::RefCell::new(Vec::<u64>::new());
+    test_algorithm_space_only(
+        "Precomputed squares table",
+        |n| {
+            *table.borrow_mut() = (0..n).map(|i| i * i).collect();
+            table.borrow().len() as u32
+        },
+        1 << 16, 1 << 17,
+        BigOAlgorithmComplexity::ON,
+    )
+}
+
+/// Attests [analyse_parallel_scaling()] runs the same iterations for every thread count, reporting their speedup.\
+/// No speedup is asserted, as the tests may run on a single core.
+#[test]
+fn parallel_scaling_analysis() {
+    let scaling = analyse_parallel_scaling("Parallel hashing", |n| (0..64).fold(n, |h, i| h.rotate_left(5) ^ i), 1_000_000, &[1, 2, 4]);
+    assert_eq!(scaling.iter().map(|&(threads, _, _)| threads).collect::<Vec<_>>(), vec![1, 2, 4], "Every thread count should be reported, in order");
+    assert_eq!(scaling[0].1, BigOAlgorithmComplexity::O1, "The first thread count is the reference for the others");
+    assert!(scaling.iter().all(|&(_, _, elapsed)| elapsed > Duration::ZERO), "Every thread count should have been measured");
+    let speedup = SpeedupAnalysis::from_scaling(&scaling);
+    assert_eq!(speedup.ideal, 4.0, "The ideal speedup is the ratio between the thread counts");
+    assert_eq!(speedup.efficiency(), speedup.observed / 4.0, "Wrong efficiency");
+}
+
+/// Attests a user provided [TimingBackend] is used to measure the passes -- see [try_run_iterator_pass_with_backend()]
+#[test]
+fn custom_timing_backend() {
+    /// reports every measurement as taking 7ms
+    #[derive(Clone)]
+    struct FixedTimingBackend;
+    impl TimingBackend for FixedTimingBackend {
+        type Instant = ();
+        fn start(&self) -> Self::Instant {}
+        fn elapsed(&self, _start: Self::Instant) -> Duration {
+            Duration::from_millis(7)
+        }
+    }
+    let (pass_result, _r) = try_run_iterator_pass_with_backend(&|n| n, &big_o_test::low_level_analysis::types::BigOIteratorAlgorithmType::ConstantSet,
+                                                               0..100, IterationOrder::Ascending, 2, &[], Aggregation::Mean, None, &FixedTimingBackend)
+        .expect("The pass shouldn't have been aborted");
+    assert_eq!(pass_result.time_measurements, Duration::from_millis(7), "The custom backend's times should have been reported");
+    assert_eq!(pass_result.summed_threads_time, Duration::from_millis(14), "Each thread should have been measured by the custom backend");
+}
+
+/// Attests the exported [ScriptedTimingBackend] drives deterministic passes -- one scripted duration per thread measurement
+#[test]
+#[cfg(feature = "testing")]
+fn scripted_timing_backend() {
+    let backend = ScriptedTimingBackend::new(&[Duration::from_millis(10), Duration::from_millis(30)]);
+    let (pass_result, _r) = run_iterator_pass_with_backend(&|n| n, &big_o_test::low_level_analysis::types::BigOIteratorAlgorithmType::ConstantSet,
+                                                           0..100, IterationOrder::Ascending, 1, &[], &backend);
+    assert_eq!(pass_result.time_measurements, Duration::from_millis(10), "The first scripted duration should have been reported");
+    let (pass_result, _r) = run_iterator_pass_with_backend(&|n| n, &big_o_test::low_level_analysis::types::BigOIteratorAlgorithmType::ConstantSet,
+                                                           0..100, IterationOrder::Ascending, 1, &[], &MockTimingBackend { elapsed: Duration::from_millis(5) });
+    assert_eq!(pass_result.time_measurements, Duration::from_millis(5), "The mocked duration should have been reported");
+}
+
+/// Attests [test_set_resizing_iterator_algorithm()] over the three segments of a pre-allocated `Vec`: pushes are O(1) in time
+/// and, as no reallocations take place, also in space
+#[test]
+fn preallocated_vec_push_segments() {
+    const DELTA_SET_SIZE: u32 = 1 << 20;
+    let vec = parking_lot::Mutex::new(Vec::<u32>::new());
+    test_set_resizing_iterator_algorithm(
+        "Pre-allocated Vec push", 15,
+        || *vec.lock() = Vec::with_capacity(3 * DELTA_SET_SIZE as usize),
+        |n| { vec.lock().push(n); n },
+        DELTA_SET_SIZE, 1,
+        BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_set_resizing_iterator_algorithm_with_verification()] lets correct operations through: each push grows the `Vec` by one.\
+/// Like in [preallocated_vec_push_segments()], the space is also asserted: the verification must not allocate
+#[test]
+fn verified_vec_push_segments() {
+    const DELTA_SET_SIZE: u32 = 1 << 20;
+    let vec = parking_lot::Mutex::new(Vec::<u32>::new());
+    test_set_resizing_iterator_algorithm_with_verification(
+        "Verified pre-allocated Vec push", 15,
+        || *vec.lock() = Vec::with_capacity(3 * DELTA_SET_SIZE as usize),
+        |n| { let mut vec = vec.lock(); vec.push(n); vec.len() as u32 },
+        |n, len| len == n + 1,
+        DELTA_SET_SIZE, 1,
+        BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_set_resizing_iterator_algorithm_with_verification()] catches degenerate implementations -- which would otherwise pass as `O(1)`
+#[test]
+#[should_panic(expected = "Correctness check failed at iteration 0: expected true")]
+fn degenerate_push_is_caught() {
+    test_set_resizing_iterator_algorithm_with_verification(
+        "No-op push", 0,
+        || (),
+        |_n| 0,
+        |n, len| len == n + 1,
+        1024, 1,
+        BigOAlgorithmComplexity::O1, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_constant_set_iterator_algorithm_with_context()] shares the context with all calls:
+/// binary searches on a sorted vec -- the first pass searching only its first half.
+#[test]
+fn binary_search_with_context() {
+    const PASS1_SET_SIZE: u32 = 1 << 20;
+    const PASS2_SET_SIZE: u32 = 1 << 21;
+    const REPETITIONS: u32 = 1 << 18;
+    let sorted_vec = (0..PASS2_SET_SIZE).collect::<Vec<u32>>();
+    test_constant_set_iterator_algorithm_with_context(
+        "Binary search on a shared sorted Vec", 15,
+        &sorted_vec,
+        |n, sorted_vec| {
+            let set_size = if n < PASS1_SET_SIZE { PASS1_SET_SIZE } else { PASS2_SET_SIZE };
+            sorted_vec[..set_size as usize].binary_search(&n).unwrap_or_default() as u32
+        },
+        PASS1_SET_SIZE, PASS2_SET_SIZE, REPETITIONS, 1,
+        BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_sort_algorithm()] on the std's unstable sort -- `O(n*log(n))` in time, in-place for space
+#[test]
+fn sort_unstable_random_vec() {
+    use rand::Rng;
+    test_sort_algorithm(
+        "sort_unstable() on a random vec", 15,
+        |data: &mut [u32]| data.sort_unstable(),
+        |n| { let mut rng = rand::thread_rng(); (0..n).map(|_| rng.gen()).collect() },
+        1 << 20,
+        BigOAlgorithmComplexity::ONLogN, BigOAlgorithmComplexity::O1,
+    )
+}
+
+/// Attests [test_sort_algorithm()] refuses algorithms that don't really sort
+#[test]
+#[should_panic(expected = "`sort_fn` didn't sort the")]
+fn sort_verification() {
+    test_sort_algorithm(
+        "A 'sort' that only reverses", 0,
+        |data: &mut [u32]| data.reverse(),
+        |n| (0..n as u32).collect(),
+        1 << 10,
+        BigOAlgorithmComplexity::WorseThanExponential, BigOAlgorithmComplexity::WorseThanExponential,
+    )
+}
+/// Attests [test_search_algorithm()] searches each pass' own set -- binary searches on sorted vecs, told apart by the searched keys
+#[test]
+fn binary_search_on_sorted_vecs() {
+    const PASS_1_N: u32 = 1 << 20;
+    const PASS_2_N: u32 = 1 << 21;
+    let sorted_vecs = ((0..PASS_1_N).collect::<Vec<u32>>(), (0..PASS_2_N).collect::<Vec<u32>>());
+    test_search_algorithm(
+        "Binary search on sorted Vecs", 15,
+        &sorted_vecs,
+        |key, (pass_1_vec, pass_2_vec)| if key < PASS_1_N { pass_1_vec } else { pass_2_vec }.binary_search(&key),
+        PASS_1_N, PASS_2_N,
+        BigOAlgorithmComplexity::OLogN, BigOAlgorithmComplexity::O1,
+    )
+}
 
 /// Attests the best case CRUD for vectors -- Create, Read, Update and Delete... all O(1):
 ///   - inserts at the end (push)
@@ -178,6 +471,7 @@ fn hashmap_algorithm_analysis() {
            n_threads, n_threads, n_threads, n_threads);
 }
 
+#[cfg(feature = "tokio")]
 #[tokio::test]
 async fn dummy_async_test() {
     RegularAsyncAnalyzerBuilder::new("dummy analysis")